@@ -0,0 +1,149 @@
+use std::{io::Read, path::{Path, PathBuf}};
+
+use anyhow::bail;
+use formatter::{FormatConfig, FormatOptions, Preset};
+use parser::Parser;
+
+const CONFIG_FILES: &[&str] = &["sqlfmt.toml", ".sqlfmt.toml", "sqlfmt.json", ".sqlfmt.json"];
+
+struct Args {
+    config: Option<PathBuf>,
+    preset: Option<Preset>,
+    check: bool,
+    files: Vec<PathBuf>,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+enum Outcome {
+    Formatted(String),
+    // `--check` found nothing to change
+    Unchanged,
+    // `--check` found differences
+    NotFormatted,
+}
+
+pub fn main() -> Result<(), anyhow::Error> {
+    let args = parse_args(std::env::args().skip(1))?;
+
+    let mut config = match args.config.as_ref() {
+        Some(path) => FormatConfig::load(path)?,
+        None => find_config()?.unwrap_or_default(),
+    };
+    if args.preset.is_some() {
+        config.preset = args.preset;
+    }
+    let options = config.options();
+    let parser = Parser::new();
+
+    if args.files.is_empty() {
+        let mut source = String::new();
+        std::io::stdin().read_to_string(&mut source)?;
+
+        match run(&parser, source, &options, args.check)? {
+            Outcome::Formatted(formatted) => print!("{formatted}"),
+            Outcome::Unchanged => {}
+            Outcome::NotFormatted => {
+                eprintln!("<stdin>: not formatted");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    let mut unformatted = 0;
+
+    for path in &args.files {
+        let source = std::fs::read_to_string(path)?;
+
+        match run(&parser, source, &options, args.check)? {
+            Outcome::Formatted(formatted) => print!("{formatted}"),
+            Outcome::Unchanged => {}
+            Outcome::NotFormatted => {
+                eprintln!("{}: not formatted", path.display());
+                unformatted += 1;
+            }
+        }
+    }
+
+    if unformatted > 0 {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn run(parser: &Parser, source: String, options: &FormatOptions, check: bool) -> Result<Outcome, anyhow::Error> {
+    let formatted = formatter::format(&parser.parse(source.clone())?, options);
+
+    match check {
+        true if formatted != source => Ok(Outcome::NotFormatted),
+        true => Ok(Outcome::Unchanged),
+        false => Ok(Outcome::Formatted(formatted)),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, anyhow::Error> {
+    let mut result = Args { config: None, preset: None, check: false, files: vec![] };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => result.check = true,
+            "--config" => {
+                let Some(path) = args.next() else { bail!("Missing value for --config") };
+                result.config = Some(PathBuf::from(path));
+            }
+            "--preset" => {
+                let Some(name) = args.next() else { bail!("Missing value for --preset") };
+                result.preset = Some(name.parse()?);
+            }
+            _ if arg.starts_with("--") => bail!("Unknown option: {arg}"),
+            _ => result.files.push(PathBuf::from(arg)),
+        }
+    }
+
+    Ok(result)
+}
+
+fn find_config() -> Result<Option<FormatConfig>, anyhow::Error> {
+    for name in CONFIG_FILES {
+        let path = Path::new(name);
+        if path.exists() {
+            return Ok(Some(FormatConfig::load(path)?));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod sqlfmt_tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Result<Args, anyhow::Error> {
+        parse_args(values.iter().map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() -> Result<(), anyhow::Error> {
+        let result = args(&["--check", "--preset", "compact", "a.sql", "b.sql"])?;
+        assert!(result.check);
+        assert_eq!(Some(Preset::Compact), result.preset);
+        assert_eq!(vec![PathBuf::from("a.sql"), PathBuf::from("b.sql")], result.files);
+
+        assert!(args(&["--config"]).is_err());
+        assert!(args(&["--preset", "unknown"]).is_err());
+        assert!(args(&["--fix"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check() -> Result<(), anyhow::Error> {
+        let parser = Parser::new();
+        let options = FormatOptions::default();
+
+        assert_eq!(Outcome::Unchanged, run(&parser, "SELECT a, b\nFROM foo;\n".into(), &options, true)?);
+        assert_eq!(Outcome::NotFormatted, run(&parser, "SELECT a,b FROM foo;".into(), &options, true)?);
+        assert_eq!(Outcome::Formatted("SELECT a, b\nFROM foo;\n".into()), run(&parser, "SELECT a,b FROM foo;".into(), &options, false)?);
+        Ok(())
+    }
+}
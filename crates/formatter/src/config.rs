@@ -0,0 +1,104 @@
+use std::path::Path;
+
+use anyhow::bail;
+
+use crate::{CommaPlacement, FormatOptions, IdentifierQuote, JoinLayout, KeywordCase, OnLayout};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Preset {
+    Standard,
+    Compact,
+    LeadingComma,
+}
+
+impl Preset {
+    pub fn options(&self) -> FormatOptions {
+        match self {
+            Preset::Standard => FormatOptions::default(),
+            Preset::Compact => FormatOptions {
+                indent_width: 2,
+                line_width: 120,
+                keyword_case: KeywordCase::Upper,
+                identifier_quote: IdentifierQuote::Minimal,
+                comma_placement: CommaPlacement::Trailing,
+                join_layout: JoinLayout::Inline,
+                on_layout: OnLayout::SameLine,
+                keep_blank_lines: false,
+                align_columns: false,
+            },
+            Preset::LeadingComma => FormatOptions {
+                comma_placement: CommaPlacement::Leading,
+                on_layout: OnLayout::NewLine,
+                keep_blank_lines: true,
+                ..FormatOptions::default()
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for Preset {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "standard" => Ok(Preset::Standard),
+            "compact" => Ok(Preset::Compact),
+            "leading-comma" => Ok(Preset::LeadingComma),
+            _ => bail!("Unknown preset (name: {s})"),
+        }
+    }
+}
+
+// Formatter configuration file (TOML or JSON).
+// Unspecified entries fall back to the preset.
+#[derive(Default, Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct FormatConfig {
+    pub preset: Option<Preset>,
+    pub indent_width: Option<usize>,
+    pub line_width: Option<usize>,
+    pub keyword_case: Option<KeywordCase>,
+    pub identifier_quote: Option<IdentifierQuote>,
+    pub comma_placement: Option<CommaPlacement>,
+    pub join_layout: Option<JoinLayout>,
+    pub on_layout: Option<OnLayout>,
+    pub keep_blank_lines: Option<bool>,
+    pub align_columns: Option<bool>,
+}
+
+impl FormatConfig {
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        let content = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            Some("json") => Self::from_json(&content),
+            _ => bail!("Unsupported config file (path: {})", path.display()),
+        }
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, anyhow::Error> {
+        Ok(toml::from_str(content)?)
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, anyhow::Error> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    pub fn options(&self) -> FormatOptions {
+        let base = self.preset.unwrap_or(Preset::Standard).options();
+
+        FormatOptions {
+            indent_width: self.indent_width.unwrap_or(base.indent_width),
+            line_width: self.line_width.unwrap_or(base.line_width),
+            keyword_case: self.keyword_case.unwrap_or(base.keyword_case),
+            identifier_quote: self.identifier_quote.unwrap_or(base.identifier_quote),
+            comma_placement: self.comma_placement.unwrap_or(base.comma_placement),
+            join_layout: self.join_layout.unwrap_or(base.join_layout),
+            on_layout: self.on_layout.unwrap_or(base.on_layout),
+            keep_blank_lines: self.keep_blank_lines.unwrap_or(base.keep_blank_lines),
+            align_columns: self.align_columns.unwrap_or(base.align_columns),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+mod config;
+mod printer;
+
+use cstree::text::{TextRange, TextSize};
+use parser::{SyntaxTree, TextEdit};
+
+use printer::Printer;
+pub use config::{FormatConfig, Preset};
+
+#[derive(Clone, Debug)]
+pub struct FormatOptions {
+    pub indent_width: usize,
+    // Select lists and WHERE / HAVING / ON conditions longer than this are broken
+    pub line_width: usize,
+    pub keyword_case: KeywordCase,
+    pub identifier_quote: IdentifierQuote,
+    pub comma_placement: CommaPlacement,
+    pub join_layout: JoinLayout,
+    pub on_layout: OnLayout,
+    pub keep_blank_lines: bool,
+    // Put the columns of a broken select list under the first one
+    pub align_columns: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indent_width: 4,
+            line_width: 80,
+            keyword_case: KeywordCase::Upper,
+            identifier_quote: IdentifierQuote::Preserve,
+            comma_placement: CommaPlacement::Trailing,
+            join_layout: JoinLayout::NewLine,
+            on_layout: OnLayout::SameLine,
+            keep_blank_lines: false,
+            align_columns: false,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeywordCase {
+    Upper,
+    Lower,
+    Preserve,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IdentifierQuote {
+    Preserve,
+    // Quote every identifier with `"`
+    Always,
+    // Unquote identifiers which do not need quotes
+    Minimal,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CommaPlacement {
+    Trailing,
+    Leading,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum JoinLayout {
+    NewLine,
+    Inline,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OnLayout {
+    SameLine,
+    NewLine,
+}
+
+pub fn format(tree: &SyntaxTree, options: &FormatOptions) -> String {
+    let mut printer = Printer::new(options);
+    printer.print(&tree.root());
+    printer.finish()
+}
+
+// Format only statements intersecting `range`.
+// Trivia outside of the statements is kept.
+pub fn format_range(tree: &SyntaxTree, range: TextRange, options: &FormatOptions) -> Vec<TextEdit> {
+    tree.root().children()
+        .filter(|statement| statement.text_range().intersect(range).is_some())
+        .filter_map(|statement| {
+            let token_range = statement.token_range();
+            if token_range.is_empty() {
+                return None;
+            }
+
+            let mut printer = Printer::new(options);
+            printer.print(&statement);
+            let new_text = printer.finish().trim_end().to_string();
+
+            // Leading comments are printed with the statement, so the edit starts at the first of them
+            let old_text = statement.text();
+            let leading_space = old_text.len() - old_text.trim_start().len();
+            let range = TextRange::new(statement.text_range().start() + TextSize::from(leading_space as u32), token_range.end());
+
+            let old_range = range.checked_sub(statement.text_range().start())?;
+            if old_text[old_range] == new_text {
+                return None;
+            }
+
+            Some(TextEdit { range, new_text })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod formatter_tests {
+    use parser::Parser;
+    use super::*;
+
+    #[test]
+    fn test_format_select() -> Result<(), anyhow::Error> {
+        let source = "SELECT a,b FROM foo t WHERE t.code = 10 ORDER BY a;";
+        let tree = Parser::new().parse(source.into())?;
+
+        let formatted = format(&tree, &FormatOptions::default());
+        assert_eq!("SELECT a, b\nFROM foo t\nWHERE t.code = 10\nORDER BY a;\n", formatted);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_subquery_and_comment() -> Result<(), anyhow::Error> {
+        let source = "SELECT * FROM (SELECT x FROM bar -- inner\n);";
+        let tree = Parser::new().parse(source.into())?;
+
+        let formatted = format(&tree, &FormatOptions::default());
+        assert_eq!("SELECT *\nFROM (\n    SELECT x\n    FROM bar -- inner\n);\n", formatted);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_idempotent() -> Result<(), anyhow::Error> {
+        let source = "/* head */ SELECT a, b FROM foo WHERE a = 1;SELECT 42;";
+        let parser = Parser::new();
+        let options = FormatOptions::default();
+
+        let formatted1 = format(&parser.parse(source.into())?, &options);
+        let formatted2 = format(&parser.parse(formatted1.clone())?, &options);
+        assert_eq!(formatted1, formatted2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_leading_comma() -> Result<(), anyhow::Error> {
+        let source = "SELECT a, b FROM foo;";
+        let tree = Parser::new().parse(source.into())?;
+        let options = FormatOptions { line_width: 10, comma_placement: CommaPlacement::Leading, ..Preset::Standard.options() };
+
+        let formatted = format(&tree, &options);
+        assert_eq!("SELECT\n    a\n    , b\nFROM foo;\n", formatted);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_long_condition() -> Result<(), anyhow::Error> {
+        let source = "SELECT a FROM foo f JOIN bar b ON b.id = f.id WHERE f.name = 'x' AND f.code IN (1, 2) OR b.id > 10;";
+        let tree = Parser::new().parse(source.into())?;
+        let options = FormatOptions { line_width: 40, ..FormatOptions::default() };
+
+        // Only the condition over the width is broken
+        let formatted = format(&tree, &options);
+        assert_eq!("SELECT a\nFROM foo f\nJOIN bar b ON b.id = f.id\nWHERE f.name = 'x'\n    AND f.code IN (1, 2)\n    OR b.id > 10;\n", formatted);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_error_verbatim() -> Result<(), anyhow::Error> {
+        let source = "SELECT 123 DELETE  FROM foo;";
+        let tree = Parser::new().parse(source.into())?;
+
+        // The broken statement keeps its spacing
+        let formatted = format(&tree, &FormatOptions::default());
+        assert_eq!("SELECT 123 DELETE  FROM foo;\n", formatted);
+
+        let source = "SELECT a,b FROM foo;\nDELETE  FROM foo WHERE;";
+        let tree = Parser::new().parse(source.into())?;
+
+        let formatted = format(&tree, &FormatOptions::default());
+        assert_eq!("SELECT a, b\nFROM foo;\nDELETE  FROM foo WHERE;\n", formatted);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_float() -> Result<(), anyhow::Error> {
+        let source = "SELECT 0.5,  2.5e-3 FROM foo;";
+        let tree = Parser::new().parse(source.into())?;
+
+        let formatted = format(&tree, &FormatOptions::default());
+        assert_eq!("SELECT 0.5, 2.5e-3\nFROM foo;\n", formatted);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_range() -> Result<(), anyhow::Error> {
+        let source = "SELECT a,b FROM foo;\n-- keep\nSELECT  c FROM bar;\n";
+        let tree = Parser::new().parse(source.into())?;
+        let second = source.find("SELECT  c").unwrap() as u32;
+
+        // Only the second statement is formatted
+        let edits = format_range(&tree, TextRange::new((second + 8).into(), (second + 9).into()), &FormatOptions::default());
+        assert_eq!(1, edits.len());
+        let comment = source.find("-- keep").unwrap() as u32;
+        assert_eq!(TextRange::new(comment.into(), (source.len() as u32 - 1).into()), edits[0].range);
+        assert_eq!("-- keep\nSELECT c\nFROM bar;", edits[0].new_text);
+
+        // Nothing to change in an already formatted statement
+        let formatted = format(&tree, &FormatOptions::default());
+        let tree = Parser::new().parse(formatted.clone())?;
+        assert!(format_range(&tree, TextRange::up_to((formatted.len() as u32).into()), &FormatOptions::default()).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_identifier_quote() -> Result<(), anyhow::Error> {
+        let source = r#"SELECT "x", "SELECT", "selected", y FROM foo;"#;
+        let tree = Parser::new().parse(source.into())?;
+
+        let options = FormatOptions { identifier_quote: IdentifierQuote::Always, ..FormatOptions::default() };
+        assert_eq!("SELECT \"x\", \"SELECT\", \"selected\", \"y\"\nFROM \"foo\";\n", format(&tree, &options));
+
+        // Only exact keywords keep their quotes
+        let options = FormatOptions { identifier_quote: IdentifierQuote::Minimal, ..FormatOptions::default() };
+        assert_eq!("SELECT x, \"SELECT\", selected, y\nFROM foo;\n", format(&tree, &options));
+
+        // Names that are not plain identifiers keep their quotes
+        let tree = Parser::new().parse(r#"SELECT "a b", "1x" FROM foo;"#.into())?;
+        assert_eq!("SELECT \"a b\", \"1x\"\nFROM foo;\n", format(&tree, &options));
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_align_columns() -> Result<(), anyhow::Error> {
+        let source = "SELECT alpha, beta, (SELECT x FROM bar) AS d FROM foo;";
+        let tree = Parser::new().parse(source.into())?;
+
+        let options = FormatOptions { line_width: 30, align_columns: true, ..FormatOptions::default() };
+        let formatted = format(&tree, &options);
+        assert_eq!("SELECT alpha,\n       beta,\n       (\n           SELECT x\n           FROM bar\n       ) AS d\nFROM foo;\n", formatted);
+        assert_eq!(formatted, format(&Parser::new().parse(formatted.clone())?, &options));
+
+        let options = FormatOptions { comma_placement: CommaPlacement::Leading, ..options };
+        let source = "SELECT alpha, beta, gamma, delta FROM foo;";
+        let tree = Parser::new().parse(source.into())?;
+        assert_eq!("SELECT alpha\n     , beta\n     , gamma\n     , delta\nFROM foo;\n", format(&tree, &options));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_config() -> Result<(), anyhow::Error> {
+        let config = FormatConfig::from_toml("preset = \"compact\"\nkeyword-case = \"lower\"\nalign-columns = true\n")?;
+        let options = config.options();
+        assert_eq!(2, options.indent_width);
+        assert_eq!(KeywordCase::Lower, options.keyword_case);
+        assert_eq!(JoinLayout::Inline, options.join_layout);
+        assert!(options.align_columns);
+
+        let config = FormatConfig::from_json(r#"{ "comma-placement": "leading", "indent-width": 8 }"#)?;
+        let options = config.options();
+        assert_eq!(8, options.indent_width);
+        assert_eq!(CommaPlacement::Leading, options.comma_placement);
+        assert_eq!(KeywordCase::Upper, options.keyword_case);
+
+        assert!(FormatConfig::from_toml("indent = 2").is_err());
+        assert!(FormatConfig::from_json(r#"{ "preset": "unknown" }"#).is_err());
+
+        let dir = std::env::temp_dir().join(format!("sqlfmt-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join("sqlfmt.toml"), "line-width = 100")?;
+        std::fs::write(dir.join("sqlfmt.json"), r#"{ "line-width": 60 }"#)?;
+        std::fs::write(dir.join("sqlfmt.yaml"), "line-width: 60")?;
+
+        assert_eq!(100, FormatConfig::load(&dir.join("sqlfmt.toml"))?.options().line_width);
+        assert_eq!(60, FormatConfig::load(&dir.join("sqlfmt.json"))?.options().line_width);
+        assert!(FormatConfig::load(&dir.join("sqlfmt.yaml")).is_err());
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,425 @@
+use cstree::text::TextRange;
+use parser::{literal::{quote_identifier, unquote_identifier}, precedence::binary_expr, NodeType, SyntaxNode};
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::{CommaPlacement, FormatOptions, IdentifierQuote, JoinLayout, KeywordCase, OnLayout};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+enum Break {
+    None,
+    Space,
+    Newline,
+    BlankLine,
+}
+
+pub struct Printer<'a> {
+    options: &'a FormatOptions,
+    out: String,
+    indent: usize,
+    // Extra spaces after the indentation (to align columns under the first one)
+    align: usize,
+    pending: Break,
+    prev_kind: Option<SyntaxKind>,
+    glue_next: bool,
+    in_call_name: bool,
+    break_columns: Vec<bool>,
+    // AND / OR tokens of long conditions to put on their own lines
+    break_connectives: Vec<TextRange>,
+}
+
+impl<'a> Printer<'a> {
+    pub fn new(options: &'a FormatOptions) -> Self {
+        Self {
+            options,
+            out: String::new(),
+            indent: 0,
+            align: 0,
+            pending: Break::None,
+            prev_kind: None,
+            glue_next: false,
+            in_call_name: false,
+            break_columns: vec![],
+            break_connectives: vec![],
+        }
+    }
+
+    pub fn print(&mut self, node: &SyntaxNode) {
+        self.print_node(node, None);
+    }
+
+    pub fn finish(mut self) -> String {
+        self.trim_trailing_spaces();
+        if ! self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out
+    }
+
+    fn print_node(&mut self, node: &SyntaxNode, parent_kind: Option<SyntaxKind>) {
+        // A statement with a broken part is kept as a whole, since its clauses can not be trusted
+        if node.is_error() || ((node.kind() == syntax_kind::r#ecmd) && node.descendants().any(|x| x.is_error())) {
+            self.print_verbatim(node);
+            return;
+        }
+
+        match node.try_metadata().map(|metadata| metadata.node_type) {
+            Some(NodeType::TokenSet) => self.print_token_set(node, parent_kind),
+            Some(NodeType::Node) => self.print_children(node, parent_kind),
+            // Not annotated by the parser, so the source text is kept
+            None => self.print_verbatim(node),
+            _ => {}
+        }
+    }
+
+    fn print_children(&mut self, node: &SyntaxNode, parent_kind: Option<SyntaxKind>) {
+        let kind = node.kind();
+
+        if starts_clause(node, parent_kind, self.options) {
+            self.request(Break::Newline);
+        }
+        if (kind == syntax_kind::r#on_using) && (self.options.on_layout == OnLayout::NewLine) {
+            self.indent += 1;
+            self.request(Break::Newline);
+        }
+        if kind == syntax_kind::r#oneselect {
+            let need_break = self.need_column_break(node);
+            self.break_columns.push(need_break);
+        }
+        if is_condition_clause(kind) {
+            let connectives = self.condition_breaks(node);
+            self.break_connectives.extend(connectives);
+        }
+
+        let children = node.children().collect::<Vec<_>>();
+        let mut index = 0;
+
+        while index < children.len() {
+            let child = &children[index];
+            let child_kind = child.kind();
+            let next_kind = children.get(index + 1).map(|x| x.kind());
+
+            match child_kind {
+                k if (k == syntax_kind::r#LP) && (next_kind == Some(syntax_kind::r#select)) && (! child.is_error()) => {
+                    // subquery
+                    self.print_node(child, Some(kind));
+                    self.indent += 1;
+                    self.request(Break::Newline);
+                    self.print_node(&children[index + 1], Some(kind));
+                    self.indent -= 1;
+                    self.request(Break::Newline);
+                    index += 2;
+                    continue;
+                }
+                k if (k == syntax_kind::r#selcollist) && (kind == syntax_kind::r#oneselect) && self.is_column_break() && self.options.align_columns => {
+                    let align = self.align;
+                    self.align = self.current_column() + 1 - (self.indent * self.options.indent_width);
+                    self.print_node(child, Some(kind));
+                    self.align = align;
+                }
+                k if (k == syntax_kind::r#selcollist) && (kind == syntax_kind::r#oneselect) && self.is_column_break() => {
+                    self.indent += 1;
+                    self.request(Break::Newline);
+                    self.print_node(child, Some(kind));
+                    self.indent -= 1;
+                }
+                k if (k == syntax_kind::r#selectnowith) && (kind == syntax_kind::r#select) && (index > 0) => {
+                    self.request(Break::Newline);
+                    self.print_node(child, Some(kind));
+                }
+                k if (index == 0) && is_unary_operator(k) && is_unary_parent(kind) => {
+                    self.print_node(child, Some(kind));
+                    self.glue_next = true;
+                }
+                _ if (index == 0) && (kind == syntax_kind::r#expr) && (next_kind == Some(syntax_kind::r#LP)) => {
+                    // function name
+                    self.in_call_name = true;
+                    self.print_node(child, Some(kind));
+                    self.in_call_name = false;
+                }
+                _ => {
+                    self.print_node(child, Some(kind));
+                }
+            }
+
+            index += 1;
+        }
+
+        if kind == syntax_kind::r#oneselect {
+            self.break_columns.pop();
+        }
+        if (kind == syntax_kind::r#on_using) && (self.options.on_layout == OnLayout::NewLine) {
+            self.indent -= 1;
+        }
+        if (kind == syntax_kind::r#multiselect_op) || (kind == syntax_kind::r#ecmd) {
+            self.request(Break::Newline);
+        }
+    }
+
+    fn print_token_set(&mut self, node: &SyntaxNode, parent_kind: Option<SyntaxKind>) {
+        let mut saw_newline = false;
+
+        for trivia in node.leading_trivia() {
+            let text = trivia.value().unwrap_or_default();
+
+            if trivia.kind() != syntax_kind::r#COMMENT {
+                saw_newline |= text.contains('\n');
+                if self.options.keep_blank_lines && (text.matches('\n').count() > 1) && (self.pending >= Break::Newline) {
+                    self.request(Break::BlankLine);
+                }
+                continue;
+            }
+
+            let pending = self.pending;
+            if saw_newline {
+                self.request(Break::Newline);
+            }
+            else if ! self.out.is_empty() {
+                self.pending = Break::Space;
+            }
+            self.emit_raw(&text);
+            self.pending = if text.starts_with("--") { Break::Newline } else { Break::max(pending, Break::Space) };
+            saw_newline = false;
+        }
+
+        let kind = node.kind();
+        if kind == syntax_kind::r#EOF {
+            return;
+        }
+        let Some(main) = node.main_tokens().into_iter().next() else {
+            return;
+        };
+
+        let text = main.value().unwrap_or_default();
+        let text = match main.kind() {
+            k if (k == syntax_kind::r#ID) && (! self.in_call_name) => self.apply_identifier_quote(text),
+            k => self.apply_keyword_case(k, text),
+        };
+
+        let list_break = match parent_kind {
+            _ if kind != syntax_kind::r#COMMA => false,
+            Some(parent) if parent == syntax_kind::r#sclp => self.is_column_break(),
+            Some(parent) => parent == syntax_kind::r#wqlist,
+            None => false,
+        };
+
+        if self.break_connectives.contains(&node.token_range()) {
+            self.request(Break::Newline);
+            self.indent += 1;
+            self.emit(main.kind(), &text, parent_kind);
+            self.indent -= 1;
+            return;
+        }
+
+        if list_break && (self.options.comma_placement == CommaPlacement::Leading) {
+            self.request(Break::Newline);
+            // `, ` is put in front of the aligned column
+            if self.options.align_columns && (parent_kind == Some(syntax_kind::r#sclp)) {
+                let align = self.align;
+                self.align = align.saturating_sub(2);
+                self.emit(main.kind(), &text, parent_kind);
+                self.align = align;
+                return;
+            }
+        }
+        self.emit(main.kind(), &text, parent_kind);
+        if list_break && (self.options.comma_placement == CommaPlacement::Trailing) {
+            self.request(Break::Newline);
+        }
+    }
+
+    fn print_verbatim(&mut self, node: &SyntaxNode) {
+        let text = node.text();
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+
+        self.emit_raw(text);
+        if text.ends_with(';') {
+            self.request(Break::Newline);
+        }
+    }
+
+    fn emit(&mut self, kind: SyntaxKind, text: &str, parent_kind: Option<SyntaxKind>) {
+        let glue = self.glue_next || is_glued(kind, self.prev_kind, parent_kind);
+
+        match self.pending {
+            Break::Newline | Break::BlankLine => self.newline(),
+            Break::Space if ! glue && ! self.out.is_empty() => self.out.push(' '),
+            _ => {}
+        }
+
+        self.out.push_str(text);
+        self.pending = Break::Space;
+        self.prev_kind = Some(kind);
+        self.glue_next = false;
+    }
+
+    fn emit_raw(&mut self, text: &str) {
+        match self.pending {
+            Break::Newline | Break::BlankLine => self.newline(),
+            Break::Space if ! self.out.is_empty() => self.out.push(' '),
+            _ => {}
+        }
+
+        self.out.push_str(text);
+        self.pending = Break::Space;
+        self.prev_kind = None;
+        self.glue_next = false;
+    }
+
+    fn request(&mut self, value: Break) {
+        self.pending = Break::max(self.pending, value);
+    }
+
+    fn newline(&mut self) {
+        if self.out.is_empty() {
+            return;
+        }
+
+        self.trim_trailing_spaces();
+        if self.pending == Break::BlankLine {
+            self.out.push('\n');
+        }
+        self.out.push('\n');
+        self.out.push_str(&" ".repeat(self.indent * self.options.indent_width + self.align));
+    }
+
+    fn current_column(&self) -> usize {
+        let line_start = self.out.rfind('\n').map_or(0, |index| index + 1);
+        self.out[line_start..].chars().count()
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let len = self.out.trim_end_matches(' ').len();
+        self.out.truncate(len);
+    }
+
+    fn is_column_break(&self) -> bool {
+        self.break_columns.last().cloned().unwrap_or(false)
+    }
+
+    fn need_column_break(&self, node: &SyntaxNode) -> bool {
+        let Some(columns) = node.children().find(|child| child.kind() == syntax_kind::r#selcollist) else {
+            return false;
+        };
+
+        self.exceeds_line_width("SELECT ".len(), &columns)
+    }
+
+    // Top level AND / OR of the condition if it does not fit in a line
+    fn condition_breaks(&self, clause: &SyntaxNode) -> Vec<TextRange> {
+        let Some(condition) = clause.children().find(|child| child.kind() == syntax_kind::r#expr) else {
+            return vec![];
+        };
+        if ! self.exceeds_line_width(0, clause) {
+            return vec![];
+        }
+
+        // Nothing binds looser than AND / OR, so every one of them in the regrouped chain is at the top level
+        binary_expr(&condition).iter()
+            .flat_map(|binary| binary.operations())
+            .filter(|operation| [syntax_kind::r#AND, syntax_kind::r#OR].contains(&operation.operator.kind()))
+            .map(|operation| operation.operator.token_range())
+            .collect()
+    }
+
+    fn exceeds_line_width(&self, head_len: usize, node: &SyntaxNode) -> bool {
+        let flat_len = node.main_tokens().iter()
+            .map(|token| token.value().map(|value| value.len()).unwrap_or_default() + 1)
+            .sum::<usize>()
+        ;
+
+        (self.indent * self.options.indent_width) + head_len + flat_len > self.options.line_width
+    }
+
+    fn apply_identifier_quote(&self, text: String) -> String {
+        let name = unquote_identifier(&text).unwrap_or_else(|_| text.clone());
+
+        match self.options.identifier_quote {
+            IdentifierQuote::Preserve => text,
+            IdentifierQuote::Always => format!("\"{}\"", name.replace('"', "\"\"")),
+            // keywords and other names needing quotes keep their own quotes
+            IdentifierQuote::Minimal if quote_identifier(&name) == name => name,
+            IdentifierQuote::Minimal => text,
+        }
+    }
+
+    fn apply_keyword_case(&self, kind: SyntaxKind, text: String) -> String {
+        if ! is_keyword_kind(kind) {
+            return text;
+        }
+
+        match self.options.keyword_case {
+            KeywordCase::Upper => text.to_uppercase(),
+            KeywordCase::Lower => text.to_lowercase(),
+            KeywordCase::Preserve => text,
+        }
+    }
+}
+
+fn starts_clause(node: &SyntaxNode, parent_kind: Option<SyntaxKind>, options: &FormatOptions) -> bool {
+    let kind = node.kind();
+    let Some(parent_kind) = parent_kind else {
+        return false;
+    };
+
+    let clauses = [
+        syntax_kind::r#from, syntax_kind::r#where_opt, syntax_kind::r#groupby_opt, syntax_kind::r#having_opt,
+        syntax_kind::r#orderby_opt, syntax_kind::r#limit_opt, syntax_kind::r#window_clause,
+        syntax_kind::r#where_opt_ret, syntax_kind::r#returning, syntax_kind::r#upsert,
+    ];
+    let statements = [syntax_kind::r#oneselect, syntax_kind::r#cmd, syntax_kind::r#trigger_cmd];
+
+    if clauses.contains(&kind) && statements.contains(&parent_kind) {
+        return true;
+    }
+    if kind == syntax_kind::r#multiselect_op {
+        return true;
+    }
+    if (kind == syntax_kind::r#joinop) && (options.join_layout == JoinLayout::NewLine) {
+        return node.main_tokens().first().map(|token| token.kind() != syntax_kind::r#COMMA).unwrap_or(false);
+    }
+
+    false
+}
+
+fn is_glued(kind: SyntaxKind, prev_kind: Option<SyntaxKind>, parent_kind: Option<SyntaxKind>) -> bool {
+    if [syntax_kind::r#COMMA, syntax_kind::r#RP, syntax_kind::r#DOT, syntax_kind::r#SEMI].contains(&kind) {
+        return true;
+    }
+
+    match prev_kind {
+        Some(prev) if (prev == syntax_kind::r#LP) || (prev == syntax_kind::r#DOT) => true,
+        // function call
+        Some(prev) if kind == syntax_kind::r#LP => {
+            let is_name = (prev == syntax_kind::r#ID) || (prev == syntax_kind::r#INDEXED) || (prev == syntax_kind::r#CAST);
+            is_name && (parent_kind == Some(syntax_kind::r#expr))
+        }
+        _ => false,
+    }
+}
+
+fn is_unary_operator(kind: SyntaxKind) -> bool {
+    [syntax_kind::r#MINUS, syntax_kind::r#PLUS, syntax_kind::r#BITNOT].contains(&kind)
+}
+
+// WHERE / HAVING / ON expr
+fn is_condition_clause(kind: SyntaxKind) -> bool {
+    [syntax_kind::r#where_opt, syntax_kind::r#where_opt_ret, syntax_kind::r#having_opt, syntax_kind::r#on_using].contains(&kind)
+}
+
+fn is_unary_parent(kind: SyntaxKind) -> bool {
+    [syntax_kind::r#expr, syntax_kind::r#plus_num, syntax_kind::r#minus_num].contains(&kind)
+}
+
+pub(crate) fn is_keyword_kind(kind: SyntaxKind) -> bool {
+    if kind.is_keyword {
+        return kind != syntax_kind::r#FLOAT;
+    }
+
+    [
+        syntax_kind::r#JOIN_KW, syntax_kind::r#LIKE_KW, syntax_kind::r#CTIME_KW,
+        syntax_kind::r#TRUEFALSE, syntax_kind::r#COLUMNKW,
+    ].contains(&kind)
+}
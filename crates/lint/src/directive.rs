@@ -0,0 +1,49 @@
+use cstree::text::TextRange;
+use parser::SyntaxNode;
+use sqlite_parser_proto::engine::kinds as syntax_kind;
+
+use crate::Level;
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub(crate) struct Directive {
+    pub level: Level,
+    // Applies to the whole file instead of the statement
+    pub file: bool,
+    pub rules: Vec<String>,
+    pub range: TextRange,
+}
+
+// Read `lint:<level>[-file] <rule>, ...` from comments in the statement
+pub(crate) fn collect(statement: &SyntaxNode) -> Vec<Directive> {
+    let mut comments = statement.descendants()
+        .filter(|node| node.kind() == syntax_kind::r#COMMENT)
+        .map(|node| (node.text_range(), node.text()))
+        .collect::<Vec<_>>()
+    ;
+    comments.dedup_by_key(|(range, _)| *range);
+
+    comments.into_iter()
+        .filter_map(|(range, text)| parse(&text).map(|(level, file, rules)| Directive { level, file, rules, range }))
+        .collect()
+}
+
+fn parse(comment: &str) -> Option<(Level, bool, Vec<String>)> {
+    let body = match comment.strip_prefix("--") {
+        Some(body) => body,
+        None => comment.strip_prefix("/*")?.strip_suffix("*/")?,
+    };
+    let body = body.trim().strip_prefix("lint:")?;
+
+    let (head, rest) = body.split_once(char::is_whitespace).unwrap_or((body, ""));
+    let (level, file) = match head.strip_suffix("-file") {
+        Some(level) => (level.parse().ok()?, true),
+        None => (head.parse().ok()?, false),
+    };
+    let rules = rest.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>()
+    ;
+
+    (!rules.is_empty()).then_some((level, file, rules))
+}
@@ -0,0 +1,288 @@
+mod directive;
+mod rules;
+
+use std::{cell::OnceCell, collections::HashMap};
+
+use anyhow::bail;
+use cstree::text::TextRange;
+use parser::{resolve::{resolve, Resolution}, schema::Catalog, Diagnostic, Severity, SyntaxNode, SyntaxTree, TextEdit};
+
+use directive::Directive;
+pub use rules::default_rules;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl std::str::FromStr for Level {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(Level::Allow),
+            "warn" => Ok(Level::Warn),
+            "deny" => Ok(Level::Deny),
+            _ => bail!("Unknown lint level (name: {s})"),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Fix {
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Finding {
+    pub range: TextRange,
+    pub message: String,
+    pub fixes: Vec<Fix>,
+}
+
+impl Finding {
+    pub fn new(range: TextRange, message: String) -> Self {
+        Self { range, message, fixes: vec![] }
+    }
+
+    pub fn with_fix(mut self, title: &str, edits: Vec<TextEdit>) -> Self {
+        self.fixes.push(Fix { title: title.to_string(), edits });
+        self
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct LintDiagnostic {
+    // Code is `lint/<rule name>`
+    pub diagnostic: Diagnostic,
+    pub fixes: Vec<Fix>,
+}
+
+pub struct LintContext<'a> {
+    pub tree: &'a SyntaxTree,
+    pub catalog: Option<&'a Catalog>,
+    resolution: OnceCell<Resolution>,
+}
+
+impl<'a> LintContext<'a> {
+    pub fn new(tree: &'a SyntaxTree, catalog: Option<&'a Catalog>) -> Self {
+        Self { tree, catalog, resolution: OnceCell::new() }
+    }
+
+    // Name resolution is computed on first use
+    pub fn resolution(&self) -> &Resolution {
+        self.resolution.get_or_init(|| {
+            match self.catalog {
+                Some(catalog) => resolve(self.tree, catalog),
+                None => resolve(self.tree, &Catalog::default()),
+            }
+        })
+    }
+}
+
+pub trait Rule {
+    // Stable name used by configuration and suppression comments
+    fn name(&self) -> &'static str;
+    fn description(&self) -> &'static str;
+
+    fn default_level(&self) -> Level {
+        Level::Warn
+    }
+
+    // Called for each non-terminal node of a statement in preorder
+    fn visit(&self, node: &SyntaxNode, context: &LintContext, findings: &mut Vec<Finding>);
+}
+
+#[derive(Default)]
+pub struct Registry {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self { rules: default_rules() }
+    }
+
+    pub fn register(&mut self, rule: Box<dyn Rule>) -> Result<(), anyhow::Error> {
+        if self.get(rule.name()).is_some() {
+            bail!("Rule is already registered (name: {})", rule.name());
+        }
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Rule> {
+        self.rules.iter().find(|rule| rule.name() == name).map(|rule| rule.as_ref())
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.iter().map(|rule| rule.as_ref())
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct LintConfig {
+    // Overrides the default level of rules
+    pub levels: HashMap<String, Level>,
+}
+
+impl LintConfig {
+    pub fn set(&mut self, name: &str, level: Level) {
+        self.levels.insert(name.to_string(), level);
+    }
+
+    fn level_of(&self, rule: &dyn Rule) -> Level {
+        self.levels.get(rule.name()).copied().unwrap_or_else(|| rule.default_level())
+    }
+}
+
+// Run the rules over each statement.
+// `-- lint:allow <rule>` comments in a statement override the level for the statement,
+// `-- lint:allow-file <rule>` for the whole file (`warn` and `deny` likewise).
+pub fn lint(tree: &SyntaxTree, catalog: Option<&Catalog>, registry: &Registry, config: &LintConfig) -> Vec<LintDiagnostic> {
+    let context = LintContext::new(tree, catalog);
+    let statements = tree.root().children().collect::<Vec<_>>();
+    let mut result = vec![];
+
+    let directives = statements.iter()
+        .map(directive::collect)
+        .collect::<Vec<_>>()
+    ;
+    for directive in directives.iter().flatten() {
+        for name in directive.rules.iter().filter(|name| registry.get(name).is_none()) {
+            result.push(LintDiagnostic {
+                diagnostic: Diagnostic::warning("lint/unknown-rule", format!("unknown lint rule: {name}"), directive.range),
+                fixes: vec![],
+            });
+        }
+    }
+
+    let mut file_config = config.clone();
+    for directive in directives.iter().flatten().filter(|directive| directive.file) {
+        for name in &directive.rules {
+            file_config.set(name, directive.level);
+        }
+    }
+
+    for (statement, directives) in statements.iter().zip(directives.iter()) {
+        let rules = registry.rules()
+            .map(|rule| (rule, statement_level(rule, &file_config, directives)))
+            .filter(|(_, level)| *level != Level::Allow)
+            .collect::<Vec<_>>()
+        ;
+        if rules.is_empty() {
+            continue;
+        }
+
+        for node in statement.descendants().filter(|node| !node.kind().is_terminal) {
+            for (rule, level) in &rules {
+                let mut findings = vec![];
+                rule.visit(&node, &context, &mut findings);
+
+                let severity = match level {
+                    Level::Deny => Severity::Error,
+                    _ => Severity::Warning,
+                };
+                result.extend(findings.into_iter().map(|finding| LintDiagnostic {
+                    diagnostic: Diagnostic::new(severity, &format!("lint/{}", rule.name()), finding.message, finding.range),
+                    fixes: finding.fixes,
+                }));
+            }
+        }
+    }
+
+    result.sort_by_key(|x| (x.diagnostic.range.start(), x.diagnostic.range.end()));
+    result
+}
+
+fn statement_level(rule: &dyn Rule, config: &LintConfig, directives: &[Directive]) -> Level {
+    directives.iter().rev()
+        .find(|directive| !directive.file && directive.rules.iter().any(|name| name == rule.name()))
+        .map_or_else(|| config.level_of(rule), |directive| directive.level)
+}
+
+#[cfg(test)]
+mod lint_tests {
+    use parser::Parser;
+    use super::*;
+
+    fn codes(diagnostics: &[LintDiagnostic]) -> Vec<&str> {
+        diagnostics.iter().map(|x| x.diagnostic.code.as_str()).collect()
+    }
+
+    #[test]
+    fn test_lint_rules() -> Result<(), anyhow::Error> {
+        let source = "DELETE FROM foo; SELECT * FROM foo, bar WHERE a = NULL AND b LIKE '%x'; CREATE TABLE baz (id INTEGER PRIMARY KEY AUTOINCREMENT);";
+        let tree = Parser::new().parse(source.into())?;
+
+        let mut config = LintConfig::default();
+        config.set("missing-if-not-exists", Level::Deny);
+        let diagnostics = lint(&tree, None, &Registry::new(), &config);
+
+        assert_eq!(vec![
+            "lint/missing-where",
+            "lint/implicit-cross-join",
+            "lint/compare-null",
+            "lint/leading-wildcard-like",
+            "lint/missing-if-not-exists",
+            "lint/autoincrement",
+        ], codes(&diagnostics));
+        assert_eq!(Severity::Error, diagnostics[4].diagnostic.severity);
+
+        let edits = diagnostics.iter().flat_map(|x| x.fixes.iter().flat_map(|fix| fix.edits.clone())).collect::<Vec<_>>();
+        assert_eq!(
+            "DELETE FROM foo; SELECT * FROM foo CROSS JOIN bar WHERE a IS NULL AND b LIKE '%x'; CREATE TABLE IF NOT EXISTS baz (id INTEGER PRIMARY KEY);",
+            TextEdit::apply(source, &edits)
+        );
+
+        // `x = 1 AND (NULL <> y) OR z` is parsed as `x = (1 AND (NULL <> (y OR z)))`
+        let source = "SELECT * FROM foo WHERE x = 1 AND NULL <> y OR z;";
+        let diagnostics = lint(&Parser::new().parse(source.into())?, None, &Registry::new(), &LintConfig::default());
+        let edits = diagnostics.iter().flat_map(|x| x.fixes.iter().flat_map(|fix| fix.edits.clone())).collect::<Vec<_>>();
+        assert_eq!("SELECT * FROM foo WHERE x = 1 AND y IS NOT NULL OR z;", TextEdit::apply(source, &edits));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_schema_rules() -> Result<(), anyhow::Error> {
+        let parser = Parser::new();
+        let catalog = Catalog::from_tree(&parser.parse("CREATE TABLE foo (id INTEGER, name TEXT);".into())?);
+
+        let source = "CREATE VIEW v AS SELECT f.* FROM foo f WHERE name = \"bar\";";
+        let tree = parser.parse(source.into())?;
+        let diagnostics = lint(&tree, Some(&catalog), &Registry::new(), &LintConfig::default());
+
+        assert_eq!(vec!["lint/select-star-in-view", "lint/double-quoted-string"], codes(&diagnostics));
+
+        let edits = diagnostics.iter().flat_map(|x| x.fixes.iter().flat_map(|fix| fix.edits.clone())).collect::<Vec<_>>();
+        assert_eq!("CREATE VIEW v AS SELECT f.id, f.name FROM foo f WHERE name = 'bar';", TextEdit::apply(source, &edits));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_function_argument_count() -> Result<(), anyhow::Error> {
+        let source = "SELECT substr(x), substr(x, 1), json_extract(), count(*), coalesce(a), my_func() FROM foo;";
+        let tree = Parser::new().parse(source.into())?;
+        let diagnostics = lint(&tree, None, &Registry::new(), &LintConfig::default());
+
+        assert_eq!(vec!["lint/function-argument-count"; 3], codes(&diagnostics));
+        let ranges = diagnostics.iter().map(|x| &source[x.diagnostic.range]).collect::<Vec<_>>();
+        assert_eq!(vec!["substr(x)", "json_extract()", "coalesce(a)"], ranges);
+        assert_eq!("substr() takes 2 to 3 argument(s) but 1 given: substr(X, Y, [Z])", diagnostics[0].diagnostic.message);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_suppression() -> Result<(), anyhow::Error> {
+        let source = "-- lint:allow-file autoincrement\nCREATE TABLE foo (id INTEGER PRIMARY KEY AUTOINCREMENT);\n-- lint:allow missing-where, no-such-rule\nDELETE FROM foo;\nUPDATE foo SET id = 1;";
+        let tree = Parser::new().parse(source.into())?;
+        let diagnostics = lint(&tree, None, &Registry::new(), &LintConfig::default());
+
+        assert_eq!(vec!["lint/unknown-rule", "lint/missing-where"], codes(&diagnostics));
+        assert!(source[diagnostics[1].diagnostic.range].starts_with("UPDATE"));
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+use cstree::text::TextRange;
+use parser::{function::FunctionCall, literal::quote_identifier, precedence::binary_expr, schema::child, LiteralValue, SyntaxNode, TextEdit};
+use sqlite_parser_proto::engine::kinds as syntax_kind;
+
+use crate::{Finding, Level, LintContext, Rule};
+
+pub fn default_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(SelectStarInView),
+        Box::new(MissingWhere),
+        Box::new(CompareNull),
+        Box::new(DoubleQuotedString),
+        Box::new(ImplicitCrossJoin),
+        Box::new(LeadingWildcardLike),
+        Box::new(Autoincrement),
+        Box::new(MissingIfNotExists),
+        Box::new(FunctionArgumentCount),
+    ]
+}
+
+struct SelectStarInView;
+
+impl Rule for SelectStarInView {
+    fn name(&self) -> &'static str { "select-star-in-view" }
+    fn description(&self) -> &'static str { "`*` in a view is expanded when the view is created" }
+
+    // CREATE VIEW ... AS select
+    fn visit(&self, node: &SyntaxNode, context: &LintContext, findings: &mut Vec<Finding>) {
+        if node.kind() != syntax_kind::r#cmd || child(node, syntax_kind::r#VIEW).is_none() {
+            return;
+        }
+        let Some(select) = child(node, syntax_kind::r#select) else {
+            return;
+        };
+
+        let selcollists = select.descendants().filter(|node| node.kind() == syntax_kind::r#selcollist).collect::<Vec<_>>();
+        for selcollist in selcollists {
+            let Some(asterisk) = child(&selcollist, syntax_kind::r#ASTERISK) else {
+                continue;
+            };
+            let qualifier = child(&selcollist, syntax_kind::r#nm);
+            let range = match qualifier.as_ref() {
+                Some(nm) => TextRange::new(nm.token_range().start(), asterisk.token_range().end()),
+                None => asterisk.token_range(),
+            };
+            let finding = Finding::new(range, "`*` in a view does not follow later schema changes".to_string());
+
+            let expansion = context.resolution().stars.iter().find(|star| star.range == asterisk.token_range());
+            let finding = match expansion {
+                Some(star) if !star.columns.is_empty() => {
+                    let prefix = qualifier.map(|nm| format!("{}.", nm.token_text())).unwrap_or_default();
+                    let columns = star.columns.iter().map(|column| format!("{prefix}{}", quote_identifier(&column.column))).collect::<Vec<_>>();
+                    finding.with_fix("Expand `*`", vec![TextEdit { range, new_text: columns.join(", ") }])
+                }
+                _ => finding,
+            };
+            findings.push(finding);
+        }
+    }
+}
+
+struct MissingWhere;
+
+impl Rule for MissingWhere {
+    fn name(&self) -> &'static str { "missing-where" }
+    fn description(&self) -> &'static str { "DELETE or UPDATE without WHERE affects every row" }
+
+    fn visit(&self, node: &SyntaxNode, _context: &LintContext, findings: &mut Vec<Finding>) {
+        if node.kind() != syntax_kind::r#cmd {
+            return;
+        }
+        let statement = if child(node, syntax_kind::r#DELETE).is_some() {
+            "DELETE"
+        }
+        else if child(node, syntax_kind::r#UPDATE).is_some() {
+            "UPDATE"
+        }
+        else {
+            return;
+        };
+
+        // where_opt_ret: WHERE expr [RETURNING selcollist] / RETURNING selcollist
+        let has_where = child(node, syntax_kind::r#where_opt_ret).and_then(|node| child(&node, syntax_kind::r#WHERE)).is_some();
+        if !has_where {
+            findings.push(Finding::new(node.token_range(), format!("{statement} without WHERE affects every row")));
+        }
+    }
+}
+
+struct CompareNull;
+
+impl Rule for CompareNull {
+    fn name(&self) -> &'static str { "compare-null" }
+    fn description(&self) -> &'static str { "`= NULL` and `<> NULL` are never true" }
+
+    // expr EQ|NE expr (regrouped by precedence)
+    fn visit(&self, node: &SyntaxNode, _context: &LintContext, findings: &mut Vec<Finding>) {
+        if node.kind() != syntax_kind::r#expr {
+            return;
+        }
+        let Some(binary) = binary_expr(node) else {
+            return;
+        };
+
+        for operation in binary.operations() {
+            let replacement = match operation.operator.kind() {
+                k if k == syntax_kind::r#EQ => "IS NULL",
+                k if k == syntax_kind::r#NE => "IS NOT NULL",
+                _ => continue,
+            };
+            let (Some(lhs), Some(rhs)) = (operation.lhs.as_expr(), operation.rhs.as_expr()) else {
+                continue;
+            };
+            let range = operation.token_range();
+
+            let edit = match (is_null(lhs), is_null(rhs)) {
+                (_, true) => TextEdit { range: TextRange::new(operation.operator.token_range().start(), rhs.token_range().end()), new_text: replacement.to_string() },
+                (true, false) => TextEdit { range, new_text: format!("{} {replacement}", rhs.token_text()) },
+                (false, false) => continue,
+            };
+            let finding = Finding::new(range, format!("comparison with NULL is always NULL; use {replacement}"));
+            findings.push(finding.with_fix(&format!("Replace with {replacement}"), vec![edit]));
+        }
+    }
+}
+
+struct DoubleQuotedString;
+
+impl Rule for DoubleQuotedString {
+    fn name(&self) -> &'static str { "double-quoted-string" }
+    fn description(&self) -> &'static str { "Double-quoted identifiers fall back to string literals if no such column exists" }
+
+    fn visit(&self, node: &SyntaxNode, context: &LintContext, findings: &mut Vec<Finding>) {
+        if node.kind() != syntax_kind::r#expr || node.main_tokens().len() != 1 || !node.token_text().starts_with('"') {
+            return;
+        }
+        let range = node.token_range();
+        let unresolved = context.resolution().diagnostics.iter().any(|x| x.code == "resolve/unresolved-column" && x.range == range);
+        if !unresolved {
+            return;
+        }
+        let Ok(text) = node.identifier() else {
+            return;
+        };
+
+        let finding = Finding::new(range, format!("{} is not a column; it is read as a string literal", node.token_text()));
+        findings.push(finding.with_fix("Use a single-quoted string", vec![TextEdit { range, new_text: format!("'{}'", text.replace('\'', "''")) }]));
+    }
+}
+
+struct ImplicitCrossJoin;
+
+impl Rule for ImplicitCrossJoin {
+    fn name(&self) -> &'static str { "implicit-cross-join" }
+    fn description(&self) -> &'static str { "Comma-separated tables are joined without a join condition" }
+
+    // joinop: COMMA
+    fn visit(&self, node: &SyntaxNode, _context: &LintContext, findings: &mut Vec<Finding>) {
+        if node.kind() != syntax_kind::r#joinop {
+            return;
+        }
+        let Some(comma) = child(node, syntax_kind::r#COMMA) else {
+            return;
+        };
+
+        let range = comma.token_range();
+        let finding = Finding::new(range, "implicit cross join".to_string());
+        findings.push(finding.with_fix("Use CROSS JOIN", vec![TextEdit { range, new_text: " CROSS JOIN".to_string() }]));
+    }
+}
+
+struct LeadingWildcardLike;
+
+impl Rule for LeadingWildcardLike {
+    fn name(&self) -> &'static str { "leading-wildcard-like" }
+    fn description(&self) -> &'static str { "A pattern starting with a wildcard cannot use an index" }
+
+    // expr likeop expr [ESCAPE expr]
+    fn visit(&self, node: &SyntaxNode, _context: &LintContext, findings: &mut Vec<Finding>) {
+        if node.kind() != syntax_kind::r#expr {
+            return;
+        }
+        let parts = node.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+        let [_, likeop, pattern, ..] = parts.as_slice() else {
+            return;
+        };
+        if likeop.kind() != syntax_kind::r#likeop {
+            return;
+        }
+        let Ok(LiteralValue::Text(text)) = pattern.literal_value() else {
+            return;
+        };
+
+        // likeop: [NOT] LIKE_KW|MATCH
+        let operator = likeop.token_text().to_ascii_uppercase();
+        let operator = operator.trim_start_matches("NOT").trim();
+        let wildcards: &[char] = match operator {
+            "GLOB" => &['*', '?', '['],
+            "LIKE" => &['%', '_'],
+            _ => return,
+        };
+        if text.starts_with(wildcards) {
+            findings.push(Finding::new(pattern.token_range(), format!("{operator} pattern starting with a wildcard cannot use an index")));
+        }
+    }
+}
+
+struct Autoincrement;
+
+impl Rule for Autoincrement {
+    fn name(&self) -> &'static str { "autoincrement" }
+    fn description(&self) -> &'static str { "AUTOINCREMENT adds overhead and is rarely needed" }
+
+    // ccons: PRIMARY KEY sortorder onconf autoinc
+    fn visit(&self, node: &SyntaxNode, context: &LintContext, findings: &mut Vec<Finding>) {
+        if node.kind() != syntax_kind::r#ccons {
+            return;
+        }
+        let Some(keyword) = child(node, syntax_kind::r#autoinc).filter(|autoinc| !autoinc.token_range().is_empty()) else {
+            return;
+        };
+
+        // Remove with the preceding whitespace
+        let range = keyword.token_range();
+        let source = context.tree.root().text();
+        let start = source[..usize::from(range.start())].trim_end().len();
+        let edit = TextEdit { range: TextRange::new((start as u32).into(), range.end()), new_text: String::new() };
+
+        let finding = Finding::new(range, "AUTOINCREMENT prevents rowid reuse at the cost of an extra table lookup".to_string());
+        findings.push(finding.with_fix("Remove AUTOINCREMENT", vec![edit]));
+    }
+}
+
+struct MissingIfNotExists;
+
+impl Rule for MissingIfNotExists {
+    fn name(&self) -> &'static str { "missing-if-not-exists" }
+    fn description(&self) -> &'static str { "CREATE without IF NOT EXISTS fails when a migration is replayed" }
+
+    // Opt-in for migration scripts
+    fn default_level(&self) -> Level {
+        Level::Allow
+    }
+
+    fn visit(&self, node: &SyntaxNode, _context: &LintContext, findings: &mut Vec<Finding>) {
+        if node.kind() != syntax_kind::r#cmd {
+            return;
+        }
+
+        // The object keyword, `createkw` and `ifnotexists` are in the same parent
+        let parent = [syntax_kind::r#create_table, syntax_kind::r#create_vtab, syntax_kind::r#trigger_decl].into_iter()
+            .find_map(|kind| child(node, kind))
+            .unwrap_or_else(|| node.clone())
+        ;
+        if child(&parent, syntax_kind::r#createkw).is_none() && child(node, syntax_kind::r#createkw).is_none() {
+            return;
+        }
+        if child(&parent, syntax_kind::r#ifnotexists).is_some_and(|node| !node.main_tokens().is_empty()) {
+            return;
+        }
+        let Some(keyword) = [syntax_kind::r#TABLE, syntax_kind::r#INDEX, syntax_kind::r#VIEW, syntax_kind::r#TRIGGER].into_iter().find_map(|kind| child(&parent, kind)) else {
+            return;
+        };
+
+        let label = keyword.token_text().to_ascii_uppercase();
+        let finding = Finding::new(keyword.token_range(), format!("CREATE {label} without IF NOT EXISTS"));
+        findings.push(finding.with_fix("Add IF NOT EXISTS", vec![TextEdit { range: TextRange::empty(keyword.token_range().end()), new_text: " IF NOT EXISTS".to_string() }]));
+    }
+}
+
+struct FunctionArgumentCount;
+
+impl Rule for FunctionArgumentCount {
+    fn name(&self) -> &'static str { "function-argument-count" }
+    fn description(&self) -> &'static str { "Built-in function called with a wrong number of arguments" }
+
+    fn visit(&self, node: &SyntaxNode, _context: &LintContext, findings: &mut Vec<Finding>) {
+        let Some(call) = FunctionCall::from_expr(node) else {
+            return;
+        };
+        let Some(function) = call.function() else {
+            return;
+        };
+        // count(*)
+        if call.star || function.accepts(call.args.len()) {
+            return;
+        }
+
+        let expected = match (function.min_args, function.max_args) {
+            (min, Some(max)) if min == max => format!("{min}"),
+            (min, Some(max)) => format!("{min} to {max}"),
+            (min, None) => format!("at least {min}"),
+        };
+        let message = format!("{}() takes {expected} argument(s) but {} given: {}", function.name, call.args.len(), function.signature());
+        findings.push(Finding::new(node.token_range(), message));
+    }
+}
+
+fn is_null(expr: &SyntaxNode) -> bool {
+    expr.main_tokens().len() == 1 && expr.token_text().eq_ignore_ascii_case("NULL")
+}
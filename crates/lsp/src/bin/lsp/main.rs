@@ -0,0 +1,4 @@
+pub fn main() -> Result<(), anyhow::Error> {
+    let code = lsp::run(std::io::stdin().lock(), std::io::stdout().lock())?;
+    std::process::exit(code);
+}
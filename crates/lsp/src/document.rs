@@ -0,0 +1,89 @@
+use anyhow::bail;
+use cstree::text::TextRange;
+use parser::{Parser, SyntaxTree};
+use serde_json::{json, Value};
+
+// Text document with LSP positions on top of the parser's document
+pub(crate) struct Document {
+    pub version: i64,
+    inner: parser::document::Document,
+    line_starts: Vec<usize>,
+}
+
+impl Document {
+    pub fn open(parser: &Parser, text: String, version: i64) -> Result<Self, anyhow::Error> {
+        let line_starts = line_starts(&text);
+        let inner = parser::document::Document::new(parser, text)?;
+        Ok(Self { version, inner, line_starts })
+    }
+
+    pub fn text(&self) -> &str {
+        self.inner.source()
+    }
+
+    pub fn tree(&self) -> &SyntaxTree {
+        self.inner.tree()
+    }
+
+    // TextDocumentContentChangeEvent.
+    // A ranged change is reparsed incrementally, otherwise the whole text is replaced.
+    pub fn apply_change(&mut self, parser: &Parser, change: &Value) -> Result<(), anyhow::Error> {
+        let Some(new_text) = change.get("text").and_then(Value::as_str) else {
+            bail!("Missing text in content change");
+        };
+        let Some(range) = change.get("range") else {
+            *self = Self::open(parser, new_text.to_string(), self.version)?;
+            return Ok(());
+        };
+
+        let start = self.offset(&range["start"])?;
+        let end = self.offset(&range["end"])?;
+        if start > end {
+            bail!("Invalid range of content change (start: {start}, end: {end})");
+        }
+
+        self.inner.apply_edit(parser, start, end - start, new_text)?;
+        self.line_starts = line_starts(self.text());
+        Ok(())
+    }
+
+    // LSP Position (UTF-16 based) to byte offset
+    pub fn offset(&self, position: &Value) -> Result<usize, anyhow::Error> {
+        let (Some(line), Some(character)) = (position["line"].as_u64(), position["character"].as_u64()) else {
+            bail!("Invalid position ({position})");
+        };
+        let Some(&line_start) = self.line_starts.get(line as usize) else {
+            return Ok(self.text().len());
+        };
+
+        let mut units = 0;
+        for (i, c) in self.text()[line_start..].char_indices() {
+            if (units >= character as usize) || (c == '\n') {
+                return Ok(line_start + i);
+            }
+            units += c.len_utf16();
+        }
+        Ok(self.text().len())
+    }
+
+    pub fn position(&self, offset: usize) -> Value {
+        let (line, character) = self.line_column(offset);
+        json!({ "line": line, "character": character })
+    }
+
+    pub fn range(&self, range: TextRange) -> Value {
+        json!({ "start": self.position(range.start().into()), "end": self.position(range.end().into()) })
+    }
+
+    // Zero based line and UTF-16 column
+    pub fn line_column(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.text().len());
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let character = self.text()[self.line_starts[line]..offset].encode_utf16().count();
+        (line, character)
+    }
+}
+
+fn line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0).chain(text.match_indices('\n').map(|(i, _)| i + 1)).collect()
+}
@@ -0,0 +1,229 @@
+use cstree::text::{TextRange, TextSize};
+use parser::{schema::Catalog, Diagnostic, Severity, SyntaxNode};
+use serde_json::{json, Value};
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::document::Document;
+
+pub(crate) const TOKEN_TYPES: &[&str] = &["keyword", "comment", "string", "number", "operator", "parameter", "variable", "function"];
+
+// SymbolKind
+const SYMBOL_FIELD: u32 = 8;
+const SYMBOL_INTERFACE: u32 = 11;
+const SYMBOL_FUNCTION: u32 = 12;
+const SYMBOL_KEY: u32 = 20;
+const SYMBOL_STRUCT: u32 = 23;
+const SYMBOL_EVENT: u32 = 24;
+
+pub(crate) fn diagnostics(document: &Document) -> Value {
+    let diagnostics = document.tree().syntax_diagnostics().iter()
+        .map(|diagnostic| diagnostic_json(document, diagnostic))
+        .collect()
+    ;
+    Value::Array(diagnostics)
+}
+
+fn diagnostic_json(document: &Document, diagnostic: &Diagnostic) -> Value {
+    let severity = match diagnostic.severity {
+        Severity::Error => 1,
+        Severity::Warning => 2,
+        Severity::Information => 3,
+        Severity::Hint => 4,
+    };
+    json!({
+        "range": document.range(diagnostic.range),
+        "severity": severity,
+        "code": diagnostic.code,
+        "source": "sqlite",
+        "message": diagnostic.message,
+    })
+}
+
+// One symbol per statement, columns are nested in tables
+pub(crate) fn document_symbols(document: &Document) -> Value {
+    let symbols = document.tree().root().children()
+        .filter(|statement| statement.kind() == syntax_kind::r#ecmd)
+        .filter_map(|statement| statement_symbol(document, &statement))
+        .collect()
+    ;
+    Value::Array(symbols)
+}
+
+fn statement_symbol(document: &Document, statement: &SyntaxNode) -> Option<Value> {
+    statement.descendants().find(|node| node.kind() == syntax_kind::r#cmd)?;
+
+    let range = statement.token_range();
+    let detail = statement.token_text().lines().next().unwrap_or_default().to_string();
+
+    let mut catalog = Catalog::default();
+    catalog.collect(statement);
+
+    let (name, kind, children) = if let Some(table) = catalog.tables.first() {
+        let columns = table.columns.iter()
+            .map(|column| symbol_json(document, &column.name, column.declared_type.as_deref().unwrap_or_default(), SYMBOL_FIELD, column.range, column.range, vec![]))
+            .collect()
+        ;
+        (table.name.clone(), SYMBOL_STRUCT, columns)
+    }
+    else if let Some(view) = catalog.views.first() {
+        (view.name.clone(), SYMBOL_INTERFACE, vec![])
+    }
+    else if let Some(index) = catalog.indexes.first() {
+        (index.name.clone(), SYMBOL_KEY, vec![])
+    }
+    else if let Some(trigger) = catalog.triggers.first() {
+        (trigger.name.clone(), SYMBOL_EVENT, vec![])
+    }
+    else {
+        let keyword = statement.main_tokens().first().map(|token| token.text().to_ascii_uppercase()).unwrap_or_default();
+        return Some(symbol_json(document, &keyword, &detail, SYMBOL_FUNCTION, range, range, vec![]));
+    };
+
+    let selection = statement.descendants()
+        .find(|node| node.kind() == syntax_kind::r#nm && node.identifier().is_ok_and(|x| x == name))
+        .map_or(range, |nm| nm.token_range())
+    ;
+    Some(symbol_json(document, &name, &detail, kind, range, selection, children))
+}
+
+fn symbol_json(document: &Document, name: &str, detail: &str, kind: u32, range: TextRange, selection: TextRange, children: Vec<Value>) -> Value {
+    json!({
+        "name": name,
+        "detail": detail,
+        "kind": kind,
+        "range": document.range(range),
+        "selectionRange": document.range(selection),
+        "children": children,
+    })
+}
+
+// Multi-line statements, subqueries and block comments
+pub(crate) fn folding_ranges(document: &Document) -> Value {
+    let root = document.tree().root();
+    let mut ranges = vec![];
+
+    for node in root.descendants().filter(|node| (node.kind() == syntax_kind::r#ecmd) || (node.kind() == syntax_kind::r#select)) {
+        // A subquery folds with its parentheses
+        let range = match (node.prev_sibling(), node.next_sibling()) {
+            (Some(lp), Some(rp)) if (lp.kind() == syntax_kind::r#LP) && (rp.kind() == syntax_kind::r#RP) => lp.token_range().cover(rp.token_range()),
+            _ => node.token_range(),
+        };
+        ranges.push((range, None));
+    }
+    for comment in comments(&root) {
+        ranges.push((comment.text_range(), Some("comment")));
+    }
+
+    let mut lines = ranges.into_iter()
+        .map(|(range, kind)| (document.line_column(range.start().into()).0, document.line_column(range.end().into()).0, kind))
+        .filter(|(start, end, _)| start < end)
+        .collect::<Vec<_>>()
+    ;
+    lines.sort();
+    lines.dedup_by_key(|(start, end, _)| (*start, *end));
+
+    let ranges = lines.into_iter()
+        .map(|(start, end, kind)| match kind {
+            Some(kind) => json!({ "startLine": start, "endLine": end, "kind": kind }),
+            None => json!({ "startLine": start, "endLine": end }),
+        })
+        .collect()
+    ;
+    Value::Array(ranges)
+}
+
+// Enclosing nodes from the token at each position outward
+pub(crate) fn selection_ranges(document: &Document, offsets: &[usize]) -> Value {
+    let root = document.tree().root();
+
+    let ranges = offsets.iter()
+        .map(|offset| {
+            let offset = TextSize::from(*offset as u32);
+            let mut ranges = vec![root.token_range()];
+            let mut node = root.clone();
+
+            while let Some(child) = node.children().find(|child| !child.token_range().is_empty() && child.token_range().contains_inclusive(offset)) {
+                if ranges.last() != Some(&child.token_range()) {
+                    ranges.push(child.token_range());
+                }
+                if child.kind().is_terminal {
+                    break;
+                }
+                node = child;
+            }
+
+            ranges.into_iter().fold(Value::Null, |parent, range| match parent {
+                Value::Null => json!({ "range": document.range(range) }),
+                parent => json!({ "range": document.range(range), "parent": parent }),
+            })
+        })
+        .collect()
+    ;
+    Value::Array(ranges)
+}
+
+// Encoded as relative positions (line, start, length, type, modifiers)
+pub(crate) fn semantic_tokens(document: &Document) -> Vec<u32> {
+    let root = document.tree().root();
+    let main_tokens = root.main_tokens();
+    let mut tokens = vec![];
+
+    for (i, token) in main_tokens.iter().enumerate() {
+        for comment in token.leading_trivia().iter().filter(|trivia| trivia.kind() == syntax_kind::r#COMMENT) {
+            tokens.push((comment.text_range(), 1));
+        }
+
+        let next = main_tokens.get(i + 1).map(|next| next.kind());
+        if let Some(token_type) = token_type(token.kind(), &token.text(), next) {
+            tokens.push((token.text_range(), token_type));
+        }
+    }
+    tokens.sort_by_key(|(range, _)| range.start());
+    tokens.dedup_by_key(|(range, _)| *range);
+
+    let mut data = vec![];
+    let mut prev = (0, 0);
+
+    for (range, token_type) in tokens {
+        // Tokens must not span multiple lines
+        let mut start = usize::from(range.start());
+        for line in document.text()[start..usize::from(range.end())].split_inclusive('\n') {
+            let text = line.trim_end_matches(['\r', '\n']);
+            let (line_no, column) = document.line_column(start);
+            start += line.len();
+            if text.is_empty() {
+                continue;
+            }
+
+            let delta_column = if line_no == prev.0 { column - prev.1 } else { column };
+            data.extend([(line_no - prev.0) as u32, delta_column as u32, text.encode_utf16().count() as u32, token_type, 0]);
+            prev = (line_no, column);
+        }
+    }
+
+    data
+}
+
+fn token_type(kind: SyntaxKind, text: &str, next: Option<SyntaxKind>) -> Option<u32> {
+    let punctuation = [syntax_kind::r#LP, syntax_kind::r#RP, syntax_kind::r#COMMA, syntax_kind::r#SEMI, syntax_kind::r#DOT];
+
+    let token_type = match kind {
+        k if k == syntax_kind::r#STRING || k == syntax_kind::r#BLOB => "string",
+        k if k == syntax_kind::r#INTEGER || k == syntax_kind::r#FLOAT || k == syntax_kind::r#QNUMBER => "number",
+        k if k == syntax_kind::r#VARIABLE => "parameter",
+        k if k == syntax_kind::r#ID && next == Some(syntax_kind::r#LP) => "function",
+        k if k == syntax_kind::r#ID => "variable",
+        k if punctuation.contains(&k) || k == syntax_kind::r#ILLEGAL || k == syntax_kind::r#EOF => return None,
+        k if k.is_keyword || [syntax_kind::r#JOIN_KW, syntax_kind::r#LIKE_KW, syntax_kind::r#CTIME_KW, syntax_kind::r#TRUEFALSE].contains(&k) => "keyword",
+        _ if text.starts_with(|c: char| !c.is_alphanumeric() && c != '_') => "operator",
+        _ => "variable",
+    };
+    TOKEN_TYPES.iter().position(|x| *x == token_type).map(|i| i as u32)
+}
+
+fn comments(root: &SyntaxNode) -> Vec<SyntaxNode> {
+    root.main_tokens().iter()
+        .flat_map(|token| token.leading_trivia())
+        .filter(|trivia| trivia.kind() == syntax_kind::r#COMMENT)
+        .collect()
+}
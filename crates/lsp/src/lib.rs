@@ -0,0 +1,309 @@
+mod document;
+mod features;
+mod transport;
+
+use std::{collections::HashMap, io::{BufRead, Write}};
+
+use anyhow::bail;
+use parser::Parser;
+use serde_json::{json, Value};
+
+use document::Document;
+pub use transport::{read_message, write_message};
+
+// JSON-RPC error codes
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+pub struct Server {
+    parser: Parser,
+    documents: HashMap<String, Document>,
+    shutdown: bool,
+    exited: bool,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Self { parser: Parser::new(), documents: HashMap::new(), shutdown: false, exited: false }
+    }
+
+    pub fn is_exited(&self) -> bool {
+        self.exited
+    }
+
+    // Handle a JSON-RPC message and return the messages to send back
+    pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+        // Responses to server requests are not used
+        let Some(method) = message["method"].as_str() else {
+            return vec![];
+        };
+        let params = &message["params"];
+
+        match message.get("id") {
+            Some(id) => {
+                let response = match self.request(method, params) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err((code, message)) => json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } }),
+                };
+                vec![response]
+            }
+            None => match self.notification(method, params) {
+                Ok(messages) => messages,
+                Err(err) => vec![json!({ "jsonrpc": "2.0", "method": "window/logMessage", "params": { "type": 1, "message": err.to_string() } })],
+            },
+        }
+    }
+
+    fn request(&mut self, method: &str, params: &Value) -> Result<Value, (i64, String)> {
+        if self.shutdown {
+            return Err((INVALID_REQUEST, "Server is shut down".to_string()));
+        }
+        let invalid_params = |err: anyhow::Error| (INVALID_PARAMS, err.to_string());
+
+        match method {
+            "initialize" => Ok(capabilities()),
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Value::Null)
+            }
+            "textDocument/documentSymbol" => self.document(params).map(features::document_symbols).map_err(invalid_params),
+            "textDocument/foldingRange" => self.document(params).map(features::folding_ranges).map_err(invalid_params),
+            "textDocument/selectionRange" => {
+                let document = self.document(params).map_err(invalid_params)?;
+                let offsets = params["positions"].as_array().into_iter().flatten()
+                    .map(|position| document.offset(position))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(invalid_params)?
+                ;
+                Ok(features::selection_ranges(document, &offsets))
+            }
+            "textDocument/semanticTokens/full" => self.document(params).map(|document| json!({ "data": features::semantic_tokens(document) })).map_err(invalid_params),
+            _ => Err((METHOD_NOT_FOUND, format!("Unknown method: {method}"))),
+        }
+    }
+
+    fn notification(&mut self, method: &str, params: &Value) -> Result<Vec<Value>, anyhow::Error> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default().to_string();
+
+        match method {
+            "exit" => {
+                self.exited = true;
+                Ok(vec![])
+            }
+            "textDocument/didOpen" => {
+                let Some(text) = params["textDocument"]["text"].as_str() else {
+                    bail!("Missing text of document (uri: {uri})");
+                };
+                let version = params["textDocument"]["version"].as_i64().unwrap_or_default();
+                let document = Document::open(&self.parser, text.to_string(), version)?;
+
+                let message = publish_diagnostics(&uri, &document);
+                self.documents.insert(uri, document);
+                Ok(vec![message])
+            }
+            "textDocument/didChange" => {
+                let Some(document) = self.documents.get_mut(&uri) else {
+                    bail!("Document is not opened (uri: {uri})");
+                };
+                for change in params["contentChanges"].as_array().into_iter().flatten() {
+                    document.apply_change(&self.parser, change)?;
+                }
+                if let Some(version) = params["textDocument"]["version"].as_i64() {
+                    document.version = version;
+                }
+                Ok(vec![publish_diagnostics(&uri, document)])
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                Ok(vec![json!({ "jsonrpc": "2.0", "method": "textDocument/publishDiagnostics", "params": { "uri": uri, "diagnostics": [] } })])
+            }
+            // initialized, $/cancelRequest, ...
+            _ => Ok(vec![]),
+        }
+    }
+
+    fn document(&self, params: &Value) -> Result<&Document, anyhow::Error> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        match self.documents.get(uri) {
+            Some(document) => Ok(document),
+            None => bail!("Document is not opened (uri: {uri})"),
+        }
+    }
+}
+
+fn capabilities() -> Value {
+    json!({
+        "capabilities": {
+            "positionEncoding": "utf-16",
+            // Incremental
+            "textDocumentSync": { "openClose": true, "change": 2 },
+            "documentSymbolProvider": true,
+            "foldingRangeProvider": true,
+            "selectionRangeProvider": true,
+            "semanticTokensProvider": {
+                "legend": { "tokenTypes": features::TOKEN_TYPES, "tokenModifiers": [] },
+                "full": true,
+            },
+        },
+        "serverInfo": { "name": "sqlite-lsp", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+fn publish_diagnostics(uri: &str, document: &Document) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "version": document.version, "diagnostics": features::diagnostics(document) },
+    })
+}
+
+// Serve until `exit` or the end of input.
+// Returns the process exit code (0 if `shutdown` was requested).
+pub fn run(mut input: impl BufRead, mut output: impl Write) -> Result<i32, anyhow::Error> {
+    let mut server = Server::new();
+
+    while let Some(message) = read_message(&mut input)? {
+        for reply in server.handle(&message) {
+            write_message(&mut output, &reply)?;
+        }
+        if server.is_exited() {
+            break;
+        }
+    }
+
+    Ok(if server.shutdown { 0 } else { 1 })
+}
+
+#[cfg(test)]
+mod lsp_tests {
+    use std::io::Cursor;
+    use super::*;
+
+    const URI: &str = "file:///test.sql";
+
+    fn request(id: i64, method: &str, params: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+    }
+
+    fn notification(method: &str, params: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "method": method, "params": params })
+    }
+
+    fn position(line: u32, character: u32) -> Value {
+        json!({ "line": line, "character": character })
+    }
+
+    // Send framed messages through `run` and read the replies back
+    fn session(messages: &[Value]) -> Result<(i32, Vec<Value>), anyhow::Error> {
+        let mut input = vec![];
+        for message in messages {
+            write_message(&mut input, message)?;
+        }
+
+        let mut output = vec![];
+        let code = run(Cursor::new(input), &mut output)?;
+
+        let mut reader = Cursor::new(output);
+        let mut replies = vec![];
+        while let Some(reply) = read_message(&mut reader)? {
+            replies.push(reply);
+        }
+        Ok((code, replies))
+    }
+
+    fn response(replies: &[Value], id: i64) -> Value {
+        replies.iter().find(|reply| reply["id"] == json!(id)).cloned().unwrap_or_default()
+    }
+
+    #[test]
+    fn test_scripted_session() -> Result<(), anyhow::Error> {
+        let text = "SELECT 1 FROM;\nCREATE TABLE foo (\n  id INTEGER,\n  name TEXT\n);\n";
+        let (code, replies) = session(&[
+            request(1, "initialize", json!({ "capabilities": {} })),
+            notification("initialized", json!({})),
+            notification("textDocument/didOpen", json!({ "textDocument": { "uri": URI, "languageId": "sql", "version": 1, "text": text } })),
+            request(2, "textDocument/documentSymbol", json!({ "textDocument": { "uri": URI } })),
+            // `FROM;` -> `FROM foo;`
+            notification("textDocument/didChange", json!({
+                "textDocument": { "uri": URI, "version": 2 },
+                "contentChanges": [{ "range": { "start": position(0, 13), "end": position(0, 13) }, "text": " foo" }],
+            })),
+            request(3, "textDocument/semanticTokens/full", json!({ "textDocument": { "uri": URI } })),
+            request(4, "textDocument/unknown", json!({})),
+            request(5, "shutdown", Value::Null),
+            notification("exit", Value::Null),
+        ])?;
+
+        assert_eq!(0, code);
+        assert_eq!(json!(true), response(&replies, 1)["result"]["capabilities"]["documentSymbolProvider"]);
+
+        let diagnostics = replies.iter()
+            .filter(|reply| reply["method"] == "textDocument/publishDiagnostics")
+            .map(|reply| reply["params"]["diagnostics"].as_array().map_or(0, |x| x.len()))
+            .collect::<Vec<_>>()
+        ;
+        assert_eq!(2, diagnostics.len());
+        assert!(diagnostics[0] > 0);
+        assert_eq!(0, diagnostics[1]);
+
+        // The broken `SELECT` has no symbol
+        let symbols = response(&replies, 2)["result"].clone();
+        assert_eq!(Some(1), symbols.as_array().map(Vec::len));
+        assert_eq!(json!("foo"), symbols[0]["name"]);
+        assert_eq!(json!(1), symbols[0]["range"]["start"]["line"]);
+        assert_eq!(json!(["id", "name"]), json!(symbols[0]["children"].as_array().into_iter().flatten().map(|x| x["name"].clone()).collect::<Vec<_>>()));
+
+        // SELECT: line 0, column 0, length 6, keyword
+        let data = response(&replies, 3)["result"]["data"].clone();
+        assert_eq!(json!([0, 0, 6, 0, 0]), json!(data.as_array().map(|x| x[..5].to_vec())));
+
+        assert_eq!(json!(METHOD_NOT_FOUND), response(&replies, 4)["error"]["code"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_change_across_statements() -> Result<(), anyhow::Error> {
+        let mut server = Server::new();
+        server.handle(&notification("textDocument/didOpen", json!({ "textDocument": { "uri": URI, "version": 1, "text": "SELECT 1;\nSELECT 2;\n" } })));
+
+        // Removing the `;` joins the statements, so the text is parsed as a whole
+        let replies = server.handle(&notification("textDocument/didChange", json!({
+            "textDocument": { "uri": URI, "version": 2 },
+            "contentChanges": [{ "range": { "start": position(0, 8), "end": position(0, 9) }, "text": "" }],
+        })));
+        assert_eq!(json!("textDocument/publishDiagnostics"), replies[0]["method"]);
+        assert!(replies[0]["params"]["diagnostics"].as_array().is_some_and(|x| !x.is_empty()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_structure_requests() -> Result<(), anyhow::Error> {
+        let text = "/* first\n   query */\nSELECT a\nFROM (\n  SELECT 1 AS a\n);";
+        let mut server = Server::new();
+        server.handle(&notification("textDocument/didOpen", json!({ "textDocument": { "uri": URI, "version": 1, "text": text } })));
+
+        let folding = server.handle(&request(1, "textDocument/foldingRange", json!({ "textDocument": { "uri": URI } })));
+        let folding = folding[0]["result"].as_array().cloned().unwrap_or_default();
+        assert!(folding.contains(&json!({ "startLine": 0, "endLine": 1, "kind": "comment" })));
+        assert!(folding.contains(&json!({ "startLine": 2, "endLine": 5 })));
+        assert!(folding.contains(&json!({ "startLine": 3, "endLine": 5 })));
+
+        // `a` in the select list
+        let selection = server.handle(&request(2, "textDocument/selectionRange", json!({ "textDocument": { "uri": URI }, "positions": [position(2, 7)] })));
+        let innermost = &selection[0]["result"][0];
+        assert_eq!(json!({ "start": position(2, 7), "end": position(2, 8) }), innermost["range"]);
+        assert!(innermost["parent"].is_object());
+
+        let closed = server.handle(&notification("textDocument/didClose", json!({ "textDocument": { "uri": URI } })));
+        assert_eq!(json!([]), closed[0]["params"]["diagnostics"]);
+        assert_eq!(json!(INVALID_PARAMS), server.handle(&request(3, "textDocument/foldingRange", json!({ "textDocument": { "uri": URI } })))[0]["error"]["code"]);
+        Ok(())
+    }
+}
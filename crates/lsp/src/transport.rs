@@ -0,0 +1,39 @@
+use std::io::{BufRead, Write};
+
+use anyhow::bail;
+use serde_json::Value;
+
+// Read a `Content-Length` framed message. None at the end of input.
+pub fn read_message(reader: &mut impl BufRead) -> Result<Option<Value>, anyhow::Error> {
+    let mut length = None;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        match line.split_once(':') {
+            Some((name, value)) if name.eq_ignore_ascii_case("Content-Length") => length = Some(value.trim().parse::<usize>()?),
+            _ => {}
+        }
+    }
+
+    let Some(length) = length else {
+        bail!("Missing Content-Length header");
+    };
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+pub fn write_message(writer: &mut impl Write, message: &Value) -> Result<(), anyhow::Error> {
+    let body = serde_json::to_string(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    writer.flush()?;
+    Ok(())
+}
@@ -1,6 +1,6 @@
 // Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
 // Options used:
-//   * default-bindings-module: "crate::bindings::app_world"
+//   * default-bindings-module: "crate::api::bindings::app_world"
 #[allow(dead_code, clippy::all)]
 pub mod ritalin {
   pub mod parser {
@@ -49,7 +49,7 @@ pub mod ritalin {
 
           #[cfg(target_arch = "wasm32")]
           {
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
             unsafe extern "C" {
               #[link_name = "[resource-drop]tree"]
               fn drop(_: u32);
@@ -95,7 +95,7 @@ pub mod ritalin {
 
           #[cfg(target_arch = "wasm32")]
           {
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
             unsafe extern "C" {
               #[link_name = "[resource-drop]node"]
               fn drop(_: u32);
@@ -106,6 +106,79 @@ pub mod ritalin {
         }
       }
       
+      #[derive(Clone)]
+      pub enum LiteralValue {
+        Integer(i64),
+        Real(f64),
+        Text(_rt::String),
+        Blob(_rt::Vec::<u8>),
+        Null,
+        Boolean(bool),
+      }
+      impl ::core::fmt::Debug for LiteralValue {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            LiteralValue::Integer(e) => {
+              f.debug_tuple("LiteralValue::Integer").field(e).finish()
+            }
+            LiteralValue::Real(e) => {
+              f.debug_tuple("LiteralValue::Real").field(e).finish()
+            }
+            LiteralValue::Text(e) => {
+              f.debug_tuple("LiteralValue::Text").field(e).finish()
+            }
+            LiteralValue::Blob(e) => {
+              f.debug_tuple("LiteralValue::Blob").field(e).finish()
+            }
+            LiteralValue::Null => {
+              f.debug_tuple("LiteralValue::Null").finish()
+            }
+            LiteralValue::Boolean(e) => {
+              f.debug_tuple("LiteralValue::Boolean").field(e).finish()
+            }
+          }
+        }
+      }
+      #[derive(Clone)]
+      pub enum LiteralError {
+        NotLiteral,
+        NotIdentifier,
+        IntegerOverflow(_rt::String),
+        InvalidReal(_rt::String),
+        InvalidEscape(_rt::String),
+        InvalidBlob(_rt::String),
+      }
+      impl ::core::fmt::Debug for LiteralError {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            LiteralError::NotLiteral => {
+              f.debug_tuple("LiteralError::NotLiteral").finish()
+            }
+            LiteralError::NotIdentifier => {
+              f.debug_tuple("LiteralError::NotIdentifier").finish()
+            }
+            LiteralError::IntegerOverflow(e) => {
+              f.debug_tuple("LiteralError::IntegerOverflow").field(e).finish()
+            }
+            LiteralError::InvalidReal(e) => {
+              f.debug_tuple("LiteralError::InvalidReal").field(e).finish()
+            }
+            LiteralError::InvalidEscape(e) => {
+              f.debug_tuple("LiteralError::InvalidEscape").field(e).finish()
+            }
+            LiteralError::InvalidBlob(e) => {
+              f.debug_tuple("LiteralError::InvalidBlob").field(e).finish()
+            }
+          }
+        }
+      }
+      impl ::core::fmt::Display for LiteralError {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          write!(f, "{:?}", self)
+        }
+      }
+
+      impl std::error::Error for LiteralError {}
       #[repr(u8)]
       #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
       pub enum NodeType {
@@ -201,11 +274,184 @@ pub mod ritalin {
         }
       }
       
+      #[repr(u8)]
+      #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+      pub enum HighlightTag {
+        Keyword,
+        Function,
+        Table,
+        Column,
+        Alias,
+        String,
+        Number,
+        Blob,
+        Parameter,
+        Comment,
+        Operator,
+        Error,
+      }
+      impl ::core::fmt::Debug for HighlightTag {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            HighlightTag::Keyword => {
+              f.debug_tuple("HighlightTag::Keyword").finish()
+            }
+            HighlightTag::Function => {
+              f.debug_tuple("HighlightTag::Function").finish()
+            }
+            HighlightTag::Table => {
+              f.debug_tuple("HighlightTag::Table").finish()
+            }
+            HighlightTag::Column => {
+              f.debug_tuple("HighlightTag::Column").finish()
+            }
+            HighlightTag::Alias => {
+              f.debug_tuple("HighlightTag::Alias").finish()
+            }
+            HighlightTag::String => {
+              f.debug_tuple("HighlightTag::String").finish()
+            }
+            HighlightTag::Number => {
+              f.debug_tuple("HighlightTag::Number").finish()
+            }
+            HighlightTag::Blob => {
+              f.debug_tuple("HighlightTag::Blob").finish()
+            }
+            HighlightTag::Parameter => {
+              f.debug_tuple("HighlightTag::Parameter").finish()
+            }
+            HighlightTag::Comment => {
+              f.debug_tuple("HighlightTag::Comment").finish()
+            }
+            HighlightTag::Operator => {
+              f.debug_tuple("HighlightTag::Operator").finish()
+            }
+            HighlightTag::Error => {
+              f.debug_tuple("HighlightTag::Error").finish()
+            }
+          }
+        }
+      }
+
+      impl HighlightTag{
+        #[doc(hidden)]
+        pub unsafe fn _lift(val: u8) -> HighlightTag{
+          if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+          }
+
+          match val {
+            0 => HighlightTag::Keyword,
+            1 => HighlightTag::Function,
+            2 => HighlightTag::Table,
+            3 => HighlightTag::Column,
+            4 => HighlightTag::Alias,
+            5 => HighlightTag::String,
+            6 => HighlightTag::Number,
+            7 => HighlightTag::Blob,
+            8 => HighlightTag::Parameter,
+            9 => HighlightTag::Comment,
+            10 => HighlightTag::Operator,
+            11 => HighlightTag::Error,
+
+            _ => panic!("invalid enum discriminant"),
+          }
+        }
+      }
+      
+      #[repr(C)]
+      #[derive(Clone, Copy)]
+      pub struct Highlight {
+        pub tag: HighlightTag,
+        pub offset_start: u32,
+        pub offset_end: u32,
+      }
+      impl ::core::fmt::Debug for Highlight {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("Highlight").field("tag", &self.tag).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).finish()
+        }
+      }
+      /// Preorder, `parent` is the index of the parent in the list
+      #[derive(Clone)]
+      pub struct FlatNode {
+        pub kind: SyntaxKind,
+        pub node_type: NodeType,
+        pub parent: Option<u32>,
+        pub offset_start: u32,
+        pub offset_end: u32,
+        pub value: Option<_rt::String>,
+        pub recovery: Option<RecoveryStatus>,
+      }
+      impl ::core::fmt::Debug for FlatNode {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("FlatNode").field("kind", &self.kind).field("node-type", &self.node_type).field("parent", &self.parent).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).field("value", &self.value).field("recovery", &self.recovery).finish()
+        }
+      }
+      #[repr(u8)]
+      #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+      pub enum Severity {
+        Error,
+        Warning,
+        Information,
+        Hint,
+      }
+      impl ::core::fmt::Debug for Severity {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            Severity::Error => {
+              f.debug_tuple("Severity::Error").finish()
+            }
+            Severity::Warning => {
+              f.debug_tuple("Severity::Warning").finish()
+            }
+            Severity::Information => {
+              f.debug_tuple("Severity::Information").finish()
+            }
+            Severity::Hint => {
+              f.debug_tuple("Severity::Hint").finish()
+            }
+          }
+        }
+      }
+
+      impl Severity{
+        #[doc(hidden)]
+        pub unsafe fn _lift(val: u8) -> Severity{
+          if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+          }
+
+          match val {
+            0 => Severity::Error,
+            1 => Severity::Warning,
+            2 => Severity::Information,
+            3 => Severity::Hint,
+
+            _ => panic!("invalid enum discriminant"),
+          }
+        }
+      }
+      
+      #[derive(Clone)]
+      pub struct Diagnostic {
+        pub severity: Severity,
+        pub code: _rt::String,
+        pub message: _rt::String,
+        pub offset_start: u32,
+        pub offset_end: u32,
+        pub expected: _rt::Vec::<SyntaxKind>,
+        pub recovery: Option<RecoveryStatus>,
+      }
+      impl ::core::fmt::Debug for Diagnostic {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("Diagnostic").field("severity", &self.severity).field("code", &self.code).field("message", &self.message).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).field("expected", &self.expected).field("recovery", &self.recovery).finish()
+        }
+      }
       #[derive(Clone)]
       pub struct Metadata {
         pub kind: SyntaxKind,
         pub node_type: NodeType,
-        pub state: u64,
+        pub state: u32,
         pub recovery: Option<RecoveryStatus>,
       }
       impl ::core::fmt::Debug for Metadata {
@@ -219,7 +465,7 @@ pub mod ritalin {
           unsafe {
 
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
             unsafe extern "C" {
               #[link_name = "[method]tree.root"]
               fn wit_import0(_: i32, ) -> i32;
@@ -232,142 +478,127 @@ pub mod ritalin {
           }
         }
       }
-      impl Node {
+      impl Tree {
         #[allow(unused_unsafe, clippy::all)]
-        pub fn metadata(&self,) -> Metadata{
+        pub fn highlight(&self,) -> _rt::Vec::<Highlight>{
           unsafe {
 
-            #[repr(align(8))]
-            struct RetArea([::core::mem::MaybeUninit::<u8>; 24+4*::core::mem::size_of::<*const u8>()]);
-            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 24+4*::core::mem::size_of::<*const u8>()]);
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 2*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
             let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
             unsafe extern "C" {
-              #[link_name = "[method]node.metadata"]
+              #[link_name = "[method]tree.highlight"]
               fn wit_import1(_: i32, _: *mut u8, );
             }
 
             #[cfg(not(target_arch = "wasm32"))]
             unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
             unsafe { wit_import1((self).handle() as i32, ptr0) };
-            let l2 = *ptr0.add(0).cast::<i32>();
-            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-            let l4 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-            let len5 = l4;
-            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
-            let l6 = i32::from(*ptr0.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
-            let l7 = i32::from(*ptr0.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
-            let l8 = i32::from(*ptr0.add(4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-            let l9 = *ptr0.add(8+4*::core::mem::size_of::<*const u8>()).cast::<i64>();
-            let l10 = i32::from(*ptr0.add(16+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-            let result12 = Metadata{
-              kind: super::super::super::ritalin::scanner::types::SyntaxKind{
-                id: l2 as u32,
-                text: _rt::string_lift(bytes5),
-                is_keyword: _rt::bool_lift(l6 as u8),
-                is_terminal: _rt::bool_lift(l7 as u8),
-              },
-              node_type: NodeType::_lift(l8 as u8),
-              state: l9 as u64,
-              recovery: match l10 {
-                0 => None,
-                1 => {
-                  let e = {
-                    let l11 = i32::from(*ptr0.add(17+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-
-                    RecoveryStatus::_lift(l11 as u8)
-                  };
-                  Some(e)
+            let l2 = *ptr0.add(0).cast::<*mut u8>();
+            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base7 = l2;
+            let len7 = l3;
+            let mut result7 = _rt::Vec::with_capacity(len7);
+            for i in 0..len7 {
+              let base = base7.add(i * 12);
+              let e7 = {
+                let l4 = i32::from(*base.add(0).cast::<u8>());
+                let l5 = *base.add(4).cast::<i32>();
+                let l6 = *base.add(8).cast::<i32>();
+
+                Highlight{
+                  tag: HighlightTag::_lift(l4 as u8),
+                  offset_start: l5 as u32,
+                  offset_end: l6 as u32,
                 }
-                _ => _rt::invalid_enum_discriminant(),
-              },
-            };
-            result12
-          }
-        }
-      }
-      impl Node {
-        #[allow(unused_unsafe, clippy::all)]
-        pub fn offset_start(&self,) -> u32{
-          unsafe {
-
-            #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
-            unsafe extern "C" {
-              #[link_name = "[method]node.offset-start"]
-              fn wit_import0(_: i32, ) -> i32;
+              };
+              result7.push(e7);
             }
-
-            #[cfg(not(target_arch = "wasm32"))]
-            unsafe extern "C" fn wit_import0(_: i32, ) -> i32{ unreachable!() }
-            let ret = unsafe { wit_import0((self).handle() as i32) };
-            ret as u32
+            _rt::cabi_dealloc(base7, len7 * 12, 4);
+            let result8 = result7;
+            result8
           }
         }
       }
-      impl Node {
+      impl Tree {
         #[allow(unused_unsafe, clippy::all)]
-        pub fn offset_end(&self,) -> u32{
+        pub fn covering_element(&self,offset_start: u32,offset_end: u32,) -> Option<Node>{
           unsafe {
 
+            #[repr(align(4))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
             unsafe extern "C" {
-              #[link_name = "[method]node.offset-end"]
-              fn wit_import0(_: i32, ) -> i32;
+              #[link_name = "[method]tree.covering-element"]
+              fn wit_import1(_: i32, _: i32, _: i32, _: *mut u8, );
             }
 
             #[cfg(not(target_arch = "wasm32"))]
-            unsafe extern "C" fn wit_import0(_: i32, ) -> i32{ unreachable!() }
-            let ret = unsafe { wit_import0((self).handle() as i32) };
-            ret as u32
+            unsafe extern "C" fn wit_import1(_: i32, _: i32, _: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, _rt::as_i32(&offset_start), _rt::as_i32(&offset_end), ptr0) };
+            let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+            let result4 = match l2 {
+              0 => None,
+              1 => {
+                let e = {
+                  let l3 = *ptr0.add(4).cast::<i32>();
+
+                  unsafe { Node::from_handle(l3 as u32) }
+                };
+                Some(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result4
           }
         }
       }
-      impl Node {
+      impl Tree {
         #[allow(unused_unsafe, clippy::all)]
-        pub fn value(&self,) -> Option<_rt::String>{
+        pub fn token_at_offset(&self,offset: u32,) -> Option<Node>{
           unsafe {
 
-            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
-            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
-            struct RetArea([::core::mem::MaybeUninit::<u8>; 3*::core::mem::size_of::<*const u8>()]);
-            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 3*::core::mem::size_of::<*const u8>()]);
+            #[repr(align(4))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
             let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
             unsafe extern "C" {
-              #[link_name = "[method]node.value"]
-              fn wit_import1(_: i32, _: *mut u8, );
+              #[link_name = "[method]tree.token-at-offset"]
+              fn wit_import1(_: i32, _: i32, _: *mut u8, );
             }
 
             #[cfg(not(target_arch = "wasm32"))]
-            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
-            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            unsafe extern "C" fn wit_import1(_: i32, _: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, _rt::as_i32(&offset), ptr0) };
             let l2 = i32::from(*ptr0.add(0).cast::<u8>());
-            let result6 = match l2 {
+            let result4 = match l2 {
               0 => None,
               1 => {
                 let e = {
-                  let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                  let l4 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                  let len5 = l4;
-                  let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                  let l3 = *ptr0.add(4).cast::<i32>();
 
-                  _rt::string_lift(bytes5)
+                  unsafe { Node::from_handle(l3 as u32) }
                 };
                 Some(e)
               }
               _ => _rt::invalid_enum_discriminant(),
             };
-            result6
+            result4
           }
         }
       }
-      impl Node {
+      impl Tree {
         #[allow(unused_unsafe, clippy::all)]
-        pub fn leading_trivia(&self,) -> _rt::Vec::<Node>{
+        pub fn flatten(&self,) -> _rt::Vec::<FlatNode>{
           unsafe {
 
             #[cfg_attr(target_pointer_width="64", repr(align(8)))]
@@ -376,9 +607,9 @@ pub mod ritalin {
             let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
             let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
             unsafe extern "C" {
-              #[link_name = "[method]node.leading-trivia"]
+              #[link_name = "[method]tree.flatten"]
               fn wit_import1(_: i32, _: *mut u8, );
             }
 
@@ -387,59 +618,401 @@ pub mod ritalin {
             unsafe { wit_import1((self).handle() as i32, ptr0) };
             let l2 = *ptr0.add(0).cast::<*mut u8>();
             let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-            let base5 = l2;
-            let len5 = l3;
-            let mut result5 = _rt::Vec::with_capacity(len5);
-            for i in 0..len5 {
-              let base = base5.add(i * 4);
-              let e5 = {
+            let base21 = l2;
+            let len21 = l3;
+            let mut result21 = _rt::Vec::with_capacity(len21);
+            for i in 0..len21 {
+              let base = base21.add(i * (16+9*::core::mem::size_of::<*const u8>()));
+              let e21 = {
                 let l4 = *base.add(0).cast::<i32>();
-
-                unsafe { Node::from_handle(l4 as u32) }
+                let l5 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                let l6 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                let len7 = l6;
+                let bytes7 = _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+                let l8 = i32::from(*base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                let l9 = i32::from(*base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                let l10 = i32::from(*base.add(4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                let l11 = i32::from(*base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                let l13 = *base.add(12+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                let l14 = *base.add(16+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                let l15 = i32::from(*base.add(16+5*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                let l19 = i32::from(*base.add(16+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                FlatNode{
+                  kind: super::super::super::ritalin::scanner::types::SyntaxKind{
+                    id: l4 as u32,
+                    text: _rt::string_lift(bytes7),
+                    is_keyword: _rt::bool_lift(l8 as u8),
+                    is_terminal: _rt::bool_lift(l9 as u8),
+                  },
+                  node_type: NodeType::_lift(l10 as u8),
+                  parent: match l11 {
+                    0 => None,
+                    1 => {
+                      let e = {
+                        let l12 = *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        l12 as u32
+                      };
+                      Some(e)
+                    }
+                    _ => _rt::invalid_enum_discriminant(),
+                  },
+                  offset_start: l13 as u32,
+                  offset_end: l14 as u32,
+                  value: match l15 {
+                    0 => None,
+                    1 => {
+                      let e = {
+                        let l16 = *base.add(16+6*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l17 = *base.add(16+7*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len18 = l17;
+                        let bytes18 = _rt::Vec::from_raw_parts(l16.cast(), len18, len18);
+
+                        _rt::string_lift(bytes18)
+                      };
+                      Some(e)
+                    }
+                    _ => _rt::invalid_enum_discriminant(),
+                  },
+                  recovery: match l19 {
+                    0 => None,
+                    1 => {
+                      let e = {
+                        let l20 = i32::from(*base.add(17+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                        RecoveryStatus::_lift(l20 as u8)
+                      };
+                      Some(e)
+                    }
+                    _ => _rt::invalid_enum_discriminant(),
+                  },
+                }
               };
-              result5.push(e5);
+              result21.push(e21);
             }
-            _rt::cabi_dealloc(base5, len5 * 4, 4);
-            let result6 = result5;
-            result6
+            _rt::cabi_dealloc(base21, len21 * (16+9*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+            let result22 = result21;
+            result22
           }
         }
       }
-      impl Node {
+      impl Tree {
         #[allow(unused_unsafe, clippy::all)]
-        pub fn traling_trivia(&self,) -> _rt::Vec::<Node>{
+        /// Error is the message of the failed serialization
+        pub fn to_json(&self,) -> Result<_rt::String,_rt::String>{
           unsafe {
 
             #[cfg_attr(target_pointer_width="64", repr(align(8)))]
             #[cfg_attr(target_pointer_width="32", repr(align(4)))]
-            struct RetArea([::core::mem::MaybeUninit::<u8>; 2*::core::mem::size_of::<*const u8>()]);
-            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 3*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 3*::core::mem::size_of::<*const u8>()]);
             let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
             unsafe extern "C" {
-              #[link_name = "[method]node.traling-trivia"]
+              #[link_name = "[method]tree.to-json"]
               fn wit_import1(_: i32, _: *mut u8, );
             }
 
             #[cfg(not(target_arch = "wasm32"))]
             unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
             unsafe { wit_import1((self).handle() as i32, ptr0) };
-            let l2 = *ptr0.add(0).cast::<*mut u8>();
-            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-            let base5 = l2;
-            let len5 = l3;
-            let mut result5 = _rt::Vec::with_capacity(len5);
-            for i in 0..len5 {
-              let base = base5.add(i * 4);
-              let e5 = {
-                let l4 = *base.add(0).cast::<i32>();
+            let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+            let result9 = match l2 {
+              0 => {
+                let e = {
+                  let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                  let l4 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                  let len5 = l4;
+                  let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
 
-                unsafe { Node::from_handle(l4 as u32) }
-              };
-              result5.push(e5);
-            }
-            _rt::cabi_dealloc(base5, len5 * 4, 4);
+                  _rt::string_lift(bytes5)
+                };
+                Ok(e)
+              }
+              1 => {
+                let e = {
+                  let l6 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                  let l7 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                  let len8 = l7;
+                  let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+
+                  _rt::string_lift(bytes8)
+                };
+                Err(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result9
+          }
+        }
+      }
+      impl Tree {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn diagnostics(&self,) -> _rt::Vec::<Diagnostic>{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 2*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]tree.diagnostics"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = *ptr0.add(0).cast::<*mut u8>();
+            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base24 = l2;
+            let len24 = l3;
+            let mut result24 = _rt::Vec::with_capacity(len24);
+            for i in 0..len24 {
+              let base = base24.add(i * (8+8*::core::mem::size_of::<*const u8>()));
+              let e24 = {
+                let l4 = i32::from(*base.add(0).cast::<u8>());
+                let l5 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                let l6 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                let len7 = l6;
+                let bytes7 = _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+                let l8 = *base.add(3*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                let l9 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                let len10 = l9;
+                let bytes10 = _rt::Vec::from_raw_parts(l8.cast(), len10, len10);
+                let l11 = *base.add(5*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                let l12 = *base.add(4+5*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                let l13 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                let l14 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                let base21 = l13;
+                let len21 = l14;
+                let mut result21 = _rt::Vec::with_capacity(len21);
+                for i in 0..len21 {
+                  let base = base21.add(i * (4*::core::mem::size_of::<*const u8>()));
+                  let e21 = {
+                    let l15 = *base.add(0).cast::<i32>();
+                    let l16 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                    let l17 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                    let len18 = l17;
+                    let bytes18 = _rt::Vec::from_raw_parts(l16.cast(), len18, len18);
+                    let l19 = i32::from(*base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                    let l20 = i32::from(*base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                    super::super::super::ritalin::scanner::types::SyntaxKind{
+                      id: l15 as u32,
+                      text: _rt::string_lift(bytes18),
+                      is_keyword: _rt::bool_lift(l19 as u8),
+                      is_terminal: _rt::bool_lift(l20 as u8),
+                    }
+                  };
+                  result21.push(e21);
+                }
+                _rt::cabi_dealloc(base21, len21 * (4*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                let l22 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                Diagnostic{
+                  severity: Severity::_lift(l4 as u8),
+                  code: _rt::string_lift(bytes7),
+                  message: _rt::string_lift(bytes10),
+                  offset_start: l11 as u32,
+                  offset_end: l12 as u32,
+                  expected: result21,
+                  recovery: match l22 {
+                    0 => None,
+                    1 => {
+                      let e = {
+                        let l23 = i32::from(*base.add(9+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                        RecoveryStatus::_lift(l23 as u8)
+                      };
+                      Some(e)
+                    }
+                    _ => _rt::invalid_enum_discriminant(),
+                  },
+                }
+              };
+              result24.push(e24);
+            }
+            _rt::cabi_dealloc(base24, len24 * (8+8*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+            let result25 = result24;
+            result25
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn metadata(&self,) -> Metadata{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8+5*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8+5*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.metadata"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = *ptr0.add(0).cast::<i32>();
+            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l4 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len5 = l4;
+            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+            let l6 = i32::from(*ptr0.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+            let l7 = i32::from(*ptr0.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+            let l8 = i32::from(*ptr0.add(4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+            let l9 = *ptr0.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+            let l10 = i32::from(*ptr0.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+            let result12 = Metadata{
+              kind: super::super::super::ritalin::scanner::types::SyntaxKind{
+                id: l2 as u32,
+                text: _rt::string_lift(bytes5),
+                is_keyword: _rt::bool_lift(l6 as u8),
+                is_terminal: _rt::bool_lift(l7 as u8),
+              },
+              node_type: NodeType::_lift(l8 as u8),
+              state: l9 as u32,
+              recovery: match l10 {
+                0 => None,
+                1 => {
+                  let e = {
+                    let l11 = i32::from(*ptr0.add(9+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                    RecoveryStatus::_lift(l11 as u8)
+                  };
+                  Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+              },
+            };
+            result12
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn offset_start(&self,) -> u32{
+          unsafe {
+
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.offset-start"]
+              fn wit_import0(_: i32, ) -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import0(_: i32, ) -> i32{ unreachable!() }
+            let ret = unsafe { wit_import0((self).handle() as i32) };
+            ret as u32
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn offset_end(&self,) -> u32{
+          unsafe {
+
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.offset-end"]
+              fn wit_import0(_: i32, ) -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import0(_: i32, ) -> i32{ unreachable!() }
+            let ret = unsafe { wit_import0((self).handle() as i32) };
+            ret as u32
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn value(&self,) -> Option<_rt::String>{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 3*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 3*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.value"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+            let result6 = match l2 {
+              0 => None,
+              1 => {
+                let e = {
+                  let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                  let l4 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                  let len5 = l4;
+                  let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+
+                  _rt::string_lift(bytes5)
+                };
+                Some(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result6
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn leading_trivia(&self,) -> _rt::Vec::<Node>{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 2*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.leading-trivia"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = *ptr0.add(0).cast::<*mut u8>();
+            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base5 = l2;
+            let len5 = l3;
+            let mut result5 = _rt::Vec::with_capacity(len5);
+            for i in 0..len5 {
+              let base = base5.add(i * 4);
+              let e5 = {
+                let l4 = *base.add(0).cast::<i32>();
+
+                unsafe { Node::from_handle(l4 as u32) }
+              };
+              result5.push(e5);
+            }
+            _rt::cabi_dealloc(base5, len5 * 4, 4);
             let result6 = result5;
             result6
           }
@@ -447,7 +1020,334 @@ pub mod ritalin {
       }
       impl Node {
         #[allow(unused_unsafe, clippy::all)]
-        pub fn children(&self,) -> _rt::Vec::<Node>{
+        pub fn trailing_trivia(&self,) -> _rt::Vec::<Node>{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 2*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.trailing-trivia"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = *ptr0.add(0).cast::<*mut u8>();
+            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base5 = l2;
+            let len5 = l3;
+            let mut result5 = _rt::Vec::with_capacity(len5);
+            for i in 0..len5 {
+              let base = base5.add(i * 4);
+              let e5 = {
+                let l4 = *base.add(0).cast::<i32>();
+
+                unsafe { Node::from_handle(l4 as u32) }
+              };
+              result5.push(e5);
+            }
+            _rt::cabi_dealloc(base5, len5 * 4, 4);
+            let result6 = result5;
+            result6
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn children(&self,) -> _rt::Vec::<Node>{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 2*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.children"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = *ptr0.add(0).cast::<*mut u8>();
+            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base5 = l2;
+            let len5 = l3;
+            let mut result5 = _rt::Vec::with_capacity(len5);
+            for i in 0..len5 {
+              let base = base5.add(i * 4);
+              let e5 = {
+                let l4 = *base.add(0).cast::<i32>();
+
+                unsafe { Node::from_handle(l4 as u32) }
+              };
+              result5.push(e5);
+            }
+            _rt::cabi_dealloc(base5, len5 * 4, 4);
+            let result6 = result5;
+            result6
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn literal_value(&self,) -> Result<LiteralValue,LiteralError>{
+          unsafe {
+
+            #[repr(align(8))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 16+2*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16+2*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.literal-value"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+            let result28 = match l2 {
+              0 => {
+                let e = {
+                  let l3 = i32::from(*ptr0.add(8).cast::<u8>());
+                  let v13 = match l3 {
+                    0 => {
+                      let e13 = {
+                        let l4 = *ptr0.add(16).cast::<i64>();
+
+                        l4
+                      };
+                      LiteralValue::Integer(e13)
+                    }
+                    1 => {
+                      let e13 = {
+                        let l5 = *ptr0.add(16).cast::<f64>();
+
+                        l5
+                      };
+                      LiteralValue::Real(e13)
+                    }
+                    2 => {
+                      let e13 = {
+                        let l6 = *ptr0.add(16).cast::<*mut u8>();
+                        let l7 = *ptr0.add(16+1*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len8 = l7;
+                        let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+
+                        _rt::string_lift(bytes8)
+                      };
+                      LiteralValue::Text(e13)
+                    }
+                    3 => {
+                      let e13 = {
+                        let l9 = *ptr0.add(16).cast::<*mut u8>();
+                        let l10 = *ptr0.add(16+1*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len11 = l10;
+
+                        _rt::Vec::from_raw_parts(l9.cast(), len11, len11)
+                      };
+                      LiteralValue::Blob(e13)
+                    }
+                    4 => {
+                      LiteralValue::Null
+                    }
+                    n => {
+                      debug_assert_eq!(n, 5, "invalid enum discriminant");
+                      let e13 = {
+                        let l12 = i32::from(*ptr0.add(16).cast::<u8>());
+
+                        _rt::bool_lift(l12 as u8)
+                      };
+                      LiteralValue::Boolean(e13)
+                    }
+                  };
+
+                  v13
+                };
+                Ok(e)
+              }
+              1 => {
+                let e = {
+                  let l14 = i32::from(*ptr0.add(8).cast::<u8>());
+                  let v27 = match l14 {
+                    0 => {
+                      LiteralError::NotLiteral
+                    }
+                    1 => {
+                      LiteralError::NotIdentifier
+                    }
+                    2 => {
+                      let e27 = {
+                        let l15 = *ptr0.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l16 = *ptr0.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len17 = l16;
+                        let bytes17 = _rt::Vec::from_raw_parts(l15.cast(), len17, len17);
+
+                        _rt::string_lift(bytes17)
+                      };
+                      LiteralError::IntegerOverflow(e27)
+                    }
+                    3 => {
+                      let e27 = {
+                        let l18 = *ptr0.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l19 = *ptr0.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len20 = l19;
+                        let bytes20 = _rt::Vec::from_raw_parts(l18.cast(), len20, len20);
+
+                        _rt::string_lift(bytes20)
+                      };
+                      LiteralError::InvalidReal(e27)
+                    }
+                    4 => {
+                      let e27 = {
+                        let l21 = *ptr0.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l22 = *ptr0.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len23 = l22;
+                        let bytes23 = _rt::Vec::from_raw_parts(l21.cast(), len23, len23);
+
+                        _rt::string_lift(bytes23)
+                      };
+                      LiteralError::InvalidEscape(e27)
+                    }
+                    n => {
+                      debug_assert_eq!(n, 5, "invalid enum discriminant");
+                      let e27 = {
+                        let l24 = *ptr0.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l25 = *ptr0.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len26 = l25;
+                        let bytes26 = _rt::Vec::from_raw_parts(l24.cast(), len26, len26);
+
+                        _rt::string_lift(bytes26)
+                      };
+                      LiteralError::InvalidBlob(e27)
+                    }
+                  };
+
+                  v27
+                };
+                Err(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result28
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn identifier(&self,) -> Result<_rt::String,LiteralError>{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 4*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 4*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.identifier"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+            let result20 = match l2 {
+              0 => {
+                let e = {
+                  let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                  let l4 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                  let len5 = l4;
+                  let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+
+                  _rt::string_lift(bytes5)
+                };
+                Ok(e)
+              }
+              1 => {
+                let e = {
+                  let l6 = i32::from(*ptr0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+                  let v19 = match l6 {
+                    0 => {
+                      LiteralError::NotLiteral
+                    }
+                    1 => {
+                      LiteralError::NotIdentifier
+                    }
+                    2 => {
+                      let e19 = {
+                        let l7 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l8 = *ptr0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len9 = l8;
+                        let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+                        _rt::string_lift(bytes9)
+                      };
+                      LiteralError::IntegerOverflow(e19)
+                    }
+                    3 => {
+                      let e19 = {
+                        let l10 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l11 = *ptr0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len12 = l11;
+                        let bytes12 = _rt::Vec::from_raw_parts(l10.cast(), len12, len12);
+
+                        _rt::string_lift(bytes12)
+                      };
+                      LiteralError::InvalidReal(e19)
+                    }
+                    4 => {
+                      let e19 = {
+                        let l13 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l14 = *ptr0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len15 = l14;
+                        let bytes15 = _rt::Vec::from_raw_parts(l13.cast(), len15, len15);
+
+                        _rt::string_lift(bytes15)
+                      };
+                      LiteralError::InvalidEscape(e19)
+                    }
+                    n => {
+                      debug_assert_eq!(n, 5, "invalid enum discriminant");
+                      let e19 = {
+                        let l16 = *ptr0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l17 = *ptr0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len18 = l17;
+                        let bytes18 = _rt::Vec::from_raw_parts(l16.cast(), len18, len18);
+
+                        _rt::string_lift(bytes18)
+                      };
+                      LiteralError::InvalidBlob(e19)
+                    }
+                  };
+
+                  v19
+                };
+                Err(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result20
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn text(&self,) -> _rt::String{
           unsafe {
 
             #[cfg_attr(target_pointer_width="64", repr(align(8)))]
@@ -456,9 +1356,74 @@ pub mod ritalin {
             let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
             let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/syntax@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
             unsafe extern "C" {
-              #[link_name = "[method]node.children"]
+              #[link_name = "[method]node.text"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = *ptr0.add(0).cast::<*mut u8>();
+            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len4 = l3;
+            let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+            let result5 = _rt::string_lift(bytes4);
+            result5
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn parent(&self,) -> Option<Node>{
+          unsafe {
+
+            #[repr(align(4))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.parent"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+            let result4 = match l2 {
+              0 => None,
+              1 => {
+                let e = {
+                  let l3 = *ptr0.add(4).cast::<i32>();
+
+                  unsafe { Node::from_handle(l3 as u32) }
+                };
+                Some(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result4
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn ancestors(&self,) -> _rt::Vec::<Node>{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 2*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.ancestors"]
               fn wit_import1(_: i32, _: *mut u8, );
             }
 
@@ -485,6 +1450,78 @@ pub mod ritalin {
           }
         }
       }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn next_sibling(&self,) -> Option<Node>{
+          unsafe {
+
+            #[repr(align(4))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.next-sibling"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+            let result4 = match l2 {
+              0 => None,
+              1 => {
+                let e = {
+                  let l3 = *ptr0.add(4).cast::<i32>();
+
+                  unsafe { Node::from_handle(l3 as u32) }
+                };
+                Some(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result4
+          }
+        }
+      }
+      impl Node {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn prev_sibling(&self,) -> Option<Node>{
+          unsafe {
+
+            #[repr(align(4))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/syntax@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]node.prev-sibling"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+            let result4 = match l2 {
+              0 => None,
+              1 => {
+                let e = {
+                  let l3 = *ptr0.add(4).cast::<i32>();
+
+                  unsafe { Node::from_handle(l3 as u32) }
+                };
+                Some(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result4
+          }
+        }
+      }
 
     }
 
@@ -534,7 +1571,7 @@ pub mod ritalin {
 
           #[cfg(target_arch = "wasm32")]
           {
-            #[link(wasm_import_module = "ritalin:parser/parsers@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
             unsafe extern "C" {
               #[link_name = "[resource-drop]parser"]
               fn drop(_: u32);
@@ -545,10 +1582,25 @@ pub mod ritalin {
         }
       }
       
+      /// Range is where parsing stopped, or the edit scope for edit errors.
+      /// Code is one of `parser/parse-failed`, `parser/edit-not-found`, `parser/edit-in-fatal-error`,
+      /// `parser/invalid-edit` or `parser/internal-error` (the whole source or edit scope).
+      #[derive(Clone)]
+      pub struct ErrorDetail {
+        pub code: _rt::String,
+        pub message: _rt::String,
+        pub offset_start: u32,
+        pub offset_end: u32,
+      }
+      impl ::core::fmt::Debug for ErrorDetail {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("ErrorDetail").field("code", &self.code).field("message", &self.message).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).finish()
+        }
+      }
       #[derive(Clone)]
       pub enum ParserError {
-        SyntaxError(_rt::String),
-        IncrementalEditError(_rt::String),
+        SyntaxError(ErrorDetail),
+        IncrementalEditError(ErrorDetail),
       }
       impl ::core::fmt::Debug for ParserError {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -559,36 +1611,83 @@ pub mod ritalin {
             ParserError::IncrementalEditError(e) => {
               f.debug_tuple("ParserError::IncrementalEditError").field(e).finish()
             }
-          }
-        }
-      }
-      impl ::core::fmt::Display for ParserError {
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-          write!(f, "{:?}", self)
-        }
-      }
+          }
+        }
+      }
+      impl ::core::fmt::Display for ParserError {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          write!(f, "{:?}", self)
+        }
+      }
+
+      impl std::error::Error for ParserError {}
+      #[repr(C)]
+      #[derive(Clone, Copy)]
+      pub struct EditScope {
+        pub offset: u32,
+        pub from_len: u32,
+        pub to_len: u32,
+      }
+      impl ::core::fmt::Debug for EditScope {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("EditScope").field("offset", &self.offset).field("from-len", &self.from_len).field("to-len", &self.to_len).finish()
+        }
+      }
+
+      #[derive(Debug)]
+      #[repr(transparent)]
+      pub struct IncrementalParser{
+        handle: _rt::Resource<IncrementalParser>,
+      }
+
+      impl IncrementalParser{
+        #[doc(hidden)]
+        pub unsafe fn from_handle(handle: u32) -> Self {
+          Self {
+            handle: unsafe { _rt::Resource::from_handle(handle) },
+          }
+        }
+
+        #[doc(hidden)]
+        pub fn take_handle(&self) -> u32 {
+          _rt::Resource::take_handle(&self.handle)
+        }
+
+        #[doc(hidden)]
+        pub fn handle(&self) -> u32 {
+          _rt::Resource::handle(&self.handle)
+        }
+      }
+      
+
+      unsafe impl _rt::WasmResource for IncrementalParser{
+        #[inline]
+        unsafe fn drop(_handle: u32) {
+          #[cfg(not(target_arch = "wasm32"))]
+          unreachable!();
+
+          #[cfg(target_arch = "wasm32")]
+          {
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[resource-drop]incremental-parser"]
+              fn drop(_: u32);
+            }
 
-      impl std::error::Error for ParserError {}
-      #[repr(C)]
-      #[derive(Clone, Copy)]
-      pub struct EditScope {
-        pub offset: u32,
-        pub from_len: u32,
-        pub to_len: u32,
-      }
-      impl ::core::fmt::Debug for EditScope {
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-          f.debug_struct("EditScope").field("offset", &self.offset).field("from-len", &self.from_len).field("to-len", &self.to_len).finish()
+            unsafe { drop(_handle) };
+          }
         }
       }
+      
+      /// Owns the source and the current tree, so only edits cross the boundary
 
       #[derive(Debug)]
       #[repr(transparent)]
-      pub struct IncrementalParser{
-        handle: _rt::Resource<IncrementalParser>,
+      pub struct Document{
+        handle: _rt::Resource<Document>,
       }
 
-      impl IncrementalParser{
+      impl Document{
         #[doc(hidden)]
         pub unsafe fn from_handle(handle: u32) -> Self {
           Self {
@@ -608,7 +1707,7 @@ pub mod ritalin {
       }
       
 
-      unsafe impl _rt::WasmResource for IncrementalParser{
+      unsafe impl _rt::WasmResource for Document{
         #[inline]
         unsafe fn drop(_handle: u32) {
           #[cfg(not(target_arch = "wasm32"))]
@@ -616,9 +1715,9 @@ pub mod ritalin {
 
           #[cfg(target_arch = "wasm32")]
           {
-            #[link(wasm_import_module = "ritalin:parser/parsers@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
             unsafe extern "C" {
-              #[link_name = "[resource-drop]incremental-parser"]
+              #[link_name = "[resource-drop]document"]
               fn drop(_: u32);
             }
 
@@ -627,13 +1726,25 @@ pub mod ritalin {
         }
       }
       
+      /// Range of the reparsed statements in the new source (empty if none changed)
+      pub struct TreeDelta {
+        pub tree: SyntaxTree,
+        pub offset_start: u32,
+        pub offset_end: u32,
+        pub incremental: bool,
+      }
+      impl ::core::fmt::Debug for TreeDelta {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("TreeDelta").field("tree", &self.tree).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).field("incremental", &self.incremental).finish()
+        }
+      }
       impl Parser {
         #[allow(unused_unsafe, clippy::all)]
         pub fn new() -> Self{
           unsafe {
 
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/parsers@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
             unsafe extern "C" {
               #[link_name = "[constructor]parser"]
               fn wit_import0() -> i32;
@@ -653,14 +1764,14 @@ pub mod ritalin {
 
             #[cfg_attr(target_pointer_width="64", repr(align(8)))]
             #[cfg_attr(target_pointer_width="32", repr(align(4)))]
-            struct RetArea([::core::mem::MaybeUninit::<u8>; 4*::core::mem::size_of::<*const u8>()]);
-            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 4*::core::mem::size_of::<*const u8>()]);
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8+6*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8+6*::core::mem::size_of::<*const u8>()]);
             let vec0 = source;
             let ptr0 = vec0.as_ptr().cast::<u8>();
             let len0 = vec0.len();
             let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/parsers@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
             unsafe extern "C" {
               #[link_name = "[method]parser.parse"]
               fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8, );
@@ -670,7 +1781,7 @@ pub mod ritalin {
             unsafe extern "C" fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8, ){ unreachable!() }
             unsafe { wit_import2((self).handle() as i32, ptr0.cast_mut(), len0, ptr1) };
             let l3 = i32::from(*ptr1.add(0).cast::<u8>());
-            let result13 = match l3 {
+            let result23 = match l3 {
               0 => {
                 let e = {
                   let l4 = *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<i32>();
@@ -682,39 +1793,61 @@ pub mod ritalin {
               1 => {
                 let e = {
                   let l5 = i32::from(*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
-                  let v12 = match l5 {
+                  let v22 = match l5 {
                     0 => {
-                      let e12 = {
+                      let e22 = {
                         let l6 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
                         let l7 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
                         let len8 = l7;
                         let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
-
-                        _rt::string_lift(bytes8)
+                        let l9 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l10 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len11 = l10;
+                        let bytes11 = _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
+                        let l12 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l13 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes8),
+                          message: _rt::string_lift(bytes11),
+                          offset_start: l12 as u32,
+                          offset_end: l13 as u32,
+                        }
                       };
-                      ParserError::SyntaxError(e12)
+                      ParserError::SyntaxError(e22)
                     }
                     n => {
                       debug_assert_eq!(n, 1, "invalid enum discriminant");
-                      let e12 = {
-                        let l9 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                        let l10 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                        let len11 = l10;
-                        let bytes11 = _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
-
-                        _rt::string_lift(bytes11)
+                      let e22 = {
+                        let l14 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l15 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len16 = l15;
+                        let bytes16 = _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+                        let l17 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l18 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len19 = l18;
+                        let bytes19 = _rt::Vec::from_raw_parts(l17.cast(), len19, len19);
+                        let l20 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l21 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes16),
+                          message: _rt::string_lift(bytes19),
+                          offset_start: l20 as u32,
+                          offset_end: l21 as u32,
+                        }
                       };
-                      ParserError::IncrementalEditError(e12)
+                      ParserError::IncrementalEditError(e22)
                     }
                   };
 
-                  v12
+                  v22
                 };
                 Err(e)
               }
               _ => _rt::invalid_enum_discriminant(),
             };
-            result13
+            result23
           }
         }
       }
@@ -725,12 +1858,12 @@ pub mod ritalin {
 
             #[cfg_attr(target_pointer_width="64", repr(align(8)))]
             #[cfg_attr(target_pointer_width="32", repr(align(4)))]
-            struct RetArea([::core::mem::MaybeUninit::<u8>; 4*::core::mem::size_of::<*const u8>()]);
-            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 4*::core::mem::size_of::<*const u8>()]);
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8+6*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8+6*::core::mem::size_of::<*const u8>()]);
             let EditScope{ offset:offset0, from_len:from_len0, to_len:to_len0, } = edit;
             let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/parsers@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
             unsafe extern "C" {
               #[link_name = "[method]parser.incremental"]
               fn wit_import2(_: i32, _: i32, _: i32, _: i32, _: i32, _: *mut u8, );
@@ -740,7 +1873,7 @@ pub mod ritalin {
             unsafe extern "C" fn wit_import2(_: i32, _: i32, _: i32, _: i32, _: i32, _: *mut u8, ){ unreachable!() }
             unsafe { wit_import2((self).handle() as i32, (&tree).take_handle() as i32, _rt::as_i32(offset0), _rt::as_i32(from_len0), _rt::as_i32(to_len0), ptr1) };
             let l3 = i32::from(*ptr1.add(0).cast::<u8>());
-            let result13 = match l3 {
+            let result23 = match l3 {
               0 => {
                 let e = {
                   let l4 = *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<i32>();
@@ -752,39 +1885,61 @@ pub mod ritalin {
               1 => {
                 let e = {
                   let l5 = i32::from(*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
-                  let v12 = match l5 {
+                  let v22 = match l5 {
                     0 => {
-                      let e12 = {
+                      let e22 = {
                         let l6 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
                         let l7 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
                         let len8 = l7;
                         let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
-
-                        _rt::string_lift(bytes8)
+                        let l9 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l10 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len11 = l10;
+                        let bytes11 = _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
+                        let l12 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l13 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes8),
+                          message: _rt::string_lift(bytes11),
+                          offset_start: l12 as u32,
+                          offset_end: l13 as u32,
+                        }
                       };
-                      ParserError::SyntaxError(e12)
+                      ParserError::SyntaxError(e22)
                     }
                     n => {
                       debug_assert_eq!(n, 1, "invalid enum discriminant");
-                      let e12 = {
-                        let l9 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                        let l10 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                        let len11 = l10;
-                        let bytes11 = _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
-
-                        _rt::string_lift(bytes11)
+                      let e22 = {
+                        let l14 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l15 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len16 = l15;
+                        let bytes16 = _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+                        let l17 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l18 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len19 = l18;
+                        let bytes19 = _rt::Vec::from_raw_parts(l17.cast(), len19, len19);
+                        let l20 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l21 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes16),
+                          message: _rt::string_lift(bytes19),
+                          offset_start: l20 as u32,
+                          offset_end: l21 as u32,
+                        }
                       };
-                      ParserError::IncrementalEditError(e12)
+                      ParserError::IncrementalEditError(e22)
                     }
                   };
 
-                  v12
+                  v22
                 };
                 Err(e)
               }
               _ => _rt::invalid_enum_discriminant(),
             };
-            result13
+            result23
           }
         }
       }
@@ -795,14 +1950,14 @@ pub mod ritalin {
 
             #[cfg_attr(target_pointer_width="64", repr(align(8)))]
             #[cfg_attr(target_pointer_width="32", repr(align(4)))]
-            struct RetArea([::core::mem::MaybeUninit::<u8>; 4*::core::mem::size_of::<*const u8>()]);
-            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 4*::core::mem::size_of::<*const u8>()]);
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8+6*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8+6*::core::mem::size_of::<*const u8>()]);
             let vec0 = source;
             let ptr0 = vec0.as_ptr().cast::<u8>();
             let len0 = vec0.len();
             let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
             #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "ritalin:parser/parsers@0.0.1")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
             unsafe extern "C" {
               #[link_name = "[method]incremental-parser.parse"]
               fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8, );
@@ -812,7 +1967,7 @@ pub mod ritalin {
             unsafe extern "C" fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8, ){ unreachable!() }
             unsafe { wit_import2((self).handle() as i32, ptr0.cast_mut(), len0, ptr1) };
             let l3 = i32::from(*ptr1.add(0).cast::<u8>());
-            let result13 = match l3 {
+            let result23 = match l3 {
               0 => {
                 let e = {
                   let l4 = *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<i32>();
@@ -824,39 +1979,305 @@ pub mod ritalin {
               1 => {
                 let e = {
                   let l5 = i32::from(*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
-                  let v12 = match l5 {
+                  let v22 = match l5 {
                     0 => {
-                      let e12 = {
+                      let e22 = {
                         let l6 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
                         let l7 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
                         let len8 = l7;
                         let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+                        let l9 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l10 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len11 = l10;
+                        let bytes11 = _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
+                        let l12 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l13 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes8),
+                          message: _rt::string_lift(bytes11),
+                          offset_start: l12 as u32,
+                          offset_end: l13 as u32,
+                        }
+                      };
+                      ParserError::SyntaxError(e22)
+                    }
+                    n => {
+                      debug_assert_eq!(n, 1, "invalid enum discriminant");
+                      let e22 = {
+                        let l14 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l15 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len16 = l15;
+                        let bytes16 = _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+                        let l17 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l18 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len19 = l18;
+                        let bytes19 = _rt::Vec::from_raw_parts(l17.cast(), len19, len19);
+                        let l20 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l21 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes16),
+                          message: _rt::string_lift(bytes19),
+                          offset_start: l20 as u32,
+                          offset_end: l21 as u32,
+                        }
+                      };
+                      ParserError::IncrementalEditError(e22)
+                    }
+                  };
 
-                        _rt::string_lift(bytes8)
+                  v22
+                };
+                Err(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result23
+          }
+        }
+      }
+      impl Document {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn create(source: &str,) -> Result<Document,ParserError>{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8+6*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8+6*::core::mem::size_of::<*const u8>()]);
+            let vec0 = source;
+            let ptr0 = vec0.as_ptr().cast::<u8>();
+            let len0 = vec0.len();
+            let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[static]document.create"]
+              fn wit_import2(_: *mut u8, _: usize, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1) };
+            let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+            let result23 = match l3 {
+              0 => {
+                let e = {
+                  let l4 = *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                  unsafe { Document::from_handle(l4 as u32) }
+                };
+                Ok(e)
+              }
+              1 => {
+                let e = {
+                  let l5 = i32::from(*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+                  let v22 = match l5 {
+                    0 => {
+                      let e22 = {
+                        let l6 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l7 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len8 = l7;
+                        let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+                        let l9 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l10 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len11 = l10;
+                        let bytes11 = _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
+                        let l12 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l13 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes8),
+                          message: _rt::string_lift(bytes11),
+                          offset_start: l12 as u32,
+                          offset_end: l13 as u32,
+                        }
                       };
-                      ParserError::SyntaxError(e12)
+                      ParserError::SyntaxError(e22)
                     }
                     n => {
                       debug_assert_eq!(n, 1, "invalid enum discriminant");
-                      let e12 = {
+                      let e22 = {
+                        let l14 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l15 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len16 = l15;
+                        let bytes16 = _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+                        let l17 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l18 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len19 = l18;
+                        let bytes19 = _rt::Vec::from_raw_parts(l17.cast(), len19, len19);
+                        let l20 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l21 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes16),
+                          message: _rt::string_lift(bytes19),
+                          offset_start: l20 as u32,
+                          offset_end: l21 as u32,
+                        }
+                      };
+                      ParserError::IncrementalEditError(e22)
+                    }
+                  };
+
+                  v22
+                };
+                Err(e)
+              }
+              _ => _rt::invalid_enum_discriminant(),
+            };
+            result23
+          }
+        }
+      }
+      impl Document {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn source(&self,) -> _rt::String{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 2*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2*::core::mem::size_of::<*const u8>()]);
+            let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]document.source"]
+              fn wit_import1(_: i32, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import1(_: i32, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import1((self).handle() as i32, ptr0) };
+            let l2 = *ptr0.add(0).cast::<*mut u8>();
+            let l3 = *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len4 = l3;
+            let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+            let result5 = _rt::string_lift(bytes4);
+            result5
+          }
+        }
+      }
+      impl Document {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn tree(&self,) -> SyntaxTree{
+          unsafe {
+
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]document.tree"]
+              fn wit_import0(_: i32, ) -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import0(_: i32, ) -> i32{ unreachable!() }
+            let ret = unsafe { wit_import0((self).handle() as i32) };
+            unsafe { super::super::super::ritalin::parser::syntax::Tree::from_handle(ret as u32) }
+          }
+        }
+      }
+      impl Document {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn apply_edit(&self,offset: u32,delete_len: u32,insert_text: &str,) -> Result<TreeDelta,ParserError>{
+          unsafe {
+
+            #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+            #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+            struct RetArea([::core::mem::MaybeUninit::<u8>; 8+6*::core::mem::size_of::<*const u8>()]);
+            let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8+6*::core::mem::size_of::<*const u8>()]);
+            let vec0 = insert_text;
+            let ptr0 = vec0.as_ptr().cast::<u8>();
+            let len0 = vec0.len();
+            let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "ritalin:parser/parsers@0.1.0")]
+            unsafe extern "C" {
+              #[link_name = "[method]document.apply-edit"]
+              fn wit_import2(_: i32, _: i32, _: i32, _: *mut u8, _: usize, _: *mut u8, );
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            unsafe extern "C" fn wit_import2(_: i32, _: i32, _: i32, _: *mut u8, _: usize, _: *mut u8, ){ unreachable!() }
+            unsafe { wit_import2((self).handle() as i32, _rt::as_i32(&offset), _rt::as_i32(&delete_len), ptr0.cast_mut(), len0, ptr1) };
+            let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+            let result26 = match l3 {
+              0 => {
+                let e = {
+                  let l4 = *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<i32>();
+                  let l5 = *ptr1.add(4+1*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                  let l6 = *ptr1.add(8+1*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                  let l7 = i32::from(*ptr1.add(12+1*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                  TreeDelta{
+                    tree: unsafe { super::super::super::ritalin::parser::syntax::Tree::from_handle(l4 as u32) },
+                    offset_start: l5 as u32,
+                    offset_end: l6 as u32,
+                    incremental: _rt::bool_lift(l7 as u8),
+                  }
+                };
+                Ok(e)
+              }
+              1 => {
+                let e = {
+                  let l8 = i32::from(*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+                  let v25 = match l8 {
+                    0 => {
+                      let e25 = {
                         let l9 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
                         let l10 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
                         let len11 = l10;
                         let bytes11 = _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
-
-                        _rt::string_lift(bytes11)
+                        let l12 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l13 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len14 = l13;
+                        let bytes14 = _rt::Vec::from_raw_parts(l12.cast(), len14, len14);
+                        let l15 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l16 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes11),
+                          message: _rt::string_lift(bytes14),
+                          offset_start: l15 as u32,
+                          offset_end: l16 as u32,
+                        }
+                      };
+                      ParserError::SyntaxError(e25)
+                    }
+                    n => {
+                      debug_assert_eq!(n, 1, "invalid enum discriminant");
+                      let e25 = {
+                        let l17 = *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l18 = *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len19 = l18;
+                        let bytes19 = _rt::Vec::from_raw_parts(l17.cast(), len19, len19);
+                        let l20 = *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l21 = *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let len22 = l21;
+                        let bytes22 = _rt::Vec::from_raw_parts(l20.cast(), len22, len22);
+                        let l23 = *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                        let l24 = *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+
+                        ErrorDetail{
+                          code: _rt::string_lift(bytes19),
+                          message: _rt::string_lift(bytes22),
+                          offset_start: l23 as u32,
+                          offset_end: l24 as u32,
+                        }
                       };
-                      ParserError::IncrementalEditError(e12)
+                      ParserError::IncrementalEditError(e25)
                     }
                   };
 
-                  v12
+                  v25
                 };
                 Err(e)
               }
               _ => _rt::invalid_enum_discriminant(),
             };
-            result13
+            result26
           }
         }
       }
@@ -887,15 +2308,35 @@ pub mod ritalin {
         }
       }
       #[derive(Clone)]
+      pub enum TokenError {
+        IllegalCharacter,
+        /// String, quoted identifier or block comment reaching the end of input (kind is the intended one)
+        Unterminated(SyntaxKind),
+      }
+      impl ::core::fmt::Debug for TokenError {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            TokenError::IllegalCharacter => {
+              f.debug_tuple("TokenError::IllegalCharacter").finish()
+            }
+            TokenError::Unterminated(e) => {
+              f.debug_tuple("TokenError::Unterminated").field(e).finish()
+            }
+          }
+        }
+      }
+      #[derive(Clone)]
       pub struct TokenItem {
         pub kind: SyntaxKind,
         pub offset: u32,
         pub len: u32,
         pub value: Option<_rt::String>,
+        /// Set on `ILLEGAL` items
+        pub error: Option<TokenError>,
       }
       impl ::core::fmt::Debug for TokenItem {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-          f.debug_struct("TokenItem").field("kind", &self.kind).field("offset", &self.offset).field("len", &self.len).field("value", &self.value).finish()
+          f.debug_struct("TokenItem").field("kind", &self.kind).field("offset", &self.offset).field("len", &self.len).field("value", &self.value).field("error", &self.error).finish()
         }
       }
       #[derive(Clone)]
@@ -919,6 +2360,18 @@ pub mod ritalin {
           f.debug_struct("ScannerScope").field("next-index", &self.next_index).field("lookahead", &self.lookahead).finish()
         }
       }
+      #[repr(C)]
+      #[derive(Clone, Copy)]
+      pub struct EditScope {
+        pub offset: u32,
+        pub from_len: u32,
+        pub to_len: u32,
+      }
+      impl ::core::fmt::Debug for EditScope {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("EditScope").field("offset", &self.offset).field("from-len", &self.from_len).field("to-len", &self.to_len).finish()
+        }
+      }
 
     }
 
@@ -1024,31 +2477,6 @@ mod _rt {
       }
     }
   }
-  pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
-    if cfg!(debug_assertions) {
-      String::from_utf8(bytes).unwrap()
-    } else {
-      String::from_utf8_unchecked(bytes)
-    }
-  }
-  pub unsafe fn bool_lift(val: u8) -> bool {
-    if cfg!(debug_assertions) {
-      match val {
-        0 => false,
-        1 => true,
-        _ => panic!("invalid bool discriminant"),
-      }
-    } else {
-      val != 0
-    }
-  }
-  pub unsafe fn invalid_enum_discriminant<T>() -> T {
-    if cfg!(debug_assertions) {
-      panic!("invalid enum discriminant")
-    } else {
-      unsafe { core::hint::unreachable_unchecked() }
-    }
-  }
   pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
     if size == 0 {
       return;
@@ -1126,6 +2554,31 @@ mod _rt {
       self as i32
     }
   }
+  pub unsafe fn invalid_enum_discriminant<T>() -> T {
+    if cfg!(debug_assertions) {
+      panic!("invalid enum discriminant")
+    } else {
+      unsafe { core::hint::unreachable_unchecked() }
+    }
+  }
+  pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+    if cfg!(debug_assertions) {
+      String::from_utf8(bytes).unwrap()
+    } else {
+      String::from_utf8_unchecked(bytes)
+    }
+  }
+  pub unsafe fn bool_lift(val: u8) -> bool {
+    if cfg!(debug_assertions) {
+      match val {
+        0 => false,
+        1 => true,
+        _ => panic!("invalid bool discriminant"),
+      }
+    } else {
+      val != 0
+    }
+  }
   extern crate alloc as alloc_crate;
   pub use alloc_crate::alloc;
 }
@@ -1134,38 +2587,69 @@ mod _rt {
 #[unsafe(link_section = "component-type:wit-bindgen:0.41.0:ritalin:parser-app@0.0.1:app-world:encoded world")]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1395] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xf3\x09\x01A\x02\x01\
-A\x08\x01B\x0c\x01r\x04\x02idy\x04texts\x0ais-keyword\x7f\x0bis-terminal\x7f\x04\
-\0\x0bsyntax-kind\x03\0\0\x01ks\x01r\x04\x04kind\x01\x06offsety\x03leny\x05value\
-\x02\x04\0\x0atoken-item\x03\0\x03\x01p\x04\x01k\x05\x01r\x03\x07leading\x06\x04\
-main\x04\x08trailing\x06\x04\0\x05token\x03\0\x07\x01k\x08\x01r\x02\x0anext-inde\
-xy\x09lookahead\x09\x04\0\x0dscanner-scope\x03\0\x0a\x03\0\x1britalin:scanner/ty\
-pes@0.0.1\x05\0\x02\x03\0\0\x0bsyntax-kind\x01B\x1d\x02\x03\x02\x01\x01\x04\0\x0b\
-syntax-kind\x03\0\0\x04\0\x04tree\x03\x01\x04\0\x04node\x03\x01\x01m\x07\x09toke\
-n-set\x0dleading-token\x0etrailing-token\x0amain-token\x04node\x05error\x0bfatal\
--error\x04\0\x09node-type\x03\0\x04\x01m\x02\x06delete\x05shift\x04\0\x0frecover\
-y-status\x03\0\x06\x01k\x07\x01r\x04\x04kind\x01\x09node-type\x05\x05statew\x08r\
-ecovery\x08\x04\0\x08metadata\x03\0\x09\x01h\x02\x01i\x03\x01@\x01\x04self\x0b\0\
-\x0c\x04\0\x11[method]tree.root\x01\x0d\x01h\x03\x01@\x01\x04self\x0e\0\x0a\x04\0\
-\x15[method]node.metadata\x01\x0f\x01@\x01\x04self\x0e\0y\x04\0\x19[method]node.\
-offset-start\x01\x10\x04\0\x17[method]node.offset-end\x01\x10\x01ks\x01@\x01\x04\
-self\x0e\0\x11\x04\0\x12[method]node.value\x01\x12\x01p\x0c\x01@\x01\x04self\x0e\
-\0\x13\x04\0\x1b[method]node.leading-trivia\x01\x14\x04\0\x1b[method]node.tralin\
-g-trivia\x01\x14\x04\0\x15[method]node.children\x01\x14\x03\0\x1britalin:parser/\
-syntax@0.0.1\x05\x02\x02\x03\0\x01\x04tree\x01B\x18\x02\x03\x02\x01\x03\x04\0\x04\
-tree\x03\0\0\x04\0\x0bsyntax-tree\x03\0\x01\x04\0\x06parser\x03\x01\x01q\x02\x0c\
-syntax-error\x01s\0\x16incremental-edit-error\x01s\0\x04\0\x0cparser-error\x03\0\
-\x04\x01r\x03\x06offsety\x08from-leny\x06to-leny\x04\0\x0aedit-scope\x03\0\x06\x04\
-\0\x12incremental-parser\x03\x01\x01i\x03\x01@\0\0\x09\x04\0\x13[constructor]par\
-ser\x01\x0a\x01h\x03\x01i\x02\x01j\x01\x0c\x01\x05\x01@\x02\x04self\x0b\x06sourc\
-es\0\x0d\x04\0\x14[method]parser.parse\x01\x0e\x01i\x08\x01j\x01\x0f\x01\x05\x01\
-@\x03\x04self\x0b\x04tree\x0c\x04edit\x07\0\x10\x04\0\x1a[method]parser.incremen\
-tal\x01\x11\x01h\x08\x01@\x02\x04self\x12\x06sources\0\x0d\x04\0\x20[method]incr\
-emental-parser.parse\x01\x13\x03\0\x1critalin:parser/parsers@0.0.1\x05\x04\x04\0\
-\"ritalin:parser-app/app-world@0.0.1\x04\0\x0b\x0f\x01\0\x09app-world\x03\0\0\0G\
-\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen\
--rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2986] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xaa\x16\x01A\x02\x01\
+A\x08\x01B\x11\x01r\x04\x02idy\x04texts\x0ais-keyword\x7f\x0bis-terminal\x7f\x04\
+\0\x0bsyntax-kind\x03\0\0\x01q\x02\x11illegal-character\0\0\x0cunterminated\x01\x01\
+\0\x04\0\x0btoken-error\x03\0\x02\x01ks\x01k\x03\x01r\x05\x04kind\x01\x06offsety\
+\x03leny\x05value\x04\x05error\x05\x04\0\x0atoken-item\x03\0\x06\x01p\x07\x01k\x08\
+\x01r\x03\x07leading\x09\x04main\x07\x08trailing\x09\x04\0\x05token\x03\0\x0a\x01\
+k\x0b\x01r\x02\x0anext-indexy\x09lookahead\x0c\x04\0\x0dscanner-scope\x03\0\x0d\x01\
+r\x03\x06offsety\x08from-leny\x06to-leny\x04\0\x0aedit-scope\x03\0\x0f\x03\0\x1b\
+ritalin:scanner/types@0.1.0\x05\0\x02\x03\0\0\x0bsyntax-kind\x01BL\x02\x03\x02\x01\
+\x01\x04\0\x0bsyntax-kind\x03\0\0\x04\0\x04tree\x03\x01\x04\0\x04node\x03\x01\x01\
+p}\x01q\x06\x07integer\x01x\0\x04real\x01u\0\x04text\x01s\0\x04blob\x01\x04\0\x04\
+null\0\0\x07boolean\x01\x7f\0\x04\0\x0dliteral-value\x03\0\x05\x01q\x06\x0bnot-l\
+iteral\0\0\x0enot-identifier\0\0\x10integer-overflow\x01s\0\x0cinvalid-real\x01s\
+\0\x0einvalid-escape\x01s\0\x0cinvalid-blob\x01s\0\x04\0\x0dliteral-error\x03\0\x07\
+\x01m\x07\x09token-set\x0dleading-token\x0etrailing-token\x0amain-token\x04node\x05\
+error\x0bfatal-error\x04\0\x09node-type\x03\0\x09\x01m\x02\x06delete\x05shift\x04\
+\0\x0frecovery-status\x03\0\x0b\x01m\x0c\x07keyword\x08function\x05table\x06colu\
+mn\x05alias\x06string\x06number\x04blob\x09parameter\x07comment\x08operator\x05e\
+rror\x04\0\x0dhighlight-tag\x03\0\x0d\x01r\x03\x03tag\x0e\x0coffset-starty\x0aof\
+fset-endy\x04\0\x09highlight\x03\0\x0f\x01ky\x01ks\x01k\x0c\x01r\x07\x04kind\x01\
+\x09node-type\x0a\x06parent\x11\x0coffset-starty\x0aoffset-endy\x05value\x12\x08\
+recovery\x13\x04\0\x09flat-node\x03\0\x14\x01m\x04\x05error\x07warning\x0binform\
+ation\x04hint\x04\0\x08severity\x03\0\x16\x01p\x01\x01r\x07\x08severity\x17\x04c\
+odes\x07messages\x0coffset-starty\x0aoffset-endy\x08expected\x18\x08recovery\x13\
+\x04\0\x0adiagnostic\x03\0\x19\x01r\x04\x04kind\x01\x09node-type\x0a\x05statey\x08\
+recovery\x13\x04\0\x08metadata\x03\0\x1b\x01h\x02\x01i\x03\x01@\x01\x04self\x1d\0\
+\x1e\x04\0\x11[method]tree.root\x01\x1f\x01p\x10\x01@\x01\x04self\x1d\0\x20\x04\0\
+\x16[method]tree.highlight\x01!\x01k\x1e\x01@\x03\x04self\x1d\x0coffset-starty\x0a\
+offset-endy\0\"\x04\0\x1d[method]tree.covering-element\x01#\x01@\x02\x04self\x1d\
+\x06offsety\0\"\x04\0\x1c[method]tree.token-at-offset\x01$\x01p\x15\x01@\x01\x04\
+self\x1d\0%\x04\0\x14[method]tree.flatten\x01&\x01j\x01s\x01s\x01@\x01\x04self\x1d\
+\0'\x04\0\x14[method]tree.to-json\x01(\x01p\x1a\x01@\x01\x04self\x1d\0)\x04\0\x18\
+[method]tree.diagnostics\x01*\x01h\x03\x01@\x01\x04self+\0\x1c\x04\0\x15[method]\
+node.metadata\x01,\x01@\x01\x04self+\0y\x04\0\x19[method]node.offset-start\x01-\x04\
+\0\x17[method]node.offset-end\x01-\x01@\x01\x04self+\0\x12\x04\0\x12[method]node\
+.value\x01.\x01p\x1e\x01@\x01\x04self+\0/\x04\0\x1b[method]node.leading-trivia\x01\
+0\x04\0\x1c[method]node.trailing-trivia\x010\x04\0\x15[method]node.children\x010\
+\x01j\x01\x06\x01\x08\x01@\x01\x04self+\01\x04\0\x1a[method]node.literal-value\x01\
+2\x01j\x01s\x01\x08\x01@\x01\x04self+\03\x04\0\x17[method]node.identifier\x014\x01\
+@\x01\x04self+\0s\x04\0\x11[method]node.text\x015\x01@\x01\x04self+\0\"\x04\0\x13\
+[method]node.parent\x016\x04\0\x16[method]node.ancestors\x010\x04\0\x19[method]n\
+ode.next-sibling\x016\x04\0\x19[method]node.prev-sibling\x016\x03\0\x1britalin:p\
+arser/syntax@0.1.0\x05\x02\x02\x03\0\x01\x04tree\x01B)\x02\x03\x02\x01\x03\x04\0\
+\x04tree\x03\0\0\x04\0\x0bsyntax-tree\x03\0\x01\x04\0\x06parser\x03\x01\x01r\x04\
+\x04codes\x07messages\x0coffset-starty\x0aoffset-endy\x04\0\x0cerror-detail\x03\0\
+\x04\x01q\x02\x0csyntax-error\x01\x05\0\x16incremental-edit-error\x01\x05\0\x04\0\
+\x0cparser-error\x03\0\x06\x01r\x03\x06offsety\x08from-leny\x06to-leny\x04\0\x0a\
+edit-scope\x03\0\x08\x04\0\x12incremental-parser\x03\x01\x04\0\x08document\x03\x01\
+\x01i\x02\x01r\x04\x04tree\x0c\x0coffset-starty\x0aoffset-endy\x0bincremental\x7f\
+\x04\0\x0atree-delta\x03\0\x0d\x01i\x03\x01@\0\0\x0f\x04\0\x13[constructor]parse\
+r\x01\x10\x01h\x03\x01j\x01\x0c\x01\x07\x01@\x02\x04self\x11\x06sources\0\x12\x04\
+\0\x14[method]parser.parse\x01\x13\x01i\x0a\x01j\x01\x14\x01\x07\x01@\x03\x04sel\
+f\x11\x04tree\x0c\x04edit\x09\0\x15\x04\0\x1a[method]parser.incremental\x01\x16\x01\
+h\x0a\x01@\x02\x04self\x17\x06sources\0\x12\x04\0\x20[method]incremental-parser.\
+parse\x01\x18\x01i\x0b\x01j\x01\x19\x01\x07\x01@\x01\x06sources\0\x1a\x04\0\x17[\
+static]document.create\x01\x1b\x01h\x0b\x01@\x01\x04self\x1c\0s\x04\0\x17[method\
+]document.source\x01\x1d\x01@\x01\x04self\x1c\0\x0c\x04\0\x15[method]document.tr\
+ee\x01\x1e\x01j\x01\x0e\x01\x07\x01@\x04\x04self\x1c\x06offsety\x0adelete-leny\x0b\
+insert-texts\0\x1f\x04\0\x1b[method]document.apply-edit\x01\x20\x03\0\x1critalin\
+:parser/parsers@0.1.0\x05\x04\x04\0\"ritalin:parser-app/app-world@0.0.1\x04\0\x0b\
+\x0f\x01\0\x09app-world\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-c\
+omponent\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 
 #[inline(never)]
 #[doc(hidden)]
@@ -1,7 +1,11 @@
 mod parser_world;
+#[cfg(feature = "compat")]
+mod compat_world;
 //mod syntax_interface;
 mod resources;
 
 use resources::ParserComponent;
 
 parser_world::export!(ParserComponent);
+#[cfg(feature = "compat")]
+compat_world::export!(ParserComponent);
@@ -1,6 +1,6 @@
 // Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
 // Options used:
-//   * with "ritalin:scanner/types@0.0.1" = "scanner_wasi::scanner_types"
+//   * with "ritalin:scanner/types@0.1.0" = "scanner_wasi::scanner_types"
 //   * default-bindings-module: "crate::bindings::parser_world"
 use scanner_wasi::scanner_types as __with_name1;
 #[allow(dead_code, clippy::all)]
@@ -148,7 +148,7 @@ pub mod exports {
 
             #[cfg(target_arch = "wasm32")]
             {
-              #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.0.1")]
+              #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.1.0")]
               unsafe extern "C" {
                 #[link_name = "[resource-drop]tree"]
                 fn drop(_: u32);
@@ -290,7 +290,7 @@ pub mod exports {
 
             #[cfg(target_arch = "wasm32")]
             {
-              #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.0.1")]
+              #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.1.0")]
               unsafe extern "C" {
                 #[link_name = "[resource-drop]node"]
                 fn drop(_: u32);
@@ -301,6 +301,79 @@ pub mod exports {
           }
         }
         
+        #[derive(Clone)]
+        pub enum LiteralValue {
+          Integer(i64),
+          Real(f64),
+          Text(_rt::String),
+          Blob(_rt::Vec::<u8>),
+          Null,
+          Boolean(bool),
+        }
+        impl ::core::fmt::Debug for LiteralValue {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+              LiteralValue::Integer(e) => {
+                f.debug_tuple("LiteralValue::Integer").field(e).finish()
+              }
+              LiteralValue::Real(e) => {
+                f.debug_tuple("LiteralValue::Real").field(e).finish()
+              }
+              LiteralValue::Text(e) => {
+                f.debug_tuple("LiteralValue::Text").field(e).finish()
+              }
+              LiteralValue::Blob(e) => {
+                f.debug_tuple("LiteralValue::Blob").field(e).finish()
+              }
+              LiteralValue::Null => {
+                f.debug_tuple("LiteralValue::Null").finish()
+              }
+              LiteralValue::Boolean(e) => {
+                f.debug_tuple("LiteralValue::Boolean").field(e).finish()
+              }
+            }
+          }
+        }
+        #[derive(Clone)]
+        pub enum LiteralError {
+          NotLiteral,
+          NotIdentifier,
+          IntegerOverflow(_rt::String),
+          InvalidReal(_rt::String),
+          InvalidEscape(_rt::String),
+          InvalidBlob(_rt::String),
+        }
+        impl ::core::fmt::Debug for LiteralError {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+              LiteralError::NotLiteral => {
+                f.debug_tuple("LiteralError::NotLiteral").finish()
+              }
+              LiteralError::NotIdentifier => {
+                f.debug_tuple("LiteralError::NotIdentifier").finish()
+              }
+              LiteralError::IntegerOverflow(e) => {
+                f.debug_tuple("LiteralError::IntegerOverflow").field(e).finish()
+              }
+              LiteralError::InvalidReal(e) => {
+                f.debug_tuple("LiteralError::InvalidReal").field(e).finish()
+              }
+              LiteralError::InvalidEscape(e) => {
+                f.debug_tuple("LiteralError::InvalidEscape").field(e).finish()
+              }
+              LiteralError::InvalidBlob(e) => {
+                f.debug_tuple("LiteralError::InvalidBlob").field(e).finish()
+              }
+            }
+          }
+        }
+        impl ::core::fmt::Display for LiteralError {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            write!(f, "{:?}", self)
+          }
+        }
+
+        impl std::error::Error for LiteralError {}
         #[repr(u8)]
         #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
         pub enum NodeType {
@@ -396,11 +469,184 @@ pub mod exports {
           }
         }
         
+        #[repr(u8)]
+        #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+        pub enum HighlightTag {
+          Keyword,
+          Function,
+          Table,
+          Column,
+          Alias,
+          String,
+          Number,
+          Blob,
+          Parameter,
+          Comment,
+          Operator,
+          Error,
+        }
+        impl ::core::fmt::Debug for HighlightTag {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+              HighlightTag::Keyword => {
+                f.debug_tuple("HighlightTag::Keyword").finish()
+              }
+              HighlightTag::Function => {
+                f.debug_tuple("HighlightTag::Function").finish()
+              }
+              HighlightTag::Table => {
+                f.debug_tuple("HighlightTag::Table").finish()
+              }
+              HighlightTag::Column => {
+                f.debug_tuple("HighlightTag::Column").finish()
+              }
+              HighlightTag::Alias => {
+                f.debug_tuple("HighlightTag::Alias").finish()
+              }
+              HighlightTag::String => {
+                f.debug_tuple("HighlightTag::String").finish()
+              }
+              HighlightTag::Number => {
+                f.debug_tuple("HighlightTag::Number").finish()
+              }
+              HighlightTag::Blob => {
+                f.debug_tuple("HighlightTag::Blob").finish()
+              }
+              HighlightTag::Parameter => {
+                f.debug_tuple("HighlightTag::Parameter").finish()
+              }
+              HighlightTag::Comment => {
+                f.debug_tuple("HighlightTag::Comment").finish()
+              }
+              HighlightTag::Operator => {
+                f.debug_tuple("HighlightTag::Operator").finish()
+              }
+              HighlightTag::Error => {
+                f.debug_tuple("HighlightTag::Error").finish()
+              }
+            }
+          }
+        }
+
+        impl HighlightTag{
+          #[doc(hidden)]
+          pub unsafe fn _lift(val: u8) -> HighlightTag{
+            if !cfg!(debug_assertions) {
+              return ::core::mem::transmute(val);
+            }
+
+            match val {
+              0 => HighlightTag::Keyword,
+              1 => HighlightTag::Function,
+              2 => HighlightTag::Table,
+              3 => HighlightTag::Column,
+              4 => HighlightTag::Alias,
+              5 => HighlightTag::String,
+              6 => HighlightTag::Number,
+              7 => HighlightTag::Blob,
+              8 => HighlightTag::Parameter,
+              9 => HighlightTag::Comment,
+              10 => HighlightTag::Operator,
+              11 => HighlightTag::Error,
+
+              _ => panic!("invalid enum discriminant"),
+            }
+          }
+        }
+        
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        pub struct Highlight {
+          pub tag: HighlightTag,
+          pub offset_start: u32,
+          pub offset_end: u32,
+        }
+        impl ::core::fmt::Debug for Highlight {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_struct("Highlight").field("tag", &self.tag).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).finish()
+          }
+        }
+        /// Preorder, `parent` is the index of the parent in the list
+        #[derive(Clone)]
+        pub struct FlatNode {
+          pub kind: SyntaxKind,
+          pub node_type: NodeType,
+          pub parent: Option<u32>,
+          pub offset_start: u32,
+          pub offset_end: u32,
+          pub value: Option<_rt::String>,
+          pub recovery: Option<RecoveryStatus>,
+        }
+        impl ::core::fmt::Debug for FlatNode {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_struct("FlatNode").field("kind", &self.kind).field("node-type", &self.node_type).field("parent", &self.parent).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).field("value", &self.value).field("recovery", &self.recovery).finish()
+          }
+        }
+        #[repr(u8)]
+        #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+        pub enum Severity {
+          Error,
+          Warning,
+          Information,
+          Hint,
+        }
+        impl ::core::fmt::Debug for Severity {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+              Severity::Error => {
+                f.debug_tuple("Severity::Error").finish()
+              }
+              Severity::Warning => {
+                f.debug_tuple("Severity::Warning").finish()
+              }
+              Severity::Information => {
+                f.debug_tuple("Severity::Information").finish()
+              }
+              Severity::Hint => {
+                f.debug_tuple("Severity::Hint").finish()
+              }
+            }
+          }
+        }
+
+        impl Severity{
+          #[doc(hidden)]
+          pub unsafe fn _lift(val: u8) -> Severity{
+            if !cfg!(debug_assertions) {
+              return ::core::mem::transmute(val);
+            }
+
+            match val {
+              0 => Severity::Error,
+              1 => Severity::Warning,
+              2 => Severity::Information,
+              3 => Severity::Hint,
+
+              _ => panic!("invalid enum discriminant"),
+            }
+          }
+        }
+        
+        #[derive(Clone)]
+        pub struct Diagnostic {
+          pub severity: Severity,
+          pub code: _rt::String,
+          pub message: _rt::String,
+          pub offset_start: u32,
+          pub offset_end: u32,
+          pub expected: _rt::Vec::<SyntaxKind>,
+          pub recovery: Option<RecoveryStatus>,
+        }
+        impl ::core::fmt::Debug for Diagnostic {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_struct("Diagnostic").field("severity", &self.severity).field("code", &self.code).field("message", &self.message).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).field("expected", &self.expected).field("recovery", &self.recovery).finish()
+          }
+        }
         #[derive(Clone)]
         pub struct Metadata {
           pub kind: SyntaxKind,
           pub node_type: NodeType,
-          pub state: u64,
+          pub state: u32,
           pub recovery: Option<RecoveryStatus>,
         }
         impl ::core::fmt::Debug for Metadata {
@@ -416,52 +662,387 @@ pub mod exports {
       }
       #[doc(hidden)]
       #[allow(non_snake_case)]
-      pub unsafe fn _export_method_node_metadata_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
-      _rt::run_ctors_once();let result0 = T::metadata(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+      pub unsafe fn _export_method_tree_highlight_cabi<T: GuestTree>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+      _rt::run_ctors_once();let result0 = T::highlight(unsafe { TreeBorrow::lift(arg0 as u32 as usize) }.get());
       let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-      let Metadata{ kind:kind2, node_type:node_type2, state:state2, recovery:recovery2, } = result0;
-      let super::super::super::super::__with_name1::SyntaxKind{ id:id3, text:text3, is_keyword:is_keyword3, is_terminal:is_terminal3, } = kind2;
-      *ptr1.add(0).cast::<i32>() = _rt::as_i32(id3);
-      let vec4 = (text3.into_bytes()).into_boxed_slice();
-      let ptr4 = vec4.as_ptr().cast::<u8>();
-      let len4 = vec4.len();
-      ::core::mem::forget(vec4);
-      *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
-      *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
-      *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword3 { true => 1, false => 0 }) as u8;
-      *ptr1.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal3 { true => 1, false => 0 }) as u8;
-      *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (node_type2.clone() as i32) as u8;
-      *ptr1.add(8+4*::core::mem::size_of::<*const u8>()).cast::<i64>() = _rt::as_i64(state2);
-      match recovery2 {
-        Some(e) => {
-          *ptr1.add(16+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-          *ptr1.add(17+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (e.clone() as i32) as u8;
-        },
-        None => {
-          {
-            *ptr1.add(16+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
-          }
-        },
-      };ptr1
+      let vec3 = result0;
+      let len3 = vec3.len();
+      let layout3 = _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 12, 4);
+      let result3 = if layout3.size() != 0 {
+        let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+        if ptr.is_null()
+        {
+          _rt::alloc::handle_alloc_error(layout3);
+        }
+        ptr
+      }else {
+        ::core::ptr::null_mut()
+      };
+      for (i, e) in vec3.into_iter().enumerate() {
+        let base = result3.add(i * 12);
+        {
+          let Highlight{ tag:tag2, offset_start:offset_start2, offset_end:offset_end2, } = e;
+          *base.add(0).cast::<u8>() = (tag2.clone() as i32) as u8;
+          *base.add(4).cast::<i32>() = _rt::as_i32(offset_start2);
+          *base.add(8).cast::<i32>() = _rt::as_i32(offset_end2);
+        }
+      }
+      *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+      *ptr1.add(0).cast::<*mut u8>() = result3;
+      ptr1
     }
     #[doc(hidden)]
     #[allow(non_snake_case)]
-    pub unsafe fn __post_return_method_node_metadata<T: GuestNode>(arg0: *mut u8,) {
-      let l0 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-      let l1 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-      _rt::cabi_dealloc(l0, l1, 1);
+    pub unsafe fn __post_return_method_tree_highlight<T: GuestTree>(arg0: *mut u8,) {
+      let l0 = *arg0.add(0).cast::<*mut u8>();
+      let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+      let base2 = l0;
+      let len2 = l1;
+      _rt::cabi_dealloc(base2, len2 * 12, 4);
     }
     #[doc(hidden)]
     #[allow(non_snake_case)]
-    pub unsafe fn _export_method_node_offset_start_cabi<T: GuestNode>(arg0: *mut u8,) -> i32 {#[cfg(target_arch="wasm32")]
-    _rt::run_ctors_once();let result0 = T::offset_start(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
-    _rt::as_i32(result0)
+    pub unsafe fn _export_method_tree_covering_element_cabi<T: GuestTree>(arg0: *mut u8,arg1: i32,arg2: i32,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+    _rt::run_ctors_once();let result0 = T::covering_element(unsafe { TreeBorrow::lift(arg0 as u32 as usize) }.get(), arg1 as u32, arg2 as u32);
+    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result0 {
+      Some(e) => {
+        *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+        *ptr1.add(4).cast::<i32>() = (e).take_handle() as i32;
+      },
+      None => {
+        {
+          *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+        }
+      },
+    };ptr1
   }
   #[doc(hidden)]
   #[allow(non_snake_case)]
-  pub unsafe fn _export_method_node_offset_end_cabi<T: GuestNode>(arg0: *mut u8,) -> i32 {#[cfg(target_arch="wasm32")]
-  _rt::run_ctors_once();let result0 = T::offset_end(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
-  _rt::as_i32(result0)
+  pub unsafe fn _export_method_tree_token_at_offset_cabi<T: GuestTree>(arg0: *mut u8,arg1: i32,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+  _rt::run_ctors_once();let result0 = T::token_at_offset(unsafe { TreeBorrow::lift(arg0 as u32 as usize) }.get(), arg1 as u32);
+  let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+  match result0 {
+    Some(e) => {
+      *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+      *ptr1.add(4).cast::<i32>() = (e).take_handle() as i32;
+    },
+    None => {
+      {
+        *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+      }
+    },
+  };ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_tree_flatten_cabi<T: GuestTree>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::flatten(unsafe { TreeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+let vec6 = result0;
+let len6 = vec6.len();
+let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * (16+9*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+let result6 = if layout6.size() != 0 {
+  let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+  if ptr.is_null()
+  {
+    _rt::alloc::handle_alloc_error(layout6);
+  }
+  ptr
+}else {
+  ::core::ptr::null_mut()
+};
+for (i, e) in vec6.into_iter().enumerate() {
+  let base = result6.add(i * (16+9*::core::mem::size_of::<*const u8>()));
+  {
+    let FlatNode{ kind:kind2, node_type:node_type2, parent:parent2, offset_start:offset_start2, offset_end:offset_end2, value:value2, recovery:recovery2, } = e;
+    let super::super::super::super::__with_name1::SyntaxKind{ id:id3, text:text3, is_keyword:is_keyword3, is_terminal:is_terminal3, } = kind2;
+    *base.add(0).cast::<i32>() = _rt::as_i32(id3);
+    let vec4 = (text3.into_bytes()).into_boxed_slice();
+    let ptr4 = vec4.as_ptr().cast::<u8>();
+    let len4 = vec4.len();
+    ::core::mem::forget(vec4);
+    *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+    *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
+    *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword3 { true => 1, false => 0 }) as u8;
+    *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal3 { true => 1, false => 0 }) as u8;
+    *base.add(4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (node_type2.clone() as i32) as u8;
+    match parent2 {
+      Some(e) => {
+        *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+        *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(e);
+      },
+      None => {
+        {
+          *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+        }
+      },
+    };*base.add(12+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start2);
+    *base.add(16+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end2);
+    match value2 {
+      Some(e) => {
+        *base.add(16+5*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+        let vec5 = (e.into_bytes()).into_boxed_slice();
+        let ptr5 = vec5.as_ptr().cast::<u8>();
+        let len5 = vec5.len();
+        ::core::mem::forget(vec5);
+        *base.add(16+7*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+        *base.add(16+6*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
+      },
+      None => {
+        {
+          *base.add(16+5*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+        }
+      },
+    };match recovery2 {
+      Some(e) => {
+        *base.add(16+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+        *base.add(17+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (e.clone() as i32) as u8;
+      },
+      None => {
+        {
+          *base.add(16+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+        }
+      },
+    };}
+  }
+  *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+  *ptr1.add(0).cast::<*mut u8>() = result6;
+  ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_tree_flatten<T: GuestTree>(arg0: *mut u8,) {
+  let l0 = *arg0.add(0).cast::<*mut u8>();
+  let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+  let base7 = l0;
+  let len7 = l1;
+  for i in 0..len7 {
+    let base = base7.add(i * (16+9*::core::mem::size_of::<*const u8>()));
+    {
+      let l2 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+      let l3 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+      _rt::cabi_dealloc(l2, l3, 1);
+      let l4 = i32::from(*base.add(16+5*::core::mem::size_of::<*const u8>()).cast::<u8>());
+      match l4 {
+        0 => (),
+        _ => {
+          let l5 = *base.add(16+6*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l6 = *base.add(16+7*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l5, l6, 1);
+        },
+      }
+    }
+  }
+  _rt::cabi_dealloc(base7, len7 * (16+9*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_tree_to_json_cabi<T: GuestTree>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::to_json(unsafe { TreeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+match result0 {
+  Ok(e) => { {
+    *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+    let vec2 = (e.into_bytes()).into_boxed_slice();
+    let ptr2 = vec2.as_ptr().cast::<u8>();
+    let len2 = vec2.len();
+    ::core::mem::forget(vec2);
+    *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
+    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr2.cast_mut();
+  } },
+  Err(e) => { {
+    *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+    let vec3 = (e.into_bytes()).into_boxed_slice();
+    let ptr3 = vec3.as_ptr().cast::<u8>();
+    let len3 = vec3.len();
+    ::core::mem::forget(vec3);
+    *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3.cast_mut();
+  } },
+};ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_tree_to_json<T: GuestTree>(arg0: *mut u8,) {
+  let l0 = i32::from(*arg0.add(0).cast::<u8>());
+  match l0 {
+    0 => {
+      let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+      let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+      _rt::cabi_dealloc(l1, l2, 1);
+    },
+    _ => {
+      let l3 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+      let l4 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+      _rt::cabi_dealloc(l3, l4, 1);
+    },
+  }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_tree_diagnostics_cabi<T: GuestTree>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::diagnostics(unsafe { TreeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+let vec8 = result0;
+let len8 = vec8.len();
+let layout8 = _rt::alloc::Layout::from_size_align_unchecked(vec8.len() * (8+8*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+let result8 = if layout8.size() != 0 {
+  let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+  if ptr.is_null()
+  {
+    _rt::alloc::handle_alloc_error(layout8);
+  }
+  ptr
+}else {
+  ::core::ptr::null_mut()
+};
+for (i, e) in vec8.into_iter().enumerate() {
+  let base = result8.add(i * (8+8*::core::mem::size_of::<*const u8>()));
+  {
+    let Diagnostic{ severity:severity2, code:code2, message:message2, offset_start:offset_start2, offset_end:offset_end2, expected:expected2, recovery:recovery2, } = e;
+    *base.add(0).cast::<u8>() = (severity2.clone() as i32) as u8;
+    let vec3 = (code2.into_bytes()).into_boxed_slice();
+    let ptr3 = vec3.as_ptr().cast::<u8>();
+    let len3 = vec3.len();
+    ::core::mem::forget(vec3);
+    *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+    *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3.cast_mut();
+    let vec4 = (message2.into_bytes()).into_boxed_slice();
+    let ptr4 = vec4.as_ptr().cast::<u8>();
+    let len4 = vec4.len();
+    ::core::mem::forget(vec4);
+    *base.add(4*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+    *base.add(3*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
+    *base.add(5*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start2);
+    *base.add(4+5*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end2);
+    let vec7 = expected2;
+    let len7 = vec7.len();
+    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(vec7.len() * (4*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+    let result7 = if layout7.size() != 0 {
+      let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+      if ptr.is_null()
+      {
+        _rt::alloc::handle_alloc_error(layout7);
+      }
+      ptr
+    }else {
+      ::core::ptr::null_mut()
+    };
+    for (i, e) in vec7.into_iter().enumerate() {
+      let base = result7.add(i * (4*::core::mem::size_of::<*const u8>()));
+      {
+        let super::super::super::super::__with_name1::SyntaxKind{ id:id5, text:text5, is_keyword:is_keyword5, is_terminal:is_terminal5, } = e;
+        *base.add(0).cast::<i32>() = _rt::as_i32(id5);
+        let vec6 = (text5.into_bytes()).into_boxed_slice();
+        let ptr6 = vec6.as_ptr().cast::<u8>();
+        let len6 = vec6.len();
+        ::core::mem::forget(vec6);
+        *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+        *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
+        *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword5 { true => 1, false => 0 }) as u8;
+        *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal5 { true => 1, false => 0 }) as u8;
+      }
+    }
+    *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+    *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result7;
+    match recovery2 {
+      Some(e) => {
+        *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+        *base.add(9+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (e.clone() as i32) as u8;
+      },
+      None => {
+        {
+          *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+        }
+      },
+    };}
+  }
+  *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+  *ptr1.add(0).cast::<*mut u8>() = result8;
+  ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_tree_diagnostics<T: GuestTree>(arg0: *mut u8,) {
+  let l0 = *arg0.add(0).cast::<*mut u8>();
+  let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+  let base11 = l0;
+  let len11 = l1;
+  for i in 0..len11 {
+    let base = base11.add(i * (8+8*::core::mem::size_of::<*const u8>()));
+    {
+      let l2 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+      let l3 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+      _rt::cabi_dealloc(l2, l3, 1);
+      let l4 = *base.add(3*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+      let l5 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<usize>();
+      _rt::cabi_dealloc(l4, l5, 1);
+      let l6 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+      let l7 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+      let base10 = l6;
+      let len10 = l7;
+      for i in 0..len10 {
+        let base = base10.add(i * (4*::core::mem::size_of::<*const u8>()));
+        {
+          let l8 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l9 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l8, l9, 1);
+        }
+      }
+      _rt::cabi_dealloc(base10, len10 * (4*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+    }
+  }
+  _rt::cabi_dealloc(base11, len11 * (8+8*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_node_metadata_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::metadata(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+let Metadata{ kind:kind2, node_type:node_type2, state:state2, recovery:recovery2, } = result0;
+let super::super::super::super::__with_name1::SyntaxKind{ id:id3, text:text3, is_keyword:is_keyword3, is_terminal:is_terminal3, } = kind2;
+*ptr1.add(0).cast::<i32>() = _rt::as_i32(id3);
+let vec4 = (text3.into_bytes()).into_boxed_slice();
+let ptr4 = vec4.as_ptr().cast::<u8>();
+let len4 = vec4.len();
+::core::mem::forget(vec4);
+*ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
+*ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword3 { true => 1, false => 0 }) as u8;
+*ptr1.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal3 { true => 1, false => 0 }) as u8;
+*ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (node_type2.clone() as i32) as u8;
+*ptr1.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(state2);
+match recovery2 {
+  Some(e) => {
+    *ptr1.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+    *ptr1.add(9+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (e.clone() as i32) as u8;
+  },
+  None => {
+    {
+      *ptr1.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+    }
+  },
+};ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_node_metadata<T: GuestNode>(arg0: *mut u8,) {
+  let l0 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+  let l1 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+  _rt::cabi_dealloc(l0, l1, 1);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_node_offset_start_cabi<T: GuestNode>(arg0: *mut u8,) -> i32 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::offset_start(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+_rt::as_i32(result0)
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_node_offset_end_cabi<T: GuestNode>(arg0: *mut u8,) -> i32 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::offset_end(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+_rt::as_i32(result0)
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
@@ -500,45 +1081,417 @@ pub unsafe fn __post_return_method_node_value<T: GuestNode>(arg0: *mut u8,) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_method_node_leading_trivia_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
-_rt::run_ctors_once();let result0 = T::leading_trivia(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+pub unsafe fn _export_method_node_leading_trivia_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::leading_trivia(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+let vec2 = result0;
+let len2 = vec2.len();
+let layout2 = _rt::alloc::Layout::from_size_align_unchecked(vec2.len() * 4, 4);
+let result2 = if layout2.size() != 0 {
+  let ptr = _rt::alloc::alloc(layout2).cast::<u8>();
+  if ptr.is_null()
+  {
+    _rt::alloc::handle_alloc_error(layout2);
+  }
+  ptr
+}else {
+  ::core::ptr::null_mut()
+};
+for (i, e) in vec2.into_iter().enumerate() {
+  let base = result2.add(i * 4);
+  {
+    *base.add(0).cast::<i32>() = (e).take_handle() as i32;
+  }
+}
+*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
+*ptr1.add(0).cast::<*mut u8>() = result2;
+ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_node_leading_trivia<T: GuestNode>(arg0: *mut u8,) {
+  let l0 = *arg0.add(0).cast::<*mut u8>();
+  let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+  let base2 = l0;
+  let len2 = l1;
+  _rt::cabi_dealloc(base2, len2 * 4, 4);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_node_trailing_trivia_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::trailing_trivia(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+let vec2 = result0;
+let len2 = vec2.len();
+let layout2 = _rt::alloc::Layout::from_size_align_unchecked(vec2.len() * 4, 4);
+let result2 = if layout2.size() != 0 {
+  let ptr = _rt::alloc::alloc(layout2).cast::<u8>();
+  if ptr.is_null()
+  {
+    _rt::alloc::handle_alloc_error(layout2);
+  }
+  ptr
+}else {
+  ::core::ptr::null_mut()
+};
+for (i, e) in vec2.into_iter().enumerate() {
+  let base = result2.add(i * 4);
+  {
+    *base.add(0).cast::<i32>() = (e).take_handle() as i32;
+  }
+}
+*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
+*ptr1.add(0).cast::<*mut u8>() = result2;
+ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_node_trailing_trivia<T: GuestNode>(arg0: *mut u8,) {
+  let l0 = *arg0.add(0).cast::<*mut u8>();
+  let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+  let base2 = l0;
+  let len2 = l1;
+  _rt::cabi_dealloc(base2, len2 * 4, 4);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_node_children_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::children(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+let vec2 = result0;
+let len2 = vec2.len();
+let layout2 = _rt::alloc::Layout::from_size_align_unchecked(vec2.len() * 4, 4);
+let result2 = if layout2.size() != 0 {
+  let ptr = _rt::alloc::alloc(layout2).cast::<u8>();
+  if ptr.is_null()
+  {
+    _rt::alloc::handle_alloc_error(layout2);
+  }
+  ptr
+}else {
+  ::core::ptr::null_mut()
+};
+for (i, e) in vec2.into_iter().enumerate() {
+  let base = result2.add(i * 4);
+  {
+    *base.add(0).cast::<i32>() = (e).take_handle() as i32;
+  }
+}
+*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
+*ptr1.add(0).cast::<*mut u8>() = result2;
+ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_node_children<T: GuestNode>(arg0: *mut u8,) {
+  let l0 = *arg0.add(0).cast::<*mut u8>();
+  let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+  let base2 = l0;
+  let len2 = l1;
+  _rt::cabi_dealloc(base2, len2 * 4, 4);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_node_literal_value_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::literal_value(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+match result0 {
+  Ok(e) => { {
+    *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+    match e {
+      LiteralValue::Integer(e) => {
+        *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+        *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+      },
+      LiteralValue::Real(e) => {
+        *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+        *ptr1.add(16).cast::<f64>() = _rt::as_f64(e);
+      },
+      LiteralValue::Text(e) => {
+        *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+        let vec2 = (e.into_bytes()).into_boxed_slice();
+        let ptr2 = vec2.as_ptr().cast::<u8>();
+        let len2 = vec2.len();
+        ::core::mem::forget(vec2);
+        *ptr1.add(16+1*::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
+        *ptr1.add(16).cast::<*mut u8>() = ptr2.cast_mut();
+      },
+      LiteralValue::Blob(e) => {
+        *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+        let vec3 = (e).into_boxed_slice();
+        let ptr3 = vec3.as_ptr().cast::<u8>();
+        let len3 = vec3.len();
+        ::core::mem::forget(vec3);
+        *ptr1.add(16+1*::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+        *ptr1.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+      },
+      LiteralValue::Null=> {
+        {
+          *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+        }
+      }
+      LiteralValue::Boolean(e) => {
+        *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+        *ptr1.add(16).cast::<u8>() = (match e { true => 1, false => 0 }) as u8;
+      },
+    }
+  } },
+  Err(e) => { {
+    *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+    match e {
+      LiteralError::NotLiteral=> {
+        {
+          *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+        }
+      }
+      LiteralError::NotIdentifier=> {
+        {
+          *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+        }
+      }
+      LiteralError::IntegerOverflow(e) => {
+        *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+        let vec4 = (e.into_bytes()).into_boxed_slice();
+        let ptr4 = vec4.as_ptr().cast::<u8>();
+        let len4 = vec4.len();
+        ::core::mem::forget(vec4);
+        *ptr1.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+        *ptr1.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
+      },
+      LiteralError::InvalidReal(e) => {
+        *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+        let vec5 = (e.into_bytes()).into_boxed_slice();
+        let ptr5 = vec5.as_ptr().cast::<u8>();
+        let len5 = vec5.len();
+        ::core::mem::forget(vec5);
+        *ptr1.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+        *ptr1.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
+      },
+      LiteralError::InvalidEscape(e) => {
+        *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+        let vec6 = (e.into_bytes()).into_boxed_slice();
+        let ptr6 = vec6.as_ptr().cast::<u8>();
+        let len6 = vec6.len();
+        ::core::mem::forget(vec6);
+        *ptr1.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+        *ptr1.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
+      },
+      LiteralError::InvalidBlob(e) => {
+        *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+        let vec7 = (e.into_bytes()).into_boxed_slice();
+        let ptr7 = vec7.as_ptr().cast::<u8>();
+        let len7 = vec7.len();
+        ::core::mem::forget(vec7);
+        *ptr1.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+        *ptr1.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7.cast_mut();
+      },
+    }
+  } },
+};ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_node_literal_value<T: GuestNode>(arg0: *mut u8,) {
+  let l0 = i32::from(*arg0.add(0).cast::<u8>());
+  match l0 {
+    0 => {
+      let l1 = i32::from(*arg0.add(8).cast::<u8>());
+      match l1 {
+        0 => (),
+        1 => (),
+        2 => {
+          let l2 = *arg0.add(16).cast::<*mut u8>();
+          let l3 = *arg0.add(16+1*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l2, l3, 1);
+        },
+        3 => {
+          let l4 = *arg0.add(16).cast::<*mut u8>();
+          let l5 = *arg0.add(16+1*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          let base6 = l4;
+          let len6 = l5;
+          _rt::cabi_dealloc(base6, len6 * 1, 1);
+        },
+        4 => (),
+        _ => (),
+      }
+    },
+    _ => {
+      let l7 = i32::from(*arg0.add(8).cast::<u8>());
+      match l7 {
+        0 => (),
+        1 => (),
+        2 => {
+          let l8 = *arg0.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l9 = *arg0.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l8, l9, 1);
+        },
+        3 => {
+          let l10 = *arg0.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l11 = *arg0.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l10, l11, 1);
+        },
+        4 => {
+          let l12 = *arg0.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l13 = *arg0.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l12, l13, 1);
+        },
+        _ => {
+          let l14 = *arg0.add(8+1*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l15 = *arg0.add(8+2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l14, l15, 1);
+        },
+      }
+    },
+  }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_node_identifier_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::identifier(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+match result0 {
+  Ok(e) => { {
+    *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+    let vec2 = (e.into_bytes()).into_boxed_slice();
+    let ptr2 = vec2.as_ptr().cast::<u8>();
+    let len2 = vec2.len();
+    ::core::mem::forget(vec2);
+    *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
+    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr2.cast_mut();
+  } },
+  Err(e) => { {
+    *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+    match e {
+      LiteralError::NotLiteral=> {
+        {
+          *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+        }
+      }
+      LiteralError::NotIdentifier=> {
+        {
+          *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+        }
+      }
+      LiteralError::IntegerOverflow(e) => {
+        *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32) as u8;
+        let vec3 = (e.into_bytes()).into_boxed_slice();
+        let ptr3 = vec3.as_ptr().cast::<u8>();
+        let len3 = vec3.len();
+        ::core::mem::forget(vec3);
+        *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+        *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3.cast_mut();
+      },
+      LiteralError::InvalidReal(e) => {
+        *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32) as u8;
+        let vec4 = (e.into_bytes()).into_boxed_slice();
+        let ptr4 = vec4.as_ptr().cast::<u8>();
+        let len4 = vec4.len();
+        ::core::mem::forget(vec4);
+        *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+        *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
+      },
+      LiteralError::InvalidEscape(e) => {
+        *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32) as u8;
+        let vec5 = (e.into_bytes()).into_boxed_slice();
+        let ptr5 = vec5.as_ptr().cast::<u8>();
+        let len5 = vec5.len();
+        ::core::mem::forget(vec5);
+        *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+        *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
+      },
+      LiteralError::InvalidBlob(e) => {
+        *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32) as u8;
+        let vec6 = (e.into_bytes()).into_boxed_slice();
+        let ptr6 = vec6.as_ptr().cast::<u8>();
+        let len6 = vec6.len();
+        ::core::mem::forget(vec6);
+        *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+        *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
+      },
+    }
+  } },
+};ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_node_identifier<T: GuestNode>(arg0: *mut u8,) {
+  let l0 = i32::from(*arg0.add(0).cast::<u8>());
+  match l0 {
+    0 => {
+      let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+      let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+      _rt::cabi_dealloc(l1, l2, 1);
+    },
+    _ => {
+      let l3 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+      match l3 {
+        0 => (),
+        1 => (),
+        2 => {
+          let l4 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l5 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l4, l5, 1);
+        },
+        3 => {
+          let l6 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l7 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l6, l7, 1);
+        },
+        4 => {
+          let l8 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l9 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l8, l9, 1);
+        },
+        _ => {
+          let l10 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l11 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l10, l11, 1);
+        },
+      }
+    },
+  }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_node_text_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::text(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
 let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-let vec2 = result0;
+let vec2 = (result0.into_bytes()).into_boxed_slice();
+let ptr2 = vec2.as_ptr().cast::<u8>();
 let len2 = vec2.len();
-let layout2 = _rt::alloc::Layout::from_size_align_unchecked(vec2.len() * 4, 4);
-let result2 = if layout2.size() != 0 {
-  let ptr = _rt::alloc::alloc(layout2).cast::<u8>();
-  if ptr.is_null()
-  {
-    _rt::alloc::handle_alloc_error(layout2);
-  }
-  ptr
-}else {
-  ::core::ptr::null_mut()
-};
-for (i, e) in vec2.into_iter().enumerate() {
-  let base = result2.add(i * 4);
-  {
-    *base.add(0).cast::<i32>() = (e).take_handle() as i32;
-  }
-}
+::core::mem::forget(vec2);
 *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
-*ptr1.add(0).cast::<*mut u8>() = result2;
+*ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
 ptr1
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_method_node_leading_trivia<T: GuestNode>(arg0: *mut u8,) {
+pub unsafe fn __post_return_method_node_text<T: GuestNode>(arg0: *mut u8,) {
   let l0 = *arg0.add(0).cast::<*mut u8>();
   let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-  let base2 = l0;
-  let len2 = l1;
-  _rt::cabi_dealloc(base2, len2 * 4, 4);
+  _rt::cabi_dealloc(l0, l1, 1);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_node_parent_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::parent(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+match result0 {
+  Some(e) => {
+    *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+    *ptr1.add(4).cast::<i32>() = (e).take_handle() as i32;
+  },
+  None => {
+    {
+      *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+    }
+  },
+};ptr1
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_method_node_traling_trivia_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
-_rt::run_ctors_once();let result0 = T::traling_trivia(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+pub unsafe fn _export_method_node_ancestors_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::ancestors(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
 let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
 let vec2 = result0;
 let len2 = vec2.len();
@@ -565,7 +1518,7 @@ ptr1
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_method_node_traling_trivia<T: GuestNode>(arg0: *mut u8,) {
+pub unsafe fn __post_return_method_node_ancestors<T: GuestNode>(arg0: *mut u8,) {
   let l0 = *arg0.add(0).cast::<*mut u8>();
   let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
   let base2 = l0;
@@ -574,40 +1527,37 @@ pub unsafe fn __post_return_method_node_traling_trivia<T: GuestNode>(arg0: *mut
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_method_node_children_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
-_rt::run_ctors_once();let result0 = T::children(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+pub unsafe fn _export_method_node_next_sibling_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::next_sibling(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
 let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-let vec2 = result0;
-let len2 = vec2.len();
-let layout2 = _rt::alloc::Layout::from_size_align_unchecked(vec2.len() * 4, 4);
-let result2 = if layout2.size() != 0 {
-  let ptr = _rt::alloc::alloc(layout2).cast::<u8>();
-  if ptr.is_null()
-  {
-    _rt::alloc::handle_alloc_error(layout2);
-  }
-  ptr
-}else {
-  ::core::ptr::null_mut()
-};
-for (i, e) in vec2.into_iter().enumerate() {
-  let base = result2.add(i * 4);
-  {
-    *base.add(0).cast::<i32>() = (e).take_handle() as i32;
-  }
-}
-*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
-*ptr1.add(0).cast::<*mut u8>() = result2;
-ptr1
+match result0 {
+  Some(e) => {
+    *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+    *ptr1.add(4).cast::<i32>() = (e).take_handle() as i32;
+  },
+  None => {
+    {
+      *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+    }
+  },
+};ptr1
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_method_node_children<T: GuestNode>(arg0: *mut u8,) {
-  let l0 = *arg0.add(0).cast::<*mut u8>();
-  let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-  let base2 = l0;
-  let len2 = l1;
-  _rt::cabi_dealloc(base2, len2 * 4, 4);
+pub unsafe fn _export_method_node_prev_sibling_cabi<T: GuestNode>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::prev_sibling(unsafe { NodeBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+match result0 {
+  Some(e) => {
+    *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+    *ptr1.add(4).cast::<i32>() = (e).take_handle() as i32;
+  },
+  None => {
+    {
+      *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+    }
+  },
+};ptr1
 }
 pub trait Guest {
   type Tree: GuestTree;
@@ -627,7 +1577,7 @@ pub trait GuestTree: 'static {
 
     #[cfg(target_arch = "wasm32")]
     {
-      #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.0.1")]
+      #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.1.0")]
       unsafe extern "C" {
         #[link_name = "[resource-new]tree"]
         fn new(_: *mut u8) -> u32;
@@ -648,7 +1598,7 @@ pub trait GuestTree: 'static {
 
     #[cfg(target_arch = "wasm32")]
     {
-      #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.0.1")]
+      #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.1.0")]
       unsafe extern "C" {
         #[link_name = "[resource-rep]tree"]
         fn rep(_: u32) -> *mut u8;
@@ -661,6 +1611,13 @@ pub trait GuestTree: 'static {
 
   
   fn root(&self,) -> Node;
+  fn highlight(&self,) -> _rt::Vec::<Highlight>;
+  fn covering_element(&self,offset_start: u32,offset_end: u32,) -> Option<Node>;
+  fn token_at_offset(&self,offset: u32,) -> Option<Node>;
+  fn flatten(&self,) -> _rt::Vec::<FlatNode>;
+  /// Error is the message of the failed serialization
+  fn to_json(&self,) -> Result<_rt::String,_rt::String>;
+  fn diagnostics(&self,) -> _rt::Vec::<Diagnostic>;
 }
 pub trait GuestNode: 'static {
 
@@ -676,7 +1633,7 @@ pub trait GuestNode: 'static {
 
     #[cfg(target_arch = "wasm32")]
     {
-      #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.0.1")]
+      #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.1.0")]
       unsafe extern "C" {
         #[link_name = "[resource-new]node"]
         fn new(_: *mut u8) -> u32;
@@ -697,7 +1654,7 @@ pub trait GuestNode: 'static {
 
     #[cfg(target_arch = "wasm32")]
     {
-      #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.0.1")]
+      #[link(wasm_import_module = "[export]ritalin:parser/syntax@0.1.0")]
       unsafe extern "C" {
         #[link_name = "[resource-rep]node"]
         fn rep(_: u32) -> *mut u8;
@@ -714,70 +1671,161 @@ pub trait GuestNode: 'static {
   fn offset_end(&self,) -> u32;
   fn value(&self,) -> Option<_rt::String>;
   fn leading_trivia(&self,) -> _rt::Vec::<Node>;
-  fn traling_trivia(&self,) -> _rt::Vec::<Node>;
+  fn trailing_trivia(&self,) -> _rt::Vec::<Node>;
   fn children(&self,) -> _rt::Vec::<Node>;
+  fn literal_value(&self,) -> Result<LiteralValue,LiteralError>;
+  fn identifier(&self,) -> Result<_rt::String,LiteralError>;
+  fn text(&self,) -> _rt::String;
+  fn parent(&self,) -> Option<Node>;
+  fn ancestors(&self,) -> _rt::Vec::<Node>;
+  fn next_sibling(&self,) -> Option<Node>;
+  fn prev_sibling(&self,) -> Option<Node>;
 }
 #[doc(hidden)]
 
-macro_rules! __export_ritalin_parser_syntax_0_0_1_cabi{
+macro_rules! __export_ritalin_parser_syntax_0_1_0_cabi{
   ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-    #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[method]tree.root")]
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]tree.root")]
     unsafe extern "C" fn export_method_tree_root(arg0: *mut u8,) -> i32 {
       unsafe { $($path_to_types)*::_export_method_tree_root_cabi::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0) }
     }
-    #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[method]node.metadata")]
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]tree.highlight")]
+    unsafe extern "C" fn export_method_tree_highlight(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_tree_highlight_cabi::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]tree.highlight")]
+    unsafe extern "C" fn _post_return_method_tree_highlight(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_tree_highlight::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]tree.covering-element")]
+    unsafe extern "C" fn export_method_tree_covering_element(arg0: *mut u8,arg1: i32,arg2: i32,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_tree_covering_element_cabi::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0, arg1, arg2) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]tree.token-at-offset")]
+    unsafe extern "C" fn export_method_tree_token_at_offset(arg0: *mut u8,arg1: i32,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_tree_token_at_offset_cabi::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0, arg1) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]tree.flatten")]
+    unsafe extern "C" fn export_method_tree_flatten(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_tree_flatten_cabi::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]tree.flatten")]
+    unsafe extern "C" fn _post_return_method_tree_flatten(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_tree_flatten::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]tree.to-json")]
+    unsafe extern "C" fn export_method_tree_to_json(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_tree_to_json_cabi::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]tree.to-json")]
+    unsafe extern "C" fn _post_return_method_tree_to_json(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_tree_to_json::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]tree.diagnostics")]
+    unsafe extern "C" fn export_method_tree_diagnostics(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_tree_diagnostics_cabi::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]tree.diagnostics")]
+    unsafe extern "C" fn _post_return_method_tree_diagnostics(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_tree_diagnostics::<<$ty as $($path_to_types)*::Guest>::Tree>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.metadata")]
     unsafe extern "C" fn export_method_node_metadata(arg0: *mut u8,) -> *mut u8 {
       unsafe { $($path_to_types)*::_export_method_node_metadata_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.0.1#[method]node.metadata")]
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]node.metadata")]
     unsafe extern "C" fn _post_return_method_node_metadata(arg0: *mut u8,) {
       unsafe { $($path_to_types)*::__post_return_method_node_metadata::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[method]node.offset-start")]
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.offset-start")]
     unsafe extern "C" fn export_method_node_offset_start(arg0: *mut u8,) -> i32 {
       unsafe { $($path_to_types)*::_export_method_node_offset_start_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[method]node.offset-end")]
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.offset-end")]
     unsafe extern "C" fn export_method_node_offset_end(arg0: *mut u8,) -> i32 {
       unsafe { $($path_to_types)*::_export_method_node_offset_end_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[method]node.value")]
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.value")]
     unsafe extern "C" fn export_method_node_value(arg0: *mut u8,) -> *mut u8 {
       unsafe { $($path_to_types)*::_export_method_node_value_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.0.1#[method]node.value")]
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]node.value")]
     unsafe extern "C" fn _post_return_method_node_value(arg0: *mut u8,) {
       unsafe { $($path_to_types)*::__post_return_method_node_value::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[method]node.leading-trivia")]
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.leading-trivia")]
     unsafe extern "C" fn export_method_node_leading_trivia(arg0: *mut u8,) -> *mut u8 {
       unsafe { $($path_to_types)*::_export_method_node_leading_trivia_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.0.1#[method]node.leading-trivia")]
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]node.leading-trivia")]
     unsafe extern "C" fn _post_return_method_node_leading_trivia(arg0: *mut u8,) {
       unsafe { $($path_to_types)*::__post_return_method_node_leading_trivia::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[method]node.traling-trivia")]
-    unsafe extern "C" fn export_method_node_traling_trivia(arg0: *mut u8,) -> *mut u8 {
-      unsafe { $($path_to_types)*::_export_method_node_traling_trivia_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.trailing-trivia")]
+    unsafe extern "C" fn export_method_node_trailing_trivia(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_node_trailing_trivia_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.0.1#[method]node.traling-trivia")]
-    unsafe extern "C" fn _post_return_method_node_traling_trivia(arg0: *mut u8,) {
-      unsafe { $($path_to_types)*::__post_return_method_node_traling_trivia::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]node.trailing-trivia")]
+    unsafe extern "C" fn _post_return_method_node_trailing_trivia(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_node_trailing_trivia::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[method]node.children")]
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.children")]
     unsafe extern "C" fn export_method_node_children(arg0: *mut u8,) -> *mut u8 {
       unsafe { $($path_to_types)*::_export_method_node_children_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
-    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.0.1#[method]node.children")]
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]node.children")]
     unsafe extern "C" fn _post_return_method_node_children(arg0: *mut u8,) {
       unsafe { $($path_to_types)*::__post_return_method_node_children::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
     }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.literal-value")]
+    unsafe extern "C" fn export_method_node_literal_value(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_node_literal_value_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]node.literal-value")]
+    unsafe extern "C" fn _post_return_method_node_literal_value(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_node_literal_value::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.identifier")]
+    unsafe extern "C" fn export_method_node_identifier(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_node_identifier_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]node.identifier")]
+    unsafe extern "C" fn _post_return_method_node_identifier(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_node_identifier::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.text")]
+    unsafe extern "C" fn export_method_node_text(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_node_text_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]node.text")]
+    unsafe extern "C" fn _post_return_method_node_text(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_node_text::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.parent")]
+    unsafe extern "C" fn export_method_node_parent(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_node_parent_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.ancestors")]
+    unsafe extern "C" fn export_method_node_ancestors(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_node_ancestors_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/syntax@0.1.0#[method]node.ancestors")]
+    unsafe extern "C" fn _post_return_method_node_ancestors(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_node_ancestors::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.next-sibling")]
+    unsafe extern "C" fn export_method_node_next_sibling(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_node_next_sibling_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[method]node.prev-sibling")]
+    unsafe extern "C" fn export_method_node_prev_sibling(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_node_prev_sibling_cabi::<<$ty as $($path_to_types)*::Guest>::Node>(arg0) }
+    }
 
     const _: () = {
       #[doc(hidden)]
-      #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[dtor]tree")]
+      #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[dtor]tree")]
       #[allow(non_snake_case)]
       unsafe extern "C" fn dtor(rep: *mut u8) {
         unsafe {
@@ -791,7 +1839,7 @@ macro_rules! __export_ritalin_parser_syntax_0_0_1_cabi{
 
     const _: () = {
       #[doc(hidden)]
-      #[unsafe(export_name = "ritalin:parser/syntax@0.0.1#[dtor]node")]
+      #[unsafe(export_name = "ritalin:parser/syntax@0.1.0#[dtor]node")]
       #[allow(non_snake_case)]
       unsafe extern "C" fn dtor(rep: *mut u8) {
         unsafe {
@@ -805,11 +1853,11 @@ macro_rules! __export_ritalin_parser_syntax_0_0_1_cabi{
   };);
 }
 #[doc(hidden)]
-pub(crate) use __export_ritalin_parser_syntax_0_0_1_cabi;
+pub(crate) use __export_ritalin_parser_syntax_0_1_0_cabi;
 
 #[repr(align(8))]
-struct _RetArea([::core::mem::MaybeUninit::<u8>; 24+4*::core::mem::size_of::<*const u8>()]);
-static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 24+4*::core::mem::size_of::<*const u8>()]);
+struct _RetArea([::core::mem::MaybeUninit::<u8>; 8+5*::core::mem::size_of::<*const u8>()]);
+static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8+5*::core::mem::size_of::<*const u8>()]);
 
 }
 
@@ -957,7 +2005,7 @@ pub mod parsers {
 
       #[cfg(target_arch = "wasm32")]
       {
-        #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.0.1")]
+        #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.1.0")]
         unsafe extern "C" {
           #[link_name = "[resource-drop]parser"]
           fn drop(_: u32);
@@ -968,10 +2016,25 @@ pub mod parsers {
     }
   }
   
+  /// Range is where parsing stopped, or the edit scope for edit errors.
+  /// Code is one of `parser/parse-failed`, `parser/edit-not-found`, `parser/edit-in-fatal-error`,
+  /// `parser/invalid-edit` or `parser/internal-error` (the whole source or edit scope).
+  #[derive(Clone)]
+  pub struct ErrorDetail {
+    pub code: _rt::String,
+    pub message: _rt::String,
+    pub offset_start: u32,
+    pub offset_end: u32,
+  }
+  impl ::core::fmt::Debug for ErrorDetail {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+      f.debug_struct("ErrorDetail").field("code", &self.code).field("message", &self.message).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).finish()
+    }
+  }
   #[derive(Clone)]
   pub enum ParserError {
-    SyntaxError(_rt::String),
-    IncrementalEditError(_rt::String),
+    SyntaxError(ErrorDetail),
+    IncrementalEditError(ErrorDetail),
   }
   impl ::core::fmt::Debug for ParserError {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -1013,16 +2076,159 @@ pub mod parsers {
 
   type _IncrementalParserRep<T> = Option<T>;
 
-  impl IncrementalParser{
+  impl IncrementalParser{
+    /// Creates a new resource from the specified representation.
+    ///
+    /// This function will create a new resource handle by moving `val` onto
+    /// the heap and then passing that heap pointer to the component model to
+    /// create a handle. The owned handle is then returned as `IncrementalParser`.
+    pub fn new<T: GuestIncrementalParser>(val: T) -> Self {
+      Self::type_guard::<T>();
+      let val: _IncrementalParserRep<T> = Some(val);
+      let ptr: *mut _IncrementalParserRep<T> =
+      _rt::Box::into_raw(_rt::Box::new(val));
+      unsafe {
+        Self::from_handle(T::_resource_new(ptr.cast()))
+      }
+    }
+
+    /// Gets access to the underlying `T` which represents this resource.
+    pub fn get<T: GuestIncrementalParser>(&self) -> &T {
+      let ptr = unsafe { &*self.as_ptr::<T>() };
+      ptr.as_ref().unwrap()
+    }
+
+    /// Gets mutable access to the underlying `T` which represents this
+    /// resource.
+    pub fn get_mut<T: GuestIncrementalParser>(&mut self) -> &mut T {
+      let ptr = unsafe { &mut *self.as_ptr::<T>() };
+      ptr.as_mut().unwrap()
+    }
+
+    /// Consumes this resource and returns the underlying `T`.
+    pub fn into_inner<T: GuestIncrementalParser>(self) -> T {
+      let ptr = unsafe { &mut *self.as_ptr::<T>() };
+      ptr.take().unwrap()
+    }
+
+    #[doc(hidden)]
+    pub unsafe fn from_handle(handle: u32) -> Self {
+      Self {
+        handle: unsafe { _rt::Resource::from_handle(handle) },
+      }
+    }
+
+    #[doc(hidden)]
+    pub fn take_handle(&self) -> u32 {
+      _rt::Resource::take_handle(&self.handle)
+    }
+
+    #[doc(hidden)]
+    pub fn handle(&self) -> u32 {
+      _rt::Resource::handle(&self.handle)
+    }
+
+    // It's theoretically possible to implement the `GuestIncrementalParser` trait twice
+    // so guard against using it with two different types here.
+    #[doc(hidden)]
+    fn type_guard<T: 'static>() {
+      use core::any::TypeId;
+      static mut LAST_TYPE: Option<TypeId> = None;
+      unsafe {
+        assert!(!cfg!(target_feature = "atomics"));
+        let id = TypeId::of::<T>();
+        match LAST_TYPE {
+          Some(ty) => assert!(ty == id, "cannot use two types with this resource type"),
+          None => LAST_TYPE = Some(id),
+        }
+      }
+    }
+
+    #[doc(hidden)]
+    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+      Self::type_guard::<T>();
+      let _ = unsafe { _rt::Box::from_raw(handle as *mut _IncrementalParserRep<T>) };
+    }
+
+    fn as_ptr<T: GuestIncrementalParser>(&self) -> *mut _IncrementalParserRep<T> {
+      IncrementalParser::type_guard::<T>();
+      T::_resource_rep(self.handle()).cast()
+    }
+  }
+
+  /// A borrowed version of [`IncrementalParser`] which represents a borrowed value
+  /// with the lifetime `'a`.
+  #[derive(Debug)]
+  #[repr(transparent)]
+  pub struct IncrementalParserBorrow<'a> {
+    rep: *mut u8,
+    _marker: core::marker::PhantomData<&'a IncrementalParser>,
+  }
+
+  impl<'a> IncrementalParserBorrow<'a>{
+    #[doc(hidden)]
+    pub unsafe fn lift(rep: usize) -> Self {
+      Self {
+        rep: rep as *mut u8,
+        _marker: core::marker::PhantomData,
+      }
+    }
+
+    /// Gets access to the underlying `T` in this resource.
+    pub fn get<T: GuestIncrementalParser>(&self) -> &T {
+      let ptr = unsafe { &mut *self.as_ptr::<T>() };
+      ptr.as_ref().unwrap()
+    }
+
+    // NB: mutable access is not allowed due to the component model allowing
+    // multiple borrows of the same resource.
+
+    fn as_ptr<T: 'static>(&self) -> *mut _IncrementalParserRep<T> {
+      IncrementalParser::type_guard::<T>();
+      self.rep.cast()
+    }
+  }
+  
+
+  unsafe impl _rt::WasmResource for IncrementalParser{
+    #[inline]
+    unsafe fn drop(_handle: u32) {
+      #[cfg(not(target_arch = "wasm32"))]
+      unreachable!();
+
+      #[cfg(target_arch = "wasm32")]
+      {
+        #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.1.0")]
+        unsafe extern "C" {
+          #[link_name = "[resource-drop]incremental-parser"]
+          fn drop(_: u32);
+        }
+
+        unsafe { drop(_handle) };
+      }
+    }
+  }
+  
+  /// Owns the source and the current tree, so only edits cross the boundary
+
+  #[derive(Debug)]
+  #[repr(transparent)]
+  pub struct Document{
+    handle: _rt::Resource<Document>,
+  }
+
+  type _DocumentRep<T> = Option<T>;
+
+  impl Document{
     /// Creates a new resource from the specified representation.
     ///
     /// This function will create a new resource handle by moving `val` onto
     /// the heap and then passing that heap pointer to the component model to
-    /// create a handle. The owned handle is then returned as `IncrementalParser`.
-    pub fn new<T: GuestIncrementalParser>(val: T) -> Self {
+    /// create a handle. The owned handle is then returned as `Document`.
+    pub fn new<T: GuestDocument>(val: T) -> Self {
       Self::type_guard::<T>();
-      let val: _IncrementalParserRep<T> = Some(val);
-      let ptr: *mut _IncrementalParserRep<T> =
+      let val: _DocumentRep<T> = Some(val);
+      let ptr: *mut _DocumentRep<T> =
       _rt::Box::into_raw(_rt::Box::new(val));
       unsafe {
         Self::from_handle(T::_resource_new(ptr.cast()))
@@ -1030,20 +2236,20 @@ pub mod parsers {
     }
 
     /// Gets access to the underlying `T` which represents this resource.
-    pub fn get<T: GuestIncrementalParser>(&self) -> &T {
+    pub fn get<T: GuestDocument>(&self) -> &T {
       let ptr = unsafe { &*self.as_ptr::<T>() };
       ptr.as_ref().unwrap()
     }
 
     /// Gets mutable access to the underlying `T` which represents this
     /// resource.
-    pub fn get_mut<T: GuestIncrementalParser>(&mut self) -> &mut T {
+    pub fn get_mut<T: GuestDocument>(&mut self) -> &mut T {
       let ptr = unsafe { &mut *self.as_ptr::<T>() };
       ptr.as_mut().unwrap()
     }
 
     /// Consumes this resource and returns the underlying `T`.
-    pub fn into_inner<T: GuestIncrementalParser>(self) -> T {
+    pub fn into_inner<T: GuestDocument>(self) -> T {
       let ptr = unsafe { &mut *self.as_ptr::<T>() };
       ptr.take().unwrap()
     }
@@ -1065,7 +2271,7 @@ pub mod parsers {
       _rt::Resource::handle(&self.handle)
     }
 
-    // It's theoretically possible to implement the `GuestIncrementalParser` trait twice
+    // It's theoretically possible to implement the `GuestDocument` trait twice
     // so guard against using it with two different types here.
     #[doc(hidden)]
     fn type_guard<T: 'static>() {
@@ -1084,25 +2290,25 @@ pub mod parsers {
     #[doc(hidden)]
     pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
       Self::type_guard::<T>();
-      let _ = unsafe { _rt::Box::from_raw(handle as *mut _IncrementalParserRep<T>) };
+      let _ = unsafe { _rt::Box::from_raw(handle as *mut _DocumentRep<T>) };
     }
 
-    fn as_ptr<T: GuestIncrementalParser>(&self) -> *mut _IncrementalParserRep<T> {
-      IncrementalParser::type_guard::<T>();
+    fn as_ptr<T: GuestDocument>(&self) -> *mut _DocumentRep<T> {
+      Document::type_guard::<T>();
       T::_resource_rep(self.handle()).cast()
     }
   }
 
-  /// A borrowed version of [`IncrementalParser`] which represents a borrowed value
+  /// A borrowed version of [`Document`] which represents a borrowed value
   /// with the lifetime `'a`.
   #[derive(Debug)]
   #[repr(transparent)]
-  pub struct IncrementalParserBorrow<'a> {
+  pub struct DocumentBorrow<'a> {
     rep: *mut u8,
-    _marker: core::marker::PhantomData<&'a IncrementalParser>,
+    _marker: core::marker::PhantomData<&'a Document>,
   }
 
-  impl<'a> IncrementalParserBorrow<'a>{
+  impl<'a> DocumentBorrow<'a>{
     #[doc(hidden)]
     pub unsafe fn lift(rep: usize) -> Self {
       Self {
@@ -1112,7 +2318,7 @@ pub mod parsers {
     }
 
     /// Gets access to the underlying `T` in this resource.
-    pub fn get<T: GuestIncrementalParser>(&self) -> &T {
+    pub fn get<T: GuestDocument>(&self) -> &T {
       let ptr = unsafe { &mut *self.as_ptr::<T>() };
       ptr.as_ref().unwrap()
     }
@@ -1120,14 +2326,14 @@ pub mod parsers {
     // NB: mutable access is not allowed due to the component model allowing
     // multiple borrows of the same resource.
 
-    fn as_ptr<T: 'static>(&self) -> *mut _IncrementalParserRep<T> {
-      IncrementalParser::type_guard::<T>();
+    fn as_ptr<T: 'static>(&self) -> *mut _DocumentRep<T> {
+      Document::type_guard::<T>();
       self.rep.cast()
     }
   }
   
 
-  unsafe impl _rt::WasmResource for IncrementalParser{
+  unsafe impl _rt::WasmResource for Document{
     #[inline]
     unsafe fn drop(_handle: u32) {
       #[cfg(not(target_arch = "wasm32"))]
@@ -1135,9 +2341,9 @@ pub mod parsers {
 
       #[cfg(target_arch = "wasm32")]
       {
-        #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.0.1")]
+        #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.1.0")]
         unsafe extern "C" {
-          #[link_name = "[resource-drop]incremental-parser"]
+          #[link_name = "[resource-drop]document"]
           fn drop(_: u32);
         }
 
@@ -1146,6 +2352,18 @@ pub mod parsers {
     }
   }
   
+  /// Range of the reparsed statements in the new source (empty if none changed)
+  pub struct TreeDelta {
+    pub tree: SyntaxTree,
+    pub offset_start: u32,
+    pub offset_end: u32,
+    pub incremental: bool,
+  }
+  impl ::core::fmt::Debug for TreeDelta {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+      f.debug_struct("TreeDelta").field("tree", &self.tree).field("offset-start", &self.offset_start).field("offset-end", &self.offset_end).field("incremental", &self.incremental).finish()
+    }
+  }
   #[doc(hidden)]
   #[allow(non_snake_case)]
   pub unsafe fn _export_constructor_parser_cabi<T: GuestParser>() -> i32 {#[cfg(target_arch="wasm32")]
@@ -1169,21 +2387,39 @@ match result1 {
     match e {
       ParserError::SyntaxError(e) => {
         *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
-        let vec3 = (e.into_bytes()).into_boxed_slice();
-        let ptr3 = vec3.as_ptr().cast::<u8>();
-        let len3 = vec3.len();
-        ::core::mem::forget(vec3);
-        *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
-        *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3.cast_mut();
-      },
-      ParserError::IncrementalEditError(e) => {
-        *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-        let vec4 = (e.into_bytes()).into_boxed_slice();
+        let ErrorDetail{ code:code3, message:message3, offset_start:offset_start3, offset_end:offset_end3, } = e;
+        let vec4 = (code3.into_bytes()).into_boxed_slice();
         let ptr4 = vec4.as_ptr().cast::<u8>();
         let len4 = vec4.len();
         ::core::mem::forget(vec4);
         *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
         *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
+        let vec5 = (message3.into_bytes()).into_boxed_slice();
+        let ptr5 = vec5.as_ptr().cast::<u8>();
+        let len5 = vec5.len();
+        ::core::mem::forget(vec5);
+        *ptr2.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+        *ptr2.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
+        *ptr2.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start3);
+        *ptr2.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end3);
+      },
+      ParserError::IncrementalEditError(e) => {
+        *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+        let ErrorDetail{ code:code6, message:message6, offset_start:offset_start6, offset_end:offset_end6, } = e;
+        let vec7 = (code6.into_bytes()).into_boxed_slice();
+        let ptr7 = vec7.as_ptr().cast::<u8>();
+        let len7 = vec7.len();
+        ::core::mem::forget(vec7);
+        *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+        *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7.cast_mut();
+        let vec8 = (message6.into_bytes()).into_boxed_slice();
+        let ptr8 = vec8.as_ptr().cast::<u8>();
+        let len8 = vec8.len();
+        ::core::mem::forget(vec8);
+        *ptr2.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+        *ptr2.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr8.cast_mut();
+        *ptr2.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start6);
+        *ptr2.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end6);
       },
     }
   } },
@@ -1202,11 +2438,17 @@ pub unsafe fn __post_return_method_parser_parse<T: GuestParser>(arg0: *mut u8,)
           let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
           let l3 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
           _rt::cabi_dealloc(l2, l3, 1);
+          let l4 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l5 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l4, l5, 1);
         },
         _ => {
-          let l4 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-          let l5 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
-          _rt::cabi_dealloc(l4, l5, 1);
+          let l6 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l7 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l6, l7, 1);
+          let l8 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l9 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l8, l9, 1);
         },
       }
     },
@@ -1231,21 +2473,39 @@ match result0 {
     match e {
       ParserError::SyntaxError(e) => {
         *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
-        let vec2 = (e.into_bytes()).into_boxed_slice();
-        let ptr2 = vec2.as_ptr().cast::<u8>();
-        let len2 = vec2.len();
-        ::core::mem::forget(vec2);
-        *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
-        *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr2.cast_mut();
-      },
-      ParserError::IncrementalEditError(e) => {
-        *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-        let vec3 = (e.into_bytes()).into_boxed_slice();
+        let ErrorDetail{ code:code2, message:message2, offset_start:offset_start2, offset_end:offset_end2, } = e;
+        let vec3 = (code2.into_bytes()).into_boxed_slice();
         let ptr3 = vec3.as_ptr().cast::<u8>();
         let len3 = vec3.len();
         ::core::mem::forget(vec3);
         *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
         *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3.cast_mut();
+        let vec4 = (message2.into_bytes()).into_boxed_slice();
+        let ptr4 = vec4.as_ptr().cast::<u8>();
+        let len4 = vec4.len();
+        ::core::mem::forget(vec4);
+        *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+        *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
+        *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start2);
+        *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end2);
+      },
+      ParserError::IncrementalEditError(e) => {
+        *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+        let ErrorDetail{ code:code5, message:message5, offset_start:offset_start5, offset_end:offset_end5, } = e;
+        let vec6 = (code5.into_bytes()).into_boxed_slice();
+        let ptr6 = vec6.as_ptr().cast::<u8>();
+        let len6 = vec6.len();
+        ::core::mem::forget(vec6);
+        *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+        *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
+        let vec7 = (message5.into_bytes()).into_boxed_slice();
+        let ptr7 = vec7.as_ptr().cast::<u8>();
+        let len7 = vec7.len();
+        ::core::mem::forget(vec7);
+        *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+        *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7.cast_mut();
+        *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start5);
+        *ptr1.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end5);
       },
     }
   } },
@@ -1264,11 +2524,17 @@ pub unsafe fn __post_return_method_parser_incremental<T: GuestParser>(arg0: *mut
           let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
           let l3 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
           _rt::cabi_dealloc(l2, l3, 1);
+          let l4 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l5 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l4, l5, 1);
         },
         _ => {
-          let l4 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-          let l5 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
-          _rt::cabi_dealloc(l4, l5, 1);
+          let l6 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l7 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l6, l7, 1);
+          let l8 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l9 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l8, l9, 1);
         },
       }
     },
@@ -1291,21 +2557,123 @@ match result1 {
     match e {
       ParserError::SyntaxError(e) => {
         *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
-        let vec3 = (e.into_bytes()).into_boxed_slice();
-        let ptr3 = vec3.as_ptr().cast::<u8>();
-        let len3 = vec3.len();
-        ::core::mem::forget(vec3);
-        *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
-        *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3.cast_mut();
+        let ErrorDetail{ code:code3, message:message3, offset_start:offset_start3, offset_end:offset_end3, } = e;
+        let vec4 = (code3.into_bytes()).into_boxed_slice();
+        let ptr4 = vec4.as_ptr().cast::<u8>();
+        let len4 = vec4.len();
+        ::core::mem::forget(vec4);
+        *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+        *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
+        let vec5 = (message3.into_bytes()).into_boxed_slice();
+        let ptr5 = vec5.as_ptr().cast::<u8>();
+        let len5 = vec5.len();
+        ::core::mem::forget(vec5);
+        *ptr2.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+        *ptr2.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
+        *ptr2.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start3);
+        *ptr2.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end3);
       },
       ParserError::IncrementalEditError(e) => {
         *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-        let vec4 = (e.into_bytes()).into_boxed_slice();
+        let ErrorDetail{ code:code6, message:message6, offset_start:offset_start6, offset_end:offset_end6, } = e;
+        let vec7 = (code6.into_bytes()).into_boxed_slice();
+        let ptr7 = vec7.as_ptr().cast::<u8>();
+        let len7 = vec7.len();
+        ::core::mem::forget(vec7);
+        *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+        *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7.cast_mut();
+        let vec8 = (message6.into_bytes()).into_boxed_slice();
+        let ptr8 = vec8.as_ptr().cast::<u8>();
+        let len8 = vec8.len();
+        ::core::mem::forget(vec8);
+        *ptr2.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+        *ptr2.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr8.cast_mut();
+        *ptr2.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start6);
+        *ptr2.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end6);
+      },
+    }
+  } },
+};ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_incremental_parser_parse<T: GuestIncrementalParser>(arg0: *mut u8,) {
+  let l0 = i32::from(*arg0.add(0).cast::<u8>());
+  match l0 {
+    0 => (),
+    _ => {
+      let l1 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+      match l1 {
+        0 => {
+          let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l3 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l2, l3, 1);
+          let l4 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l5 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l4, l5, 1);
+        },
+        _ => {
+          let l6 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l7 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l6, l7, 1);
+          let l8 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l9 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l8, l9, 1);
+        },
+      }
+    },
+  }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_static_document_create_cabi<T: GuestDocument>(arg0: *mut u8,arg1: usize,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let len0 = arg1;
+let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+let result1 = T::create(_rt::string_lift(bytes0));
+let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+match result1 {
+  Ok(e) => { {
+    *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<i32>() = (e).take_handle() as i32;
+  } },
+  Err(e) => { {
+    *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+    match e {
+      ParserError::SyntaxError(e) => {
+        *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+        let ErrorDetail{ code:code3, message:message3, offset_start:offset_start3, offset_end:offset_end3, } = e;
+        let vec4 = (code3.into_bytes()).into_boxed_slice();
         let ptr4 = vec4.as_ptr().cast::<u8>();
         let len4 = vec4.len();
         ::core::mem::forget(vec4);
         *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
         *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4.cast_mut();
+        let vec5 = (message3.into_bytes()).into_boxed_slice();
+        let ptr5 = vec5.as_ptr().cast::<u8>();
+        let len5 = vec5.len();
+        ::core::mem::forget(vec5);
+        *ptr2.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+        *ptr2.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
+        *ptr2.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start3);
+        *ptr2.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end3);
+      },
+      ParserError::IncrementalEditError(e) => {
+        *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+        let ErrorDetail{ code:code6, message:message6, offset_start:offset_start6, offset_end:offset_end6, } = e;
+        let vec7 = (code6.into_bytes()).into_boxed_slice();
+        let ptr7 = vec7.as_ptr().cast::<u8>();
+        let len7 = vec7.len();
+        ::core::mem::forget(vec7);
+        *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+        *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7.cast_mut();
+        let vec8 = (message6.into_bytes()).into_boxed_slice();
+        let ptr8 = vec8.as_ptr().cast::<u8>();
+        let len8 = vec8.len();
+        ::core::mem::forget(vec8);
+        *ptr2.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+        *ptr2.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr8.cast_mut();
+        *ptr2.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start6);
+        *ptr2.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end6);
       },
     }
   } },
@@ -1313,7 +2681,7 @@ match result1 {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_method_incremental_parser_parse<T: GuestIncrementalParser>(arg0: *mut u8,) {
+pub unsafe fn __post_return_static_document_create<T: GuestDocument>(arg0: *mut u8,) {
   let l0 = i32::from(*arg0.add(0).cast::<u8>());
   match l0 {
     0 => (),
@@ -1324,12 +2692,132 @@ pub unsafe fn __post_return_method_incremental_parser_parse<T: GuestIncrementalP
           let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
           let l3 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
           _rt::cabi_dealloc(l2, l3, 1);
+          let l4 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l5 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l4, l5, 1);
         },
         _ => {
-          let l4 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-          let l5 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          let l6 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l7 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l6, l7, 1);
+          let l8 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l9 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l8, l9, 1);
+        },
+      }
+    },
+  }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_document_source_cabi<T: GuestDocument>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::source(unsafe { DocumentBorrow::lift(arg0 as u32 as usize) }.get());
+let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+let vec2 = (result0.into_bytes()).into_boxed_slice();
+let ptr2 = vec2.as_ptr().cast::<u8>();
+let len2 = vec2.len();
+::core::mem::forget(vec2);
+*ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
+*ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_document_source<T: GuestDocument>(arg0: *mut u8,) {
+  let l0 = *arg0.add(0).cast::<*mut u8>();
+  let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+  _rt::cabi_dealloc(l0, l1, 1);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_document_tree_cabi<T: GuestDocument>(arg0: *mut u8,) -> i32 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let result0 = T::tree(unsafe { DocumentBorrow::lift(arg0 as u32 as usize) }.get());
+(result0).take_handle() as i32
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_method_document_apply_edit_cabi<T: GuestDocument>(arg0: *mut u8,arg1: i32,arg2: i32,arg3: *mut u8,arg4: usize,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+_rt::run_ctors_once();let len0 = arg4;
+let bytes0 = _rt::Vec::from_raw_parts(arg3.cast(), len0, len0);
+let result1 = T::apply_edit(unsafe { DocumentBorrow::lift(arg0 as u32 as usize) }.get(), arg1 as u32, arg2 as u32, _rt::string_lift(bytes0));
+let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+match result1 {
+  Ok(e) => { {
+    *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+    let TreeDelta{ tree:tree3, offset_start:offset_start3, offset_end:offset_end3, incremental:incremental3, } = e;
+    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<i32>() = (tree3).take_handle() as i32;
+    *ptr2.add(4+1*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start3);
+    *ptr2.add(8+1*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end3);
+    *ptr2.add(12+1*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match incremental3 { true => 1, false => 0 }) as u8;
+  } },
+  Err(e) => { {
+    *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+    match e {
+      ParserError::SyntaxError(e) => {
+        *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+        let ErrorDetail{ code:code4, message:message4, offset_start:offset_start4, offset_end:offset_end4, } = e;
+        let vec5 = (code4.into_bytes()).into_boxed_slice();
+        let ptr5 = vec5.as_ptr().cast::<u8>();
+        let len5 = vec5.len();
+        ::core::mem::forget(vec5);
+        *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+        *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
+        let vec6 = (message4.into_bytes()).into_boxed_slice();
+        let ptr6 = vec6.as_ptr().cast::<u8>();
+        let len6 = vec6.len();
+        ::core::mem::forget(vec6);
+        *ptr2.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+        *ptr2.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
+        *ptr2.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start4);
+        *ptr2.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end4);
+      },
+      ParserError::IncrementalEditError(e) => {
+        *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+        let ErrorDetail{ code:code7, message:message7, offset_start:offset_start7, offset_end:offset_end7, } = e;
+        let vec8 = (code7.into_bytes()).into_boxed_slice();
+        let ptr8 = vec8.as_ptr().cast::<u8>();
+        let len8 = vec8.len();
+        ::core::mem::forget(vec8);
+        *ptr2.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+        *ptr2.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr8.cast_mut();
+        let vec9 = (message7.into_bytes()).into_boxed_slice();
+        let ptr9 = vec9.as_ptr().cast::<u8>();
+        let len9 = vec9.len();
+        ::core::mem::forget(vec9);
+        *ptr2.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+        *ptr2.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr9.cast_mut();
+        *ptr2.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_start7);
+        *ptr2.add(4+6*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset_end7);
+      },
+    }
+  } },
+};ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_method_document_apply_edit<T: GuestDocument>(arg0: *mut u8,) {
+  let l0 = i32::from(*arg0.add(0).cast::<u8>());
+  match l0 {
+    0 => (),
+    _ => {
+      let l1 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+      match l1 {
+        0 => {
+          let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l3 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l2, l3, 1);
+          let l4 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l5 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
           _rt::cabi_dealloc(l4, l5, 1);
         },
+        _ => {
+          let l6 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l7 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l6, l7, 1);
+          let l8 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+          let l9 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+          _rt::cabi_dealloc(l8, l9, 1);
+        },
       }
     },
   }
@@ -1337,6 +2825,7 @@ pub unsafe fn __post_return_method_incremental_parser_parse<T: GuestIncrementalP
 pub trait Guest {
   type Parser: GuestParser;
   type IncrementalParser: GuestIncrementalParser;
+  type Document: GuestDocument;
 }
 pub trait GuestParser: 'static {
 
@@ -1352,7 +2841,7 @@ pub trait GuestParser: 'static {
 
     #[cfg(target_arch = "wasm32")]
     {
-      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.0.1")]
+      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.1.0")]
       unsafe extern "C" {
         #[link_name = "[resource-new]parser"]
         fn new(_: *mut u8) -> u32;
@@ -1373,7 +2862,7 @@ pub trait GuestParser: 'static {
 
     #[cfg(target_arch = "wasm32")]
     {
-      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.0.1")]
+      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.1.0")]
       unsafe extern "C" {
         #[link_name = "[resource-rep]parser"]
         fn rep(_: u32) -> *mut u8;
@@ -1403,7 +2892,7 @@ pub trait GuestIncrementalParser: 'static {
 
     #[cfg(target_arch = "wasm32")]
     {
-      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.0.1")]
+      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.1.0")]
       unsafe extern "C" {
         #[link_name = "[resource-new]incremental-parser"]
         fn new(_: *mut u8) -> u32;
@@ -1424,7 +2913,7 @@ pub trait GuestIncrementalParser: 'static {
 
     #[cfg(target_arch = "wasm32")]
     {
-      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.0.1")]
+      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.1.0")]
       unsafe extern "C" {
         #[link_name = "[resource-rep]incremental-parser"]
         fn rep(_: u32) -> *mut u8;
@@ -1438,43 +2927,123 @@ pub trait GuestIncrementalParser: 'static {
   
   fn parse(&self,source: _rt::String,) -> Result<SyntaxTree,ParserError>;
 }
+pub trait GuestDocument: 'static {
+
+  #[doc(hidden)]
+  unsafe fn _resource_new(val: *mut u8) -> u32
+  where Self: Sized
+  {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+      let _ = val;
+      unreachable!();
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.1.0")]
+      unsafe extern "C" {
+        #[link_name = "[resource-new]document"]
+        fn new(_: *mut u8) -> u32;
+      }
+      unsafe { new(val) }
+    }
+  }
+
+  #[doc(hidden)]
+  fn _resource_rep(handle: u32) -> *mut u8
+  where Self: Sized
+  {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+      let _ = handle;
+      unreachable!();
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+      #[link(wasm_import_module = "[export]ritalin:parser/parsers@0.1.0")]
+      unsafe extern "C" {
+        #[link_name = "[resource-rep]document"]
+        fn rep(_: u32) -> *mut u8;
+      }
+      unsafe {
+        rep(handle)
+      }
+    }
+  }
+
+  
+  fn create(source: _rt::String,) -> Result<Document,ParserError>;
+  fn source(&self,) -> _rt::String;
+  fn tree(&self,) -> SyntaxTree;
+  fn apply_edit(&self,offset: u32,delete_len: u32,insert_text: _rt::String,) -> Result<TreeDelta,ParserError>;
+}
 #[doc(hidden)]
 
-macro_rules! __export_ritalin_parser_parsers_0_0_1_cabi{
+macro_rules! __export_ritalin_parser_parsers_0_1_0_cabi{
   ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-    #[unsafe(export_name = "ritalin:parser/parsers@0.0.1#[constructor]parser")]
+    #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[constructor]parser")]
     unsafe extern "C" fn export_constructor_parser() -> i32 {
       unsafe { $($path_to_types)*::_export_constructor_parser_cabi::<<$ty as $($path_to_types)*::Guest>::Parser>() }
     }
-    #[unsafe(export_name = "ritalin:parser/parsers@0.0.1#[method]parser.parse")]
+    #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[method]parser.parse")]
     unsafe extern "C" fn export_method_parser_parse(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
       unsafe { $($path_to_types)*::_export_method_parser_parse_cabi::<<$ty as $($path_to_types)*::Guest>::Parser>(arg0, arg1, arg2) }
     }
-    #[unsafe(export_name = "cabi_post_ritalin:parser/parsers@0.0.1#[method]parser.parse")]
+    #[unsafe(export_name = "cabi_post_ritalin:parser/parsers@0.1.0#[method]parser.parse")]
     unsafe extern "C" fn _post_return_method_parser_parse(arg0: *mut u8,) {
       unsafe { $($path_to_types)*::__post_return_method_parser_parse::<<$ty as $($path_to_types)*::Guest>::Parser>(arg0) }
     }
-    #[unsafe(export_name = "ritalin:parser/parsers@0.0.1#[method]parser.incremental")]
+    #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[method]parser.incremental")]
     unsafe extern "C" fn export_method_parser_incremental(arg0: *mut u8,arg1: i32,arg2: i32,arg3: i32,arg4: i32,) -> *mut u8 {
       unsafe { $($path_to_types)*::_export_method_parser_incremental_cabi::<<$ty as $($path_to_types)*::Guest>::Parser>(arg0, arg1, arg2, arg3, arg4) }
     }
-    #[unsafe(export_name = "cabi_post_ritalin:parser/parsers@0.0.1#[method]parser.incremental")]
+    #[unsafe(export_name = "cabi_post_ritalin:parser/parsers@0.1.0#[method]parser.incremental")]
     unsafe extern "C" fn _post_return_method_parser_incremental(arg0: *mut u8,) {
       unsafe { $($path_to_types)*::__post_return_method_parser_incremental::<<$ty as $($path_to_types)*::Guest>::Parser>(arg0) }
     }
-    #[unsafe(export_name = "ritalin:parser/parsers@0.0.1#[method]incremental-parser.parse")]
+    #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[method]incremental-parser.parse")]
     unsafe extern "C" fn export_method_incremental_parser_parse(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
       unsafe { $($path_to_types)*::_export_method_incremental_parser_parse_cabi::<<$ty as $($path_to_types)*::Guest>::IncrementalParser>(arg0, arg1, arg2) }
     }
-    #[unsafe(export_name = "cabi_post_ritalin:parser/parsers@0.0.1#[method]incremental-parser.parse")]
+    #[unsafe(export_name = "cabi_post_ritalin:parser/parsers@0.1.0#[method]incremental-parser.parse")]
     unsafe extern "C" fn _post_return_method_incremental_parser_parse(arg0: *mut u8,) {
       unsafe { $($path_to_types)*::__post_return_method_incremental_parser_parse::<<$ty as $($path_to_types)*::Guest>::IncrementalParser>(arg0) }
     }
+    #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[static]document.create")]
+    unsafe extern "C" fn export_static_document_create(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_static_document_create_cabi::<<$ty as $($path_to_types)*::Guest>::Document>(arg0, arg1) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/parsers@0.1.0#[static]document.create")]
+    unsafe extern "C" fn _post_return_static_document_create(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_static_document_create::<<$ty as $($path_to_types)*::Guest>::Document>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[method]document.source")]
+    unsafe extern "C" fn export_method_document_source(arg0: *mut u8,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_document_source_cabi::<<$ty as $($path_to_types)*::Guest>::Document>(arg0) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/parsers@0.1.0#[method]document.source")]
+    unsafe extern "C" fn _post_return_method_document_source(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_document_source::<<$ty as $($path_to_types)*::Guest>::Document>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[method]document.tree")]
+    unsafe extern "C" fn export_method_document_tree(arg0: *mut u8,) -> i32 {
+      unsafe { $($path_to_types)*::_export_method_document_tree_cabi::<<$ty as $($path_to_types)*::Guest>::Document>(arg0) }
+    }
+    #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[method]document.apply-edit")]
+    unsafe extern "C" fn export_method_document_apply_edit(arg0: *mut u8,arg1: i32,arg2: i32,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_method_document_apply_edit_cabi::<<$ty as $($path_to_types)*::Guest>::Document>(arg0, arg1, arg2, arg3, arg4) }
+    }
+    #[unsafe(export_name = "cabi_post_ritalin:parser/parsers@0.1.0#[method]document.apply-edit")]
+    unsafe extern "C" fn _post_return_method_document_apply_edit(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_method_document_apply_edit::<<$ty as $($path_to_types)*::Guest>::Document>(arg0) }
+    }
 
     const _: () = {
       #[doc(hidden)]
-      #[unsafe(export_name = "ritalin:parser/parsers@0.0.1#[dtor]parser")]
+      #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[dtor]parser")]
       #[allow(non_snake_case)]
       unsafe extern "C" fn dtor(rep: *mut u8) {
         unsafe {
@@ -1488,7 +3057,7 @@ macro_rules! __export_ritalin_parser_parsers_0_0_1_cabi{
 
     const _: () = {
       #[doc(hidden)]
-      #[unsafe(export_name = "ritalin:parser/parsers@0.0.1#[dtor]incremental-parser")]
+      #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[dtor]incremental-parser")]
       #[allow(non_snake_case)]
       unsafe extern "C" fn dtor(rep: *mut u8) {
         unsafe {
@@ -1499,15 +3068,29 @@ macro_rules! __export_ritalin_parser_parsers_0_0_1_cabi{
       }
     };
     
+
+    const _: () = {
+      #[doc(hidden)]
+      #[unsafe(export_name = "ritalin:parser/parsers@0.1.0#[dtor]document")]
+      #[allow(non_snake_case)]
+      unsafe extern "C" fn dtor(rep: *mut u8) {
+        unsafe {
+          $($path_to_types)*::Document::dtor::<
+          <$ty as $($path_to_types)*::Guest>::Document
+          >(rep)
+        }
+      }
+    };
+    
   };);
 }
 #[doc(hidden)]
-pub(crate) use __export_ritalin_parser_parsers_0_0_1_cabi;
+pub(crate) use __export_ritalin_parser_parsers_0_1_0_cabi;
 
 #[cfg_attr(target_pointer_width="64", repr(align(8)))]
 #[cfg_attr(target_pointer_width="32", repr(align(4)))]
-struct _RetArea([::core::mem::MaybeUninit::<u8>; 4*::core::mem::size_of::<*const u8>()]);
-static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 4*::core::mem::size_of::<*const u8>()]);
+struct _RetArea([::core::mem::MaybeUninit::<u8>; 8+6*::core::mem::size_of::<*const u8>()]);
+static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8+6*::core::mem::size_of::<*const u8>()]);
 
 }
 
@@ -1613,6 +3196,8 @@ mod _rt {
     }
   }
   pub use alloc_crate::boxed::Box;
+  pub use alloc_crate::string::String;
+  pub use alloc_crate::vec::Vec;
 
   #[cfg(target_arch = "wasm32")]
   pub fn run_ctors_once() {
@@ -1688,6 +3273,14 @@ mod _rt {
       self as i32
     }
   }
+  pub use alloc_crate::alloc;
+  pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+    if size == 0 {
+      return;
+    }
+    let layout = alloc::Layout::from_size_align_unchecked(size, align);
+    alloc::dealloc(ptr, layout);
+  }
   
   pub fn as_i64<T: AsI64>(t: T) -> i64 {
     t.as_i64()
@@ -1716,16 +3309,27 @@ mod _rt {
       self as i64
     }
   }
-  pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
-    if size == 0 {
-      return;
+  
+  pub fn as_f64<T: AsF64>(t: T) -> f64 {
+    t.as_f64()
+  }
+
+  pub trait AsF64 {
+    fn as_f64(self) -> f64;
+  }
+
+  impl<'a, T: Copy + AsF64> AsF64 for &'a T {
+    fn as_f64(self) -> f64 {
+      (*self).as_f64()
+    }
+  }
+  
+  impl AsF64 for f64 {
+    #[inline]
+    fn as_f64(self) -> f64 {
+      self as f64
     }
-    let layout = alloc::Layout::from_size_align_unchecked(size, align);
-    alloc::dealloc(ptr, layout);
   }
-  pub use alloc_crate::string::String;
-  pub use alloc_crate::alloc;
-  pub use alloc_crate::vec::Vec;
   pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
     if cfg!(debug_assertions) {
       String::from_utf8(bytes).unwrap()
@@ -1758,54 +3362,88 @@ mod _rt {
 macro_rules! __export_parser_world_impl {
   ($ty:ident) => (crate::bindings::parser_world::export!($ty with_types_in crate::bindings::parser_world););
   ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
-  $($path_to_types_root)*::exports::ritalin::parser::syntax::__export_ritalin_parser_syntax_0_0_1_cabi!($ty with_types_in $($path_to_types_root)*::exports::ritalin::parser::syntax);
-  $($path_to_types_root)*::exports::ritalin::parser::parsers::__export_ritalin_parser_parsers_0_0_1_cabi!($ty with_types_in $($path_to_types_root)*::exports::ritalin::parser::parsers);
+  $($path_to_types_root)*::exports::ritalin::parser::syntax::__export_ritalin_parser_syntax_0_1_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::ritalin::parser::syntax);
+  $($path_to_types_root)*::exports::ritalin::parser::parsers::__export_ritalin_parser_parsers_0_1_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::ritalin::parser::parsers);
   )
 }
 #[doc(inline)]
 pub(crate) use __export_parser_world_impl as export;
 
 #[cfg(target_arch = "wasm32")]
-#[unsafe(link_section = "component-type:wit-bindgen:0.41.0:ritalin:parser@0.0.1:parser-world:encoded world")]
+#[unsafe(link_section = "component-type:wit-bindgen:0.41.0:ritalin:parser@0.1.0:parser-world:encoded world")]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1628] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xd9\x0b\x01A\x02\x01\
-A\x0a\x01B\x0c\x01r\x04\x02idy\x04texts\x0ais-keyword\x7f\x0bis-terminal\x7f\x04\
-\0\x0bsyntax-kind\x03\0\0\x01ks\x01r\x04\x04kind\x01\x06offsety\x03leny\x05value\
-\x02\x04\0\x0atoken-item\x03\0\x03\x01p\x04\x01k\x05\x01r\x03\x07leading\x06\x04\
-main\x04\x08trailing\x06\x04\0\x05token\x03\0\x07\x01k\x08\x01r\x02\x0anext-inde\
-xy\x09lookahead\x09\x04\0\x0dscanner-scope\x03\0\x0a\x03\0\x1britalin:scanner/ty\
-pes@0.0.1\x05\0\x01B\x0c\x01r\x04\x02idy\x04texts\x0ais-keyword\x7f\x0bis-termin\
-al\x7f\x04\0\x0bsyntax-kind\x03\0\0\x01ks\x01r\x04\x04kind\x01\x06offsety\x03len\
-y\x05value\x02\x04\0\x0atoken-item\x03\0\x03\x01p\x04\x01k\x05\x01r\x03\x07leadi\
-ng\x06\x04main\x04\x08trailing\x06\x04\0\x05token\x03\0\x07\x01k\x08\x01r\x02\x0a\
-next-indexy\x09lookahead\x09\x04\0\x0dscanner-scope\x03\0\x0a\x04\0\x1britalin:s\
-canner/types@0.0.1\x05\x01\x02\x03\0\x01\x0bsyntax-kind\x01B\x1d\x02\x03\x02\x01\
-\x02\x04\0\x0bsyntax-kind\x03\0\0\x04\0\x04tree\x03\x01\x04\0\x04node\x03\x01\x01\
-m\x07\x09token-set\x0dleading-token\x0etrailing-token\x0amain-token\x04node\x05e\
-rror\x0bfatal-error\x04\0\x09node-type\x03\0\x04\x01m\x02\x06delete\x05shift\x04\
-\0\x0frecovery-status\x03\0\x06\x01k\x07\x01r\x04\x04kind\x01\x09node-type\x05\x05\
-statew\x08recovery\x08\x04\0\x08metadata\x03\0\x09\x01h\x02\x01i\x03\x01@\x01\x04\
-self\x0b\0\x0c\x04\0\x11[method]tree.root\x01\x0d\x01h\x03\x01@\x01\x04self\x0e\0\
-\x0a\x04\0\x15[method]node.metadata\x01\x0f\x01@\x01\x04self\x0e\0y\x04\0\x19[me\
-thod]node.offset-start\x01\x10\x04\0\x17[method]node.offset-end\x01\x10\x01ks\x01\
-@\x01\x04self\x0e\0\x11\x04\0\x12[method]node.value\x01\x12\x01p\x0c\x01@\x01\x04\
-self\x0e\0\x13\x04\0\x1b[method]node.leading-trivia\x01\x14\x04\0\x1b[method]nod\
-e.traling-trivia\x01\x14\x04\0\x15[method]node.children\x01\x14\x04\0\x1britalin\
-:parser/syntax@0.0.1\x05\x03\x02\x03\0\x02\x04tree\x01B\x18\x02\x03\x02\x01\x04\x04\
-\0\x04tree\x03\0\0\x04\0\x0bsyntax-tree\x03\0\x01\x04\0\x06parser\x03\x01\x01q\x02\
-\x0csyntax-error\x01s\0\x16incremental-edit-error\x01s\0\x04\0\x0cparser-error\x03\
-\0\x04\x01r\x03\x06offsety\x08from-leny\x06to-leny\x04\0\x0aedit-scope\x03\0\x06\
-\x04\0\x12incremental-parser\x03\x01\x01i\x03\x01@\0\0\x09\x04\0\x13[constructor\
-]parser\x01\x0a\x01h\x03\x01i\x02\x01j\x01\x0c\x01\x05\x01@\x02\x04self\x0b\x06s\
-ources\0\x0d\x04\0\x14[method]parser.parse\x01\x0e\x01i\x08\x01j\x01\x0f\x01\x05\
-\x01@\x03\x04self\x0b\x04tree\x0c\x04edit\x07\0\x10\x04\0\x1a[method]parser.incr\
-emental\x01\x11\x01h\x08\x01@\x02\x04self\x12\x06sources\0\x0d\x04\0\x20[method]\
-incremental-parser.parse\x01\x13\x04\0\x1critalin:parser/parsers@0.0.1\x05\x05\x04\
-\0!ritalin:parser/parser-world@0.0.1\x04\0\x0b\x12\x01\0\x0cparser-world\x03\0\0\
-\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bind\
-gen-rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3330] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xff\x18\x01A\x02\x01\
+A\x0a\x01B\x11\x01r\x04\x02idy\x04texts\x0ais-keyword\x7f\x0bis-terminal\x7f\x04\
+\0\x0bsyntax-kind\x03\0\0\x01q\x02\x11illegal-character\0\0\x0cunterminated\x01\x01\
+\0\x04\0\x0btoken-error\x03\0\x02\x01ks\x01k\x03\x01r\x05\x04kind\x01\x06offsety\
+\x03leny\x05value\x04\x05error\x05\x04\0\x0atoken-item\x03\0\x06\x01p\x07\x01k\x08\
+\x01r\x03\x07leading\x09\x04main\x07\x08trailing\x09\x04\0\x05token\x03\0\x0a\x01\
+k\x0b\x01r\x02\x0anext-indexy\x09lookahead\x0c\x04\0\x0dscanner-scope\x03\0\x0d\x01\
+r\x03\x06offsety\x08from-leny\x06to-leny\x04\0\x0aedit-scope\x03\0\x0f\x03\0\x1b\
+ritalin:scanner/types@0.1.0\x05\0\x01B\x11\x01r\x04\x02idy\x04texts\x0ais-keywor\
+d\x7f\x0bis-terminal\x7f\x04\0\x0bsyntax-kind\x03\0\0\x01q\x02\x11illegal-charac\
+ter\0\0\x0cunterminated\x01\x01\0\x04\0\x0btoken-error\x03\0\x02\x01ks\x01k\x03\x01\
+r\x05\x04kind\x01\x06offsety\x03leny\x05value\x04\x05error\x05\x04\0\x0atoken-it\
+em\x03\0\x06\x01p\x07\x01k\x08\x01r\x03\x07leading\x09\x04main\x07\x08trailing\x09\
+\x04\0\x05token\x03\0\x0a\x01k\x0b\x01r\x02\x0anext-indexy\x09lookahead\x0c\x04\0\
+\x0dscanner-scope\x03\0\x0d\x01r\x03\x06offsety\x08from-leny\x06to-leny\x04\0\x0a\
+edit-scope\x03\0\x0f\x04\0\x1britalin:scanner/types@0.1.0\x05\x01\x02\x03\0\x01\x0b\
+syntax-kind\x01BL\x02\x03\x02\x01\x02\x04\0\x0bsyntax-kind\x03\0\0\x04\0\x04tree\
+\x03\x01\x04\0\x04node\x03\x01\x01p}\x01q\x06\x07integer\x01x\0\x04real\x01u\0\x04\
+text\x01s\0\x04blob\x01\x04\0\x04null\0\0\x07boolean\x01\x7f\0\x04\0\x0dliteral-\
+value\x03\0\x05\x01q\x06\x0bnot-literal\0\0\x0enot-identifier\0\0\x10integer-ove\
+rflow\x01s\0\x0cinvalid-real\x01s\0\x0einvalid-escape\x01s\0\x0cinvalid-blob\x01\
+s\0\x04\0\x0dliteral-error\x03\0\x07\x01m\x07\x09token-set\x0dleading-token\x0et\
+railing-token\x0amain-token\x04node\x05error\x0bfatal-error\x04\0\x09node-type\x03\
+\0\x09\x01m\x02\x06delete\x05shift\x04\0\x0frecovery-status\x03\0\x0b\x01m\x0c\x07\
+keyword\x08function\x05table\x06column\x05alias\x06string\x06number\x04blob\x09p\
+arameter\x07comment\x08operator\x05error\x04\0\x0dhighlight-tag\x03\0\x0d\x01r\x03\
+\x03tag\x0e\x0coffset-starty\x0aoffset-endy\x04\0\x09highlight\x03\0\x0f\x01ky\x01\
+ks\x01k\x0c\x01r\x07\x04kind\x01\x09node-type\x0a\x06parent\x11\x0coffset-starty\
+\x0aoffset-endy\x05value\x12\x08recovery\x13\x04\0\x09flat-node\x03\0\x14\x01m\x04\
+\x05error\x07warning\x0binformation\x04hint\x04\0\x08severity\x03\0\x16\x01p\x01\
+\x01r\x07\x08severity\x17\x04codes\x07messages\x0coffset-starty\x0aoffset-endy\x08\
+expected\x18\x08recovery\x13\x04\0\x0adiagnostic\x03\0\x19\x01r\x04\x04kind\x01\x09\
+node-type\x0a\x05statey\x08recovery\x13\x04\0\x08metadata\x03\0\x1b\x01h\x02\x01\
+i\x03\x01@\x01\x04self\x1d\0\x1e\x04\0\x11[method]tree.root\x01\x1f\x01p\x10\x01\
+@\x01\x04self\x1d\0\x20\x04\0\x16[method]tree.highlight\x01!\x01k\x1e\x01@\x03\x04\
+self\x1d\x0coffset-starty\x0aoffset-endy\0\"\x04\0\x1d[method]tree.covering-elem\
+ent\x01#\x01@\x02\x04self\x1d\x06offsety\0\"\x04\0\x1c[method]tree.token-at-offs\
+et\x01$\x01p\x15\x01@\x01\x04self\x1d\0%\x04\0\x14[method]tree.flatten\x01&\x01j\
+\x01s\x01s\x01@\x01\x04self\x1d\0'\x04\0\x14[method]tree.to-json\x01(\x01p\x1a\x01\
+@\x01\x04self\x1d\0)\x04\0\x18[method]tree.diagnostics\x01*\x01h\x03\x01@\x01\x04\
+self+\0\x1c\x04\0\x15[method]node.metadata\x01,\x01@\x01\x04self+\0y\x04\0\x19[m\
+ethod]node.offset-start\x01-\x04\0\x17[method]node.offset-end\x01-\x01@\x01\x04s\
+elf+\0\x12\x04\0\x12[method]node.value\x01.\x01p\x1e\x01@\x01\x04self+\0/\x04\0\x1b\
+[method]node.leading-trivia\x010\x04\0\x1c[method]node.trailing-trivia\x010\x04\0\
+\x15[method]node.children\x010\x01j\x01\x06\x01\x08\x01@\x01\x04self+\01\x04\0\x1a\
+[method]node.literal-value\x012\x01j\x01s\x01\x08\x01@\x01\x04self+\03\x04\0\x17\
+[method]node.identifier\x014\x01@\x01\x04self+\0s\x04\0\x11[method]node.text\x01\
+5\x01@\x01\x04self+\0\"\x04\0\x13[method]node.parent\x016\x04\0\x16[method]node.\
+ancestors\x010\x04\0\x19[method]node.next-sibling\x016\x04\0\x19[method]node.pre\
+v-sibling\x016\x04\0\x1britalin:parser/syntax@0.1.0\x05\x03\x02\x03\0\x02\x04tre\
+e\x01B)\x02\x03\x02\x01\x04\x04\0\x04tree\x03\0\0\x04\0\x0bsyntax-tree\x03\0\x01\
+\x04\0\x06parser\x03\x01\x01r\x04\x04codes\x07messages\x0coffset-starty\x0aoffse\
+t-endy\x04\0\x0cerror-detail\x03\0\x04\x01q\x02\x0csyntax-error\x01\x05\0\x16inc\
+remental-edit-error\x01\x05\0\x04\0\x0cparser-error\x03\0\x06\x01r\x03\x06offset\
+y\x08from-leny\x06to-leny\x04\0\x0aedit-scope\x03\0\x08\x04\0\x12incremental-par\
+ser\x03\x01\x04\0\x08document\x03\x01\x01i\x02\x01r\x04\x04tree\x0c\x0coffset-st\
+arty\x0aoffset-endy\x0bincremental\x7f\x04\0\x0atree-delta\x03\0\x0d\x01i\x03\x01\
+@\0\0\x0f\x04\0\x13[constructor]parser\x01\x10\x01h\x03\x01j\x01\x0c\x01\x07\x01\
+@\x02\x04self\x11\x06sources\0\x12\x04\0\x14[method]parser.parse\x01\x13\x01i\x0a\
+\x01j\x01\x14\x01\x07\x01@\x03\x04self\x11\x04tree\x0c\x04edit\x09\0\x15\x04\0\x1a\
+[method]parser.incremental\x01\x16\x01h\x0a\x01@\x02\x04self\x17\x06sources\0\x12\
+\x04\0\x20[method]incremental-parser.parse\x01\x18\x01i\x0b\x01j\x01\x19\x01\x07\
+\x01@\x01\x06sources\0\x1a\x04\0\x17[static]document.create\x01\x1b\x01h\x0b\x01\
+@\x01\x04self\x1c\0s\x04\0\x17[method]document.source\x01\x1d\x01@\x01\x04self\x1c\
+\0\x0c\x04\0\x15[method]document.tree\x01\x1e\x01j\x01\x0e\x01\x07\x01@\x04\x04s\
+elf\x1c\x06offsety\x0adelete-leny\x0binsert-texts\0\x1f\x04\0\x1b[method]documen\
+t.apply-edit\x01\x20\x04\0\x1critalin:parser/parsers@0.1.0\x05\x05\x04\0!ritalin\
+:parser/parser-world@0.1.0\x04\0\x0b\x12\x01\0\x0cparser-world\x03\0\0\0G\x09pro\
+ducers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x06\
+0.41.0";
 
 #[inline(never)]
 #[doc(hidden)]
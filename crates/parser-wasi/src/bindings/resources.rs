@@ -13,16 +13,18 @@ impl parsers::GuestParser for ParserImpl {
     }
 
     fn parse(&self,source: String,) -> Result<parsers::SyntaxTree,parsers::ParserError> {
+        let len = source.len() as u32;
         match self.inner.borrow().parse(source) {
             Ok(tree) => Ok(parsers::SyntaxTree::new(SyntaxTreeImpl::new(tree))),
-            Err(err) => Err(parsers::ParserError::SyntaxError(err.to_string()),)
+            Err(err) => Err(parsers::ParserError::SyntaxError(error_detail(err, (0, len)))),
         }
     }
 
     fn incremental(&self,tree: parsers::SyntaxTree,edit: parsers::EditScope,) -> Result<parsers::IncrementalParser,parsers::ParserError> {
+        let (offset_start, offset_end) = (edit.offset, edit.offset + edit.from_len);
         match self.inner.borrow().incremental(&tree.into(), edit.into()) {
             Ok(parser) => Ok(parsers::IncrementalParser::new(IncrementalParserImpl::new(parser))),
-            Err(err) => Err(parsers::ParserError::IncrementalEditError(err.to_string())),
+            Err(err) => Err(parsers::ParserError::IncrementalEditError(error_detail(err, (offset_start, offset_end)))),
         }
     }
 }
@@ -39,13 +41,67 @@ impl IncrementalParserImpl {
 
 impl parsers::GuestIncrementalParser for IncrementalParserImpl {
     fn parse(&self,source: String,) -> Result<parsers::SyntaxTree,parsers::ParserError> {
+        let len = source.len() as u32;
         match self.inner.borrow().parse(source) {
             Ok(tree) => Ok(parsers::SyntaxTree::new(SyntaxTreeImpl::new(tree))),
-            Err(err) => Err(parsers::ParserError::SyntaxError(err.to_string()),)
+            Err(err) => Err(parsers::ParserError::SyntaxError(error_detail(err, (0, len)))),
         }
     }
 }
 
+pub struct DocumentImpl {
+    parser: ::parser::Parser,
+    inner: RefCell<::parser::document::Document>,
+}
+
+impl parsers::GuestDocument for DocumentImpl {
+    fn create(source: String,) -> Result<parsers::Document,parsers::ParserError> {
+        let len = source.len() as u32;
+        let parser = ::parser::Parser::new();
+        match ::parser::document::Document::new(&parser, source) {
+            Ok(document) => Ok(parsers::Document::new(DocumentImpl { parser, inner: RefCell::new(document) })),
+            Err(err) => Err(parsers::ParserError::SyntaxError(error_detail(err, (0, len)))),
+        }
+    }
+
+    fn source(&self,) -> String {
+        self.inner.borrow().source().to_string()
+    }
+
+    fn tree(&self,) -> parsers::SyntaxTree {
+        parsers::SyntaxTree::new(SyntaxTreeImpl::new(self.inner.borrow().tree().clone()))
+    }
+
+    fn apply_edit(&self,offset: u32,delete_len: u32,insert_text: String,) -> Result<parsers::TreeDelta,parsers::ParserError> {
+        let mut document = self.inner.borrow_mut();
+        match document.apply_edit(&self.parser, offset as usize, delete_len as usize, &insert_text) {
+            Ok(delta) => Ok(parsers::TreeDelta {
+                tree: parsers::SyntaxTree::new(SyntaxTreeImpl::new(document.tree().clone())),
+                offset_start: delta.changed.start().into(),
+                offset_end: delta.changed.end().into(),
+                incremental: delta.incremental,
+            }),
+            Err(err) => Err(parsers::ParserError::IncrementalEditError(error_detail(err, (offset, offset.saturating_add(delete_len))))),
+        }
+    }
+}
+
+// Code and range of the failure (`fallback` if the parser does not tell where it failed)
+fn error_detail(err: anyhow::Error, fallback: (u32, u32)) -> parsers::ErrorDetail {
+    use ::parser::ParseError;
+    let edit_range = |edit: &::parser::EditScope| (edit.offset, edit.offset.saturating_add(edit.from_len));
+
+    let (code, (offset_start, offset_end)) = match ParseError::of(&err) {
+        Some(ParseError::Failed { range, .. }) => ("parser/parse-failed", (range.start().into(), range.end().into())),
+        Some(ParseError::EditNotFound(edit)) => ("parser/edit-not-found", edit_range(edit)),
+        Some(ParseError::EditInFatalError(edit)) => ("parser/edit-in-fatal-error", edit_range(edit)),
+        Some(ParseError::InvalidEdit(edit)) => ("parser/invalid-edit", edit_range(edit)),
+        None => ("parser/internal-error", fallback),
+    };
+
+    parsers::ErrorDetail { code: code.to_string(), message: err.to_string(), offset_start, offset_end }
+}
+
 pub struct SyntaxTreeImpl {
     inner: ::parser::SyntaxTree,
 }
@@ -60,6 +116,36 @@ impl syntax::GuestTree for SyntaxTreeImpl {
     fn root(&self,) -> syntax::Node {
         syntax::Node::new(SyntaxNodeImpl { inner: self.inner.root() })
     }
+
+    fn highlight(&self,) -> Vec::<syntax::Highlight> {
+        ::parser::highlight::highlight(&self.inner).into_iter()
+            .map(|(range, tag)| syntax::Highlight { tag: tag.into(), offset_start: range.start().into(), offset_end: range.end().into() })
+            .collect()
+    }
+
+    fn covering_element(&self,offset_start: u32,offset_end: u32,) -> Option<syntax::Node> {
+        if offset_start > offset_end {
+            return None;
+        }
+        let range = ::parser::TextRange::new(offset_start.into(), offset_end.into());
+        self.inner.covering_element(range).as_ref().map(From::from)
+    }
+
+    fn token_at_offset(&self,offset: u32,) -> Option<syntax::Node> {
+        self.inner.token_at_offset(offset.into()).as_ref().map(From::from)
+    }
+
+    fn flatten(&self,) -> Vec::<syntax::FlatNode> {
+        ::parser::flatten::flatten(&self.inner).into_iter().map(From::from).collect()
+    }
+
+    fn to_json(&self,) -> Result<String,String> {
+        ::parser::flatten::to_json(&self.inner).map_err(|err| err.to_string())
+    }
+
+    fn diagnostics(&self,) -> Vec::<syntax::Diagnostic> {
+        self.inner.syntax_errors().into_iter().map(From::from).collect()
+    }
 }
 
 impl From<syntax::Tree> for ::parser::SyntaxTree {
@@ -79,7 +165,7 @@ impl syntax::GuestNode for SyntaxNodeImpl {
         syntax::Metadata {
             kind: scanner_wasi::scanner_types::SyntaxKind::from(&self.inner.kind()),
             node_type: metadata.node_type.into(),
-            state: metadata.state as u64,
+            state: metadata.state as u32,
             recovery: metadata.recovery.map(From::from),
         }
     }
@@ -100,13 +186,41 @@ impl syntax::GuestNode for SyntaxNodeImpl {
         self.inner.leading_trivia().iter().map(From::from).collect()
     }
     
-    fn traling_trivia(&self,) -> Vec::<syntax::Node> {
+    fn trailing_trivia(&self,) -> Vec::<syntax::Node> {
         self.inner.trailing_trivia().iter().map(From::from).collect()
     }
     
     fn children(&self,) -> Vec::<syntax::Node> {
         self.inner.children().map(|node| From::from(&node)).collect()
     }
+    
+    fn literal_value(&self,) -> Result<syntax::LiteralValue,syntax::LiteralError> {
+        self.inner.literal_value().map(From::from).map_err(From::from)
+    }
+    
+    fn identifier(&self,) -> Result<String,syntax::LiteralError> {
+        self.inner.identifier().map_err(From::from)
+    }
+
+    fn text(&self,) -> String {
+        self.inner.text()
+    }
+
+    fn parent(&self,) -> Option<syntax::Node> {
+        self.inner.parent().as_ref().map(From::from)
+    }
+
+    fn ancestors(&self,) -> Vec::<syntax::Node> {
+        self.inner.ancestors().iter().map(From::from).collect()
+    }
+
+    fn next_sibling(&self,) -> Option<syntax::Node> {
+        self.inner.next_sibling().as_ref().map(From::from)
+    }
+
+    fn prev_sibling(&self,) -> Option<syntax::Node> {
+        self.inner.prev_sibling().as_ref().map(From::from)
+    }
 }
 
 impl From<&::parser::SyntaxNode> for syntax::Node {
@@ -115,6 +229,47 @@ impl From<&::parser::SyntaxNode> for syntax::Node {
     }
 }
 
+impl From<::parser::flatten::FlatNode> for syntax::FlatNode {
+    fn from(value: ::parser::flatten::FlatNode) -> Self {
+        syntax::FlatNode {
+            kind: scanner_wasi::scanner_types::SyntaxKind::from(&value.kind),
+            node_type: value.node_type.into(),
+            parent: value.parent.map(|index| index as u32),
+            offset_start: value.range.start().into(),
+            offset_end: value.range.end().into(),
+            value: value.value,
+            recovery: value.recovery.map(From::from),
+        }
+    }
+}
+
+impl From<::parser::SyntaxError> for syntax::Diagnostic {
+    fn from(value: ::parser::SyntaxError) -> Self {
+        let diagnostic = value.diagnostic;
+
+        syntax::Diagnostic {
+            severity: diagnostic.severity.into(),
+            code: diagnostic.code,
+            message: diagnostic.message,
+            offset_start: diagnostic.range.start().into(),
+            offset_end: diagnostic.range.end().into(),
+            expected: value.expected.iter().map(scanner_wasi::scanner_types::SyntaxKind::from).collect(),
+            recovery: value.recovery.map(From::from),
+        }
+    }
+}
+
+impl From<::parser::Severity> for syntax::Severity {
+    fn from(value: ::parser::Severity) -> Self {
+        match value {
+            ::parser::Severity::Error => syntax::Severity::Error,
+            ::parser::Severity::Warning => syntax::Severity::Warning,
+            ::parser::Severity::Information => syntax::Severity::Information,
+            ::parser::Severity::Hint => syntax::Severity::Hint,
+        }
+    }
+}
+
 impl From<::parser::NodeType> for syntax::NodeType {
     fn from(value: ::parser::NodeType) -> Self {
         match value {
@@ -138,6 +293,51 @@ impl From<::parser::Recovery> for syntax::RecoveryStatus {
     }
 }
 
+impl From<::parser::highlight::HighlightTag> for syntax::HighlightTag {
+    fn from(value: ::parser::highlight::HighlightTag) -> Self {
+        match value {
+            ::parser::highlight::HighlightTag::Keyword => syntax::HighlightTag::Keyword,
+            ::parser::highlight::HighlightTag::Function => syntax::HighlightTag::Function,
+            ::parser::highlight::HighlightTag::Table => syntax::HighlightTag::Table,
+            ::parser::highlight::HighlightTag::Column => syntax::HighlightTag::Column,
+            ::parser::highlight::HighlightTag::Alias => syntax::HighlightTag::Alias,
+            ::parser::highlight::HighlightTag::String => syntax::HighlightTag::String,
+            ::parser::highlight::HighlightTag::Number => syntax::HighlightTag::Number,
+            ::parser::highlight::HighlightTag::Blob => syntax::HighlightTag::Blob,
+            ::parser::highlight::HighlightTag::Parameter => syntax::HighlightTag::Parameter,
+            ::parser::highlight::HighlightTag::Comment => syntax::HighlightTag::Comment,
+            ::parser::highlight::HighlightTag::Operator => syntax::HighlightTag::Operator,
+            ::parser::highlight::HighlightTag::Error => syntax::HighlightTag::Error,
+        }
+    }
+}
+
+impl From<::parser::LiteralValue> for syntax::LiteralValue {
+    fn from(value: ::parser::LiteralValue) -> Self {
+        match value {
+            ::parser::LiteralValue::Integer(x) => syntax::LiteralValue::Integer(x),
+            ::parser::LiteralValue::Real(x) => syntax::LiteralValue::Real(x),
+            ::parser::LiteralValue::Text(x) => syntax::LiteralValue::Text(x),
+            ::parser::LiteralValue::Blob(x) => syntax::LiteralValue::Blob(x),
+            ::parser::LiteralValue::Null => syntax::LiteralValue::Null,
+            ::parser::LiteralValue::Bool(x) => syntax::LiteralValue::Boolean(x),
+        }
+    }
+}
+
+impl From<::parser::LiteralError> for syntax::LiteralError {
+    fn from(value: ::parser::LiteralError) -> Self {
+        match value {
+            ::parser::LiteralError::NotLiteral => syntax::LiteralError::NotLiteral,
+            ::parser::LiteralError::NotIdentifier => syntax::LiteralError::NotIdentifier,
+            ::parser::LiteralError::IntegerOverflow(text) => syntax::LiteralError::IntegerOverflow(text),
+            ::parser::LiteralError::InvalidReal(text) => syntax::LiteralError::InvalidReal(text),
+            ::parser::LiteralError::InvalidEscape(text) => syntax::LiteralError::InvalidEscape(text),
+            ::parser::LiteralError::InvalidBlob(text) => syntax::LiteralError::InvalidBlob(text),
+        }
+    }
+}
+
 impl From<parsers::EditScope> for ::parser::EditScope {
     fn from(value: parsers::EditScope) -> Self {
         Self {
@@ -153,9 +353,126 @@ pub struct ParserComponent;
 impl parsers::Guest for ParserComponent {
     type Parser = ParserImpl;
     type IncrementalParser = IncrementalParserImpl;
+    type Document = DocumentImpl;
 }
 
 impl syntax::Guest for ParserComponent {
     type Tree = SyntaxTreeImpl;
     type Node = SyntaxNodeImpl;
-}
\ No newline at end of file
+}
+
+// Exports of `ritalin:parser@0.0.1` sharing the resources above
+#[cfg(feature = "compat")]
+mod compat {
+    use std::cell::RefCell;
+
+    use super::{ParserComponent, ParserImpl, IncrementalParserImpl, SyntaxTreeImpl, SyntaxNodeImpl};
+    use super::super::compat_world::exports::ritalin::parser::parsers;
+    use super::super::compat_world::exports::ritalin::parser::syntax;
+
+    impl parsers::GuestParser for ParserImpl {
+        fn new() -> Self {
+            Self { inner: RefCell::new(::parser::Parser::new()) }
+        }
+
+        fn parse(&self,source: String,) -> Result<parsers::SyntaxTree,parsers::ParserError> {
+            match self.inner.borrow().parse(source) {
+                Ok(tree) => Ok(parsers::SyntaxTree::new(SyntaxTreeImpl::new(tree))),
+                Err(err) => Err(parsers::ParserError::SyntaxError(err.to_string()),)
+            }
+        }
+
+        fn incremental(&self,tree: parsers::SyntaxTree,edit: parsers::EditScope,) -> Result<parsers::IncrementalParser,parsers::ParserError> {
+            let edit = ::parser::EditScope { offset: edit.offset, from_len: edit.from_len, to_len: edit.to_len };
+            match self.inner.borrow().incremental(&tree.into(), edit) {
+                Ok(parser) => Ok(parsers::IncrementalParser::new(IncrementalParserImpl::new(parser))),
+                Err(err) => Err(parsers::ParserError::IncrementalEditError(err.to_string())),
+            }
+        }
+    }
+
+    impl parsers::GuestIncrementalParser for IncrementalParserImpl {
+        fn parse(&self,source: String,) -> Result<parsers::SyntaxTree,parsers::ParserError> {
+            match self.inner.borrow().parse(source) {
+                Ok(tree) => Ok(parsers::SyntaxTree::new(SyntaxTreeImpl::new(tree))),
+                Err(err) => Err(parsers::ParserError::SyntaxError(err.to_string()),)
+            }
+        }
+    }
+
+    impl syntax::GuestTree for SyntaxTreeImpl {
+        fn root(&self,) -> syntax::Node {
+            syntax::Node::new(SyntaxNodeImpl { inner: self.inner.root() })
+        }
+    }
+
+    impl From<syntax::Tree> for ::parser::SyntaxTree {
+        fn from(value: syntax::Tree) -> Self {
+            value.get::<SyntaxTreeImpl>().inner.clone()
+        }
+    }
+
+    impl syntax::GuestNode for SyntaxNodeImpl {
+        fn metadata(&self,) -> syntax::Metadata {
+            let metadata = self.inner.metadata();
+
+            syntax::Metadata {
+                kind: scanner_wasi::compat_scanner_types::SyntaxKind::from(&self.inner.kind()),
+                node_type: match metadata.node_type {
+                    ::parser::NodeType::TokenSet => syntax::NodeType::TokenSet,
+                    ::parser::NodeType::LeadingToken => syntax::NodeType::LeadingToken,
+                    ::parser::NodeType::TrailingToken => syntax::NodeType::TrailingToken,
+                    ::parser::NodeType::MainToken => syntax::NodeType::MainToken,
+                    ::parser::NodeType::Node => syntax::NodeType::Node,
+                    ::parser::NodeType::Error => syntax::NodeType::Error,
+                    ::parser::NodeType::FatalError => syntax::NodeType::FatalError,
+                },
+                state: metadata.state as u64,
+                recovery: metadata.recovery.map(|recovery| match recovery {
+                    ::parser::Recovery::Delete => syntax::RecoveryStatus::Delete,
+                    ::parser::Recovery::Shift => syntax::RecoveryStatus::Shift,
+                }),
+            }
+        }
+
+        fn offset_start(&self,) -> u32 {
+            self.inner.text_range().start().into()
+        }
+
+        fn offset_end(&self,) -> u32 {
+            self.inner.text_range().end().into()
+        }
+
+        fn value(&self,) -> Option<String> {
+            self.inner.value()
+        }
+
+        fn leading_trivia(&self,) -> Vec::<syntax::Node> {
+            self.inner.leading_trivia().iter().map(From::from).collect()
+        }
+
+        fn traling_trivia(&self,) -> Vec::<syntax::Node> {
+            self.inner.trailing_trivia().iter().map(From::from).collect()
+        }
+
+        fn children(&self,) -> Vec::<syntax::Node> {
+            self.inner.children().map(|node| From::from(&node)).collect()
+        }
+    }
+
+    impl From<&::parser::SyntaxNode> for syntax::Node {
+        fn from(value: &::parser::SyntaxNode) -> Self {
+            syntax::Node::new(SyntaxNodeImpl { inner: value.clone() })
+        }
+    }
+
+    impl parsers::Guest for ParserComponent {
+        type Parser = ParserImpl;
+        type IncrementalParser = IncrementalParserImpl;
+    }
+
+    impl syntax::Guest for ParserComponent {
+        type Tree = SyntaxTreeImpl;
+        type Node = SyntaxNodeImpl;
+    }
+}
@@ -121,6 +121,7 @@ mod parser_tests {
             let annotation = error_node.metadata();
             assert_eq!(NodeType::Error, annotation.node_type);
             assert_eq!(Some(Recovery::Shift), annotation.recovery);
+            assert!(!annotation.inserted.is_empty());
             break 'error_node;
         }
         
@@ -220,6 +221,10 @@ mod parser_tests {
         let tree = parser.parse(source0.into())?;
 
         dump_tree(&tree);
+
+        // The failed state is not kept, so edits inside need a full parse
+        let err = parser.incremental(&tree, parser::EditScope { offset: 11, from_len: 3, to_len: 3 }).err().expect("Edit inside a fatal error node");
+        assert!(matches!(parser::ParseError::of(&err), Some(parser::ParseError::EditInFatalError(_))));
         Ok(())
     }
 
@@ -239,4 +244,642 @@ mod parser_tests {
         dump_tree(&tree);
         Ok(())
     }
+
+    #[test]
+    fn test_quick_fixes() -> Result<(), anyhow::Error> {
+        let source = "SELECT 123 DELETE FROM foo;";
+        let tree = Parser::new().parse(source.into())?;
+
+        let fixes = tree.quick_fixes();
+        assert_eq!(1, fixes.len());
+        assert_eq!("Remove stray `DELETE`", fixes[0].title);
+        assert_eq!("SELECT 123 FROM foo;", parser::TextEdit::apply(source, &fixes[0].edits));
+
+        let source = "INSERT foo VALUES (1);";
+        let tree = Parser::new().parse(source.into())?;
+
+        let fixes = tree.quick_fixes();
+        assert_eq!(1, fixes.len());
+        assert_eq!("Insert `INTO`", fixes[0].title);
+        assert_eq!("INSERT INTO foo VALUES (1);", parser::TextEdit::apply(source, &fixes[0].edits));
+
+        // Dropped EOF has no text to remove
+        let tree = Parser::new().parse("SELECT (1 + 2;".into())?;
+        assert_eq!(vec!["Remove stray `;`"], tree.quick_fixes().iter().map(|fix| fix.title.as_str()).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_expr_fragment() -> Result<(), anyhow::Error> {
+        let node = parser::make::expr("t.code  =  /* x */ 10")?;
+
+        assert_eq!(syntax_kind::r#expr, node.kind());
+        assert_eq!("t.code = 10", node.text());
+
+        let source = "SELECT * FROM foo WHERE a = 1;";
+        let tree = Parser::new().parse(source.into())?;
+        let target = tree.root().descendants()
+            .find(|node| node.kind() == syntax_kind::r#expr && node.text().trim() == "a = 1")
+            .expect("Target expr does not exist.")
+        ;
+        let new_tree = parser::make::replace(&tree, &target, &node)?;
+
+        assert_eq!("SELECT * FROM foo WHERE t.code = 10;", new_tree.display());
+
+        // A node of another tree is rejected, even with the same text
+        let other = Parser::new().parse(source.into())?;
+        let foreign = other.root().descendants()
+            .find(|node| node.kind() == syntax_kind::r#expr && node.text().trim() == "a = 1")
+            .expect("Target expr does not exist.")
+        ;
+        assert!(parser::make::replace(&tree, &foreign, &node).is_err());
+
+        // The replacement must fit in the place of the target
+        let table = tree.root().descendants()
+            .find(|node| node.kind() == syntax_kind::r#seltablist)
+            .expect("Target table does not exist.")
+        ;
+        assert!(parser::make::replace(&tree, &table, &parser::make::where_clause("b = 2")?).is_err());
+
+        // Swapping the errors of a broken target for new ones is rejected too
+        let tree = Parser::new().parse("INSERT INTO foo VALUES (1 2 3);".into())?;
+        let cmd = tree.root().descendants()
+            .find(|node| node.kind() == syntax_kind::r#cmd)
+            .expect("Target cmd does not exist.")
+        ;
+        assert!(parser::make::replace(&tree, &cmd, &parser::make::expr("x")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_catalog() -> Result<(), anyhow::Error> {
+        use parser::schema::{Affinity, Catalog};
+
+        let source = concat!(
+            "CREATE TABLE foo (id INTEGER PRIMARY KEY AUTOINCREMENT, \"Name\" VARCHAR(20) NOT NULL DEFAULT 'x', ref_id INT REFERENCES bar(id), price) STRICT;\n",
+            "CREATE TABLE bar (id INT, code TEXT, PRIMARY KEY (id, code), UNIQUE (code)) WITHOUT ROWID;\n",
+            "CREATE UNIQUE INDEX idx_foo ON foo (\"Name\") WHERE price > 0;\n",
+            "CREATE VIEW v_foo AS SELECT id FROM foo;\n",
+        );
+        let parser = Parser::new();
+        let tree = parser.parse(source.into())?;
+        let mut catalog = Catalog::from_tree(&tree);
+        catalog.collect(&parser.parse("CREATE TABLE broken (a INT, b TEXT DEFAULT );".into())?.root());
+
+        let foo = catalog.table("foo").expect("Table foo does not exist.");
+        assert!(foo.strict);
+        assert_eq!(vec!["id", "Name", "ref_id", "price"], foo.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>());
+        assert_eq!(vec!["id".to_string()], foo.primary_key);
+        assert!(foo.columns[0].autoincrement);
+        assert_eq!(Some("VARCHAR(20)".to_string()), foo.columns[1].declared_type);
+        assert_eq!(Affinity::Text, foo.columns[1].affinity);
+        assert!(foo.columns[1].not_null);
+        assert_eq!(Some("'x'".to_string()), foo.columns[1].default);
+        assert_eq!(Affinity::Blob, foo.columns[3].affinity);
+        assert_eq!("bar", foo.foreign_keys[0].foreign_table);
+        assert_eq!("id INTEGER PRIMARY KEY AUTOINCREMENT", &source[foo.columns[0].range]);
+
+        let bar = catalog.table("bar").expect("Table bar does not exist.");
+        assert!(bar.without_rowid);
+        assert_eq!(vec!["id".to_string(), "code".to_string()], bar.primary_key);
+        assert_eq!(vec![vec!["code".to_string()]], bar.unique_keys);
+
+        let index = catalog.index("idx_foo").expect("Index does not exist.");
+        assert!(index.unique);
+        assert_eq!("foo", index.table);
+        assert_eq!(vec!["Name".to_string()], index.columns);
+        assert_eq!(Some("price > 0".to_string()), index.where_clause);
+
+        assert!(catalog.view("v_foo").is_some());
+        let broken = catalog.table("broken").expect("Table broken does not exist.");
+        assert_eq!(vec!["a", "b"], broken.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_migration_replay() -> Result<(), anyhow::Error> {
+        let parser = Parser::new();
+        let migrations = [
+            ("001", parser.parse("CREATE TABLE foo (id INT PRIMARY KEY, name TEXT); CREATE INDEX idx_name ON foo (name);".into())?),
+            ("002", parser.parse("ALTER TABLE foo RENAME COLUMN name TO title; ALTER TABLE foo ADD COLUMN code INT NOT NULL; ALTER TABLE foo ADD age INT;".into())?),
+            ("003", parser.parse("ALTER TABLE foo RENAME TO bar; ALTER TABLE bar DROP COLUMN missing; DROP INDEX idx_name;".into())?),
+        ];
+        let steps = parser::schema::replay(migrations.iter().map(|(name, tree)| (*name, tree)));
+
+        assert_eq!(3, steps.len());
+        assert!(steps[0].diagnostics.is_empty());
+
+        let foo = steps[1].catalog.table("foo").expect("Table foo does not exist.");
+        assert_eq!(vec!["id", "title", "age"], foo.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>());
+        assert_eq!(vec!["title".to_string()], steps[1].catalog.index("idx_name").map(|index| index.columns.clone()).unwrap_or_default());
+        assert_eq!(vec!["schema/not-null-without-default"], steps[1].diagnostics.iter().map(|d| d.code.as_str()).collect::<Vec<_>>());
+
+        assert!(steps[2].catalog.table("foo").is_none());
+        assert!(steps[2].catalog.table("bar").is_some());
+        assert!(steps[2].catalog.indexes.is_empty());
+        assert_eq!(vec!["schema/no-such-column"], steps[2].diagnostics.iter().map(|d| d.code.as_str()).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_names() -> Result<(), anyhow::Error> {
+        use parser::{resolve::{resolve, SourceKind}, schema::Catalog};
+
+        let parser = Parser::new();
+        let schema = parser.parse("CREATE TABLE foo (id INT, code INT, name TEXT); CREATE TABLE bar (id INT, foo_id INT, name TEXT);".into())?;
+        let catalog = Catalog::from_tree(&schema);
+
+        let source = concat!(
+            "WITH c(x) AS (SELECT code FROM foo) ",
+            "SELECT f.code, b.name, x, id, s.total, * FROM foo f JOIN bar b USING (id) JOIN c, (SELECT count(*) AS total FROM bar) s ",
+            "WHERE EXISTS (SELECT 1 FROM bar WHERE bar.foo_id = f.id) AND name = 'x' AND missing = 1 ORDER BY total;",
+        );
+        let tree = parser.parse(source.into())?;
+        let resolution = resolve(&tree, &catalog);
+
+        let target_of = |name: &str| resolution.columns.iter()
+            .find(|reference| &source[reference.range] == name)
+            .and_then(|reference| reference.target.clone())
+        ;
+        assert_eq!(Some("f".to_string()), target_of("f.code").map(|target| target.source));
+        assert_eq!(Some(SourceKind::Cte), target_of("x").map(|target| target.kind));
+        assert_eq!(Some(SourceKind::Table), target_of("id").map(|target| target.kind));
+        assert_eq!(Some(SourceKind::Subquery), target_of("s.total").map(|target| target.kind));
+        assert_eq!(Some("f".to_string()), target_of("f.id").map(|target| target.source));
+        assert_eq!(Some(SourceKind::ResultColumn), target_of("total").map(|target| target.kind));
+
+        let star = resolution.stars.last().expect("Star expansion does not exist.");
+        assert_eq!(vec!["id", "code", "name", "foo_id", "name", "x", "total"], star.columns.iter().map(|c| c.column.as_str()).collect::<Vec<_>>());
+
+        let codes = resolution.diagnostics.iter().map(|d| (d.code.as_str(), &source[d.range])).collect::<Vec<_>>();
+        assert_eq!(vec![("resolve/ambiguous-column", "name"), ("resolve/unresolved-column", "missing")], codes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_result_columns() -> Result<(), anyhow::Error> {
+        use parser::{infer::infer_statements, schema::{Affinity, Catalog}};
+
+        let parser = Parser::new();
+        let schema = parser.parse("CREATE TABLE foo (id INTEGER PRIMARY KEY, name TEXT NOT NULL, score REAL); CREATE TABLE bar (foo_id INT NOT NULL, memo VARCHAR(10) NOT NULL);".into())?;
+        let catalog = Catalog::from_tree(&schema);
+
+        let source = "SELECT f.id, f.name, b.memo, COALESCE(f.score, 0.5) AS score, count(*) AS n, max(b.foo_id), CAST(f.name AS BLOB) FROM foo f LEFT JOIN bar b ON b.foo_id = f.id;";
+        let tree = parser.parse(source.into())?;
+        let result_sets = infer_statements(&tree, &catalog);
+
+        assert_eq!(1, result_sets.len());
+        let columns = result_sets[0].columns.iter()
+            .map(|column| (column.name.as_str(), column.column_type.affinity, column.column_type.nullable))
+            .collect::<Vec<_>>()
+        ;
+        assert_eq!(vec![
+            ("id", Some(Affinity::Integer), false),
+            ("name", Some(Affinity::Text), false),
+            ("memo", Some(Affinity::Text), true),
+            ("score", Some(Affinity::Real), false),
+            ("n", Some(Affinity::Integer), false),
+            ("max(b.foo_id)", Some(Affinity::Integer), true),
+            ("CAST(f.name AS BLOB)", Some(Affinity::Blob), false),
+        ], columns);
+        assert_eq!(Some("VARCHAR(10)".to_string()), result_sets[0].columns[2].column_type.declared_type);
+
+        // Operators are applied by precedence, not by the right nested tree
+        let tree = parser.parse("SELECT f.name || 'x' = 'y' AS matched, f.id * 2 + f.score AS total FROM foo f;".into())?;
+        let result_sets = infer_statements(&tree, &catalog);
+        let columns = result_sets[0].columns.iter().map(|column| (column.name.as_str(), column.column_type.affinity)).collect::<Vec<_>>();
+        assert_eq!(vec![("matched", Some(Affinity::Integer)), ("total", Some(Affinity::Real))], columns);
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_parameters() -> Result<(), anyhow::Error> {
+        use parser::{infer::infer_parameters, schema::{Affinity, Catalog}};
+
+        let parser = Parser::new();
+        let schema = parser.parse("CREATE TABLE foo (id INTEGER PRIMARY KEY, name TEXT NOT NULL, score REAL);".into())?;
+        let catalog = Catalog::from_tree(&schema);
+
+        let affinities = |source: &str| -> Result<_, anyhow::Error> {
+            let tree = parser.parse(source.into())?;
+            let parameters = infer_parameters(&tree, &catalog);
+            let affinities = parameters.parameters.iter()
+                .map(|parameter| (parameter.index, parameter.expected.as_ref().and_then(|x| x.affinity), parameter.column.clone()))
+                .collect::<Vec<_>>()
+            ;
+            Ok((affinities, parameters.diagnostics.iter().map(|x| x.code.clone()).collect::<Vec<_>>()))
+        };
+
+        assert_eq!(
+            (vec![
+                (1, Some(Affinity::Integer), Some("id".to_string())),
+                (2, Some(Affinity::Text), None),
+                (3, Some(Affinity::Integer), None),
+            ], vec![]),
+            affinities("SELECT name FROM foo WHERE id = ? AND name LIKE ? LIMIT ?;")?
+        );
+        assert_eq!(
+            (vec![
+                (1, Some(Affinity::Text), Some("name".to_string())),
+                (2, Some(Affinity::Real), Some("score".to_string())),
+            ], vec![]),
+            affinities("INSERT INTO foo (name, score) VALUES (?, ?);")?
+        );
+        assert_eq!(
+            (vec![
+                (1, Some(Affinity::Real), Some("score".to_string())),
+                (2, None, None),
+            ], vec!["infer/untyped-parameter".to_string()]),
+            affinities("UPDATE foo SET score = ? WHERE ? IS NULL;")?
+        );
+        assert_eq!(
+            (vec![
+                (1, Some(Affinity::Text), Some("name".to_string())),
+                (2, Some(Affinity::Real), Some("score".to_string())),
+            ], vec![]),
+            affinities("SELECT id FROM foo WHERE ? = name AND id = 1 OR score > ?;")?
+        );
+        // Named parameters share an index, numbered ones keep theirs
+        assert_eq!(
+            (vec![
+                (1, Some(Affinity::Text), Some("name".to_string())),
+                (3, Some(Affinity::Integer), Some("id".to_string())),
+            ], vec!["infer/conflicting-parameter-type".to_string()]),
+            affinities("SELECT id FROM foo WHERE name = :name OR id = ?3 OR score = :name;")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename() -> Result<(), anyhow::Error> {
+        use parser::{rename::rename, TextEdit};
+
+        let parser = Parser::new();
+        let rename_at = |source: &str, needle: &str, new_name: &str| -> Result<String, anyhow::Error> {
+            let tree = parser.parse(source.into())?;
+            let offset = TextSize::from(source.find(needle).unwrap() as u32);
+            Ok(TextEdit::apply(source, &rename(&tree, offset, new_name)?))
+        };
+
+        let schema = "CREATE TABLE foo (id INTEGER, name TEXT);\nCREATE INDEX foo_name ON foo (name);\n-- seed\nINSERT INTO foo (name) VALUES ('a');\nSELECT foo.id FROM foo WHERE name <> '';";
+        assert_eq!(
+            "CREATE TABLE bar (id INTEGER, name TEXT);\nCREATE INDEX foo_name ON bar (name);\n-- seed\nINSERT INTO bar (name) VALUES ('a');\nSELECT bar.id FROM bar WHERE name <> '';",
+            rename_at(schema, "foo (id", "bar")?
+        );
+        assert_eq!(
+            "CREATE TABLE foo (id INTEGER, \"order\" TEXT);\nCREATE INDEX foo_name ON foo (\"order\");\n-- seed\nINSERT INTO foo (\"order\") VALUES ('a');\nSELECT foo.id FROM foo WHERE \"order\" <> '';",
+            rename_at(schema, "name TEXT", "order")?
+        );
+        assert_eq!(
+            "SELECT t.id FROM foo t WHERE t.id IN (SELECT f.id FROM foo f);",
+            rename_at("SELECT f.id FROM foo f WHERE f.id IN (SELECT f.id FROM foo f);", "f WHERE", "t")?
+        );
+        assert_eq!(
+            "WITH d AS (SELECT 1 AS x) SELECT d.x FROM d;",
+            rename_at("WITH c AS (SELECT 1 AS x) SELECT c.x FROM c;", "c AS", "d")?
+        );
+        let trigger = "CREATE TABLE foo (id INTEGER, score INTEGER);\nCREATE TRIGGER foo_score AFTER UPDATE OF score ON foo BEGIN UPDATE foo SET id = NEW.score WHERE id = OLD.score; END;";
+        assert_eq!(
+            "CREATE TABLE foo (id INTEGER, points INTEGER);\nCREATE TRIGGER foo_score AFTER UPDATE OF points ON foo BEGIN UPDATE foo SET id = NEW.points WHERE id = OLD.points; END;",
+            rename_at(trigger, "score ON", "points")?
+        );
+        assert_eq!(
+            "CREATE TABLE bar (id INTEGER, score INTEGER);\nCREATE TRIGGER foo_score AFTER UPDATE OF score ON bar BEGIN UPDATE bar SET id = NEW.score WHERE id = OLD.score; END;",
+            rename_at(trigger, "foo (id", "bar")?
+        );
+        assert!(rename_at("SELECT 1;", "1", "x").is_err());
+        assert_eq!(("\"index\"", "indexes"), (parser::literal::quote_identifier("index").as_str(), parser::literal::quote_identifier("indexes").as_str()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dependency_graph() -> Result<(), anyhow::Error> {
+        use parser::dependency::{DependencyGraph, Object, ObjectKind};
+
+        let parser = Parser::new();
+        let tables = parser.parse("CREATE TABLE foo (id INTEGER PRIMARY KEY, name TEXT, score REAL);\nCREATE INDEX foo_score ON foo (score);".into())?;
+        let views = parser.parse(concat!(
+            "CREATE VIEW v AS SELECT id, name FROM foo;\n",
+            "CREATE VIEW w AS SELECT name FROM v;\n",
+            "CREATE TRIGGER t AFTER UPDATE OF name ON foo BEGIN UPDATE foo SET score = 0 WHERE id = NEW.id; END;\n",
+        ).into())?;
+        let graph = DependencyGraph::build(&[("tables.sql", &tables), ("views.sql", &views)]);
+
+        assert_eq!(
+            vec![Object::new(ObjectKind::View, "v"), Object::new(ObjectKind::View, "w"), Object::new(ObjectKind::Trigger, "t")],
+            graph.affected_by(&Object::column("foo", "name"))
+        );
+        assert_eq!(
+            vec![Object::new(ObjectKind::Trigger, "t"), Object::new(ObjectKind::Index, "foo_score")],
+            graph.affected_by(&Object::column("foo", "score"))
+        );
+        assert!(graph.dependents_of(&Object::new(ObjectKind::View, "v")).all(|x| x.file == "views.sql"));
+        assert!(graph.to_dot().contains("\"view:w\" -> \"column:v.name\";"));
+        assert!(graph.to_json()?.contains("\"kind\": \"trigger\""));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fingerprint() -> Result<(), anyhow::Error> {
+        let parser = Parser::new();
+        let tree = parser.parse(concat!(
+            "SELECT id, name FROM foo WHERE id IN (1, 2, 3) AND name = 'x' -- slow\n LIMIT 10;\n",
+            "SELECT  id,name\nFROM foo /* retry */ WHERE id IN (4) AND name = 'yy' LIMIT 5;\n",
+            "SELECT -1.5, count(*) FROM foo WHERE id IN (id, 2);\n",
+        ).into())?;
+        let fingerprints = tree.fingerprints();
+
+        assert_eq!(3, fingerprints.len());
+        assert_eq!("SELECT id, name FROM foo WHERE id IN (...) AND name = ? LIMIT ?", fingerprints[0].normalized);
+        assert_eq!(fingerprints[0], fingerprints[1]);
+        assert_eq!("SELECT ?, count(*) FROM foo WHERE id IN (id, ?)", fingerprints[2].normalized);
+        assert_ne!(fingerprints[0].hash, fingerprints[2].hash);
+
+        let tree = parser.parse("SELECT 0.5, .5, 2.5e-3 FROM foo WHERE score > -0.25;".into())?;
+        assert_eq!("SELECT ?, ?, ? FROM foo WHERE score > ?", tree.fingerprints()[0].normalized);
+
+        // Truncated query from a log
+        let tree = parser.parse("SELECT id FROM foo WHERE id =".into())?;
+        assert!(tree.fingerprints()[0].normalized.starts_with("SELECT id FROM foo WHERE id ="));
+        Ok(())
+    }
+
+    #[test]
+    fn test_completions_at() -> Result<(), anyhow::Error> {
+        use parser::{completion::{completions_at, CompletionKind}, schema::Catalog};
+
+        let parser = Parser::new();
+        let schema = "CREATE TABLE foo (id INTEGER, name TEXT);\n";
+        let complete = |source: &str| -> Result<Vec<(String, CompletionKind)>, anyhow::Error> {
+            let tree = parser.parse(source.into())?;
+            let catalog = Catalog::from_tree(&tree);
+            let items = completions_at(&tree, &catalog, TextSize::of(source));
+            Ok(items.into_iter().map(|item| (item.label, item.kind)).collect())
+        };
+        let labels = |items: &[(String, CompletionKind)], kind: CompletionKind| -> Vec<String> {
+            items.iter().filter(|(_, k)| *k == kind).map(|(label, _)| label.clone()).collect()
+        };
+
+        let items = complete("")?;
+        assert!(labels(&items, CompletionKind::Keyword).contains(&"SELECT".to_string()));
+        assert!(labels(&items, CompletionKind::Table).is_empty());
+
+        let items = complete(&format!("{schema}SEL"))?;
+        assert_eq!(vec![("SELECT".to_string(), CompletionKind::Keyword)], items);
+
+        let items = complete(&format!("{schema}SELECT * FROM "))?;
+        assert_eq!(vec!["foo"], labels(&items, CompletionKind::Table));
+        assert!(labels(&items, CompletionKind::Function).is_empty());
+
+        let items = complete(&format!("{schema}SELECT * FROM foo WHERE "))?;
+        assert_eq!(vec!["id", "name"], labels(&items, CompletionKind::Column));
+        assert!(labels(&items, CompletionKind::Function).contains(&"coalesce".to_string()));
+        assert!(labels(&items, CompletionKind::Keyword).contains(&"NOT".to_string()));
+
+        let items = complete(&format!("{schema}SELECT * FROM foo f WHERE f.na"))?;
+        assert_eq!(vec![("name".to_string(), CompletionKind::Column)], items);
+        Ok(())
+    }
+
+    #[test]
+    fn test_highlight() -> Result<(), anyhow::Error> {
+        use parser::highlight::{highlight, HighlightTag};
+
+        let parser = Parser::new();
+        let source = "CREATE TABLE foo (key INTEGER, name TEXT); -- seed\nSELECT f.key, count(*) FROM foo AS f WHERE name LIKE 'a%' AND key = ? AND x'00' IS NOT NULL;";
+        let tree = parser.parse(source.into())?;
+
+        let highlights = highlight(&tree).into_iter()
+            .map(|(range, tag)| (source[usize::from(range.start())..usize::from(range.end())].to_string(), tag))
+            .collect::<Vec<_>>()
+        ;
+        let tags_of = |text: &str| highlights.iter().filter(|(x, _)| x == text).map(|(_, tag)| *tag).collect::<Vec<_>>();
+
+        assert_eq!(vec![HighlightTag::Keyword, HighlightTag::Keyword, HighlightTag::Table], highlights[..3].iter().map(|(_, tag)| *tag).collect::<Vec<_>>());
+        assert_eq!(vec![HighlightTag::Table, HighlightTag::Table], tags_of("foo"));
+        // Keywords are scanned in upper case only, so `key` is a column name
+        assert_eq!(vec![HighlightTag::Column; 3], tags_of("key"));
+        assert_eq!(vec![HighlightTag::Column; 2], tags_of("name"));
+        assert_eq!(vec![HighlightTag::Alias; 2], tags_of("f"));
+        assert_eq!(vec![HighlightTag::Keyword], tags_of("INTEGER"));
+        assert_eq!(vec![HighlightTag::Function], tags_of("count"));
+        assert_eq!(vec![HighlightTag::Operator], tags_of("*"));
+        assert_eq!(vec![HighlightTag::Keyword], tags_of("LIKE"));
+        assert_eq!(vec![HighlightTag::String], tags_of("'a%'"));
+        assert_eq!(vec![HighlightTag::Parameter], tags_of("?"));
+        assert_eq!(vec![HighlightTag::Blob], tags_of("x'00'"));
+        assert_eq!(vec![HighlightTag::Comment], tags_of("-- seed"));
+        assert!(tags_of(";").is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_function_signature_help() -> Result<(), anyhow::Error> {
+        use parser::function::{hover, lookup, signature_help};
+
+        let parser = Parser::new();
+        let source = "SELECT substr(name, length(name) - 2, 2), coalesce(a, b, c, d) FROM foo;";
+        let tree = parser.parse(source.into())?;
+        let offset_of = |needle: &str| TextSize::from(source.find(needle).unwrap() as u32);
+
+        let help = signature_help(&tree, offset_of("name,")).unwrap();
+        assert_eq!(("substr", 0), (help.function.name, help.active_parameter));
+        assert_eq!("substr(X, Y, [Z])", help.function.signature());
+
+        // Innermost call
+        let help = signature_help(&tree, offset_of("name) -")).unwrap();
+        assert_eq!(("length", 0), (help.function.name, help.active_parameter));
+        let help = signature_help(&tree, offset_of(" - 2")).unwrap();
+        assert_eq!(("substr", 1), (help.function.name, help.active_parameter));
+
+        // Variadic parameters stay on the last one
+        let help = signature_help(&tree, offset_of("d)")).unwrap();
+        assert_eq!(("coalesce", 1), (help.function.name, help.active_parameter));
+        assert!(signature_help(&tree, offset_of("FROM")).is_none());
+
+        let (function, range) = hover(&tree, offset_of("ubstr")).unwrap();
+        assert_eq!("substr", function.name);
+        assert_eq!(TextRange::new(offset_of("substr"), offset_of("(name")), range);
+        assert!(hover(&tree, offset_of("foo")).is_none());
+
+        let json = lookup("JSON_EXTRACT").unwrap();
+        assert!(json.deterministic && !json.accepts(1) && json.accepts(5));
+        assert_eq!("3.9.0", json.since);
+        assert!(!lookup("random").unwrap().deterministic);
+        Ok(())
+    }
+
+    #[test]
+    fn test_node_navigation() -> Result<(), anyhow::Error> {
+        let parser = Parser::new();
+        let source = "SELECT a FROM foo; DELETE FROM bar;";
+        let tree = parser.parse(source.into())?;
+        let offset_of = |needle: &str| TextSize::from(source.find(needle).unwrap() as u32);
+
+        let token = tree.token_at_offset(offset_of("a FROM")).unwrap();
+        assert_eq!(("a", NodeType::MainToken), (token.text().as_str(), token.metadata().node_type));
+        // Right token on a boundary
+        assert_eq!("FROM", tree.token_at_offset(offset_of("FROM foo")).unwrap().text());
+        assert!(tree.token_at_offset(TextSize::from(source.len() as u32 + 1)).is_none());
+
+        let parent = token.parent().unwrap();
+        assert_eq!((syntax_kind::r#ID, NodeType::TokenSet), (parent.kind(), parent.metadata().node_type));
+        let ancestors = token.ancestors();
+        assert!(ancestors.iter().any(|node| node.kind() == syntax_kind::r#expr));
+        assert_eq!(source, ancestors.last().unwrap().text());
+        assert!(ancestors.last().unwrap().parent().is_none());
+
+        let statements = tree.root().children().collect::<Vec<_>>();
+        let next = statements[0].next_sibling().unwrap();
+        assert_eq!(statements[1].text_range(), next.text_range());
+        assert_eq!(statements[0].text_range(), next.prev_sibling().unwrap().text_range());
+        assert!(statements[0].prev_sibling().is_none());
+
+        let node = tree.covering_element(TextRange::at(offset_of("bar"), TextSize::from(3))).unwrap();
+        assert!(node.ancestors().iter().any(|node| node.text_range() == statements[1].text_range()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten() -> Result<(), anyhow::Error> {
+        use parser::flatten::{flatten, to_json};
+
+        let parser = Parser::new();
+        let source = "SELECT a FROM foo;";
+        let tree = parser.parse(source.into())?;
+
+        let nodes = flatten(&tree);
+        assert_eq!(tree.root().descendants().count(), nodes.len());
+        assert_eq!((None, TextRange::up_to(TextSize::from(source.len() as u32))), (nodes[0].parent, nodes[0].range));
+        // Parents precede their children
+        assert!(nodes.iter().enumerate().skip(1).all(|(i, node)| node.parent.is_some_and(|parent| parent < i)));
+
+        let token = nodes.iter().find(|node| node.value.as_deref() == Some("foo") && (node.node_type == NodeType::MainToken)).unwrap();
+        let parent = &nodes[token.parent.unwrap()];
+        assert_eq!((syntax_kind::r#ID, NodeType::TokenSet), (parent.kind, parent.node_type.clone()));
+        assert_eq!(TextRange::new(14.into(), 17.into()), token.range);
+
+        let json = to_json(&tree)?;
+        assert!(json.starts_with("[{\"kind\":"));
+        assert!(json.contains("\"node_type\":\"main-token\",\"parent\":"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_syntax_errors() -> Result<(), anyhow::Error> {
+        let parser = Parser::new();
+
+        let tree = parser.parse("SELECT 123 DELETE FROM foo;".into())?;
+        let errors = tree.syntax_errors();
+        assert_eq!(1, errors.len());
+        assert_eq!(("syntax/unexpected-token", TextRange::new(11.into(), 17.into())), (errors[0].diagnostic.code.as_str(), errors[0].diagnostic.range));
+        assert_eq!(Some(Recovery::Delete), errors[0].recovery);
+        // Terminals acceptable after the result column
+        assert!(errors[0].expected.contains(&syntax_kind::r#FROM));
+        assert!(!errors[0].expected.contains(&syntax_kind::r#DELETE));
+        assert!(errors[0].expected.iter().all(|kind| kind.is_terminal));
+        assert_eq!(vec![errors[0].diagnostic.clone()], tree.syntax_diagnostics());
+
+        let tree = parser.parse("SELECT  FROM foo;".into())?;
+        let errors = tree.syntax_errors();
+        assert_eq!(1, errors.len());
+        assert_eq!(Some(Recovery::Shift), errors[0].recovery);
+        assert_eq!(1, errors[0].expected.len());
+
+        // Kinds inserted at one offset are reported together
+        let tree = parser.parse("SELECT * foo;".into())?;
+        let errors = tree.syntax_errors();
+        assert_eq!(1, errors.len());
+        assert_eq!(syntax_kind::r#FROM, errors[0].expected[0]);
+        assert!(errors[0].diagnostic.message.starts_with("missing `FROM` "));
+
+        // Dropped EOF is not reported
+        let tree = parser.parse("SELECT (1 + 2;".into())?;
+        let errors = tree.syntax_errors();
+        assert_eq!(vec!["unexpected `;`"], errors.iter().map(|error| error.diagnostic.message.as_str()).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_document_apply_edit() -> Result<(), anyhow::Error> {
+        use parser::document::Document;
+
+        let parser = Parser::new();
+        let source = "SELECT a FROM foo;\nSELECT b FROM bar;\n";
+        let mut document = Document::new(&parser, source.to_string())?;
+
+        let delta = document.apply_edit(&parser, source.find("b FROM").unwrap(), 1, "b, c")?;
+        assert_eq!("SELECT a FROM foo;\nSELECT b, c FROM bar;\n", document.source());
+        assert_eq!(document.source(), document.tree().root().text());
+        // Only the second statement is reparsed
+        let statements = document.tree().root().children().collect::<Vec<_>>();
+        assert!(statements[1].text_range().contains_range(delta.changed));
+        assert!(!delta.changed.is_empty() && !statements[0].text_range().contains_range(delta.changed));
+
+        let delta = document.apply_edit(&parser, 7, 1, "x")?;
+        assert_eq!("SELECT x FROM foo;\nSELECT b, c FROM bar;\n", document.source());
+        assert!(delta.changed.start() < TextSize::from(9));
+        assert!(delta.changed.end() < TextSize::from(document.source().find("SELECT b").unwrap() as u32 + 1));
+
+        let err = document.apply_edit(&parser, document.source().len(), 1, "").unwrap_err();
+        assert!(matches!(parser::ParseError::of(&err), Some(parser::ParseError::InvalidEdit(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_statements() -> Result<(), anyhow::Error> {
+        let source = concat!(
+            "SELECT ';' FROM foo; -- a;b\n",
+            "CREATE TRIGGER t1 AFTER INSERT ON foo BEGIN UPDATE bar SET x = CASE WHEN 1 THEN 2 END; DELETE FROM baz; END;\n",
+            "SELECT 123 DELETE  FROM foo;;\n",
+            "SELECT 1",
+        );
+        let spans = Parser::split_statements(source);
+        let texts = spans.iter().map(|span| &source[span.range]).collect::<Vec<_>>();
+
+        assert_eq!(vec![
+            "SELECT ';' FROM foo;",
+            "CREATE TRIGGER t1 AFTER INSERT ON foo BEGIN UPDATE bar SET x = CASE WHEN 1 THEN 2 END; DELETE FROM baz; END;",
+            "SELECT 123 DELETE  FROM foo;",
+            "SELECT 1",
+        ], texts);
+        assert_eq!(vec![true, true, true, false], spans.iter().map(|span| span.terminated).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_literal_value() -> Result<(), anyhow::Error> {
+        use parser::{literal, LiteralValue, LiteralError};
+
+        let source = "SELECT 1_000, -42, 'abc', X'0A0B', NULL, \"My Col\", 0x1F, -0XfF, 0xFFFFFFFFFFFFFFFF;";
+        let tree = Parser::new().parse(source.into())?;
+        let mut values = tree.root().descendants()
+            .filter(|node| node.kind() == syntax_kind::r#selcollist)
+            .filter_map(|node| node.children().find(|child| child.kind() == syntax_kind::r#expr))
+            .collect::<Vec<_>>()
+        ;
+        values.sort_by_key(|node| node.text_range().start());
+
+        assert_eq!(9, values.len());
+        assert_eq!(Ok(LiteralValue::Integer(1000)), values[0].literal_value());
+        assert_eq!(Ok(LiteralValue::Integer(-42)), values[1].literal_value());
+        assert_eq!(Ok(LiteralValue::Text("abc".into())), values[2].literal_value());
+        assert_eq!(Ok(LiteralValue::Blob(vec![0x0a, 0x0b])), values[3].literal_value());
+        assert_eq!(Ok(LiteralValue::Null), values[4].literal_value());
+        assert_eq!(Err(LiteralError::NotLiteral), values[5].literal_value());
+        assert_eq!(Ok("My Col".to_string()), values[5].identifier());
+        assert_eq!(Ok(LiteralValue::Integer(0x1f)), values[6].literal_value());
+        assert_eq!(Ok(LiteralValue::Integer(-0xff)), values[7].literal_value());
+        // hexadecimal literals are 64-bit two's-complement
+        assert_eq!(Ok(LiteralValue::Integer(-1)), values[8].literal_value());
+
+        assert_eq!(Ok("My\"Col".to_string()), literal::unquote_identifier("\"My\"\"Col\""));
+        assert_eq!(Ok("x".to_string()), literal::unquote_identifier("[x]"));
+        assert_eq!(Ok(LiteralValue::Text("it's".into())), literal::decode_literal(syntax_kind::r#STRING, "'it''s'", false));
+        assert_eq!(Ok(LiteralValue::Integer(16)), literal::decode_literal(syntax_kind::r#INTEGER, "0x10", false));
+        assert!(literal::decode_literal(syntax_kind::r#QNUMBER, "x10", false).is_err());
+        assert!(matches!(literal::decode_literal(syntax_kind::r#INTEGER, "9223372036854775808", false), Err(LiteralError::IntegerOverflow(_))));
+        assert!(matches!(literal::decode_literal(syntax_kind::r#STRING, "'it's'", false), Err(LiteralError::InvalidEscape(_))));
+        Ok(())
+    }
 }
\ No newline at end of file
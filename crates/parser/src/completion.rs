@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+use cstree::{text::{TextRange, TextSize}, Syntax};
+use sqlite_parser_proto::{engine::{self, kinds as syntax_kind}, LookaheadTransition, SyntaxKind};
+
+use crate::{function::functions, resolve::{resolve, SourceKind}, schema::{child, Catalog}, SyntaxNode, SyntaxTree};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum CompletionKind {
+    Column,
+    Alias,
+    Table,
+    Function,
+    Keyword,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: CompletionKind,
+    pub detail: Option<String>,
+    // Word replaced by the item (empty if the cursor is not on a word)
+    pub range: TextRange,
+}
+
+// Candidates at the offset.
+// Keywords come from the terminals acceptable in the parser state at the cursor,
+// names are offered only where `ID` is acceptable.
+pub fn completions_at(tree: &SyntaxTree, catalog: &Catalog, offset: TextSize) -> Vec<CompletionItem> {
+    let root = tree.root();
+    let tokens = root.main_tokens().into_iter()
+        .filter(|token| !token.text_range().is_empty() && (token.kind() != syntax_kind::r#EOF))
+        .collect::<Vec<_>>()
+    ;
+
+    // Word under the cursor is replaced
+    let word = tokens.iter()
+        .find(|token| (token.text_range().start() < offset) && (offset <= token.text_range().end()) && is_word(token))
+    ;
+    let (state, range, prefix, prev) = match word {
+        Some(word) => {
+            let range = word.text_range();
+            let prefix = word.text()[..usize::from(offset - range.start())].to_ascii_lowercase();
+            let prev = tokens.iter().rev().find(|token| token.text_range().end() <= range.start());
+            // The word itself may be an error token (e.g. `SEL`), so its state comes from the preceding token
+            (next_state(tree, prev), range, prefix, prev)
+        }
+        None => {
+            let prev = tokens.iter().rev().find(|token| token.text_range().end() <= offset);
+            (next_state(tree, prev), TextRange::empty(offset), String::new(), prev)
+        }
+    };
+
+    let expected = tree.language().fetch_state_actions(state).into_iter()
+        .filter(|(_, transition)| !matches!(transition, LookaheadTransition::Unknown))
+        .map(|(id, _)| SyntaxKind::from_raw(cstree::RawSyntaxKind(*id)))
+        .collect::<Vec<_>>()
+    ;
+
+    let mut items = vec![];
+    if expected.contains(&syntax_kind::r#ID) {
+        let statement = root.children().find(|statement| statement.text_range().contains_inclusive(offset));
+        // Literals are acceptable only in expressions
+        let in_expr = expected.contains(&syntax_kind::r#INTEGER);
+        let qualifier = match prev {
+            Some(dot) if dot.kind() == syntax_kind::r#DOT => tokens.iter().rev().find(|token| token.text_range().end() <= dot.text_range().start()).and_then(|token| token.identifier().ok()),
+            _ => None,
+        };
+        name_candidates(tree, catalog, statement.as_ref(), qualifier.as_deref(), in_expr, range, &mut items);
+    }
+    // Keywords are not acceptable after `x.`
+    if !matches!(prev, Some(dot) if dot.kind() == syntax_kind::r#DOT) {
+        keyword_candidates(&expected, range, &mut items);
+    }
+
+    let mut used = HashSet::new();
+    items.into_iter()
+        .filter(|item| item.label.to_ascii_lowercase().starts_with(&prefix))
+        .filter(|item| used.insert((item.label.to_ascii_lowercase(), item.kind)))
+        .collect()
+}
+
+// State after the preceding token
+fn next_state(tree: &SyntaxTree, prev: Option<&SyntaxNode>) -> usize {
+    let Some(prev) = prev else {
+        return 0;
+    };
+    // Each statement is parsed from the initial state
+    if prev.kind() == syntax_kind::r#SEMI {
+        return 0;
+    }
+    let state = prev.metadata().state;
+
+    match tree.language().resolve_lookahead_state(Some(&parsed_kind(prev.kind())), state) {
+        Ok(LookaheadTransition::Shift { next_state }) => next_state,
+        // Token dropped by error recovery
+        _ => state,
+    }
+}
+
+// `*` in the result columns is renamed after parsing
+fn parsed_kind(kind: SyntaxKind) -> SyntaxKind {
+    match kind {
+        k if k == syntax_kind::r#ASTERISK => syntax_kind::r#STAR,
+        k => k,
+    }
+}
+
+fn is_word(token: &SyntaxNode) -> bool {
+    token.text().starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+}
+
+fn keyword_candidates(expected: &[SyntaxKind], range: TextRange, items: &mut Vec<CompletionItem>) {
+    let mut keywords = expected.iter()
+        .filter(|kind| kind.is_terminal && (**kind != syntax_kind::r#ID))
+        .flat_map(|kind| engine::lexmes_of(*kind))
+        .filter(|lexme| lexme.chars().all(|c| c.is_ascii_alphabetic() || c == '_'))
+        .collect::<Vec<_>>()
+    ;
+    keywords.sort();
+
+    items.extend(keywords.into_iter().map(|keyword| CompletionItem { label: keyword.to_string(), kind: CompletionKind::Keyword, detail: None, range }));
+}
+
+fn name_candidates(tree: &SyntaxTree, catalog: &Catalog, statement: Option<&SyntaxNode>, qualifier: Option<&str>, in_expr: bool, range: TextRange, items: &mut Vec<CompletionItem>) {
+    let scope = statement.map_or(TextRange::empty(0.into()), |statement| statement.text_range());
+    let resolution = resolve(tree, catalog);
+    let references = resolution.tables.iter()
+        .filter(|reference| scope.contains_range(reference.range))
+        .collect::<Vec<_>>()
+    ;
+    let item = |label: &str, kind: CompletionKind, detail: Option<&str>| CompletionItem { label: label.to_string(), kind, detail: detail.map(str::to_string), range };
+
+    // Columns of the qualified table only
+    if let Some(qualifier) = qualifier {
+        let table = references.iter()
+            .find(|reference| reference.alias.as_deref().unwrap_or(&reference.name).eq_ignore_ascii_case(qualifier))
+            .map_or(qualifier, |reference| reference.name.as_str())
+        ;
+        items.extend(columns_of(catalog, table).into_iter().map(|column| item(&column, CompletionKind::Column, Some(table))));
+        return;
+    }
+
+    let mut columns = references.iter()
+        .filter(|reference| matches!(reference.kind, SourceKind::Table | SourceKind::View))
+        .flat_map(|reference| columns_of(catalog, &reference.name).into_iter().map(|column| (column, reference.name.clone())))
+        .collect::<Vec<_>>()
+    ;
+    columns.sort();
+    items.extend(columns.iter().map(|(column, table)| item(column, CompletionKind::Column, Some(table))));
+
+    let mut aliases = references.iter()
+        .filter_map(|reference| reference.alias.as_ref().map(|alias| (alias.clone(), reference.name.clone())))
+        .collect::<Vec<_>>()
+    ;
+    aliases.sort();
+    items.extend(aliases.iter().map(|(alias, table)| item(alias, CompletionKind::Alias, Some(table))));
+
+    if in_expr {
+        items.extend(functions().iter().map(|function| item(function.name, CompletionKind::Function, Some(&function.signature()))));
+        return;
+    }
+
+    let mut tables = catalog.tables.iter().map(|table| (table.name.clone(), "table"))
+        .chain(catalog.views.iter().map(|view| (view.name.clone(), "view")))
+        .chain(statement.into_iter().flat_map(cte_names).map(|name| (name, "cte")))
+        .collect::<Vec<_>>()
+    ;
+    tables.sort();
+    items.extend(tables.iter().map(|(name, detail)| item(name, CompletionKind::Table, Some(detail))));
+}
+
+fn columns_of(catalog: &Catalog, name: &str) -> Vec<String> {
+    match (catalog.table(name), catalog.view(name)) {
+        (Some(table), _) => table.columns.iter().map(|column| column.name.clone()).collect(),
+        (None, Some(view)) => view.columns.clone(),
+        (None, None) => vec![],
+    }
+}
+
+// wqitem ::= withnm eidlist_opt wqas LP select RP
+fn cte_names(statement: &SyntaxNode) -> Vec<String> {
+    statement.descendants()
+        .filter(|node| node.kind() == syntax_kind::r#wqitem)
+        .filter_map(|wqitem| child(&wqitem, syntax_kind::r#withnm).and_then(|node| child(&node, syntax_kind::r#nm)))
+        .filter_map(|nm| nm.identifier().ok())
+        .collect()
+}
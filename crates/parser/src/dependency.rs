@@ -0,0 +1,327 @@
+use std::collections::VecDeque;
+
+use cstree::text::TextRange;
+use sqlite_parser_proto::engine::kinds as syntax_kind;
+
+use crate::{resolve::{is_column_token, resolve, SourceKind}, schema::{child, children, flatten_list, qualified_name, Catalog}, SyntaxNode, SyntaxTree};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ObjectKind {
+    Table,
+    Column,
+    View,
+    Trigger,
+    Index,
+}
+
+impl ObjectKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ObjectKind::Table => "table",
+            ObjectKind::Column => "column",
+            ObjectKind::View => "view",
+            ObjectKind::Trigger => "trigger",
+            ObjectKind::Index => "index",
+        }
+    }
+}
+
+// Names are compared in lower case
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, serde::Serialize)]
+pub struct Object {
+    pub kind: ObjectKind,
+    // Table or view owning the column
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<String>,
+    pub name: String,
+}
+
+impl Object {
+    pub fn new(kind: ObjectKind, name: &str) -> Self {
+        Self { kind, table: None, name: name.to_ascii_lowercase() }
+    }
+
+    pub fn column(table: &str, name: &str) -> Self {
+        Self { kind: ObjectKind::Column, table: Some(table.to_ascii_lowercase()), name: name.to_ascii_lowercase() }
+    }
+
+    // Unique key (e.g. `column:foo.id`)
+    pub fn key(&self) -> String {
+        format!("{}:{self}", self.kind.as_str())
+    }
+
+    fn owns(&self, column: &Object) -> bool {
+        matches!(self.kind, ObjectKind::Table | ObjectKind::View) && (column.kind == ObjectKind::Column) && (column.table.as_ref() == Some(&self.name))
+    }
+}
+
+impl std::fmt::Display for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.table.as_ref() {
+            Some(table) => write!(f, "{table}.{}", self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, serde::Serialize)]
+pub struct Dependency {
+    // The dependent object
+    pub from: Object,
+    pub to: Object,
+    pub file: String,
+    // Range of the reference in the file
+    #[serde(serialize_with = "serialize_range")]
+    pub range: TextRange,
+}
+
+#[derive(Default, Clone, Debug, serde::Serialize)]
+pub struct DependencyGraph {
+    pub objects: Vec<Object>,
+    pub dependencies: Vec<Dependency>,
+}
+
+impl DependencyGraph {
+    // Files are `(file name, tree)` in the order they are applied.
+    // Views, triggers, indexes and foreign keys are linked to the tables and columns they reference.
+    pub fn build(files: &[(&str, &SyntaxTree)]) -> Self {
+        let mut catalog = Catalog::default();
+        for (_, tree) in files {
+            catalog.collect(&tree.root());
+        }
+
+        let mut graph = Self::default();
+        for table in &catalog.tables {
+            graph.objects.push(Object::new(ObjectKind::Table, &table.name));
+            graph.objects.extend(table.columns.iter().map(|column| Object::column(&table.name, &column.name)));
+        }
+        graph.objects.extend(catalog.views.iter().map(|view| Object::new(ObjectKind::View, &view.name)));
+        graph.objects.extend(catalog.triggers.iter().map(|trigger| Object::new(ObjectKind::Trigger, &trigger.name)));
+        graph.objects.extend(catalog.indexes.iter().map(|index| Object::new(ObjectKind::Index, &index.name)));
+
+        for (file, tree) in files {
+            let resolution = resolve(tree, &catalog);
+            // CREATE TRIGGER has no `cmd` node; its parts are children of `ecmd`
+            let statements = tree.root().descendants()
+                .filter(|node| node.as_inner_node().is_some() && ((node.kind() == syntax_kind::r#cmd) || child(node, syntax_kind::r#trigger_decl).is_some()) && !node.is_error())
+                .collect::<Vec<_>>()
+            ;
+
+            for cmd in statements {
+                let Some(owner) = owner_of(&cmd) else {
+                    continue;
+                };
+                let range = cmd.token_range();
+                let mut builder = Builder { graph: &mut graph, owner, file };
+
+                for reference in resolution.tables.iter().filter(|reference| range.contains_range(reference.range)) {
+                    match reference.kind {
+                        SourceKind::Table => builder.add(Object::new(ObjectKind::Table, &reference.name), reference.range),
+                        SourceKind::View => builder.add(Object::new(ObjectKind::View, &reference.name), reference.range),
+                        _ => {}
+                    }
+                }
+                for reference in resolution.columns.iter().filter(|reference| range.contains_range(reference.range)) {
+                    let Some(target) = reference.target.as_ref().filter(|target| matches!(target.kind, SourceKind::Table | SourceKind::View)) else {
+                        continue;
+                    };
+                    if let Some(table) = target.table.as_ref() {
+                        builder.add(Object::column(table, &target.column), reference.range);
+                    }
+                }
+                // `*` depends on every column
+                for star in resolution.stars.iter().filter(|star| range.contains_range(star.range)) {
+                    for target in star.columns.iter().filter(|target| matches!(target.kind, SourceKind::Table | SourceKind::View)) {
+                        if let Some(table) = target.table.as_ref() {
+                            builder.add(Object::column(table, &target.column), star.range);
+                        }
+                    }
+                }
+
+                builder.schema_statement(&cmd);
+            }
+        }
+
+        graph.objects.sort();
+        graph.objects.dedup();
+        graph.dependencies.dedup();
+        graph
+    }
+
+    // Objects the object directly depends on
+    pub fn dependencies_of<'a>(&'a self, object: &'a Object) -> impl Iterator<Item = &'a Dependency> + 'a {
+        self.dependencies.iter().filter(move |dependency| &dependency.from == object)
+    }
+
+    // Objects directly depending on the object
+    pub fn dependents_of<'a>(&'a self, object: &'a Object) -> impl Iterator<Item = &'a Dependency> + 'a {
+        self.dependencies.iter().filter(move |dependency| &dependency.to == object)
+    }
+
+    // Objects broken by dropping the object, including indirect dependents.
+    // Dropping a table or view also affects the dependents of its columns.
+    pub fn affected_by(&self, object: &Object) -> Vec<Object> {
+        let mut affected = vec![];
+        let mut queue = VecDeque::from([object.clone()]);
+
+        while let Some(current) = queue.pop_front() {
+            let dependents = self.dependencies.iter()
+                .filter(|dependency| (dependency.to == current) || current.owns(&dependency.to))
+                .map(|dependency| &dependency.from)
+            ;
+            for dependent in dependents {
+                if (dependent != object) && !affected.contains(dependent) {
+                    affected.push(dependent.clone());
+                    queue.push_back(dependent.clone());
+                }
+            }
+        }
+
+        affected.sort();
+        affected
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec!["digraph dependencies {".to_string(), "    node [shape=box];".to_string()];
+
+        for object in &self.objects {
+            let shape = match object.kind {
+                ObjectKind::Column => ", shape=ellipse",
+                _ => "",
+            };
+            lines.push(format!("    {} [label={}{shape}];", dot_string(&object.key()), dot_string(&format!("{} {object}", object.kind.as_str()))));
+        }
+
+        let mut edges = self.dependencies.iter().map(|dependency| (&dependency.from, &dependency.to)).collect::<Vec<_>>();
+        edges.sort();
+        edges.dedup();
+        for (from, to) in edges {
+            lines.push(format!("    {} -> {};", dot_string(&from.key()), dot_string(&to.key())));
+        }
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    pub fn to_json(&self) -> Result<String, anyhow::Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+struct Builder<'a> {
+    graph: &'a mut DependencyGraph,
+    owner: Object,
+    file: &'a str,
+}
+
+impl Builder<'_> {
+    fn add(&mut self, to: Object, range: TextRange) {
+        if to == self.owner {
+            return;
+        }
+        if !self.graph.objects.contains(&to) {
+            self.graph.objects.push(to.clone());
+        }
+        self.graph.dependencies.push(Dependency { from: self.owner.clone(), to, file: self.file.to_string(), range });
+    }
+
+    // DDL other than views and triggers is not resolved
+    fn schema_statement(&mut self, cmd: &SyntaxNode) {
+        match self.owner.kind {
+            ObjectKind::Table => {
+                // REFERENCES nm eidlist_opt
+                let constraints = child(cmd, syntax_kind::r#create_table_args).into_iter()
+                    .flat_map(|args| args.descendants().filter(|node| [syntax_kind::r#ccons, syntax_kind::r#tcons].contains(&node.kind()) && !node.is_error()).collect::<Vec<_>>())
+                    .filter(|constraint| child(constraint, syntax_kind::r#REFERENCES).is_some())
+                ;
+                for constraint in constraints {
+                    let Some(nm) = child(&constraint, syntax_kind::r#nm) else {
+                        continue;
+                    };
+                    let Ok(table) = nm.identifier() else {
+                        continue;
+                    };
+                    self.add(Object::new(ObjectKind::Table, &table), nm.token_range());
+
+                    let eidlist = child(&constraint, syntax_kind::r#eidlist_opt).and_then(|node| child(&node, syntax_kind::r#eidlist));
+                    for nm in eidlist.iter().flat_map(flatten_list).filter_map(|item| child(&item, syntax_kind::r#nm)) {
+                        if let Ok(column) = nm.identifier() {
+                            self.add(Object::column(&table, &column), nm.token_range());
+                        }
+                    }
+                }
+            }
+            ObjectKind::Index => {
+                // createkw uniqueflag INDEX ifnotexists nm dbnm ON nm LP sortlist RP where_opt
+                let Some(nm) = children(cmd, syntax_kind::r#nm).nth(1) else {
+                    return;
+                };
+                let Ok(table) = nm.identifier() else {
+                    return;
+                };
+                self.add(Object::new(ObjectKind::Table, &table), nm.token_range());
+
+                for node in [syntax_kind::r#sortlist, syntax_kind::r#where_opt].into_iter().filter_map(|kind| child(cmd, kind)) {
+                    for expr in node.descendants().filter(|node| node.kind() == syntax_kind::r#expr && !node.is_error()) {
+                        let parts = expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+                        let [id] = parts.as_slice() else {
+                            continue;
+                        };
+                        match id.identifier() {
+                            Ok(column) if is_column_token(id.kind()) => self.add(Object::column(&table, &column), id.token_range()),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            ObjectKind::Trigger => {
+                // trigger_event ::= UPDATE OF idlist
+                let Some(decl) = child(cmd, syntax_kind::r#trigger_decl) else {
+                    return;
+                };
+                let table = child(&decl, syntax_kind::r#fullname).and_then(|node| qualified_name(&node)).map(|(_, name)| name);
+                let idlist = child(&decl, syntax_kind::r#trigger_event).and_then(|node| child(&node, syntax_kind::r#idlist));
+
+                if let (Some(table), Some(idlist)) = (table, idlist) {
+                    for nm in flatten_list(&idlist).iter().filter_map(|item| child(item, syntax_kind::r#nm)) {
+                        if let Ok(column) = nm.identifier() {
+                            self.add(Object::column(&table, &column), nm.token_range());
+                        }
+                    }
+                }
+            }
+            ObjectKind::View | ObjectKind::Column => {}
+        }
+    }
+}
+
+// Object defined by the statement
+fn owner_of(cmd: &SyntaxNode) -> Option<Object> {
+    if let Some(create_table) = child(cmd, syntax_kind::r#create_table) {
+        return qualified_name(&create_table).map(|(_, name)| Object::new(ObjectKind::Table, &name));
+    }
+    if let Some(decl) = child(cmd, syntax_kind::r#trigger_decl) {
+        return qualified_name(&decl).map(|(_, name)| Object::new(ObjectKind::Trigger, &name));
+    }
+    child(cmd, syntax_kind::r#createkw)?;
+
+    let kind = if child(cmd, syntax_kind::r#VIEW).is_some() {
+        ObjectKind::View
+    }
+    else if child(cmd, syntax_kind::r#INDEX).is_some() {
+        ObjectKind::Index
+    }
+    else {
+        return None;
+    };
+    qualified_name(cmd).map(|(_, name)| Object::new(kind, &name))
+}
+
+fn dot_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn serialize_range<S: serde::Serializer>(range: &TextRange, serializer: S) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&[u32::from(range.start()), u32::from(range.end())], serializer)
+}
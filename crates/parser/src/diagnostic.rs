@@ -0,0 +1,119 @@
+use cstree::{text::TextRange, Syntax};
+use sqlite_parser_proto::{engine, LookaheadTransition, SyntaxKind};
+
+use crate::{NodeType, Recovery, SyntaxTree};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum Severity {
+    Error,
+    Warning,
+    Information,
+    Hint,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    // Stable identifier (e.g. `schema/no-such-table`)
+    pub code: String,
+    pub message: String,
+    pub range: TextRange,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &str, message: String, range: TextRange) -> Self {
+        Self { severity, code: code.to_string(), message, range }
+    }
+
+    pub fn error(code: &str, message: String, range: TextRange) -> Self {
+        Self::new(Severity::Error, code, message, range)
+    }
+
+    pub fn warning(code: &str, message: String, range: TextRange) -> Self {
+        Self::new(Severity::Warning, code, message, range)
+    }
+}
+
+// Error recovered by the parser
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct SyntaxError {
+    // Code is `syntax/<kind of error>`
+    pub diagnostic: Diagnostic,
+    // Terminals acceptable at the error (the inserted ones for shift recovery)
+    pub expected: Vec<SyntaxKind>,
+    pub recovery: Option<Recovery>,
+}
+
+impl SyntaxTree {
+    // Errors recovered by the parser
+    pub fn syntax_diagnostics(&self) -> Vec<Diagnostic> {
+        self.syntax_errors().into_iter().map(|error| error.diagnostic).collect()
+    }
+
+    pub fn syntax_errors(&self) -> Vec<SyntaxError> {
+        // Blank nodes at one offset all carry the kinds inserted there, so they are reported once
+        let mut inserted_at = vec![];
+
+        self.root().descendants()
+            .filter(|node| node.as_inner_node().is_some() && node.is_error())
+            .filter_map(|node| {
+                let metadata = node.metadata();
+
+                let diagnostic = match (&metadata.node_type, &metadata.recovery) {
+                    (NodeType::FatalError, _) => Diagnostic::error("syntax/fatal", "cannot parse the statement".to_string(), node.token_range()),
+                    // e.g. the EOF dropped from an unterminated statement
+                    (_, Some(Recovery::Delete)) if node.token_range().is_empty() => return None,
+                    (_, Some(Recovery::Delete)) => Diagnostic::error("syntax/unexpected-token", format!("unexpected `{}`", node.token_text()), node.token_range()),
+                    (_, Some(Recovery::Shift)) => {
+                        let offset = node.text_range().start();
+                        if inserted_at.contains(&offset) {
+                            return None;
+                        }
+                        inserted_at.push(offset);
+
+                        let expected = match metadata.inserted.is_empty() {
+                            false => metadata.inserted.iter()
+                                .map(|kind| engine::lexme_of(*kind).map(|lexme| format!("`{lexme}`")).unwrap_or_else(|| kind.text.to_string()))
+                                .collect::<Vec<_>>()
+                                .join(" "),
+                            true => "token".to_string(),
+                        };
+                        Diagnostic::error("syntax/missing-token", format!("missing {expected}"), node.text_range())
+                    }
+                    (_, None) => return None,
+                };
+                let expected = match metadata.inserted.is_empty() {
+                    false => metadata.inserted,
+                    true => self.expected_terminals(metadata.state),
+                };
+
+                Some(SyntaxError { diagnostic, expected, recovery: metadata.recovery })
+            })
+            .collect()
+    }
+
+    fn expected_terminals(&self, state: usize) -> Vec<SyntaxKind> {
+        self.language().fetch_state_actions(state).into_iter()
+            .filter(|(_, transition)| !matches!(transition, LookaheadTransition::Unknown))
+            .map(|(id, _)| SyntaxKind::from_raw(cstree::RawSyntaxKind(*id)))
+            .filter(|kind| kind.is_terminal)
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Information => write!(f, "info"),
+            Severity::Hint => write!(f, "hint"),
+        }
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]: {} ({:?})", self.severity, self.code, self.message, self.range)
+    }
+}
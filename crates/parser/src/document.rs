@@ -0,0 +1,81 @@
+use anyhow::bail;
+use cstree::text::TextRange;
+
+use crate::{EditScope, ParseError, Parser, SyntaxNode, SyntaxTree};
+
+// Source and its current tree, updated edit by edit
+#[derive(Clone)]
+pub struct Document {
+    source: String,
+    tree: SyntaxTree,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct TreeDelta {
+    // Range of the reparsed top-level statements in the new source (empty if none changed)
+    pub changed: TextRange,
+    // False if the edit fell back to the full parse
+    pub incremental: bool,
+}
+
+impl Document {
+    pub fn new(parser: &Parser, source: String) -> Result<Self, anyhow::Error> {
+        let tree = parser.parse(source.clone())?;
+        Ok(Self { source, tree })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn tree(&self) -> &SyntaxTree {
+        &self.tree
+    }
+
+    // Replace `delete_len` bytes at `offset` with `insert_text`.
+    // The tree is reparsed incrementally, or fully if no node covers the edit.
+    pub fn apply_edit(&mut self, parser: &Parser, offset: usize, delete_len: usize, insert_text: &str) -> Result<TreeDelta, anyhow::Error> {
+        let end = offset + delete_len;
+        let edit = EditScope { offset: offset as u32, from_len: delete_len as u32, to_len: insert_text.len() as u32 };
+        if (end > self.source.len()) || !self.source.is_char_boundary(offset) || !self.source.is_char_boundary(end) {
+            bail!(ParseError::InvalidEdit(edit));
+        }
+
+        let mut source = self.source.clone();
+        source.replace_range(offset..end, insert_text);
+
+        let (tree, incremental) = match parser.incremental(&self.tree, edit).and_then(|incremental| incremental.parse(source.clone())) {
+            Ok(tree) => (tree, true),
+            Err(_) => (parser.parse(source.clone())?, false),
+        };
+
+        let shift = insert_text.len() as i64 - delete_len as i64;
+        let changed = changed_range(&self.tree.root(), &tree.root(), offset, shift);
+
+        self.source = source;
+        self.tree = tree;
+        Ok(TreeDelta { changed, incremental })
+    }
+}
+
+// Statements are unchanged if they keep their text (and position, shifted after the edit)
+fn changed_range(old_root: &SyntaxNode, new_root: &SyntaxNode, offset: usize, shift: i64) -> TextRange {
+    let old = old_root.children().map(|node| (node.text_range(), node.text())).collect::<Vec<_>>();
+    let new = new_root.children().map(|node| (node.text_range(), node.text())).collect::<Vec<_>>();
+
+    let prefix = old.iter().zip(&new)
+        .take_while(|(old, new)| old == new)
+        .count()
+    ;
+    let suffix = old.iter().rev().zip(new.iter().rev())
+        .take(usize::min(old.len(), new.len()) - prefix)
+        .take_while(|((old_range, old_text), (new_range, new_text))| (old_text == new_text) && (i64::from(u32::from(old_range.end())) + shift == i64::from(u32::from(new_range.end()))))
+        .count()
+    ;
+
+    match new.get(prefix..(new.len() - suffix)) {
+        Some([first, .., last]) => TextRange::new(first.0.start(), last.0.end()),
+        Some([node]) => node.0,
+        _ => TextRange::empty((offset as u32).into()),
+    }
+}
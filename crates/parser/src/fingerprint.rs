@@ -0,0 +1,116 @@
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::{schema::{child, flatten_list}, SyntaxNode, SyntaxTree};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Fingerprint {
+    // Statement with literals replaced by `?`
+    pub normalized: String,
+    // FNV-1a hash of the normalized text
+    pub hash: u64,
+}
+
+impl SyntaxNode {
+    // Shape of the statement.
+    // Keywords are upper-cased, comments and whitespace are dropped and literal `IN` lists are collapsed to `(...)`.
+    pub fn fingerprint(&self) -> Fingerprint {
+        let mut words = vec![];
+        collect_words(self, &mut words);
+
+        let normalized = join_words(&words);
+        let hash = fnv1a(normalized.as_bytes());
+        Fingerprint { normalized, hash }
+    }
+}
+
+impl SyntaxTree {
+    pub fn fingerprints(&self) -> Vec<Fingerprint> {
+        self.root().children()
+            .map(|statement| statement.fingerprint())
+            .filter(|fingerprint| !fingerprint.normalized.is_empty())
+            .collect()
+    }
+}
+
+fn collect_words(node: &SyntaxNode, words: &mut Vec<(SyntaxKind, String)>) {
+    if node.kind().is_terminal {
+        // Tokens dropped by error recovery are kept as written
+        words.extend(node.main_tokens().iter().filter(|token| token.kind() != syntax_kind::r#SEMI).map(|token| (token.kind(), normalize_token(token))));
+        return;
+    }
+    // Signed numbers
+    if (node.kind() == syntax_kind::r#expr) && is_literal(node) {
+        words.push((syntax_kind::r#VARIABLE, "?".to_string()));
+        return;
+    }
+
+    // expr in_op LP exprlist RP
+    let children = node.children().collect::<Vec<_>>();
+    for (i, child) in children.iter().enumerate() {
+        let in_list = (child.kind() == syntax_kind::r#exprlist) && (i >= 2) && (children[i - 2].kind() == syntax_kind::r#in_op);
+        match in_list && is_literal_list(child) {
+            true => words.push((syntax_kind::r#exprlist, "...".to_string())),
+            false => collect_words(child, words),
+        }
+    }
+}
+
+fn normalize_token(token: &SyntaxNode) -> String {
+    let kind = token.kind();
+
+    if is_literal_kind(kind) {
+        "?".to_string()
+    }
+    else if (kind.is_keyword && kind.is_terminal) || [syntax_kind::r#JOIN_KW, syntax_kind::r#LIKE_KW, syntax_kind::r#CTIME_KW].contains(&kind) {
+        token.text().to_ascii_uppercase()
+    }
+    else {
+        token.text()
+    }
+}
+
+fn is_literal_kind(kind: SyntaxKind) -> bool {
+    [syntax_kind::r#INTEGER, syntax_kind::r#FLOAT, syntax_kind::r#QNUMBER, syntax_kind::r#STRING, syntax_kind::r#BLOB, syntax_kind::r#VARIABLE].contains(&kind)
+}
+
+fn is_literal(expr: &SyntaxNode) -> bool {
+    match expr.main_tokens().as_slice() {
+        [token] => is_literal_kind(token.kind()),
+        [sign, token] => [syntax_kind::r#MINUS, syntax_kind::r#PLUS].contains(&sign.kind()) && is_literal_kind(token.kind()),
+        _ => false,
+    }
+}
+
+// exprlist ::= nexprlist
+fn is_literal_list(exprlist: &SyntaxNode) -> bool {
+    let Some(nexprlist) = child(exprlist, syntax_kind::r#nexprlist) else {
+        return false;
+    };
+    flatten_list(&nexprlist).iter().all(|item| child(item, syntax_kind::r#expr).is_some_and(|expr| is_literal(&expr)))
+}
+
+// Words are separated by a single space except around `(`, `)`, `,` and `.`
+fn join_words(words: &[(SyntaxKind, String)]) -> String {
+    let mut text = String::new();
+    let mut prev = None;
+
+    for (kind, word) in words {
+        let space = match prev {
+            None => false,
+            // Function call
+            Some(prev) if (prev == syntax_kind::r#ID) && (*kind == syntax_kind::r#LP) => false,
+            Some(prev) => ![syntax_kind::r#LP, syntax_kind::r#DOT].contains(&prev) && ![syntax_kind::r#RP, syntax_kind::r#COMMA, syntax_kind::r#DOT].contains(kind),
+        };
+        if space {
+            text.push(' ');
+        }
+        text.push_str(word);
+        prev = Some(*kind);
+    }
+
+    text
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3))
+}
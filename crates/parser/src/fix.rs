@@ -0,0 +1,91 @@
+use cstree::text::{TextRange, TextSize};
+use sqlite_parser_proto::{engine, SyntaxKind};
+
+use crate::{Recovery, SyntaxTree, TextEdit};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct QuickFix {
+    pub title: String,
+    // Range of the recovered error
+    pub range: TextRange,
+    pub edits: Vec<TextEdit>,
+}
+
+impl SyntaxTree {
+    // Fix suggestions derived from error recovery.
+    // Dropped tokens are removed, virtually inserted tokens are inserted.
+    pub fn quick_fixes(&self) -> Vec<QuickFix> {
+        let source = self.root().text();
+        let mut fixes = vec![];
+        let mut insertions: Vec<(TextSize, Vec<SyntaxKind>)> = vec![];
+
+        for node in self.root().descendants().filter(|node| node.as_inner_node().is_some() && node.is_error()) {
+            let metadata = node.metadata();
+
+            match metadata.recovery {
+                Some(Recovery::Delete) => {
+                    // Trailing trivia is whitespace only
+                    let token_range = node.token_range();
+                    // e.g. the EOF dropped from an unterminated statement
+                    if token_range.is_empty() {
+                        continue;
+                    }
+                    let range = TextRange::new(token_range.start(), node.text_range().end());
+                    fixes.push(QuickFix {
+                        title: format!("Remove stray `{}`", node.token_text()),
+                        range: token_range,
+                        edits: vec![TextEdit { range, new_text: String::new() }],
+                    });
+                }
+                Some(Recovery::Shift) => {
+                    // Blank nodes at one offset all carry the kinds inserted there
+                    let offset = node.text_range().start();
+                    if metadata.inserted.is_empty() || insertions.iter().any(|(at, _)| *at == offset) {
+                        continue;
+                    }
+                    insertions.push((offset, metadata.inserted));
+                }
+                None => {}
+            }
+        }
+
+        for (offset, kinds) in insertions {
+            // Tokens without fixed spelling (e.g. identifiers) can not be inserted
+            let Some(lexmes) = kinds.iter().map(|kind| engine::lexme_of(*kind)).collect::<Option<Vec<_>>>() else {
+                continue;
+            };
+            let new_text = insertion_text(&source, usize::from(offset), &lexmes);
+            let range = TextRange::empty(offset);
+
+            fixes.push(QuickFix {
+                title: format!("Insert `{}`", new_text.trim()),
+                range,
+                edits: vec![TextEdit { range, new_text }],
+            });
+        }
+
+        fixes.sort_by_key(|fix| (fix.range.start(), fix.range.end()));
+        fixes
+    }
+}
+
+// Join lexmes with spaces where words would be merged
+fn insertion_text(source: &str, offset: usize, lexmes: &[&str]) -> String {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut text = String::new();
+
+    for lexme in lexmes {
+        let prev = text.chars().last().or_else(|| source[..offset].chars().last());
+        if prev.is_some_and(is_word) && lexme.starts_with(is_word) {
+            text.push(' ');
+        }
+        text.push_str(lexme);
+    }
+
+    let next = source[offset..].chars().next();
+    if next.is_some_and(|c| is_word(c) || c == '\'' || c == '"') && text.ends_with(is_word) {
+        text.push(' ');
+    }
+
+    text
+}
@@ -0,0 +1,55 @@
+use cstree::text::TextRange;
+use sqlite_parser_proto::SyntaxKind;
+
+use crate::{NodeType, Recovery, SyntaxNode, SyntaxTree};
+
+#[derive(PartialEq, Eq, Clone, Debug, serde::Serialize)]
+pub struct FlatNode {
+    #[serde(serialize_with = "serialize_kind")]
+    pub kind: SyntaxKind,
+    pub node_type: NodeType,
+    // Index of the parent node in the flattened list (`None` for the root)
+    pub parent: Option<usize>,
+    #[serde(serialize_with = "serialize_range")]
+    pub range: TextRange,
+    pub value: Option<String>,
+    pub recovery: Option<Recovery>,
+}
+
+// Preorder list of the nodes and tokens, same order as `SyntaxNode::children` walks.
+// Parents always precede their children.
+pub fn flatten(tree: &SyntaxTree) -> Vec<FlatNode> {
+    let mut nodes = vec![];
+    flatten_node(&tree.root(), None, &mut nodes);
+    nodes
+}
+
+pub fn to_json(tree: &SyntaxTree) -> Result<String, anyhow::Error> {
+    Ok(serde_json::to_string(&flatten(tree))?)
+}
+
+fn flatten_node(node: &SyntaxNode, parent: Option<usize>, nodes: &mut Vec<FlatNode>) {
+    let index = nodes.len();
+    let metadata = node.metadata();
+
+    nodes.push(FlatNode {
+        kind: node.kind(),
+        node_type: metadata.node_type,
+        parent,
+        range: node.text_range(),
+        value: node.value(),
+        recovery: metadata.recovery,
+    });
+
+    for child in node.children() {
+        flatten_node(&child, Some(index), nodes);
+    }
+}
+
+fn serialize_kind<S: serde::Serializer>(kind: &SyntaxKind, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(kind.text)
+}
+
+fn serialize_range<S: serde::Serializer>(range: &TextRange, serializer: S) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&[u32::from(range.start()), u32::from(range.end())], serializer)
+}
@@ -0,0 +1,300 @@
+use cstree::text::{TextRange, TextSize};
+use sqlite_parser_proto::engine::kinds as syntax_kind;
+
+use crate::{resolve::is_column_token, schema::{child, flatten_list}, SyntaxNode, SyntaxTree};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum FunctionCategory {
+    Scalar,
+    Aggregate,
+    DateTime,
+    Json,
+    Math,
+    Window,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct FunctionInfo {
+    pub name: &'static str,
+    pub category: FunctionCategory,
+    pub params: &'static [&'static str],
+    pub min_args: usize,
+    // None for variadic functions (the last parameter repeats)
+    pub max_args: Option<usize>,
+    pub deterministic: bool,
+    // Minimum SQLite version
+    pub since: &'static str,
+    pub description: &'static str,
+}
+
+impl FunctionInfo {
+    // e.g. `substr(X, Y, [Z])`, `coalesce(X, Y, ...)`
+    pub fn signature(&self) -> String {
+        let mut params = self.params.iter().enumerate()
+            .map(|(i, param)| match i < self.min_args {
+                true => param.to_string(),
+                false => format!("[{param}]"),
+            })
+            .collect::<Vec<_>>()
+        ;
+        if self.max_args.is_none() {
+            params.push("...".to_string());
+        }
+        format!("{}({})", self.name, params.join(", "))
+    }
+
+    pub fn accepts(&self, count: usize) -> bool {
+        (self.min_args <= count) && self.max_args.is_none_or(|max| count <= max)
+    }
+}
+
+// Parameter names, minimum and maximum argument count
+struct Params(&'static [&'static str], usize, Option<usize>);
+
+const fn function(name: &'static str, category: FunctionCategory, params: Params, deterministic: bool, since: &'static str, description: &'static str) -> FunctionInfo {
+    let Params(params, min_args, max_args) = params;
+    FunctionInfo { name, category, params, min_args, max_args, deterministic, since, description }
+}
+
+use FunctionCategory::*;
+
+// See: https://www.sqlite.org/lang_corefunc.html
+static FUNCTIONS: &[FunctionInfo] = &[
+    function("abs", Scalar, Params(&["X"], 1, Some(1)), true, "3.0.0", "Absolute value of the numeric argument"),
+    function("changes", Scalar, Params(&[], 0, Some(0)), false, "3.0.0", "Number of rows modified by the most recent INSERT, UPDATE or DELETE"),
+    function("char", Scalar, Params(&["X1"], 0, None), true, "3.7.16", "String composed of characters having the given unicode code points"),
+    function("coalesce", Scalar, Params(&["X", "Y"], 2, None), true, "3.0.0", "First non-NULL argument, or NULL if all arguments are NULL"),
+    function("concat", Scalar, Params(&["X"], 1, None), true, "3.44.0", "Concatenation of the non-NULL arguments"),
+    function("concat_ws", Scalar, Params(&["SEP", "X"], 2, None), true, "3.44.0", "Concatenation of the non-NULL arguments separated by SEP"),
+    function("format", Scalar, Params(&["FORMAT", "X"], 1, None), true, "3.38.0", "String formatted like printf()"),
+    function("glob", Scalar, Params(&["X", "Y"], 2, Some(2)), true, "3.0.0", "Same as `Y GLOB X`"),
+    function("hex", Scalar, Params(&["X"], 1, Some(1)), true, "3.0.0", "Upper-case hexadecimal rendering of the content"),
+    function("ifnull", Scalar, Params(&["X", "Y"], 2, Some(2)), true, "3.0.0", "First non-NULL of the two arguments"),
+    function("iif", Scalar, Params(&["X", "Y", "Z"], 3, Some(3)), true, "3.32.0", "Y if X is true, otherwise Z"),
+    function("instr", Scalar, Params(&["X", "Y"], 2, Some(2)), true, "3.7.15", "1-based position of the first occurrence of Y in X, or 0 if not found"),
+    function("last_insert_rowid", Scalar, Params(&[], 0, Some(0)), false, "3.0.0", "ROWID of the last row inserted by the connection"),
+    function("length", Scalar, Params(&["X"], 1, Some(1)), true, "3.0.0", "Number of characters in a string or bytes in a blob"),
+    function("like", Scalar, Params(&["X", "Y", "Z"], 2, Some(3)), true, "3.0.0", "Same as `Y LIKE X [ESCAPE Z]`"),
+    function("likelihood", Scalar, Params(&["X", "Y"], 2, Some(2)), true, "3.8.1", "X unchanged, with a hint that X is true with probability Y"),
+    function("likely", Scalar, Params(&["X"], 1, Some(1)), true, "3.8.1", "X unchanged, with a hint that X is usually true"),
+    function("load_extension", Scalar, Params(&["X", "Y"], 1, Some(2)), false, "3.3.6", "Load an extension from the shared library X"),
+    function("lower", Scalar, Params(&["X"], 1, Some(1)), true, "3.0.0", "Copy of the string with ASCII characters converted to lower case"),
+    function("ltrim", Scalar, Params(&["X", "Y"], 1, Some(2)), true, "3.0.0", "String with characters in Y (spaces by default) removed from the left"),
+    function("max", Scalar, Params(&["X", "Y"], 1, None), true, "3.0.0", "Maximum of the arguments, or the maximum value in the group with a single argument"),
+    function("min", Scalar, Params(&["X", "Y"], 1, None), true, "3.0.0", "Minimum of the arguments, or the minimum value in the group with a single argument"),
+    function("nullif", Scalar, Params(&["X", "Y"], 2, Some(2)), true, "3.0.0", "X if the arguments are different, otherwise NULL"),
+    function("octet_length", Scalar, Params(&["X"], 1, Some(1)), true, "3.43.0", "Number of bytes in the encoding of X"),
+    function("printf", Scalar, Params(&["FORMAT", "X"], 1, None), true, "3.8.3", "String formatted like printf()"),
+    function("quote", Scalar, Params(&["X"], 1, Some(1)), true, "3.0.0", "SQL literal text of the argument"),
+    function("random", Scalar, Params(&[], 0, Some(0)), false, "3.0.0", "Pseudo-random 64-bit integer"),
+    function("randomblob", Scalar, Params(&["N"], 1, Some(1)), false, "3.3.13", "N-byte blob of pseudo-random bytes"),
+    function("replace", Scalar, Params(&["X", "Y", "Z"], 3, Some(3)), true, "3.0.0", "X with every occurrence of Y replaced by Z"),
+    function("round", Scalar, Params(&["X", "Y"], 1, Some(2)), true, "3.0.0", "X rounded to Y digits right of the decimal point"),
+    function("rtrim", Scalar, Params(&["X", "Y"], 1, Some(2)), true, "3.0.0", "String with characters in Y (spaces by default) removed from the right"),
+    function("sign", Scalar, Params(&["X"], 1, Some(1)), true, "3.35.0", "-1, 0 or +1 for negative, zero or positive X"),
+    function("soundex", Scalar, Params(&["X"], 1, Some(1)), true, "3.0.0", "Soundex encoding of the string"),
+    function("sqlite_compileoption_get", Scalar, Params(&["N"], 1, Some(1)), true, "3.6.23", "N-th compile-time option"),
+    function("sqlite_compileoption_used", Scalar, Params(&["X"], 1, Some(1)), true, "3.6.23", "True if the compile-time option X is used"),
+    function("sqlite_offset", Scalar, Params(&["X"], 1, Some(1)), false, "3.28.0", "Byte offset in the database file of the record holding the column X"),
+    function("sqlite_source_id", Scalar, Params(&[], 0, Some(0)), true, "3.6.18", "Check-in identifier of the SQLite source"),
+    function("sqlite_version", Scalar, Params(&[], 0, Some(0)), true, "3.0.0", "Version string of the SQLite library"),
+    function("substr", Scalar, Params(&["X", "Y", "Z"], 2, Some(3)), true, "3.0.0", "Substring of X starting at the Y-th character, Z characters long"),
+    function("substring", Scalar, Params(&["X", "Y", "Z"], 2, Some(3)), true, "3.34.0", "Alias for substr()"),
+    function("total_changes", Scalar, Params(&[], 0, Some(0)), false, "3.0.0", "Number of rows modified since the connection was opened"),
+    function("trim", Scalar, Params(&["X", "Y"], 1, Some(2)), true, "3.0.0", "String with characters in Y (spaces by default) removed from both ends"),
+    function("typeof", Scalar, Params(&["X"], 1, Some(1)), true, "3.0.0", "Datatype of X: `null`, `integer`, `real`, `text` or `blob`"),
+    function("unhex", Scalar, Params(&["X", "Y"], 1, Some(2)), true, "3.41.0", "Blob decoded from the hexadecimal string X, ignoring characters in Y"),
+    function("unicode", Scalar, Params(&["X"], 1, Some(1)), true, "3.7.15", "Unicode code point of the first character of the string"),
+    function("unlikely", Scalar, Params(&["X"], 1, Some(1)), true, "3.8.1", "X unchanged, with a hint that X is usually false"),
+    function("upper", Scalar, Params(&["X"], 1, Some(1)), true, "3.0.0", "Copy of the string with ASCII characters converted to upper case"),
+    function("zeroblob", Scalar, Params(&["N"], 1, Some(1)), true, "3.5.0", "N-byte blob of 0x00"),
+
+    // See: https://www.sqlite.org/lang_aggfunc.html
+    function("avg", Aggregate, Params(&["X"], 1, Some(1)), true, "3.0.0", "Average of the non-NULL values in the group"),
+    function("count", Aggregate, Params(&["X"], 1, Some(1)), true, "3.0.0", "Number of non-NULL values in the group (`count(*)` counts rows)"),
+    function("group_concat", Aggregate, Params(&["X", "Y"], 1, Some(2)), true, "3.0.0", "Concatenation of the non-NULL values separated by Y (`,` by default)"),
+    function("string_agg", Aggregate, Params(&["X", "Y"], 2, Some(2)), true, "3.44.0", "Concatenation of the non-NULL values separated by Y"),
+    function("sum", Aggregate, Params(&["X"], 1, Some(1)), true, "3.0.0", "Sum of the non-NULL values, or NULL for no values"),
+    function("total", Aggregate, Params(&["X"], 1, Some(1)), true, "3.0.0", "Sum of the non-NULL values as a floating point value, or 0.0 for no values"),
+
+    // See: https://www.sqlite.org/lang_datefunc.html
+    // Not deterministic because of `'now'`
+    function("date", DateTime, Params(&["TIME", "MOD"], 0, None), false, "3.0.0", "Date as `YYYY-MM-DD`"),
+    function("time", DateTime, Params(&["TIME", "MOD"], 0, None), false, "3.0.0", "Time as `HH:MM:SS`"),
+    function("datetime", DateTime, Params(&["TIME", "MOD"], 0, None), false, "3.0.0", "Date and time as `YYYY-MM-DD HH:MM:SS`"),
+    function("julianday", DateTime, Params(&["TIME", "MOD"], 0, None), false, "3.0.0", "Julian day number"),
+    function("unixepoch", DateTime, Params(&["TIME", "MOD"], 0, None), false, "3.38.0", "Unix timestamp as an integer"),
+    function("strftime", DateTime, Params(&["FORMAT", "TIME", "MOD"], 1, None), false, "3.0.0", "Date and time formatted by FORMAT"),
+    function("timediff", DateTime, Params(&["A", "B"], 2, Some(2)), true, "3.43.0", "Time difference A - B as `(+|-)YYYY-MM-DD HH:MM:SS.SSS`"),
+
+    // See: https://www.sqlite.org/json1.html
+    function("json", Json, Params(&["X"], 1, Some(1)), true, "3.9.0", "Minified JSON text of X"),
+    function("jsonb", Json, Params(&["X"], 1, Some(1)), true, "3.45.0", "JSONB blob of X"),
+    function("json_array", Json, Params(&["VALUE"], 0, None), true, "3.9.0", "JSON array of the arguments"),
+    function("jsonb_array", Json, Params(&["VALUE"], 0, None), true, "3.45.0", "JSONB array of the arguments"),
+    function("json_array_length", Json, Params(&["JSON", "PATH"], 1, Some(2)), true, "3.9.0", "Number of elements in the JSON array"),
+    function("json_error_position", Json, Params(&["JSON"], 1, Some(1)), true, "3.42.0", "1-based position of the first syntax error, or 0 for well-formed JSON"),
+    function("json_extract", Json, Params(&["JSON", "PATH"], 2, None), true, "3.9.0", "Values at the paths (an SQL value for a single path, a JSON array otherwise)"),
+    function("jsonb_extract", Json, Params(&["JSON", "PATH"], 2, None), true, "3.45.0", "Values at the paths, returned as JSONB for arrays and objects"),
+    function("json_insert", Json, Params(&["JSON", "PATH", "VALUE"], 3, None), true, "3.9.0", "JSON with the values inserted at the paths that do not exist yet"),
+    function("jsonb_insert", Json, Params(&["JSON", "PATH", "VALUE"], 3, None), true, "3.45.0", "JSONB with the values inserted at the paths that do not exist yet"),
+    function("json_object", Json, Params(&["LABEL", "VALUE"], 0, None), true, "3.9.0", "JSON object of the label/value pairs"),
+    function("jsonb_object", Json, Params(&["LABEL", "VALUE"], 0, None), true, "3.45.0", "JSONB object of the label/value pairs"),
+    function("json_patch", Json, Params(&["TARGET", "PATCH"], 2, Some(2)), true, "3.18.0", "TARGET with the RFC-7396 MergePatch applied"),
+    function("jsonb_patch", Json, Params(&["TARGET", "PATCH"], 2, Some(2)), true, "3.45.0", "TARGET with the RFC-7396 MergePatch applied, as JSONB"),
+    function("json_pretty", Json, Params(&["JSON", "INDENT"], 1, Some(2)), true, "3.46.0", "Indented JSON text"),
+    function("json_quote", Json, Params(&["X"], 1, Some(1)), true, "3.14.0", "JSON representation of the SQL value"),
+    function("json_remove", Json, Params(&["JSON", "PATH"], 1, None), true, "3.9.0", "JSON with the elements at the paths removed"),
+    function("jsonb_remove", Json, Params(&["JSON", "PATH"], 1, None), true, "3.45.0", "JSONB with the elements at the paths removed"),
+    function("json_replace", Json, Params(&["JSON", "PATH", "VALUE"], 3, None), true, "3.9.0", "JSON with the values at the existing paths replaced"),
+    function("jsonb_replace", Json, Params(&["JSON", "PATH", "VALUE"], 3, None), true, "3.45.0", "JSONB with the values at the existing paths replaced"),
+    function("json_set", Json, Params(&["JSON", "PATH", "VALUE"], 3, None), true, "3.9.0", "JSON with the values at the paths inserted or replaced"),
+    function("jsonb_set", Json, Params(&["JSON", "PATH", "VALUE"], 3, None), true, "3.45.0", "JSONB with the values at the paths inserted or replaced"),
+    function("json_type", Json, Params(&["JSON", "PATH"], 1, Some(2)), true, "3.9.0", "Type of the JSON element: `null`, `true`, `false`, `integer`, `real`, `text`, `array` or `object`"),
+    function("json_valid", Json, Params(&["JSON", "FLAGS"], 1, Some(2)), true, "3.9.0", "1 if the argument is well-formed JSON"),
+    function("json_group_array", Json, Params(&["VALUE"], 1, Some(1)), true, "3.9.0", "JSON array of the values in the group (aggregate)"),
+    function("jsonb_group_array", Json, Params(&["VALUE"], 1, Some(1)), true, "3.45.0", "JSONB array of the values in the group (aggregate)"),
+    function("json_group_object", Json, Params(&["LABEL", "VALUE"], 2, Some(2)), true, "3.9.0", "JSON object of the label/value pairs in the group (aggregate)"),
+    function("jsonb_group_object", Json, Params(&["LABEL", "VALUE"], 2, Some(2)), true, "3.45.0", "JSONB object of the label/value pairs in the group (aggregate)"),
+
+    // See: https://www.sqlite.org/lang_mathfunc.html
+    function("acos", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Arccosine of X"),
+    function("acosh", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Hyperbolic arccosine of X"),
+    function("asin", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Arcsine of X"),
+    function("asinh", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Hyperbolic arcsine of X"),
+    function("atan", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Arctangent of X"),
+    function("atan2", Math, Params(&["Y", "X"], 2, Some(2)), true, "3.35.0", "Arctangent of Y/X in radians"),
+    function("atanh", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Hyperbolic arctangent of X"),
+    function("ceil", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Smallest integer not less than X"),
+    function("ceiling", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Smallest integer not less than X"),
+    function("cos", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Cosine of X"),
+    function("cosh", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Hyperbolic cosine of X"),
+    function("degrees", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "X converted from radians to degrees"),
+    function("exp", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "e raised to the power X"),
+    function("floor", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Largest integer not greater than X"),
+    function("ln", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Natural logarithm of X"),
+    function("log", Math, Params(&["B", "X"], 1, Some(2)), true, "3.35.0", "Base-10 logarithm of X, or base-B logarithm with two arguments"),
+    function("log10", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Base-10 logarithm of X"),
+    function("log2", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Base-2 logarithm of X"),
+    function("mod", Math, Params(&["X", "Y"], 2, Some(2)), true, "3.35.0", "Remainder of X divided by Y"),
+    function("pi", Math, Params(&[], 0, Some(0)), true, "3.35.0", "Approximation of π"),
+    function("pow", Math, Params(&["X", "Y"], 2, Some(2)), true, "3.35.0", "X raised to the power Y"),
+    function("power", Math, Params(&["X", "Y"], 2, Some(2)), true, "3.35.0", "X raised to the power Y"),
+    function("radians", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "X converted from degrees to radians"),
+    function("sin", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Sine of X"),
+    function("sinh", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Hyperbolic sine of X"),
+    function("sqrt", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Square root of X"),
+    function("tan", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Tangent of X"),
+    function("tanh", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "Hyperbolic tangent of X"),
+    function("trunc", Math, Params(&["X"], 1, Some(1)), true, "3.35.0", "X rounded towards zero"),
+
+    // See: https://www.sqlite.org/windowfunctions.html
+    function("row_number", Window, Params(&[], 0, Some(0)), true, "3.25.0", "Number of the row within the partition, starting from 1"),
+    function("rank", Window, Params(&[], 0, Some(0)), true, "3.25.0", "Rank of the row with gaps"),
+    function("dense_rank", Window, Params(&[], 0, Some(0)), true, "3.25.0", "Rank of the row without gaps"),
+    function("percent_rank", Window, Params(&[], 0, Some(0)), true, "3.25.0", "(rank - 1) / (rows in partition - 1)"),
+    function("cume_dist", Window, Params(&[], 0, Some(0)), true, "3.25.0", "Cumulative distribution of the row"),
+    function("ntile", Window, Params(&["N"], 1, Some(1)), true, "3.25.0", "Bucket number when the partition is divided into N groups"),
+    function("lag", Window, Params(&["EXPR", "OFFSET", "DEFAULT"], 1, Some(3)), true, "3.25.0", "EXPR evaluated at the row OFFSET rows before"),
+    function("lead", Window, Params(&["EXPR", "OFFSET", "DEFAULT"], 1, Some(3)), true, "3.25.0", "EXPR evaluated at the row OFFSET rows after"),
+    function("first_value", Window, Params(&["EXPR"], 1, Some(1)), true, "3.25.0", "EXPR evaluated at the first row of the window frame"),
+    function("last_value", Window, Params(&["EXPR"], 1, Some(1)), true, "3.25.0", "EXPR evaluated at the last row of the window frame"),
+    function("nth_value", Window, Params(&["EXPR", "N"], 2, Some(2)), true, "3.25.0", "EXPR evaluated at the N-th row of the window frame"),
+];
+
+pub fn functions() -> &'static [FunctionInfo] {
+    FUNCTIONS
+}
+
+pub fn lookup(name: &str) -> Option<&'static FunctionInfo> {
+    FUNCTIONS.iter().find(|function| function.name.eq_ignore_ascii_case(name))
+}
+
+// idj LP distinct exprlist RP filter_over / idj LP STAR RP filter_over
+#[derive(Clone)]
+pub struct FunctionCall {
+    pub name: String,
+    pub name_range: TextRange,
+    pub args: Vec<SyntaxNode>,
+    // `count(*)`
+    pub star: bool,
+    // Between the parentheses
+    pub arg_range: TextRange,
+    commas: Vec<TextRange>,
+}
+
+impl FunctionCall {
+    pub fn from_expr(expr: &SyntaxNode) -> Option<Self> {
+        if expr.kind() != syntax_kind::r#expr {
+            return None;
+        }
+        let parts = expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+        let (name, lp) = match parts.as_slice() {
+            // (`idj` may be reduced to the name token itself)
+            [name, lp, ..] if ([syntax_kind::r#idj, syntax_kind::r#id].contains(&name.kind()) || is_column_token(name.kind())) && (lp.kind() == syntax_kind::r#LP) => (name, lp),
+            _ => return None,
+        };
+        // Missing `)` is inserted by error recovery
+        let end = parts.iter().find(|part| part.kind() == syntax_kind::r#RP).map_or(expr.text_range().end(), |rp| rp.token_range().start());
+
+        let items = child(expr, syntax_kind::r#exprlist)
+            .and_then(|exprlist| child(&exprlist, syntax_kind::r#nexprlist))
+            .map(|nexprlist| flatten_list(&nexprlist))
+            .unwrap_or_default()
+        ;
+
+        Some(Self {
+            name: name.identifier().unwrap_or_else(|_| name.token_text()),
+            name_range: name.token_range(),
+            args: items.iter().filter_map(|item| child(item, syntax_kind::r#expr)).collect(),
+            star: child(expr, syntax_kind::r#STAR).is_some() || child(expr, syntax_kind::r#ASTERISK).is_some(),
+            arg_range: TextRange::new(lp.token_range().end(), end.max(lp.token_range().end())),
+            commas: items.iter().filter_map(|item| child(item, syntax_kind::r#COMMA)).map(|comma| comma.token_range()).collect(),
+        })
+    }
+
+    pub fn function(&self) -> Option<&'static FunctionInfo> {
+        lookup(&self.name)
+    }
+
+    // Index of the argument at the offset
+    pub fn active_arg(&self, offset: TextSize) -> usize {
+        self.commas.iter().filter(|comma| comma.end() <= offset).count()
+    }
+}
+
+#[derive(Clone)]
+pub struct SignatureHelp {
+    pub function: &'static FunctionInfo,
+    // Clamped to the last parameter for variadic functions
+    pub active_parameter: usize,
+    pub call: FunctionCall,
+}
+
+// Innermost known function call whose argument list contains the offset
+pub fn signature_help(tree: &SyntaxTree, offset: TextSize) -> Option<SignatureHelp> {
+    let call = calls(tree)
+        .filter(|call| call.arg_range.contains_inclusive(offset))
+        .filter(|call| call.function().is_some())
+        .min_by_key(|call| call.arg_range.len())?
+    ;
+    let function = call.function()?;
+    let active_parameter = call.active_arg(offset).min(function.params.len().saturating_sub(1));
+
+    Some(SignatureHelp { function, active_parameter, call })
+}
+
+// Function at the name under the offset
+pub fn hover(tree: &SyntaxTree, offset: TextSize) -> Option<(&'static FunctionInfo, TextRange)> {
+    calls(tree)
+        .find(|call| call.name_range.contains_inclusive(offset))
+        .and_then(|call| Some((call.function()?, call.name_range)))
+}
+
+fn calls(tree: &SyntaxTree) -> impl Iterator<Item = FunctionCall> {
+    tree.root().descendants()
+        .filter(|node| node.kind() == syntax_kind::r#expr)
+        .filter_map(|expr| FunctionCall::from_expr(&expr))
+        .collect::<Vec<_>>()
+        .into_iter()
+}
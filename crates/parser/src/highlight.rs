@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use cstree::text::TextRange;
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::{function::FunctionCall, resolve::{is_column_token, resolve}, schema::Catalog, NodeType, SyntaxNode, SyntaxTree};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum HighlightTag {
+    Keyword,
+    Function,
+    Table,
+    Column,
+    Alias,
+    String,
+    Number,
+    Blob,
+    Parameter,
+    Comment,
+    Operator,
+    Error,
+}
+
+// Classify tokens for syntax highlighting (sorted by offset).
+// Names are classified by their position in the tree, so keywords used as names (e.g. `key`) get the name tag.
+pub fn highlight(tree: &SyntaxTree) -> Vec<(TextRange, HighlightTag)> {
+    let aliases = resolve(tree, &Catalog::from_tree(tree)).tables.into_iter()
+        .filter_map(|reference| reference.alias.map(|alias| alias.to_ascii_lowercase()))
+        .collect()
+    ;
+    let mut highlighter = Highlighter { aliases, highlights: vec![] };
+
+    let root = tree.root();
+    for comment in root.descendants().filter(|node| node.kind() == syntax_kind::r#COMMENT) {
+        highlighter.highlights.push((comment.text_range(), HighlightTag::Comment));
+    }
+    highlighter.visit(&root, None);
+
+    let mut highlights = highlighter.highlights;
+    highlights.sort_by_key(|(range, _)| range.start());
+    highlights
+}
+
+struct Highlighter {
+    aliases: HashSet<String>,
+    highlights: Vec<(TextRange, HighlightTag)>,
+}
+
+impl Highlighter {
+    // `tag` is the name context given by the parent
+    fn visit(&mut self, node: &SyntaxNode, tag: Option<HighlightTag>) {
+        // Tokens dropped by error recovery
+        if node.is_error() && (node.metadata().node_type == NodeType::Error) {
+            for token in node.main_tokens().iter().filter(|token| !token.text_range().is_empty()) {
+                self.highlights.push((token.text_range(), HighlightTag::Error));
+            }
+            return;
+        }
+        if node.kind().is_terminal && (node.metadata().node_type != NodeType::FatalError) {
+            for token in node.main_tokens().iter().filter(|token| !token.text_range().is_empty()) {
+                let name_tag = tag.filter(|_| is_name_token(token.kind()));
+                if let Some(tag) = name_tag.or_else(|| token_tag(token.kind(), &token.text())) {
+                    self.highlights.push((token.text_range(), tag));
+                }
+            }
+            return;
+        }
+
+        let children = node.children().filter(|child| child.as_inner_node().is_some()).collect::<Vec<_>>();
+        let tags = match is_name_node(node.kind()) {
+            true => vec![tag; children.len()],
+            false => self.child_tags(node, &children),
+        };
+        for (child, tag) in children.iter().zip(tags) {
+            self.visit(child, tag);
+        }
+    }
+
+    fn child_tags(&self, node: &SyntaxNode, children: &[SyntaxNode]) -> Vec<Option<HighlightTag>> {
+        let kinds = children.iter().map(|child| child.kind()).collect::<Vec<_>>();
+        let names = |tag: HighlightTag| kinds.iter().map(|kind| is_name_node(*kind).then_some(tag)).collect::<Vec<_>>();
+
+        match node.kind() {
+            k if k == syntax_kind::r#expr => match kinds.as_slice() {
+                _ if FunctionCall::from_expr(node).is_some() => {
+                    let mut tags = vec![None; children.len()];
+                    tags[0] = Some(HighlightTag::Function);
+                    tags
+                }
+                [name] if is_name_node(*name) || is_column_token(*name) => vec![Some(HighlightTag::Column)],
+                // nm DOT nm / nm DOT nm DOT nm
+                [.., table, dot, column] if (*table == syntax_kind::r#nm) && (*dot == syntax_kind::r#DOT) && (*column == syntax_kind::r#nm) => {
+                    let qualifier = match children[children.len() - 3].identifier() {
+                        Ok(name) if self.aliases.contains(&name.to_ascii_lowercase()) => HighlightTag::Alias,
+                        _ => HighlightTag::Table,
+                    };
+                    let mut tags = names(HighlightTag::Table);
+                    tags[children.len() - 3] = Some(qualifier);
+                    tags[children.len() - 1] = Some(HighlightTag::Column);
+                    tags
+                }
+                _ => vec![None; children.len()],
+            },
+            k if k == syntax_kind::r#as => names(HighlightTag::Alias),
+            // xfullname ::= nm AS nm
+            k if k == syntax_kind::r#xfullname => kinds.iter().enumerate()
+                .map(|(i, kind)| match is_name_node(*kind) {
+                    true if (i > 0) && (kinds[i - 1] == syntax_kind::r#AS) => Some(HighlightTag::Alias),
+                    true => Some(HighlightTag::Table),
+                    false => None,
+                })
+                .collect(),
+            k if [syntax_kind::r#seltablist, syntax_kind::r#fullname, syntax_kind::r#create_table, syntax_kind::r#trigger_decl, syntax_kind::r#trigger_cmd].contains(&k) => names(HighlightTag::Table),
+            k if [syntax_kind::r#columnname, syntax_kind::r#eidlist, syntax_kind::r#idlist, syntax_kind::r#setlist].contains(&k) => names(HighlightTag::Column),
+            // Declared type (e.g. `INTEGER`)
+            k if (k == syntax_kind::r#typetoken) || (k == syntax_kind::r#typename) => vec![Some(HighlightTag::Keyword); children.len()],
+            // REFERENCES nm eidlist_opt refargs
+            k if (k == syntax_kind::r#ccons) || (k == syntax_kind::r#tcons) => kinds.iter().enumerate()
+                .map(|(i, kind)| (is_name_node(*kind) && (i > 0) && (kinds[i - 1] == syntax_kind::r#REFERENCES)).then_some(HighlightTag::Table))
+                .collect(),
+            // ALTER TABLE fullname RENAME kwcolumn_opt nm TO nm
+            k if (k == syntax_kind::r#cmd) && kinds.contains(&syntax_kind::r#kwcolumn_opt) => names(HighlightTag::Column),
+            // CREATE VIEW/INDEX/TRIGGER, ALTER TABLE ... RENAME TO nm
+            k if (k == syntax_kind::r#cmd) && kinds.iter().any(|kind| [syntax_kind::r#TABLE, syntax_kind::r#VIEW, syntax_kind::r#INDEX, syntax_kind::r#TRIGGER].contains(kind)) => names(HighlightTag::Table),
+            _ => vec![None; children.len()],
+        }
+    }
+}
+
+fn is_name_node(kind: SyntaxKind) -> bool {
+    [syntax_kind::r#nm, syntax_kind::r#id, syntax_kind::r#ids, syntax_kind::r#idj, syntax_kind::r#dbnm, syntax_kind::r#withnm, syntax_kind::r#trnm].contains(&kind)
+}
+
+fn is_name_token(kind: SyntaxKind) -> bool {
+    is_column_token(kind) || (kind == syntax_kind::r#STRING) || (kind == syntax_kind::r#INDEXED) || kind.is_keyword || [syntax_kind::r#LIKE_KW, syntax_kind::r#CTIME_KW].contains(&kind)
+}
+
+fn token_tag(kind: SyntaxKind, text: &str) -> Option<HighlightTag> {
+    let punctuation = [syntax_kind::r#LP, syntax_kind::r#RP, syntax_kind::r#COMMA, syntax_kind::r#SEMI, syntax_kind::r#DOT, syntax_kind::r#EOF];
+
+    let tag = match kind {
+        k if punctuation.contains(&k) => return None,
+        k if k == syntax_kind::r#ILLEGAL => HighlightTag::Error,
+        k if k == syntax_kind::r#STRING => HighlightTag::String,
+        k if k == syntax_kind::r#BLOB => HighlightTag::Blob,
+        k if [syntax_kind::r#INTEGER, syntax_kind::r#FLOAT, syntax_kind::r#QNUMBER].contains(&k) => HighlightTag::Number,
+        k if k == syntax_kind::r#VARIABLE => HighlightTag::Parameter,
+        k if is_column_token(k) && (k != syntax_kind::r#JOIN_KW) => HighlightTag::Column,
+        // LIKE/GLOB/REGEXP/MATCH, LEFT/RIGHT/..., CURRENT_TIME/..., TRUE/FALSE
+        k if k.is_keyword || [syntax_kind::r#JOIN_KW, syntax_kind::r#LIKE_KW, syntax_kind::r#CTIME_KW, syntax_kind::r#TRUEFALSE].contains(&k) => HighlightTag::Keyword,
+        _ if text.starts_with(|c: char| !c.is_alphanumeric() && (c != '_')) => HighlightTag::Operator,
+        _ => HighlightTag::Keyword,
+    };
+    Some(tag)
+}
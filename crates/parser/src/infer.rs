@@ -0,0 +1,267 @@
+use cstree::text::TextRange;
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::{function::FunctionCall, precedence::{binary_expr, BinaryExpr, Operand}, resolve::{is_column_token, resolve, Resolution}, schema::{child, flatten_list, Affinity, Catalog, Column, Table}, SyntaxNode, SyntaxTree};
+
+mod params;
+pub use params::{infer_parameters, Parameter, Parameters};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ColumnType {
+    pub declared_type: Option<String>,
+    // None if the type is not known statically
+    pub affinity: Option<Affinity>,
+    pub nullable: bool,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ResultColumn {
+    pub name: String,
+    pub column_type: ColumnType,
+    pub definition: Option<TextRange>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ResultSet {
+    // Range of the `select` node
+    pub range: TextRange,
+    pub columns: Vec<ResultColumn>,
+}
+
+impl ColumnType {
+    pub fn unknown() -> Self {
+        Self { declared_type: None, affinity: None, nullable: true }
+    }
+
+    fn of(affinity: Affinity, nullable: bool) -> Self {
+        Self { declared_type: None, affinity: Some(affinity), nullable }
+    }
+
+    pub fn from_column(column: &Column, table: &Table) -> Self {
+        // INTEGER PRIMARY KEY is an alias of rowid
+        let rowid_alias = column.primary_key && column.declared_type.as_deref().is_some_and(|ty| ty.eq_ignore_ascii_case("INTEGER"));
+        let not_null = column.not_null || rowid_alias || (column.primary_key && table.without_rowid);
+
+        Self {
+            declared_type: column.declared_type.clone(),
+            affinity: Some(column.affinity),
+            nullable: !not_null,
+        }
+    }
+
+    pub fn with_nullable(self, nullable: bool) -> Self {
+        Self { nullable, ..self }
+    }
+}
+
+// Output columns of top level SELECT statements
+pub fn infer_statements(tree: &SyntaxTree, catalog: &Catalog) -> Vec<ResultSet> {
+    let resolution = resolve(tree, catalog);
+
+    tree.root().children()
+        .filter_map(|statement| {
+            statement.descendants()
+                .find(|node| node.kind() == syntax_kind::r#cmd)
+                .and_then(|cmd| child(&cmd, syntax_kind::r#select))
+        })
+        .filter_map(|select| resolution.result_sets.iter().find(|result_set| result_set.range == select.token_range()).cloned())
+        .collect()
+}
+
+// Infer the type of an expression.
+// Column references must be resolved in `resolution` beforehand.
+// `grouped` is true if the expression is evaluated per group (GROUP BY).
+pub fn expr_type(expr: &SyntaxNode, resolution: &Resolution, grouped: bool) -> ColumnType {
+    let parts = expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+    let kinds = parts.iter().map(|part| part.kind()).collect::<Vec<_>>();
+    let expr_of = |part: &SyntaxNode| expr_type(part, resolution, grouped);
+
+    match kinds.as_slice() {
+        // literal
+        [k] if *k == syntax_kind::r#term => literal_type(&parts[0]),
+        [k] if (*k == syntax_kind::r#INTEGER) || (*k == syntax_kind::r#FLOAT) || (*k == syntax_kind::r#STRING) || (*k == syntax_kind::r#BLOB) || (*k == syntax_kind::r#NULL) => literal_type(expr),
+        [k] if *k == syntax_kind::r#CTIME_KW => ColumnType::of(Affinity::Text, false),
+        [k] if *k == syntax_kind::r#TRUEFALSE => ColumnType::of(Affinity::Integer, false),
+        [k] if *k == syntax_kind::r#VARIABLE => ColumnType::unknown(),
+        // column reference
+        [k] if is_column_token(*k) => column_type(expr, resolution),
+        [.., k1, k2, k3] if (*k1 == syntax_kind::r#nm) && (*k2 == syntax_kind::r#DOT) && (*k3 == syntax_kind::r#nm) => column_type(expr, resolution),
+        // LP expr RP
+        [lp, k, rp] if (*lp == syntax_kind::r#LP) && (*rp == syntax_kind::r#RP) && (*k == syntax_kind::r#nexprlist) => {
+            match nexprlist_items(&parts[1]).as_slice() {
+                [inner] => expr_of(inner),
+                _ => ColumnType::unknown(),
+            }
+        }
+        [lp, k, rp] if (*lp == syntax_kind::r#LP) && (*rp == syntax_kind::r#RP) && (*k == syntax_kind::r#expr) => expr_of(&parts[1]),
+        // scalar subquery
+        [lp, k, rp] if (*lp == syntax_kind::r#LP) && (*rp == syntax_kind::r#RP) && (*k == syntax_kind::r#select) => {
+            let first = resolution.result_sets.iter()
+                .find(|result_set| result_set.range == parts[1].token_range())
+                .and_then(|result_set| result_set.columns.first())
+            ;
+            match first {
+                Some(column) => column.column_type.clone().with_nullable(true),
+                None => ColumnType::unknown(),
+            }
+        }
+        [k, ..] if *k == syntax_kind::r#EXISTS => ColumnType::of(Affinity::Integer, false),
+        [k, ..] if *k == syntax_kind::r#CAST => {
+            let inner = child(expr, syntax_kind::r#expr).map(|inner| expr_of(&inner)).unwrap_or_else(ColumnType::unknown);
+            let declared_type = child(expr, syntax_kind::r#typetoken).map(|node| node.token_text());
+            ColumnType {
+                affinity: Some(Affinity::from_declared_type(declared_type.as_deref())),
+                declared_type,
+                nullable: inner.nullable,
+            }
+        }
+        [k, ..] if *k == syntax_kind::r#CASE => case_type(expr, resolution, grouped),
+        [_, lp, ..] if *lp == syntax_kind::r#LP => match FunctionCall::from_expr(expr) {
+            Some(call) => function_type(&call, resolution, grouped),
+            None => ColumnType::unknown(),
+        },
+        // unary operator
+        [k, _] if *k == syntax_kind::r#NOT => ColumnType::of(Affinity::Integer, expr_of(&parts[1]).nullable),
+        [k, _] if (*k == syntax_kind::r#MINUS) || (*k == syntax_kind::r#PLUS) || (*k == syntax_kind::r#BITNOT) => expr_of(&parts[1]),
+        [_, k, _] if *k == syntax_kind::r#COLLATE => expr_of(&parts[0]),
+        // postfix `ISNULL` / `NOTNULL` / `NOT NULL`
+        [_, k] if (*k == syntax_kind::r#ISNULL) || (*k == syntax_kind::r#NOTNULL) => ColumnType::of(Affinity::Integer, false),
+        [_, k1, k2] if (*k1 == syntax_kind::r#NOT) && (*k2 == syntax_kind::r#NULL) => ColumnType::of(Affinity::Integer, false),
+        // binary operator
+        [k, ..] if *k == syntax_kind::r#expr => match binary_expr(expr) {
+            Some(binary) => binary_type(&binary, resolution, grouped),
+            // BETWEEN, IN, LIKE ... ESCAPE
+            None => match kinds.get(1) {
+                Some(operator) => operator_type(*operator, &parts.iter().filter(|part| part.kind() == syntax_kind::r#expr).map(expr_of).collect::<Vec<_>>()),
+                None => ColumnType::unknown(),
+            },
+        },
+        _ => ColumnType::unknown(),
+    }
+}
+
+fn literal_type(node: &SyntaxNode) -> ColumnType {
+    let Some(token) = node.main_tokens().into_iter().next() else {
+        return ColumnType::unknown();
+    };
+
+    match token.kind() {
+        k if k == syntax_kind::r#INTEGER || k == syntax_kind::r#QNUMBER => ColumnType::of(Affinity::Integer, false),
+        k if k == syntax_kind::r#FLOAT => ColumnType::of(Affinity::Real, false),
+        k if k == syntax_kind::r#STRING => ColumnType::of(Affinity::Text, false),
+        k if k == syntax_kind::r#BLOB => ColumnType::of(Affinity::Blob, false),
+        k if k == syntax_kind::r#CTIME_KW => ColumnType::of(Affinity::Text, false),
+        _ => ColumnType::unknown(),
+    }
+}
+
+fn column_type(expr: &SyntaxNode, resolution: &Resolution) -> ColumnType {
+    let range = expr.token_range();
+
+    resolution.columns.iter()
+        .find(|reference| reference.range == range)
+        .and_then(|reference| reference.target.as_ref())
+        .map(|target| target.column_type.clone())
+        .unwrap_or_else(ColumnType::unknown)
+}
+
+fn binary_type(binary: &BinaryExpr, resolution: &Resolution, grouped: bool) -> ColumnType {
+    let operand_type = |operand: &Operand| match operand {
+        Operand::Expr(expr) => expr_type(expr, resolution, grouped),
+        Operand::Binary(binary) => binary_type(binary, resolution, grouped),
+    };
+    operator_type(binary.operator.kind(), &[operand_type(&binary.lhs), operand_type(&binary.rhs)])
+}
+
+fn operator_type(operator: SyntaxKind, operands: &[ColumnType]) -> ColumnType {
+    let nullable = operands.iter().any(|operand| operand.nullable);
+
+    match operator {
+        // `IS [NOT] [DISTINCT FROM]` never returns NULL
+        k if (k == syntax_kind::r#IS) || (k == syntax_kind::r#ISNOT) => ColumnType::of(Affinity::Integer, false),
+        k if k == syntax_kind::r#CONCAT => ColumnType::of(Affinity::Text, nullable),
+        k if [syntax_kind::r#PLUS, syntax_kind::r#MINUS, syntax_kind::r#STAR, syntax_kind::r#REM, syntax_kind::r#BITAND, syntax_kind::r#BITOR, syntax_kind::r#LSHIFT, syntax_kind::r#RSHIFT].contains(&k) => {
+            numeric_type(operands, nullable)
+        }
+        // division by zero returns NULL
+        k if k == syntax_kind::r#SLASH => numeric_type(operands, true),
+        k if k == syntax_kind::r#PTR => ColumnType::unknown(),
+        _ => ColumnType::of(Affinity::Integer, nullable),
+    }
+}
+
+fn numeric_type(operands: &[ColumnType], nullable: bool) -> ColumnType {
+    let affinities = operands.iter().map(|operand| operand.affinity).collect::<Vec<_>>();
+
+    match affinities.as_slice() {
+        xs if xs.iter().all(|x| *x == Some(Affinity::Integer)) => ColumnType::of(Affinity::Integer, nullable),
+        xs if xs.contains(&Some(Affinity::Real)) => ColumnType::of(Affinity::Real, nullable),
+        _ => ColumnType::of(Affinity::Numeric, nullable),
+    }
+}
+
+// CASE case_operand case_exprlist case_else END
+fn case_type(expr: &SyntaxNode, resolution: &Resolution, grouped: bool) -> ColumnType {
+    let results = child(expr, syntax_kind::r#case_exprlist)
+        .map(|list| {
+            flatten_list(&list).iter()
+                .filter_map(|item| item.children().filter(|child| child.kind() == syntax_kind::r#expr).last())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default()
+    ;
+    let else_expr = child(expr, syntax_kind::r#case_else).and_then(|node| child(&node, syntax_kind::r#expr));
+
+    let types = results.iter().chain(else_expr.iter()).map(|node| expr_type(node, resolution, grouped)).collect::<Vec<_>>();
+    let nullable = else_expr.is_none() || types.iter().any(|ty| ty.nullable);
+
+    match types.first() {
+        Some(first) => first.clone().with_nullable(nullable),
+        None => ColumnType::unknown(),
+    }
+}
+
+// idj LP distinct exprlist RP filter_over / idj LP STAR RP filter_over
+fn function_type(call: &FunctionCall, resolution: &Resolution, grouped: bool) -> ColumnType {
+    let name = &call.name;
+    let args = call.args.iter().map(|arg| expr_type(arg, resolution, grouped)).collect::<Vec<_>>();
+    let any_nullable = args.iter().any(|arg| arg.nullable);
+    let first = args.first().cloned().unwrap_or_else(ColumnType::unknown);
+    // Aggregate without GROUP BY returns NULL for empty input
+    let aggregate_nullable = !grouped || any_nullable;
+
+    match name.to_ascii_lowercase().as_str() {
+        "count" => ColumnType::of(Affinity::Integer, false),
+        "total" => ColumnType::of(Affinity::Real, false),
+        "avg" => ColumnType::of(Affinity::Real, aggregate_nullable),
+        "sum" => first.with_nullable(aggregate_nullable),
+        "min" | "max" if args.len() == 1 => first.with_nullable(aggregate_nullable),
+        "min" | "max" => first.with_nullable(any_nullable),
+        "group_concat" | "string_agg" => ColumnType::of(Affinity::Text, true),
+        "coalesce" | "ifnull" => first.with_nullable(args.iter().all(|arg| arg.nullable)),
+        "iif" => {
+            let branches = args.iter().skip(1).collect::<Vec<_>>();
+            match branches.first() {
+                Some(branch) => (*branch).clone().with_nullable(branches.len() < 2 || branches.iter().any(|arg| arg.nullable)),
+                None => ColumnType::unknown(),
+            }
+        }
+        "nullif" | "likelihood" | "likely" | "unlikely" => first.with_nullable(true),
+        "abs" | "round" => first.with_nullable(any_nullable),
+        "length" | "octet_length" | "instr" | "unicode" | "sign" => ColumnType::of(Affinity::Integer, any_nullable),
+        "random" | "changes" | "total_changes" | "last_insert_rowid" => ColumnType::of(Affinity::Integer, false),
+        "lower" | "upper" | "trim" | "ltrim" | "rtrim" | "replace" | "substr" | "substring" | "hex" | "quote" | "char" | "concat" | "concat_ws" => {
+            ColumnType::of(Affinity::Text, any_nullable)
+        }
+        "typeof" | "printf" | "format" => ColumnType::of(Affinity::Text, false),
+        "randomblob" | "zeroblob" => ColumnType::of(Affinity::Blob, any_nullable),
+        // invalid time values return NULL
+        "date" | "time" | "datetime" | "strftime" | "timediff" => ColumnType::of(Affinity::Text, true),
+        "julianday" | "unixepoch" => ColumnType::of(Affinity::Real, true),
+        _ => ColumnType::unknown(),
+    }
+}
+
+fn nexprlist_items(nexprlist: &SyntaxNode) -> Vec<SyntaxNode> {
+    flatten_list(nexprlist).iter().filter_map(|item| child(item, syntax_kind::r#expr)).collect()
+}
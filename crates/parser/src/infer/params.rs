@@ -0,0 +1,313 @@
+use cstree::text::TextRange;
+use sqlite_parser_proto::engine::kinds as syntax_kind;
+
+use crate::{diagnostic::Diagnostic, precedence::{binary_expr, BinaryExpr}, resolve::{resolve, Resolution}, schema::{child, children, flatten_list, qualified_name, Affinity, Catalog, Table}, SyntaxNode, SyntaxTree};
+use super::{expr_type, ColumnType};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Parameter {
+    // 1-based index used for binding
+    pub index: usize,
+    // None for anonymous `?`
+    pub name: Option<String>,
+    pub ranges: Vec<TextRange>,
+    // None if the type cannot be determined from the usage
+    pub expected: Option<ColumnType>,
+    // Column the parameter is compared with or assigned to
+    pub column: Option<String>,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct Parameters {
+    pub parameters: Vec<Parameter>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Parameters {
+    pub fn get(&self, index: usize) -> Option<&Parameter> {
+        self.parameters.iter().find(|parameter| parameter.index == index)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Hint {
+    range: TextRange,
+    column_type: ColumnType,
+    column: Option<String>,
+}
+
+// Infer the expected type of bind parameters from their usage.
+pub fn infer_parameters(tree: &SyntaxTree, catalog: &Catalog) -> Parameters {
+    let resolution = resolve(tree, catalog);
+    let mut collector = HintCollector { catalog, resolution: &resolution, hints: vec![] };
+    collector.visit(&tree.root(), None);
+
+    let mut result = Parameters::default();
+
+    for node in tree.root().descendants().filter(|node| node.kind() == syntax_kind::r#VARIABLE && node.as_inner_node().is_some() && !node.is_error()) {
+        let text = node.token_text();
+        let range = node.token_range();
+        let hint = collector.hints.iter().find(|hint| hint.range == range);
+        let next_index = result.parameters.iter().map(|parameter| parameter.index).max().unwrap_or_default() + 1;
+
+        let (index, name) = match parameter_name(&text) {
+            ParameterName::Anonymous => (next_index, None),
+            ParameterName::Numbered(index) => (index, None),
+            ParameterName::Named(name) => {
+                let index = result.parameters.iter()
+                    .find(|parameter| parameter.name.as_deref() == Some(&name))
+                    .map_or(next_index, |parameter| parameter.index)
+                ;
+                (index, Some(name))
+            }
+        };
+
+        match result.parameters.iter_mut().find(|parameter| parameter.index == index) {
+            Some(parameter) => {
+                parameter.ranges.push(range);
+
+                let Some(hint) = hint else {
+                    continue;
+                };
+                match &parameter.expected {
+                    Some(expected) if expected.affinity != hint.column_type.affinity => {
+                        result.diagnostics.push(Diagnostic::warning("infer/conflicting-parameter-type", format!("parameter {text} is used as {} and {}", affinity_name(expected), affinity_name(&hint.column_type)), range));
+                    }
+                    Some(_) => {}
+                    None => {
+                        parameter.expected = Some(hint.column_type.clone());
+                        parameter.column = hint.column.clone();
+                    }
+                }
+            }
+            None => {
+                result.parameters.push(Parameter {
+                    index,
+                    name,
+                    ranges: vec![range],
+                    expected: hint.map(|hint| hint.column_type.clone()),
+                    column: hint.and_then(|hint| hint.column.clone()),
+                });
+            }
+        }
+    }
+
+    for parameter in result.parameters.iter().filter(|parameter| parameter.expected.is_none()) {
+        let label = parameter.name.clone().unwrap_or_else(|| format!("?{}", parameter.index));
+        result.diagnostics.push(Diagnostic::warning("infer/untyped-parameter", format!("cannot infer the type of parameter {label}"), parameter.ranges[0]));
+    }
+    result.parameters.sort_by_key(|parameter| parameter.index);
+
+    result
+}
+
+enum ParameterName {
+    Anonymous,
+    Numbered(usize),
+    Named(String),
+}
+
+// `?` / `?NNN` / `:AAA` / `@AAA` / `$AAA`
+fn parameter_name(text: &str) -> ParameterName {
+    match text.strip_prefix('?') {
+        Some("") => ParameterName::Anonymous,
+        Some(number) => number.parse().map(ParameterName::Numbered).unwrap_or_else(|_| ParameterName::Named(text.to_string())),
+        None => ParameterName::Named(text.to_string()),
+    }
+}
+
+fn affinity_name(column_type: &ColumnType) -> String {
+    match column_type.affinity {
+        Some(affinity) => format!("{affinity:?}").to_uppercase(),
+        None => "unknown".to_string(),
+    }
+}
+
+struct HintCollector<'a> {
+    catalog: &'a Catalog,
+    resolution: &'a Resolution,
+    hints: Vec<Hint>,
+}
+
+impl<'a> HintCollector<'a> {
+    fn visit(&mut self, node: &SyntaxNode, table: Option<&'a Table>) {
+        let kind = node.kind();
+
+        if (kind == syntax_kind::r#cmd) || (kind == syntax_kind::r#trigger_cmd) {
+            let catalog = self.catalog;
+            let table = child(node, syntax_kind::r#xfullname)
+                .or_else(|| child(node, syntax_kind::r#trnm))
+                .and_then(|node| qualified_name(&node))
+                .and_then(|(_, name)| catalog.table(&name))
+            ;
+            if let Some(table) = table.filter(|_| child(node, syntax_kind::r#insert_cmd).is_some()) {
+                self.insert_values(node, table);
+            }
+            self.visit_children(node, table);
+            return;
+        }
+
+        if kind == syntax_kind::r#expr {
+            self.expr(node);
+        }
+        else if kind == syntax_kind::r#setlist {
+            if let Some(table) = table {
+                self.setlist(node, table);
+            }
+        }
+        else if kind == syntax_kind::r#limit_opt {
+            // LIMIT expr [OFFSET|COMMA expr]
+            for expr in children(node, syntax_kind::r#expr) {
+                self.hint(&expr, ColumnType::of(Affinity::Integer, false), None);
+            }
+        }
+
+        self.visit_children(node, table);
+    }
+
+    fn visit_children(&mut self, node: &SyntaxNode, table: Option<&'a Table>) {
+        for child in node.children() {
+            self.visit(&child, table);
+        }
+    }
+
+    fn expr(&mut self, expr: &SyntaxNode) {
+        if let Some(binary) = binary_expr(expr) {
+            for operation in binary.operations() {
+                self.operation(operation);
+            }
+            return;
+        }
+
+        let parts = expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+        let kinds = parts.iter().map(|part| part.kind()).collect::<Vec<_>>();
+
+        match kinds.as_slice() {
+            // CAST LP expr AS typetoken RP
+            [k, ..] if *k == syntax_kind::r#CAST => {
+                if let (Some(inner), Some(typetoken)) = (child(expr, syntax_kind::r#expr), child(expr, syntax_kind::r#typetoken)) {
+                    let declared_type = typetoken.token_text();
+                    let column_type = ColumnType { affinity: Some(Affinity::from_declared_type(Some(&declared_type))), declared_type: Some(declared_type), nullable: true };
+                    self.hint(&inner, column_type, None);
+                }
+            }
+            // expr between_op expr AND expr
+            [k0, k1, k2, _, k4] if (*k0 == syntax_kind::r#expr) && (*k1 == syntax_kind::r#between_op) && (*k2 == syntax_kind::r#expr) && (*k4 == syntax_kind::r#expr) => {
+                self.compare(&parts[0], &[&parts[2], &parts[4]]);
+            }
+            // expr in_op LP exprlist RP
+            [k0, k1, ..] if (*k0 == syntax_kind::r#expr) && (*k1 == syntax_kind::r#in_op) => {
+                let items = child(expr, syntax_kind::r#exprlist)
+                    .and_then(|node| child(&node, syntax_kind::r#nexprlist))
+                    .map(|node| flatten_list(&node).iter().filter_map(|item| child(item, syntax_kind::r#expr)).collect::<Vec<_>>())
+                    .unwrap_or_default()
+                ;
+                self.compare(&parts[0], &items.iter().collect::<Vec<_>>());
+            }
+            // expr likeop expr ESCAPE expr
+            [k0, k1, ..] if (*k0 == syntax_kind::r#expr) && (*k1 == syntax_kind::r#likeop) => {
+                for operand in children(expr, syntax_kind::r#expr) {
+                    self.hint(&operand, ColumnType::of(Affinity::Text, true), None);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Binary operator regrouped by precedence
+    fn operation(&mut self, binary: &BinaryExpr) {
+        let operands = [&binary.lhs, &binary.rhs].into_iter().filter_map(|operand| operand.as_expr()).collect::<Vec<_>>();
+
+        match binary.operator.kind() {
+            k if (k == syntax_kind::r#likeop) || (k == syntax_kind::r#CONCAT) => {
+                for operand in operands {
+                    self.hint(operand, ColumnType::of(Affinity::Text, true), None);
+                }
+            }
+            // Logical operators give no hint for their operands
+            k if (k == syntax_kind::r#AND) || (k == syntax_kind::r#OR) => {}
+            // comparison / arithmetic / IS [NOT] [DISTINCT FROM]
+            _ => {
+                if let [lhs, rhs] = operands.as_slice() {
+                    self.compare(lhs, &[rhs]);
+                    self.compare(rhs, &[lhs]);
+                }
+            }
+        }
+    }
+
+    // Operands take the type of `subject`
+    fn compare(&mut self, subject: &SyntaxNode, operands: &[&SyntaxNode]) {
+        let column_type = expr_type(subject, self.resolution, false);
+        if column_type.affinity.is_none() {
+            return;
+        }
+        let column = self.resolution.columns.iter()
+            .find(|reference| reference.range == subject.token_range())
+            .map(|reference| reference.name.clone())
+        ;
+
+        for operand in operands {
+            self.hint(operand, column_type.clone(), column.clone());
+        }
+    }
+
+    // INSERT INTO xfullname idlist_opt VALUES (...), (...)
+    fn insert_values(&mut self, cmd: &SyntaxNode, table: &Table) {
+        let columns = match child(cmd, syntax_kind::r#idlist_opt).and_then(|node| child(&node, syntax_kind::r#idlist)) {
+            Some(idlist) => {
+                flatten_list(&idlist).iter()
+                    .filter_map(|item| children(item, syntax_kind::r#nm).last())
+                    .map(|nm| nm.identifier().ok().and_then(|name| table.column(&name)))
+                    .collect::<Vec<_>>()
+            }
+            None => table.columns.iter().map(Some).collect(),
+        };
+        let rows = child(cmd, syntax_kind::r#select)
+            .into_iter()
+            .flat_map(|select| select.descendants().collect::<Vec<_>>())
+            .filter(|node| (node.kind() == syntax_kind::r#values) || (node.kind() == syntax_kind::r#mvalues))
+            .filter_map(|values| child(&values, syntax_kind::r#nexprlist))
+            .collect::<Vec<_>>()
+        ;
+
+        for row in rows {
+            let items = flatten_list(&row).iter().filter_map(|item| child(item, syntax_kind::r#expr)).collect::<Vec<_>>();
+
+            for (expr, column) in items.iter().zip(columns.iter()) {
+                if let Some(column) = column {
+                    self.hint(expr, ColumnType::from_column(column, table), Some(column.name.clone()));
+                }
+            }
+        }
+    }
+
+    // setlist: nm EQ expr / LP idlist RP EQ expr
+    fn setlist(&mut self, setlist: &SyntaxNode, table: &Table) {
+        let Some(expr) = child(setlist, syntax_kind::r#expr) else {
+            return;
+        };
+        let Some(column) = child(setlist, syntax_kind::r#nm).and_then(|nm| nm.identifier().ok()).and_then(|name| table.column(&name)) else {
+            return;
+        };
+
+        self.hint(&expr, ColumnType::from_column(column, table), Some(column.name.clone()));
+    }
+
+    fn hint(&mut self, expr: &SyntaxNode, column_type: ColumnType, column: Option<String>) {
+        let parts = expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+
+        match parts.as_slice() {
+            [variable] if variable.kind() == syntax_kind::r#VARIABLE => {
+                self.hints.push(Hint { range: variable.token_range(), column_type, column });
+            }
+            // LP nexprlist RP
+            [lp, list, rp] if (lp.kind() == syntax_kind::r#LP) && (list.kind() == syntax_kind::r#nexprlist) && (rp.kind() == syntax_kind::r#RP) => {
+                if let [item] = flatten_list(list).as_slice() && let Some(inner) = child(item, syntax_kind::r#expr) {
+                    self.hint(&inner, column_type, column);
+                }
+            }
+            _ => {}
+        }
+    }
+}
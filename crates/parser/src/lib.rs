@@ -1,10 +1,33 @@
 use std::{collections::HashMap, rc::Rc};
 
-use cstree::{green::{GreenNode, GreenToken}, interning::{InternKey, TokenKey}, syntax::SyntaxToken, text::TextRange, util::NodeOrToken};
+use cstree::{green::{GreenNode, GreenToken}, interning::{InternKey, TokenKey}, syntax::SyntaxToken, util::{NodeOrToken, TokenAtOffset}};
 use sqlite_parser_proto::{engine, LookaheadTransition, SyntaxKind};
 
 mod parser;
-pub use parser::{Parser, AnnotationKey, NodeId, EditScope, IncrementalParser};
+mod split;
+mod fix;
+mod fingerprint;
+pub mod make;
+pub mod literal;
+pub mod schema;
+pub mod diagnostic;
+pub mod resolve;
+pub mod infer;
+pub mod precedence;
+pub mod rename;
+pub mod dependency;
+pub mod completion;
+pub mod highlight;
+pub mod function;
+pub mod flatten;
+pub mod document;
+pub use parser::{Parser, ParseError, AnnotationKey, NodeId, EditScope, IncrementalParser};
+pub use literal::{LiteralValue, LiteralError};
+pub use split::StatementSpan;
+pub use fix::QuickFix;
+pub use fingerprint::Fingerprint;
+pub use diagnostic::{Diagnostic, Severity, SyntaxError};
+pub use cstree::text::{TextRange, TextSize};
 
 type NodeElement = NodeOrToken::<GreenNode, GreenToken>;
 
@@ -70,6 +93,17 @@ impl SyntaxTree {
         self.annotations.get(&key).map(|(_, annotation)| annotation)
     }
 
+    // Raw token (main token or trivia) at the offset, the right one on a boundary
+    pub fn token_at_offset(&self, offset: TextSize) -> Option<SyntaxNode> {
+        if offset > self.root.text_range().end() {
+            return None;
+        }
+        match self.root.token_at_offset(offset) {
+            TokenAtOffset::Single(token) | TokenAtOffset::Between(_, token) => Some(SyntaxNode::from_token(&token, self.annotations.clone())),
+            TokenAtOffset::None => None,
+        }
+    }
+
     pub fn covering_element(&self, range: cstree::text::TextRange) -> Option<SyntaxNode> {
         let mut element = &self.root;
 
@@ -175,6 +209,11 @@ impl SyntaxNode {
     pub fn metadata(&self) -> Annotation {
         Self::metadata_with_key(self.metadata_map.clone(), &self.metadata_key()).expect("Lookup metadata failed")
     }
+
+    // None if the node has no annotation (instead of panicking like `metadata`)
+    pub fn try_metadata(&self) -> Option<Annotation> {
+        Self::metadata_with_key(self.metadata_map.clone(), &self.metadata_key())
+    }
     
     fn metadata_with_key(metadata_map: Rc<HashMap<AnnotationKey, (NodeId, Annotation)>>, key: &AnnotationKey) -> Option<Annotation> {
         metadata_map.get(&key).map(|(_, metadata)| metadata.clone())
@@ -278,6 +317,118 @@ impl SyntaxNode {
     pub fn children(&self) -> SyntaxChildren {
         SyntaxChildren::new(self.as_inner_node(), &self.metadata_map)
     }
+
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        self.inner_node.parent().map(|node| Self::from_node(node, self.metadata_map.clone()))
+    }
+
+    // From the parent to the root
+    pub fn ancestors(&self) -> Vec<SyntaxNode> {
+        std::iter::successors(self.parent(), |node| node.parent()).collect()
+    }
+
+    // Siblings include tokens (e.g. trivia in a token set)
+    pub fn next_sibling(&self) -> Option<SyntaxNode> {
+        let sibling = match &self.inner_node {
+            NodeOrToken::Node(x) => x.next_sibling_or_token(),
+            NodeOrToken::Token(x) => x.next_sibling_or_token(),
+        };
+        sibling.map(|element| Self::new(element, self.metadata_map.clone()))
+    }
+
+    pub fn prev_sibling(&self) -> Option<SyntaxNode> {
+        let sibling = match &self.inner_node {
+            NodeOrToken::Node(x) => x.prev_sibling_or_token(),
+            NodeOrToken::Token(x) => x.prev_sibling_or_token(),
+        };
+        sibling.map(|element| Self::new(element, self.metadata_map.clone()))
+    }
+
+    // Preorder traversal including self and tokens
+    pub fn descendants(&self) -> impl Iterator<Item = SyntaxNode> + '_ {
+        let metadata_map = self.metadata_map.clone();
+
+        self.as_inner_node().into_iter()
+        .flat_map(|node| node.descendants_with_tokens())
+        .map(move |child| match child {
+            NodeOrToken::Node(x) => self::SyntaxNode::from_node(x, metadata_map.clone()),
+            NodeOrToken::Token(x) => self::SyntaxNode::from_token(x, metadata_map.clone()),
+        })
+    }
+
+    pub fn text(&self) -> String {
+        match &self.inner_node {
+            NodeOrToken::Node(x) => x.resolved().text().to_string(),
+            NodeOrToken::Token(x) => x.resolved().text().to_string(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        match Self::metadata_with_key(self.metadata_map.clone(), &self.metadata_key()) {
+            Some(metadata) => matches!(metadata.node_type, NodeType::Error | NodeType::FatalError),
+            None => false,
+        }
+    }
+
+    pub fn main_tokens(&self) -> Vec<SyntaxNode> {
+        self.descendants()
+        .filter(|node| {
+            let Some(metadata) = Self::metadata_with_key(node.metadata_map.clone(), &node.metadata_key()) else {
+                return false;
+            };
+            metadata.node_type == NodeType::MainToken
+        })
+        .collect()
+    }
+
+    // Text range without leading/trailing trivia
+    pub fn token_range(&self) -> TextRange {
+        let tokens = self.main_tokens();
+
+        match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) => TextRange::new(first.text_range().start(), last.text_range().end()),
+            _ => TextRange::empty(self.text_range().start()),
+        }
+    }
+
+    // Text without leading/trailing trivia
+    pub fn token_text(&self) -> String {
+        let text = self.text();
+        let range = self.token_range() - self.text_range().start();
+
+        text[range].to_string()
+    }
+
+    // Decode a literal term (signed numbers are accepted)
+    pub fn literal_value(&self) -> Result<LiteralValue, LiteralError> {
+        match self.main_tokens().as_slice() {
+            [token] => literal::decode_literal(token.kind(), &token.text(), false),
+            [sign, token] if sign.kind() == engine::kinds::r#MINUS => literal::decode_literal(token.kind(), &token.text(), true),
+            [sign, token] if sign.kind() == engine::kinds::r#PLUS => literal::decode_literal(token.kind(), &token.text(), false),
+            _ => Err(LiteralError::NotLiteral),
+        }
+    }
+
+    // Unquote an identifier (`"..."`, `[...]`, `` `...` `` or `'...'`)
+    pub fn identifier(&self) -> Result<String, LiteralError> {
+        match self.main_tokens().as_slice() {
+            [token] if is_identifier_kind(token.kind()) => literal::unquote_identifier(&token.text()),
+            _ => Err(LiteralError::NotIdentifier),
+        }
+    }
+}
+
+fn is_identifier_kind(kind: SyntaxKind) -> bool {
+    use engine::kinds as syntax_kind;
+
+    (kind == syntax_kind::r#ID) ||
+    (kind == syntax_kind::r#STRING) ||
+    (kind == syntax_kind::r#JOIN_KW) ||
+    (kind == syntax_kind::r#LIKE_KW) ||
+    (kind == syntax_kind::r#CTIME_KW) ||
+    (kind == syntax_kind::r#COLUMNKW) ||
+    (kind == syntax_kind::r#INDEXED) ||
+    (kind.is_keyword && kind.is_terminal)
 }
 
 pub struct SyntaxChildren {
@@ -318,7 +469,8 @@ impl Iterator for SyntaxChildren {
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum NodeType {
     TokenSet,
     LeadingToken,
@@ -329,17 +481,48 @@ pub enum NodeType {
     FatalError,
 }
 
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Recovery {
     Delete,
     Shift,
 }
 
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct TextEdit {
+    pub range: TextRange,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn apply(source: &str, edits: &[TextEdit]) -> String {
+        let mut edits = edits.iter().collect::<Vec<_>>();
+        edits.sort_by_key(|edit| (edit.range.start(), edit.range.end()));
+
+        let mut result = String::with_capacity(source.len());
+        let mut offset = 0;
+
+        for edit in edits {
+            let start = usize::from(edit.range.start());
+            if start < offset { continue }
+
+            result.push_str(&source[offset..start]);
+            result.push_str(&edit.new_text);
+            offset = usize::from(edit.range.end());
+        }
+        result.push_str(&source[offset..]);
+
+        result
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Annotation {
     pub node_type: NodeType,
     pub state: usize,
     pub recovery: Option<Recovery>,
+    // Token kinds virtually inserted by shift recovery at the offset of the node (in shift order)
+    pub inserted: Vec<SyntaxKind>,
 }
 
 impl Annotation {
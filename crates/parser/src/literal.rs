@@ -0,0 +1,138 @@
+use sqlite_parser_proto::{engine::{self, kinds as syntax_kind}, SyntaxKind};
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum LiteralValue {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+    Null,
+    Bool(bool),
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum LiteralError {
+    NotLiteral,
+    NotIdentifier,
+    IntegerOverflow(String),
+    InvalidReal(String),
+    InvalidEscape(String),
+    InvalidBlob(String),
+}
+
+impl std::fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LiteralError::NotLiteral => write!(f, "not a literal"),
+            LiteralError::NotIdentifier => write!(f, "not an identifier"),
+            LiteralError::IntegerOverflow(text) => write!(f, "integer overflow (text: {text})"),
+            LiteralError::InvalidReal(text) => write!(f, "invalid real number (text: {text})"),
+            LiteralError::InvalidEscape(text) => write!(f, "invalid escape sequence (text: {text})"),
+            LiteralError::InvalidBlob(text) => write!(f, "invalid blob literal (text: {text})"),
+        }
+    }
+}
+
+impl std::error::Error for LiteralError {}
+
+pub fn decode_literal(kind: SyntaxKind, text: &str, negative: bool) -> Result<LiteralValue, LiteralError> {
+    match kind {
+        k if (k == syntax_kind::r#INTEGER) || (k == syntax_kind::r#QNUMBER) => decode_integer(text, negative),
+        k if k == syntax_kind::r#FLOAT => decode_real(text, negative),
+        _ if negative => Err(LiteralError::NotLiteral),
+        k if k == syntax_kind::r#STRING => unquote(text, '\'', '\'').map(LiteralValue::Text),
+        k if k == syntax_kind::r#BLOB => decode_blob(text),
+        k if k == syntax_kind::r#NULL => Ok(LiteralValue::Null),
+        k if k == syntax_kind::r#TRUEFALSE => Ok(LiteralValue::Bool(text.eq_ignore_ascii_case("TRUE"))),
+        _ => Err(LiteralError::NotLiteral),
+    }
+}
+
+pub fn unquote_identifier(text: &str) -> Result<String, LiteralError> {
+    match text.chars().next() {
+        Some('"') => unquote(text, '"', '"'),
+        Some('`') => unquote(text, '`', '`'),
+        Some('\'') => unquote(text, '\'', '\''),
+        Some('[') => match text.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(inner) => Ok(inner.to_string()),
+            None => Err(LiteralError::InvalidEscape(text.to_string())),
+        },
+        Some(_) => Ok(text.to_string()),
+        None => Err(LiteralError::NotIdentifier),
+    }
+}
+
+// Quote with `"` unless the name is a plain identifier and not a keyword
+pub fn quote_identifier(name: &str) -> String {
+    let plain = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    let keyword = plain && engine::lexme_kind(&name.to_ascii_uppercase()).is_some();
+
+    match plain && !keyword {
+        true => name.to_string(),
+        false => format!("\"{}\"", name.replace('"', "\"\"")),
+    }
+}
+
+// Strip enclosing quotes and resolve doubled quotes
+fn unquote(text: &str, open: char, close: char) -> Result<String, LiteralError> {
+    let Some(inner) = text.strip_prefix(open).and_then(|s| s.strip_suffix(close)) else {
+        return Err(LiteralError::InvalidEscape(text.to_string()));
+    };
+
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c == close {
+            match chars.next() {
+                Some(next) if next == close => {}
+                _ => return Err(LiteralError::InvalidEscape(text.to_string())),
+            }
+        }
+        result.push(c);
+    }
+
+    Ok(result)
+}
+
+fn decode_integer(text: &str, negative: bool) -> Result<LiteralValue, LiteralError> {
+    let digits = text.replace('_', "");
+
+    let hex_digits = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X"));
+
+    let value = match hex_digits {
+        Some(hex) => {
+            // hexadecimal literals are interpreted as 64-bit two's-complement
+            let value = u64::from_str_radix(hex, 16).map_err(|_| LiteralError::IntegerOverflow(text.to_string()))?;
+            let value = value as i64;
+            if negative { value.wrapping_neg() } else { value }
+        }
+        None => {
+            let digits = if negative { format!("-{digits}") } else { digits };
+            digits.parse::<i64>().map_err(|_| LiteralError::IntegerOverflow(text.to_string()))?
+        }
+    };
+
+    Ok(LiteralValue::Integer(value))
+}
+
+fn decode_real(text: &str, negative: bool) -> Result<LiteralValue, LiteralError> {
+    let digits = text.replace('_', "");
+    let value = digits.parse::<f64>().map_err(|_| LiteralError::InvalidReal(text.to_string()))?;
+
+    Ok(LiteralValue::Real(if negative { -value } else { value }))
+}
+
+fn decode_blob(text: &str) -> Result<LiteralValue, LiteralError> {
+    let Some(hex) = text.get(1..).and_then(|s| s.strip_prefix('\'')).and_then(|s| s.strip_suffix('\'')) else {
+        return Err(LiteralError::InvalidBlob(text.to_string()));
+    };
+    if !hex.is_ascii() || (hex.len() % 2 != 0) {
+        return Err(LiteralError::InvalidBlob(text.to_string()));
+    }
+
+    (0..hex.len()).step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| LiteralError::InvalidBlob(text.to_string())))
+        .collect::<Result<Vec<_>, _>>()
+        .map(LiteralValue::Blob)
+}
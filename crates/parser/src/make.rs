@@ -0,0 +1,116 @@
+use anyhow::bail;
+use cstree::text::{TextRange, TextSize};
+use scanner::Scanner;
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::{Parser, SyntaxNode, SyntaxTree};
+
+// Syntax node constructors.
+// Each fragment is parsed through a small statement template and the matching node is extracted.
+
+pub fn expr(source: &str) -> Result<SyntaxNode, anyhow::Error> {
+    from_template("SELECT ", source, ";", syntax_kind::r#expr)
+}
+
+pub fn result_column(source: &str) -> Result<SyntaxNode, anyhow::Error> {
+    from_template("SELECT ", source, ";", syntax_kind::r#selcollist)
+}
+
+pub fn where_clause(condition: &str) -> Result<SyntaxNode, anyhow::Error> {
+    from_template("SELECT 1 WHERE ", condition, ";", syntax_kind::r#where_opt)
+}
+
+pub fn table_ref(source: &str) -> Result<SyntaxNode, anyhow::Error> {
+    from_template("SELECT 1 FROM ", source, ";", syntax_kind::r#seltablist)
+}
+
+pub fn select(source: &str) -> Result<SyntaxNode, anyhow::Error> {
+    from_template("", source, ";", syntax_kind::r#select)
+}
+
+// Replace `target` with `replacement` and re-parse.
+// Leading/trailing trivia of `target` is kept.
+// Fails if `target` is not a node of `tree`, or if the replacement breaks the statement.
+pub fn replace(tree: &SyntaxTree, target: &SyntaxNode, replacement: &SyntaxNode) -> Result<SyntaxTree, anyhow::Error> {
+    let root = tree.root();
+    let belongs = match (target.as_inner_node(), root.as_inner_node()) {
+        (Some(node), Some(root)) => node.ancestors().last() == Some(root),
+        _ => false,
+    };
+    if ! belongs {
+        bail!("Target node is not in the tree (kind: {}, range: {:?})", target.kind().text, target.text_range());
+    }
+
+    let source = tree.display();
+    let range = target.token_range();
+    let Some(replacement_range) = replacement.token_range().checked_sub(replacement.text_range().start()) else {
+        bail!("Invalid replacement node (kind: {})", replacement.kind().text);
+    };
+    let replacement_text = replacement.text();
+
+    let mut new_source = String::with_capacity(source.len() + replacement_text.len());
+    new_source.push_str(&source[..usize::from(range.start())]);
+    new_source.push_str(&replacement_text[replacement_range]);
+    new_source.push_str(&source[usize::from(range.end())..]);
+
+    let new_tree = Parser::new().parse(new_source)?;
+    let new_range = TextRange::at(range.start(), TextSize::of(&replacement_text[replacement_range]));
+    if has_error_within(&new_tree, new_range) || (count_errors(&new_tree) > count_errors(tree)) {
+        bail!("Replacement does not fit the target (kind: {}, replacement: `{}`)", target.kind().text, &replacement_text[replacement_range]);
+    }
+
+    Ok(new_tree)
+}
+
+fn count_errors(tree: &SyntaxTree) -> usize {
+    tree.root().descendants().filter(|node| node.is_error()).count()
+}
+
+// Error nodes inside the range (empty ones at its edges included)
+fn has_error_within(tree: &SyntaxTree, range: TextRange) -> bool {
+    tree.root().descendants().filter(|node| node.is_error()).any(|node| range.contains_range(node.token_range()))
+}
+
+fn from_template(prefix: &str, fragment: &str, suffix: &str, kind: SyntaxKind) -> Result<SyntaxNode, anyhow::Error> {
+    let fragment = normalize_trivia(fragment);
+    if fragment.is_empty() {
+        bail!("Empty fragment (kind: {})", kind.text);
+    }
+
+    let fragment_range = TextRange::at(TextSize::of(prefix), TextSize::of(fragment.as_str()));
+    let tree = Parser::new().parse(format!("{prefix}{fragment}{suffix}"))?;
+
+    let Some(node) = tree.root().descendants().find(|node| node.kind() == kind && node.text_range().contains_range(fragment_range)) else {
+        bail!("Can not build node from fragment (kind: {}, fragment: `{}`)", kind.text, fragment);
+    };
+    if node.descendants().any(|node| node.is_error()) {
+        bail!("Malformed fragment (kind: {}, fragment: `{}`)", kind.text, fragment);
+    }
+
+    Ok(node)
+}
+
+// Drop comments and collapse whitespace to single spaces
+fn normalize_trivia(fragment: &str) -> String {
+    let mut scanner = Scanner::create(fragment.to_string(), 0);
+    let mut result = String::with_capacity(fragment.len());
+    let mut prev_kind: Option<SyntaxKind> = None;
+
+    while let Some(token) = scanner.shift() {
+        let kind = token.main.tag;
+        if kind == syntax_kind::r#EOF { break }
+
+        let text = token.main.value.unwrap_or_default();
+        let glued = match prev_kind {
+            None => true,
+            Some(prev) => (prev == syntax_kind::r#LP) || (prev == syntax_kind::r#DOT) || (kind == syntax_kind::r#COMMA) || (kind == syntax_kind::r#RP) || (kind == syntax_kind::r#DOT),
+        };
+        if ! glued {
+            result.push(' ');
+        }
+        result.push_str(&text);
+        prev_kind = Some(kind);
+    }
+
+    result
+}
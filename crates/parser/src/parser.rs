@@ -1,7 +1,7 @@
 use std::{collections::HashMap, rc::Rc, time::Instant};
 use anyhow::bail;
 use cactus::Cactus;
-use cstree::{build::NodeCache, green::{GreenNode, GreenToken}, syntax::{ResolvedNode, SyntaxNode, SyntaxToken}, text::TextSize, util::NodeOrToken, Syntax};
+use cstree::{build::NodeCache, green::{GreenNode, GreenToken}, syntax::{ResolvedNode, SyntaxNode, SyntaxToken}, text::{TextRange, TextSize}, util::NodeOrToken, Syntax};
 use scanner::{Scanner, Token, TokenItem};
 use sqlite_parser_proto::{engine::kinds as syntax_kind, LookaheadTransition, SyntaxKind, TransitionEvent};
 use crate::{Annotation, InternCache, Language, NodeElement, NodeType, Recovery, SyntaxTree};
@@ -11,6 +11,46 @@ pub struct Parser {
     language: Language,
 }
 
+// Failures of the parser with their position, carried in `anyhow::Error` (see `ParseError::of`)
+#[derive(Clone, Debug)]
+pub enum ParseError {
+    // Parsing stopped at the token in `range`
+    Failed { range: TextRange, message: String },
+    // No node covers the edit
+    EditNotFound(EditScope),
+    // The edit is inside a fatal error node
+    EditInFatalError(EditScope),
+    // The edit is out of the source (or splits a character)
+    InvalidEdit(EditScope),
+}
+
+impl ParseError {
+    pub fn of(err: &anyhow::Error) -> Option<&ParseError> {
+        err.downcast_ref::<ParseError>()
+    }
+
+    // Wrap an untyped error as a failure at `range`
+    fn failed_at(err: anyhow::Error, range: TextRange) -> anyhow::Error {
+        match ParseError::of(&err) {
+            Some(_) => err,
+            None => ParseError::Failed { range, message: err.to_string() }.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Failed { range, message } => write!(f, "{message} (range: {range:?})"),
+            ParseError::EditNotFound(edit) => write!(f, "Can not find incremental parse target node ({edit:?})"),
+            ParseError::EditInFatalError(edit) => write!(f, "Can not parse incrementally inside a fatal error node ({edit:?})"),
+            ParseError::InvalidEdit(edit) => write!(f, "Invalid range of edit ({edit:?})"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct AnnotationKey {
     pub kind: SyntaxKind, 
@@ -94,14 +134,15 @@ impl StateStack {
         val
     }
 
-    pub fn pop_n(&mut self, mut count: usize) {
+    pub fn pop_n(&mut self, mut count: usize) -> Result<(), anyhow::Error> {
         while count > 0 {
             let Some(parent) = self.stack.parent() else { break };
             self.stack = parent;
             count -= 1;
         }
 
-        assert!(count == 0);
+        if count > 0 { bail!("State stack underflow (remaining pop_count: {count})"); }
+        Ok(())
     }
 
     #[inline]
@@ -222,7 +263,11 @@ impl Parser {
                 break;
             }
 
-            match parse_internal(&mut scanner, &mut state_stack, &mut element_stack, &mut node_annotations, &mut cache, &mut penalty, &self.language)? {
+            let range = TextRange::at(TextSize::from(lookahead.main.offset as u32), TextSize::from(lookahead.main.len as u32));
+            let generated = parse_internal(&mut scanner, &mut state_stack, &mut element_stack, &mut node_annotations, &mut cache, &mut penalty, &self.language)
+                .map_err(|err| ParseError::failed_at(err, range))?
+            ;
+            match generated {
                 NodeGenerated::Node(Some((_, element))) => {
                     element_stack.push(Some(element));
                 }
@@ -241,11 +286,21 @@ impl Parser {
                 }
                 NodeGenerated::Fatal(id, element) => {
                     root_members.push(Some((id, element)));
+
+                    // The error node consumed the statement, so the next one starts from the initial state
+                    state_stack.reset();
                 }
                 _ => {}
             }
         }
 
+        // Error recovery may drop `EOF` itself, so the rest of the stack is the last statement
+        if element_stack.iter().any(Option::is_some) {
+            let state = state_stack.pop().unwrap_or(0);
+            let root_member = create_green_node(root_member_kind, state, element_stack.len(), &mut element_stack, &mut node_annotations)?;
+            root_members.push(root_member);
+        }
+
         let root = GreenNode::new(
             cstree::RawSyntaxKind(root_kind.id), 
             root_members.into_iter().filter_map(std::convert::identity).map(|(_, member)| member).collect::<Vec<_>>()
@@ -282,7 +337,7 @@ fn parse_internal(
     let root_member_kind = syntax_kind::r#ecmd;
     let terminte_kind = syntax_kind::r#SEMI;
     
-    let current_state = state_stack.peek().unwrap();
+    let Some(current_state) = state_stack.peek() else { bail!("State stack is empty") };
     let lookahead = scanner.lookahead().cloned();
     let main_kind = lookahead.as_ref().map(|token| token.main.tag);
 
@@ -312,7 +367,7 @@ fn parse_internal(
         }
         TransitionEvent::Accept { current_state: _current_state, syntax_kind: kind } if element_stack.is_empty() => {
             // For empty source
-            let lookahead = Token { leading: None, main: TokenItem { tag: syntax_kind::r#EOF, offset: 0, len: 0, value: None }, trailing: None };
+            let lookahead = Token { leading: None, main: TokenItem { tag: syntax_kind::r#EOF, offset: 0, len: 0, value: None, error: None }, trailing: None };
             let root_member = create_green_token(lookahead, syntax_kind::r#EOF, 0, cache, node_annotations)?
                 .map(|(id, node)| (kind, id, node))
             ;
@@ -406,10 +461,10 @@ fn parse_state(lookahead: Option<&SyntaxKind>, current_state: usize, state_stack
             TransitionEvent::Shift { syntax_kind: tag, next_state, current_state }  
         }
         (Ok(LookaheadTransition::Reduce { pop_count, lhs }), _) => {
-            state_stack.pop_n(pop_count);
+            state_stack.pop_n(pop_count)?;
 
             use cstree::Syntax;
-            let peek = *state_stack.peek().unwrap();
+            let Some(&peek) = state_stack.peek() else { bail!("State stack is empty after reduce (pop_count: {pop_count})") };
             let next_state = language.resolve_goto_state(peek, lhs)?;
             let kind = SyntaxKind::from_raw(cstree::RawSyntaxKind(lhs));
             
@@ -431,7 +486,7 @@ fn parse_state(lookahead: Option<&SyntaxKind>, current_state: usize, state_stack
 fn create_green_token(token: Token, main_kind: SyntaxKind, current_state: usize, cache: &mut NodeCache<InternCache>, annotations: &mut HashMap<NodeId, (Annotation, AnnotationStatus)>) -> Result<Option<(NodeId, NodeElement)>, anyhow::Error> {
     match create_green_token_items(&token, main_kind, current_state, cache, annotations)? {
         Some(node) => {
-            let annotation = Annotation { node_type: NodeType::TokenSet, state: current_state, recovery: None };
+            let annotation = Annotation { node_type: NodeType::TokenSet, state: current_state, recovery: None, inserted: vec![] };
             let status = AnnotationStatus::new(&token);
             let id = next_node_id();
         
@@ -484,7 +539,7 @@ fn create_green_token_internal(token: &TokenItem, node_type: NodeType, current_s
     let mut builder = cstree::build::GreenNodeBuilder::<SyntaxKind, InternCache>::with_cache(cache);
     builder.start_node(token.tag);
 
-    match (token.tag.is_keyword, token.tag.is_terminal) {
+    match (token.tag.static_text().is_some(), token.tag.is_terminal) {
         (true, true) => {
             builder.static_token(token.tag);
         }
@@ -505,7 +560,7 @@ fn create_green_token_internal(token: &TokenItem, node_type: NodeType, current_s
 
     match node.as_ref() {
         Some(NodeElement::Token(_)) => {
-            let annotation = Annotation { node_type, state: current_state, recovery: None };
+            let annotation = Annotation { node_type, state: current_state, recovery: None, inserted: vec![] };
             let status = AnnotationStatus{ 
                 kind: token.tag,
                 range_from: token.offset, 
@@ -521,7 +576,7 @@ fn create_green_token_internal(token: &TokenItem, node_type: NodeType, current_s
 
 fn create_green_node(kind: SyntaxKind, current_state: usize, pop_count: usize, stack: &mut Vec<Option<NodeElementOrError>>, annotation_map: &mut HashMap<NodeId, (Annotation, AnnotationStatus)>) -> Result<Option<(NodeId, NodeElement)>, anyhow::Error> {
     use cstree::Syntax;
-    let (children, id_set) = pop_elements(stack, pop_count);
+    let (children, id_set) = pop_elements(stack, pop_count)?;
 
     if children.is_empty() {
         return Ok(None);
@@ -537,7 +592,7 @@ fn create_green_node(kind: SyntaxKind, current_state: usize, pop_count: usize, s
     let node = cstree::green::GreenNode::new(kind.into_raw(), children);
     let id = next_node_id();
 
-    let annotation = Annotation { node_type: crate::NodeType::Node, state: current_state, recovery: None };
+    let annotation = Annotation { node_type: crate::NodeType::Node, state: current_state, recovery: None, inserted: vec![] };
     let staus = AnnotationStatus{ kind, range_from: offset, len };
 
     annotation_map.insert(id, (annotation, staus));
@@ -545,8 +600,11 @@ fn create_green_node(kind: SyntaxKind, current_state: usize, pop_count: usize, s
     Ok(Some((id, NodeElement::Node(node))))
 }
 
-fn pop_elements(element_stack: &mut Vec<Option<NodeElementOrError>>, mut pop_count: usize) -> (Vec<NodeElement>, Vec<NodeId>) {
-    assert!(pop_count <= element_stack.len(), "pop_count: {}, stack/len: {}", pop_count, element_stack.len());
+fn pop_elements(element_stack: &mut Vec<Option<NodeElementOrError>>, mut pop_count: usize) -> Result<(Vec<NodeElement>, Vec<NodeId>), anyhow::Error> {
+    // The stack can be short if an incremental parse starts from an inconsistent state
+    if pop_count > element_stack.len() {
+        bail!("Element stack underflow (pop_count: {}, stack/len: {})", pop_count, element_stack.len());
+    }
     let mut elements = Vec::with_capacity(pop_count + 1);
 
     while pop_count > 0 {
@@ -561,7 +619,8 @@ fn pop_elements(element_stack: &mut Vec<Option<NodeElementOrError>>, mut pop_cou
             Some(Some(NodeElementOrError::Error{ id, element })) => {
                 elements.push((element, id));
             }
-            _ => {}
+            // Error elements are not counted, so the stack can run out first
+            None => break,
         }
         if pop_count == 0 { break }
     }
@@ -573,7 +632,7 @@ fn pop_elements(element_stack: &mut Vec<Option<NodeElementOrError>>, mut pop_cou
     }
 
     elements.reverse();
-    elements.into_iter().unzip()
+    Ok(elements.into_iter().unzip())
 }
 
 fn resolve_anotation_status(
@@ -630,7 +689,7 @@ fn create_drop_error_node(lookahead: Option<Token>, state: usize, cache: &mut No
 
     match create_green_token_items(&lookahead, kind, state, cache, annotations)? {
         Some(node) => {
-            let annotation = Annotation { node_type: NodeType::Error, state, recovery: Some(Recovery::Delete) };
+            let annotation = Annotation { node_type: NodeType::Error, state, recovery: Some(Recovery::Delete), inserted: vec![] };
             let status = AnnotationStatus{ 
                 kind,
                 range_from: lookahead.offset_start(), 
@@ -646,17 +705,36 @@ fn create_drop_error_node(lookahead: Option<Token>, state: usize, cache: &mut No
     }
 }
 
+// Blank nodes at one offset share their annotation key, so each of them keeps all kinds inserted there (in shift order)
+fn record_insertion(offset: usize, kind: SyntaxKind, annotations: &mut HashMap<NodeId, (Annotation, AnnotationStatus)>) {
+    let is_blank_at = |(annotation, status): &(Annotation, AnnotationStatus)| {
+        (annotation.recovery == Some(Recovery::Shift)) && (status.kind == syntax_kind::r#ILLEGAL) && (status.range_from == offset) && (status.len == 0)
+    };
+
+    let mut inserted = annotations.values()
+        .filter(|entry| is_blank_at(entry))
+        .map(|(annotation, _)| annotation.inserted.clone())
+        .max_by_key(|kinds| kinds.len())
+        .unwrap_or_default()
+    ;
+    inserted.push(kind);
+
+    for entry in annotations.values_mut().filter(|entry| is_blank_at(entry)) {
+        entry.0.inserted = inserted.clone();
+    }
+}
+
 fn create_blank_error_node(lookahead_offset: usize, current_state: usize, cache: &mut NodeCache<InternCache>, annotations: &mut HashMap<NodeId, (Annotation, AnnotationStatus)>) -> Result<Option<NodeElement>, anyhow::Error> {
     let brank_token = Token {
         leading: None,
-        main: TokenItem { tag: syntax_kind::r#SPACE, offset: lookahead_offset, len: 0, value: None },
+        main: TokenItem { tag: syntax_kind::r#SPACE, offset: lookahead_offset, len: 0, value: None, error: None },
         trailing: None,
     };
     let kind = syntax_kind::r#ILLEGAL;
 
     match create_green_token_items(&brank_token, kind, current_state, cache, annotations)? {
         Some(node) => {
-            let annotation = Annotation { node_type: NodeType::Error, state: current_state, recovery: Some(Recovery::Shift) };
+            let annotation = Annotation { node_type: NodeType::Error, state: current_state, recovery: Some(Recovery::Shift), inserted: vec![] };
             let status = AnnotationStatus{ 
                 kind,
                 range_from: brank_token.offset_start(), 
@@ -695,7 +773,7 @@ fn create_fatal_error_node(scanner: &mut Scanner, kind: SyntaxKind, state: usize
         len += lookahead.token_len();
 
         if let Some(child) = create_green_token_items(&lookahead, kind, state, cache, annotation_map)? {
-            let annotation = Annotation { node_type: NodeType::Error, state, recovery: None };
+            let annotation = Annotation { node_type: NodeType::Error, state, recovery: None, inserted: vec![] };
             let status = AnnotationStatus{ 
                 kind,
                 range_from: lookahead.offset_start(), 
@@ -719,7 +797,7 @@ fn create_fatal_error_node_internal(children: Vec<NodeOrToken<GreenNode, GreenTo
     let node = cstree::green::GreenNode::new(kind.into_raw(), children);
     let id = next_node_id();
 
-    let annotation = Annotation { node_type: crate::NodeType::FatalError, state, recovery: None };
+    let annotation = Annotation { node_type: crate::NodeType::FatalError, state, recovery: None, inserted: vec![] };
     let staus = AnnotationStatus{ kind, range_from: offset, len };
 
     annotation_map.insert(id, (annotation, staus));
@@ -804,11 +882,12 @@ fn replay_translation_event(
                         create_blank_error_node(token.offset_start(), current_state, cache, node_annotations)?
                         .map(|node| {
                             let kind = SyntaxKind::from_raw(node.kind());
-                            let annotation = Annotation { node_type: node_type.clone(), state: current_state, recovery: recovery_type.clone() };
+                            let annotation = Annotation { node_type: node_type.clone(), state: current_state, recovery: recovery_type.clone(), inserted: vec![] };
                             let status = AnnotationStatus { kind, range_from: token.offset_start(), len: 0 };
                             let id = next_node_id();
                         
                             node_annotations.insert(id, (annotation, status));
+                            record_insertion(token.offset_start(), *syntax_kind, node_annotations);
                             element_stack.push(Some(NodeElementOrError::into_element(id, node)));
                             id
                         })
@@ -817,7 +896,7 @@ fn replay_translation_event(
                         let token = scanner.shift().unwrap();
                         create_green_token_items(&token, *syntax_kind, current_state, cache, node_annotations)?
                         .map(|node| {
-                            let annotation = Annotation { node_type: node_type.clone(), state: current_state, recovery: recovery_type.clone() };
+                            let annotation = Annotation { node_type: node_type.clone(), state: current_state, recovery: recovery_type.clone(), inserted: vec![] };
                             let status = AnnotationStatus::new(&token);
                             let id = next_node_id();
                         
@@ -831,7 +910,7 @@ fn replay_translation_event(
                 eprintln!("[DEBUG] Shift/kind: {}, state: {}, id: {:?}, push ({:?})", syntax_kind.text, current_state, id, state_stack.values());
             }
             TransitionEvent::Reduce { syntax_kind, current_state, next_state, pop_count } => {
-                state_stack.pop_n(*pop_count);
+                state_stack.pop_n(*pop_count)?;
                 let current_state = if *pop_count > 0 { state_stack.resolve_checkpoint(*pop_count) } else { state_stack.mark_checkpoint(*current_state) };
                 state_stack.push(*next_state);
 
@@ -871,7 +950,7 @@ fn create_syntax_tree(root: GreenNode, id: NodeId, intern_cache: InternCache, no
     let red_root = SyntaxNode::new_root_with_resolver(root, intern_cache.clone());
     
     let key = AnnotationKey::from(red_root.syntax());
-    let annotation = Annotation { node_type: crate::NodeType::Node, state: 0, recovery: None };
+    let annotation = Annotation { node_type: crate::NodeType::Node, state: 0, recovery: None, inserted: vec![] };
     annotations.insert(key, (id, annotation));
 
     SyntaxTree::new(red_root, language.clone(), intern_cache.clone(), annotations)
@@ -1138,7 +1217,7 @@ fn try_state_recovery_internal(scanner: &mut Scanner, state_stack: &StateStack,
         if (lookahead.main.tag == syntax_kind::r#SEMI) || (lookahead.main.tag == syntax_kind::r#EOF) {
             break
         }
-        let current_state = state_stack.peek().unwrap();
+        let current_state = state_stack.peek()?;
     
         match parse_state(Some(&lookahead.main.tag), *current_state, &mut state_stack, language, false) {
             Ok(TransitionEvent::Error { .. }) => return None,
@@ -1164,7 +1243,7 @@ fn try_state_recovery_internal(scanner: &mut Scanner, state_stack: &StateStack,
     Some(events)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct EditScope {
     pub offset: u32,
     pub from_len: u32,
@@ -1188,10 +1267,16 @@ impl IncrementalParser {
                 node
             }
             None => {
-                bail!("Can not find incrementa parse target node ({:?})", edit);
+                bail!(ParseError::EditNotFound(edit));
             }
         };
 
+        // A fatal error node keeps the failed state, so the statement can not be resumed from it
+        let is_fatal = |node: &SyntaxNode<SyntaxKind>| tree.get_annotation_of(AnnotationKey::from(node)).is_some_and(|annotation| annotation.node_type == NodeType::FatalError);
+        if edit_node.ancestors().any(is_fatal) {
+            bail!(ParseError::EditInFatalError(edit));
+        }
+
         Ok(Self {
             tree: tree.clone(),
             edit_node,
@@ -1216,7 +1301,10 @@ impl IncrementalParser {
             next_shift_decay: 2, 
         };
 
-        let (red_node, new_annotations) = match incremental_parse(&mut scanner, &mut state_stack, self.edit_node.kind(), &mut node_annotations, &mut cache, &mut penalty, &self.tree.language)? {
+        let generated = incremental_parse(&mut scanner, &mut state_stack, self.edit_node.kind(), &mut node_annotations, &mut cache, &mut penalty, &self.tree.language)
+            .map_err(|err| ParseError::failed_at(err, self.edit_node.text_range()))?
+        ;
+        let (red_node, new_annotations) = match generated {
             IncrementalNodeGenerated::Success { id, node: new_node } => {
                 let Some(parent) = self.edit_node.parent() else {
                     bail!("Need parent for edit node (kind: {})", self.edit_node.kind().text);
@@ -1368,8 +1456,9 @@ fn incremental_parse(scanner: &mut Scanner, state_stack: &mut StateStack, termin
             NodeGenerated::Root(kind, id, element) if kind == terminate_kind => {
                 return Ok(IncrementalNodeGenerated::Success {id, node: NodeElement::Node(element) });
             }
+            // The edit spills over into the next statement, so it can not be reparsed by itself
             NodeGenerated::RootMember(_) => {
-                todo!()
+                bail!("Incremental parse crossed the statement boundary");
             }
             NodeGenerated::Fatal(id, element) => {
                 return Ok(IncrementalNodeGenerated::FatalError { id, node: element });
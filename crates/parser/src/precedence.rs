@@ -0,0 +1,147 @@
+use cstree::text::TextRange;
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::SyntaxNode;
+
+// Binary operators are nested to the right regardless of precedence (`a = b AND c` is parsed as `a = (b AND c)`),
+// so a chain of them is regrouped here by SQLite's operator precedence.
+
+#[derive(Clone)]
+pub enum Operand {
+    Expr(SyntaxNode),
+    // Operands spanning several operators of the chain have no node of their own
+    Binary(Box<BinaryExpr>),
+}
+
+#[derive(Clone)]
+pub struct BinaryExpr {
+    pub lhs: Operand,
+    // Operator token (or `likeop` / `ISNOT` node)
+    pub operator: SyntaxNode,
+    pub rhs: Operand,
+}
+
+impl Operand {
+    pub fn as_expr(&self) -> Option<&SyntaxNode> {
+        match self {
+            Operand::Expr(expr) => Some(expr),
+            Operand::Binary(_) => None,
+        }
+    }
+
+    pub fn token_range(&self) -> TextRange {
+        match self {
+            Operand::Expr(expr) => expr.token_range(),
+            Operand::Binary(binary) => binary.token_range(),
+        }
+    }
+}
+
+impl BinaryExpr {
+    pub fn token_range(&self) -> TextRange {
+        TextRange::new(self.lhs.token_range().start(), self.rhs.token_range().end())
+    }
+
+    // This and the nested binary expressions (preorder)
+    pub fn operations(&self) -> Vec<&BinaryExpr> {
+        let mut operations = vec![self];
+        for operand in [&self.lhs, &self.rhs] {
+            if let Operand::Binary(binary) = operand {
+                operations.extend(binary.operations());
+            }
+        }
+        operations
+    }
+}
+
+// Binary expression of the chain rooted at `expr`.
+// None if `expr` is not a binary expression or is a right operand in the chain (regrouped with its root).
+pub fn binary_expr(expr: &SyntaxNode) -> Option<BinaryExpr> {
+    // The right operand of `IS NOT` is nested in the unary `NOT`
+    let chained = expr.ancestors().into_iter().take(2)
+        .any(|parent| split(&parent).is_some_and(|(_, _, rhs)| rhs.token_range() == expr.token_range()))
+    ;
+    if chained {
+        return None;
+    }
+
+    let mut operands = vec![];
+    let mut operators = vec![];
+    let mut next = expr.clone();
+    while let Some((lhs, operator, rhs)) = split(&next) {
+        operands.push(Operand::Expr(lhs));
+        operators.push(operator);
+        next = rhs;
+    }
+    operands.push(Operand::Expr(next));
+
+    match regroup(operands, operators)? {
+        Operand::Binary(binary) => Some(*binary),
+        Operand::Expr(_) => None,
+    }
+}
+
+// Binding strength of a binary operator (all of them are left associative)
+fn precedence(kind: SyntaxKind) -> Option<u8> {
+    match kind {
+        k if k == syntax_kind::r#OR => Some(1),
+        k if k == syntax_kind::r#AND => Some(2),
+        k if [syntax_kind::r#EQ, syntax_kind::r#NE, syntax_kind::r#IS, syntax_kind::r#ISNOT, syntax_kind::r#likeop].contains(&k) => Some(3),
+        k if [syntax_kind::r#LT, syntax_kind::r#LE, syntax_kind::r#GT, syntax_kind::r#GE].contains(&k) => Some(4),
+        k if [syntax_kind::r#BITAND, syntax_kind::r#BITOR, syntax_kind::r#LSHIFT, syntax_kind::r#RSHIFT].contains(&k) => Some(5),
+        k if (k == syntax_kind::r#PLUS) || (k == syntax_kind::r#MINUS) => Some(6),
+        k if [syntax_kind::r#STAR, syntax_kind::r#SLASH, syntax_kind::r#REM].contains(&k) => Some(7),
+        k if (k == syntax_kind::r#CONCAT) || (k == syntax_kind::r#PTR) => Some(8),
+        _ => None,
+    }
+}
+
+// expr operator [DISTINCT FROM] expr
+// (`a IS NOT b` is parsed as `a IS (NOT b)`)
+fn split(expr: &SyntaxNode) -> Option<(SyntaxNode, SyntaxNode, SyntaxNode)> {
+    if expr.kind() != syntax_kind::r#expr {
+        return None;
+    }
+    let parts = expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+
+    let (lhs, operator, rhs) = match parts.as_slice() {
+        [lhs, operator, rhs] => (lhs, operator, rhs),
+        [lhs, operator, distinct, from, rhs] if (distinct.kind() == syntax_kind::r#DISTINCT) && (from.kind() == syntax_kind::r#FROM) => (lhs, operator, rhs),
+        _ => return None,
+    };
+    if (lhs.kind() != syntax_kind::r#expr) || (rhs.kind() != syntax_kind::r#expr) || precedence(operator.kind()).is_none() {
+        return None;
+    }
+
+    let rhs = match rhs.children().filter(|child| !child.is_error()).collect::<Vec<_>>().as_slice() {
+        [not, inner] if (operator.kind() == syntax_kind::r#IS) && (not.kind() == syntax_kind::r#NOT) && (inner.kind() == syntax_kind::r#expr) => inner.clone(),
+        _ => rhs.clone(),
+    };
+    Some((lhs.clone(), operator.clone(), rhs))
+}
+
+// Operands and operators in source order (one less operator than operands)
+fn regroup(operands: Vec<Operand>, operators: Vec<SyntaxNode>) -> Option<Operand> {
+    let mut operands = operands.into_iter();
+    let mut output = operands.next().into_iter().collect::<Vec<_>>();
+    let mut stack: Vec<SyntaxNode> = vec![];
+
+    for (operator, operand) in operators.into_iter().zip(operands) {
+        while let Some(top) = stack.pop_if(|top| precedence(top.kind()) >= precedence(operator.kind())) {
+            reduce(&mut output, top);
+        }
+        stack.push(operator);
+        output.push(operand);
+    }
+    while let Some(top) = stack.pop() {
+        reduce(&mut output, top);
+    }
+
+    output.pop()
+}
+
+fn reduce(output: &mut Vec<Operand>, operator: SyntaxNode) {
+    if let (Some(rhs), Some(lhs)) = (output.pop(), output.pop()) {
+        output.push(Operand::Binary(Box::new(BinaryExpr { lhs, operator, rhs })));
+    }
+}
@@ -0,0 +1,359 @@
+use anyhow::bail;
+use cstree::text::{TextRange, TextSize};
+use sqlite_parser_proto::engine::kinds as syntax_kind;
+
+use crate::{literal::quote_identifier, resolve::{is_column_token, resolve, ColumnReference, ColumnTarget, SourceKind}, schema::{child, children, flatten_list, Catalog}, SyntaxNode, SyntaxTree, TextEdit};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+enum Symbol {
+    // Tables and views (lower case)
+    Table(String),
+    Column(String, String),
+    // Table aliases, CTEs and result column names by the range of the definition
+    Local(TextRange),
+}
+
+struct Definition {
+    name: String,
+    range: TextRange,
+    // Range where the name is visible
+    scope: TextRange,
+}
+
+// Edits renaming the table, column, alias or CTE at the offset and every reference to it.
+// The new name is quoted if it is a keyword or not a plain identifier.
+pub fn rename(tree: &SyntaxTree, offset: TextSize, new_name: &str) -> Result<Vec<TextEdit>, anyhow::Error> {
+    if new_name.is_empty() {
+        bail!("New name must not be empty");
+    }
+
+    let occurrences = Collector::new(tree).collect(tree);
+    let symbol = occurrences.iter().find(|(_, range)| range.contains(offset))
+        .or_else(|| occurrences.iter().find(|(_, range)| range.contains_inclusive(offset)))
+        .map(|(symbol, _)| symbol)
+    ;
+    let Some(symbol) = symbol else {
+        bail!("No renameable symbol at offset (offset: {})", u32::from(offset));
+    };
+
+    let new_text = quote_identifier(new_name);
+    let mut edits = occurrences.iter()
+        .filter(|(x, _)| x == symbol)
+        .map(|(_, range)| TextEdit { range: *range, new_text: new_text.clone() })
+        .collect::<Vec<_>>()
+    ;
+    edits.sort_by_key(|edit| (edit.range.start(), edit.range.end()));
+    edits.dedup();
+
+    Ok(edits)
+}
+
+struct Collector {
+    nodes: Vec<SyntaxNode>,
+    scopes: Vec<TextRange>,
+    ctes: Vec<Definition>,
+    aliases: Vec<Definition>,
+    locals: Vec<TextRange>,
+    occurrences: Vec<(Symbol, TextRange)>,
+}
+
+impl Collector {
+    fn new(tree: &SyntaxTree) -> Self {
+        let nodes = tree.root().descendants()
+            .filter(|node| node.as_inner_node().is_some() && !node.is_error())
+            .collect::<Vec<_>>()
+        ;
+        let scopes = nodes.iter()
+            .filter(|node| [syntax_kind::r#oneselect, syntax_kind::r#cmd, syntax_kind::r#trigger_cmd].contains(&node.kind()))
+            .map(|node| node.token_range())
+            .collect()
+        ;
+
+        Self { nodes, scopes, ctes: vec![], aliases: vec![], locals: vec![], occurrences: vec![] }
+    }
+
+    fn collect(mut self, tree: &SyntaxTree) -> Vec<(Symbol, TextRange)> {
+        self.collect_definitions();
+
+        let resolution = resolve(tree, &Catalog::from_tree(tree));
+        for reference in &resolution.tables {
+            self.table_reference(&reference.name, reference.alias.as_deref(), reference.alias_range, reference.kind, reference.range);
+        }
+        for reference in &resolution.columns {
+            self.column_reference(reference, &resolution.columns);
+        }
+
+        // CREATE TRIGGER has no `cmd` node; its parts are children of `ecmd`
+        for node in self.nodes.clone().iter().filter(|node| (node.kind() == syntax_kind::r#cmd) || child(node, syntax_kind::r#trigger_decl).is_some()) {
+            self.schema_statement(node);
+        }
+
+        self.occurrences
+    }
+
+    // CTE names, CTE column names and result column aliases
+    fn collect_definitions(&mut self) {
+        for node in self.nodes.clone() {
+            let wqlist = match node.kind() {
+                k if k == syntax_kind::r#select => child(&node, syntax_kind::r#wqlist),
+                k if (k == syntax_kind::r#cmd) || (k == syntax_kind::r#trigger_cmd) => child(&node, syntax_kind::r#with).and_then(|with| child(&with, syntax_kind::r#wqlist)),
+                k if k == syntax_kind::r#selcollist => {
+                    if let Some(range) = child(&node, syntax_kind::r#as).and_then(|node| alias_range(&node)) {
+                        self.define_local(range);
+                    }
+                    None
+                }
+                _ => None,
+            };
+            let Some(wqlist) = wqlist else {
+                continue;
+            };
+
+            for wqitem in flatten_list(&wqlist).iter().filter_map(|item| child(item, syntax_kind::r#wqitem)) {
+                let Some(nm) = child(&wqitem, syntax_kind::r#withnm).and_then(|node| child(&node, syntax_kind::r#nm)) else {
+                    continue;
+                };
+                if let Ok(name) = nm.identifier() {
+                    self.ctes.push(Definition { name, range: nm.token_range(), scope: node.token_range() });
+                    self.define_local(nm.token_range());
+                }
+
+                let eidlist = child(&wqitem, syntax_kind::r#eidlist_opt).and_then(|node| child(&node, syntax_kind::r#eidlist));
+                for nm in eidlist.iter().flat_map(list_names) {
+                    self.define_local(nm.token_range());
+                }
+            }
+        }
+    }
+
+    fn define_local(&mut self, range: TextRange) {
+        self.locals.push(range);
+        self.occurrences.push((Symbol::Local(range), range));
+    }
+
+    fn table_reference(&mut self, name: &str, alias: Option<&str>, alias_range: Option<TextRange>, kind: SourceKind, range: TextRange) {
+        if let (Some(alias), Some(alias_range)) = (alias, alias_range) {
+            let scope = self.scope_of(alias_range);
+            self.aliases.push(Definition { name: alias.to_string(), range: alias_range, scope });
+            self.define_local(alias_range);
+        }
+
+        let symbol = match kind {
+            SourceKind::Table | SourceKind::View => Symbol::Table(name.to_ascii_lowercase()),
+            SourceKind::Cte => match innermost(&self.ctes, name, range) {
+                Some(definition) => Symbol::Local(definition),
+                None => return,
+            },
+            _ => return,
+        };
+
+        // The table name is the last `nm` of `[schema.]name`
+        if let Some(nm) = self.nodes.iter().rev().find(|node| node.kind() == syntax_kind::r#nm && range.contains_range(node.token_range())) {
+            self.occurrences.push((symbol, nm.token_range()));
+        }
+    }
+
+    fn column_reference(&mut self, reference: &ColumnReference, columns: &[ColumnReference]) {
+        // expr ::= [nm DOT] nm DOT nm
+        let parts = self.nodes.iter()
+            .find(|node| node.kind() == syntax_kind::r#expr && node.token_range() == reference.range)
+            .map(|expr| expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>())
+            .unwrap_or_default()
+        ;
+        let (qualifier_range, column_range) = match parts.as_slice() {
+            [.., table, dot, column] if reference.qualifier.is_some() && (dot.kind() == syntax_kind::r#DOT) => (Some(table.token_range()), column.token_range()),
+            _ => (None, reference.range),
+        };
+
+        if let (Some(qualifier), Some(qualifier_range)) = (reference.qualifier.as_deref(), qualifier_range) {
+            let symbol = match innermost(&self.aliases, qualifier, reference.range) {
+                Some(alias) => Some(Symbol::Local(alias)),
+                None => match reference.target.as_ref() {
+                    Some(target) if target.kind == SourceKind::Cte => innermost(&self.ctes, qualifier, reference.range).map(Symbol::Local),
+                    // NEW/OLD in triggers are not table names
+                    Some(target) if target.table.as_ref().is_some_and(|table| !table.eq_ignore_ascii_case(qualifier)) => None,
+                    _ => Some(Symbol::Table(qualifier.to_ascii_lowercase())),
+                },
+            };
+            if let Some(symbol) = symbol {
+                self.occurrences.push((symbol, qualifier_range));
+            }
+        }
+
+        if let Some(symbol) = reference.target.as_ref().and_then(|target| self.target_symbol(target, columns, 0)) {
+            self.occurrences.push((symbol, column_range));
+        }
+    }
+
+    fn target_symbol(&self, target: &ColumnTarget, columns: &[ColumnReference], depth: usize) -> Option<Symbol> {
+        // Implicit columns (e.g. rowid) have no definition
+        let definition = target.definition?;
+
+        if target.kind == SourceKind::Table {
+            return target.table.as_ref().map(|table| Symbol::Column(table.to_ascii_lowercase(), target.column.to_ascii_lowercase()));
+        }
+        if self.locals.contains(&definition) {
+            return Some(Symbol::Local(definition));
+        }
+
+        // A result column without alias is named after the column it selects
+        let reference = columns.iter().find(|reference| reference.range == definition).filter(|_| depth < 8)?;
+        self.target_symbol(reference.target.as_ref()?, columns, depth + 1)
+    }
+
+    // DDL is not resolved except for views and triggers
+    fn schema_statement(&mut self, cmd: &SyntaxNode) {
+        if let Some(create_table) = child(cmd, syntax_kind::r#create_table) {
+            let Some(table) = object_name(&create_table).and_then(|nm| self.table_name(&nm)) else {
+                return;
+            };
+            let Some(args) = child(cmd, syntax_kind::r#create_table_args) else {
+                return;
+            };
+
+            if let Some(columnlist) = child(&args, syntax_kind::r#columnlist) {
+                for item in flatten_list(&columnlist) {
+                    if let Some(nm) = child(&item, syntax_kind::r#columnname).and_then(|node| child(&node, syntax_kind::r#nm)) {
+                        self.column_name(&table, &nm);
+                    }
+                }
+                self.table_constraints(&table, &columnlist);
+            }
+            if let Some(conslist) = child(&args, syntax_kind::r#conslist_opt) {
+                self.table_constraints(&table, &conslist);
+            }
+        }
+        else if child(cmd, syntax_kind::r#createkw).is_some() && child(cmd, syntax_kind::r#INDEX).is_some() {
+            // createkw uniqueflag INDEX ifnotexists nm dbnm ON nm LP sortlist RP where_opt
+            let Some(table) = children(cmd, syntax_kind::r#nm).nth(1).and_then(|nm| self.table_name(&nm)) else {
+                return;
+            };
+            for kind in [syntax_kind::r#sortlist, syntax_kind::r#where_opt] {
+                if let Some(node) = child(cmd, kind) {
+                    self.bare_columns(&table, &node);
+                }
+            }
+        }
+        else if child(cmd, syntax_kind::r#createkw).is_some() && child(cmd, syntax_kind::r#VIEW).is_some() {
+            if let Some(nm) = object_name(cmd) {
+                self.table_name(&nm);
+            }
+        }
+        else if let Some(decl) = child(cmd, syntax_kind::r#trigger_decl) {
+            // trigger_event ::= UPDATE OF idlist
+            let table = child(&decl, syntax_kind::r#fullname).and_then(|node| object_name(&node)).and_then(|nm| self.table_name(&nm));
+            let idlist = child(&decl, syntax_kind::r#trigger_event).and_then(|node| child(&node, syntax_kind::r#idlist));
+            if let (Some(table), Some(idlist)) = (table, idlist) {
+                for nm in list_names(&idlist) {
+                    self.column_name(&table, &nm);
+                }
+            }
+        }
+        else if child(cmd, syntax_kind::r#ALTER).is_some() {
+            let Some(table) = cmd.descendants().find(|node| node.kind() == syntax_kind::r#fullname).and_then(|node| object_name(&node)).and_then(|nm| self.table_name(&nm)) else {
+                return;
+            };
+            let names = children(cmd, syntax_kind::r#nm).collect::<Vec<_>>();
+
+            // RENAME [COLUMN] nm TO nm / DROP [COLUMN] nm / ADD [COLUMN] columnname carglist
+            match names.as_slice() {
+                [column, _] if child(cmd, syntax_kind::r#RENAME).is_some() => self.column_name(&table, column),
+                [column] if child(cmd, syntax_kind::r#DROP).is_some() => self.column_name(&table, column),
+                _ => {}
+            }
+            if let Some(nm) = child(cmd, syntax_kind::r#columnname).and_then(|node| child(&node, syntax_kind::r#nm)) {
+                self.column_name(&table, &nm);
+            }
+            if let Some(carglist) = child(cmd, syntax_kind::r#carglist) {
+                self.table_constraints(&table, &carglist);
+            }
+        }
+        else if child(cmd, syntax_kind::r#DROP).is_some() && (child(cmd, syntax_kind::r#TABLE).is_some() || child(cmd, syntax_kind::r#VIEW).is_some())
+            && let Some(nm) = child(cmd, syntax_kind::r#fullname).and_then(|node| object_name(&node))
+        {
+            self.table_name(&nm);
+        }
+    }
+
+    // Column and table constraints (CHECK, PRIMARY KEY, FOREIGN KEY, REFERENCES, ...)
+    fn table_constraints(&mut self, table: &str, node: &SyntaxNode) {
+        self.bare_columns(table, node);
+
+        let constraints = node.descendants()
+            .filter(|node| [syntax_kind::r#ccons, syntax_kind::r#tcons].contains(&node.kind()) && !node.is_error())
+            .collect::<Vec<_>>()
+        ;
+        for constraint in constraints {
+            // FOREIGN KEY LP eidlist RP REFERENCES nm eidlist_opt ...
+            if let Some(eidlist) = child(&constraint, syntax_kind::r#eidlist) {
+                for nm in list_names(&eidlist) {
+                    self.column_name(table, &nm);
+                }
+            }
+            let Some(foreign_table) = child(&constraint, syntax_kind::r#nm).filter(|_| child(&constraint, syntax_kind::r#REFERENCES).is_some()).and_then(|nm| self.table_name(&nm)) else {
+                continue;
+            };
+            let eidlist = child(&constraint, syntax_kind::r#eidlist_opt).and_then(|node| child(&node, syntax_kind::r#eidlist));
+            for nm in eidlist.iter().flat_map(list_names) {
+                self.column_name(&foreign_table, &nm);
+            }
+        }
+    }
+
+    fn bare_columns(&mut self, table: &str, node: &SyntaxNode) {
+        for expr in node.descendants().filter(|node| node.kind() == syntax_kind::r#expr && !node.is_error()) {
+            let parts = expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+            match parts.as_slice() {
+                [id] if is_column_token(id.kind()) => self.column_name(table, id),
+                _ => {}
+            }
+        }
+    }
+
+    fn table_name(&mut self, nm: &SyntaxNode) -> Option<String> {
+        let name = nm.identifier().ok()?;
+        self.occurrences.push((Symbol::Table(name.to_ascii_lowercase()), nm.token_range()));
+        Some(name)
+    }
+
+    fn column_name(&mut self, table: &str, nm: &SyntaxNode) {
+        if let Ok(name) = nm.identifier() {
+            self.occurrences.push((Symbol::Column(table.to_ascii_lowercase(), name.to_ascii_lowercase()), nm.token_range()));
+        }
+    }
+
+    // Smallest statement or SELECT containing the range
+    fn scope_of(&self, range: TextRange) -> TextRange {
+        self.scopes.iter()
+            .filter(|scope| scope.contains_range(range))
+            .min_by_key(|scope| scope.len())
+            .copied()
+            .unwrap_or(range)
+    }
+}
+
+fn innermost(definitions: &[Definition], name: &str, range: TextRange) -> Option<TextRange> {
+    definitions.iter()
+        .filter(|definition| definition.name.eq_ignore_ascii_case(name) && definition.scope.contains_range(range))
+        .min_by_key(|definition| definition.scope.len())
+        .map(|definition| definition.range)
+}
+
+// `nm dbnm` or `nm DOT nm`
+fn object_name(node: &SyntaxNode) -> Option<SyntaxNode> {
+    child(node, syntax_kind::r#dbnm)
+        .and_then(|dbnm| child(&dbnm, syntax_kind::r#nm))
+        .or_else(|| children(node, syntax_kind::r#nm).last())
+}
+
+// as ::= AS nm | ids
+// (`ids` is reduced from a single token, so the token may be a direct child of `as`)
+fn alias_range(node: &SyntaxNode) -> Option<TextRange> {
+    let name = child(node, syntax_kind::r#nm).or_else(|| child(node, syntax_kind::r#ids)).unwrap_or_else(|| node.clone());
+    (name.main_tokens().len() == 1).then(|| name.token_range())
+}
+
+// idlist / eidlist
+fn list_names(list: &SyntaxNode) -> Vec<SyntaxNode> {
+    flatten_list(list).iter().filter_map(|item| child(item, syntax_kind::r#nm)).collect()
+}
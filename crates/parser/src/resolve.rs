@@ -0,0 +1,781 @@
+use cstree::text::{TextRange, TextSize};
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::{diagnostic::Diagnostic, infer::{expr_type, ColumnType, ResultColumn, ResultSet}, schema::{child, children, flatten_list, qualified_name, Affinity, Catalog}, Parser, SyntaxNode, SyntaxTree};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum SourceKind {
+    Table,
+    View,
+    Cte,
+    Subquery,
+    TableFunction,
+    // Result column alias (ORDER BY)
+    ResultColumn,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ColumnTarget {
+    // Name visible in the query (alias or table name)
+    pub source: String,
+    pub kind: SourceKind,
+    pub table: Option<String>,
+    pub column: String,
+    pub column_type: ColumnType,
+    // Range of the definition (in the catalog source for tables)
+    pub definition: Option<TextRange>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ColumnReference {
+    pub qualifier: Option<String>,
+    pub name: String,
+    pub range: TextRange,
+    // None if the source columns are unknown
+    pub target: Option<ColumnTarget>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct TableReference {
+    pub name: String,
+    pub alias: Option<String>,
+    pub alias_range: Option<TextRange>,
+    pub kind: SourceKind,
+    pub range: TextRange,
+    pub definition: Option<TextRange>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct StarExpansion {
+    pub range: TextRange,
+    pub columns: Vec<ColumnTarget>,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct Resolution {
+    pub columns: Vec<ColumnReference>,
+    pub tables: Vec<TableReference>,
+    pub stars: Vec<StarExpansion>,
+    pub result_sets: Vec<ResultSet>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Resolution {
+    pub fn column_at(&self, offset: TextSize) -> Option<&ColumnReference> {
+        self.columns.iter().find(|reference| reference.range.contains_inclusive(offset))
+    }
+
+    pub fn table_at(&self, offset: TextSize) -> Option<&TableReference> {
+        self.tables.iter().find(|reference| reference.range.contains_inclusive(offset))
+    }
+}
+
+// Link column/table references to their definitions.
+// Definitions are looked up from the catalog, CTEs and subqueries.
+pub fn resolve(tree: &SyntaxTree, catalog: &Catalog) -> Resolution {
+    let mut resolver = Resolver::new(catalog, 0);
+    resolver.visit(&tree.root());
+    resolver.resolution
+}
+
+#[derive(Clone, Debug)]
+struct Source {
+    name: String,
+    table: Option<String>,
+    kind: SourceKind,
+    // None if unknown (unresolved table, table-valued function, ...)
+    columns: Option<Vec<ResultColumn>>,
+    using: Vec<String>,
+    rowid: bool,
+    // Right side of LEFT JOIN (or left side of RIGHT JOIN)
+    nullable: bool,
+}
+
+impl Source {
+    fn target(&self, column: &ResultColumn) -> ColumnTarget {
+        ColumnTarget {
+            source: self.name.clone(),
+            kind: self.kind,
+            table: self.table.clone(),
+            column: column.name.clone(),
+            column_type: column.column_type.clone().with_nullable(column.column_type.nullable || self.nullable),
+            definition: column.definition,
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Lookup {
+        let Some(columns) = self.columns.as_ref() else {
+            return Lookup::Unknown;
+        };
+
+        match columns.iter().find(|column| column.name.eq_ignore_ascii_case(name)) {
+            Some(column) => Lookup::Found(self.target(column)),
+            None if self.rowid && ["ROWID", "OID", "_ROWID_"].iter().any(|x| x.eq_ignore_ascii_case(name)) => {
+                Lookup::Found(self.target(&ResultColumn { name: name.to_string(), column_type: ColumnType { declared_type: None, affinity: Some(Affinity::Integer), nullable: false }, definition: None }))
+            }
+            None => Lookup::Missing,
+        }
+    }
+}
+
+enum Lookup {
+    Found(ColumnTarget),
+    Unknown,
+    Missing,
+}
+
+#[derive(Default)]
+struct Scope {
+    sources: Vec<Source>,
+    ctes: Vec<Source>,
+    result_columns: Vec<ResultColumn>,
+}
+
+struct Resolver<'a> {
+    catalog: &'a Catalog,
+    scopes: Vec<Scope>,
+    resolution: Resolution,
+    view_depth: usize,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(catalog: &'a Catalog, view_depth: usize) -> Self {
+        Self { catalog, scopes: vec![], resolution: Resolution::default(), view_depth }
+    }
+
+    fn visit(&mut self, node: &SyntaxNode) {
+        match node.kind() {
+            k if k == syntax_kind::r#select => {
+                self.visit_select(node);
+            }
+            k if (k == syntax_kind::r#cmd) || (k == syntax_kind::r#trigger_cmd) => {
+                self.visit_cmd(node);
+            }
+            k if k == syntax_kind::r#expr => {
+                self.visit_expr(node);
+            }
+            // CREATE TRIGGER is not reduced to `cmd`, so its parts are children of `ecmd`
+            k if (k == syntax_kind::r#ecmd) && child(node, syntax_kind::r#trigger_decl).is_some() => {
+                self.visit_trigger(node);
+            }
+            // A statement cut off at EOF is not reduced, so its clauses are children of `ecmd` (e.g. `SELECT * FROM foo WHERE `)
+            k if (k == syntax_kind::r#ecmd) && child(node, syntax_kind::r#from).is_some() => {
+                self.visit_oneselect(node);
+            }
+            _ => self.visit_children(node),
+        }
+    }
+
+    fn visit_children(&mut self, node: &SyntaxNode) {
+        for child in node.children() {
+            self.visit(&child);
+        }
+    }
+
+    fn visit_cmd(&mut self, node: &SyntaxNode) {
+        // Except for views and triggers, DDL is not resolved
+        if child(node, syntax_kind::r#createkw).is_some() {
+            if let Some(select) = child(node, syntax_kind::r#select) {
+                self.visit_select(&select);
+            }
+            else if child(node, syntax_kind::r#trigger_decl).is_some() {
+                self.visit_trigger(node);
+            }
+            return;
+        }
+        if child(node, syntax_kind::r#ALTER).is_some() || child(node, syntax_kind::r#DROP).is_some() {
+            return;
+        }
+
+        self.scopes.push(Scope::default());
+
+        if let Some(wqlist) = child(node, syntax_kind::r#with).and_then(|with| child(&with, syntax_kind::r#wqlist)) {
+            self.define_ctes(&wqlist);
+        }
+
+        let target = child(node, syntax_kind::r#xfullname)
+            .or_else(|| child(node, syntax_kind::r#trnm))
+            .and_then(|name| self.target_source(&name))
+        ;
+
+        match target {
+            Some(target) if child(node, syntax_kind::r#insert_cmd).is_some() => self.visit_insert(node, target),
+            Some(target) => self.visit_update_or_delete(node, target),
+            None => {
+                for child in node.children().filter(|child| child.kind() != syntax_kind::r#with) {
+                    self.visit(&child);
+                }
+            }
+        }
+
+        self.scopes.pop();
+    }
+
+    // INSERT INTO xfullname idlist_opt select upsert
+    fn visit_insert(&mut self, node: &SyntaxNode, target: Source) {
+        if let Some(idlist) = child(node, syntax_kind::r#idlist_opt).and_then(|node| child(&node, syntax_kind::r#idlist)) {
+            for nm in idlist_items(&idlist) {
+                self.resolve_in_source(&target, &nm);
+            }
+        }
+        if let Some(select) = child(node, syntax_kind::r#select) {
+            self.visit_select(&select);
+        }
+
+        let mut excluded = target.clone();
+        excluded.name = "excluded".to_string();
+        self.scopes.push(Scope { sources: vec![target, excluded], ..Default::default() });
+
+        for kind in [syntax_kind::r#upsert, syntax_kind::r#returning] {
+            if let Some(child) = child(node, kind) {
+                self.visit(&child);
+            }
+        }
+
+        self.scopes.pop();
+    }
+
+    // UPDATE xfullname SET setlist from where_opt_ret ...
+    // DELETE FROM xfullname where_opt_ret ...
+    fn visit_update_or_delete(&mut self, node: &SyntaxNode, target: Source) {
+        let mut sources = vec![target.clone()];
+        let mut conditions = vec![];
+
+        if let Some(seltablist) = child(node, syntax_kind::r#from).and_then(|from| child(&from, syntax_kind::r#seltablist)) {
+            self.collect_sources(&seltablist, &mut sources, &mut conditions);
+        }
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.sources.extend(sources);
+        }
+        for condition in conditions {
+            self.visit(&condition);
+        }
+
+        if let Some(setlist) = child(node, syntax_kind::r#setlist) {
+            for item in flatten_list(&setlist) {
+                let idlist = child(&item, syntax_kind::r#idlist).map(|node| idlist_items(&node)).unwrap_or_default();
+                for nm in children(&item, syntax_kind::r#nm).chain(idlist) {
+                    self.resolve_in_source(&target, &nm);
+                }
+                if let Some(expr) = child(&item, syntax_kind::r#expr) {
+                    self.visit(&expr);
+                }
+            }
+        }
+
+        let skipped = [syntax_kind::r#with, syntax_kind::r#xfullname, syntax_kind::r#trnm, syntax_kind::r#from, syntax_kind::r#setlist];
+        for child in node.children().filter(|child| !skipped.contains(&child.kind())) {
+            self.visit(&child);
+        }
+    }
+
+    // CREATE TRIGGER ... ON fullname ... BEGIN trigger_cmd_list END
+    fn visit_trigger(&mut self, node: &SyntaxNode) {
+        let Some(decl) = child(node, syntax_kind::r#trigger_decl) else {
+            return;
+        };
+        let Some(fullname) = child(&decl, syntax_kind::r#fullname) else {
+            return;
+        };
+        let Some((_, name)) = qualified_name(&fullname) else {
+            return;
+        };
+
+        let source = self.table_source(&name, None, fullname.token_range());
+        let sources = ["NEW", "OLD"].into_iter()
+            .map(|alias| Source { name: alias.to_string(), ..source.clone() })
+            .collect()
+        ;
+        self.scopes.push(Scope { sources, ..Default::default() });
+
+        if let Some(when_clause) = child(&decl, syntax_kind::r#when_clause) {
+            self.visit(&when_clause);
+        }
+        // The first command may be a direct child of `ecmd`
+        for cmd in children(node, syntax_kind::r#trigger_cmd_list).chain(children(node, syntax_kind::r#trigger_cmd)) {
+            self.visit(&cmd);
+        }
+
+        self.scopes.pop();
+    }
+
+    fn visit_select(&mut self, select: &SyntaxNode) -> Option<Vec<ResultColumn>> {
+        let wqlist = child(select, syntax_kind::r#wqlist);
+
+        if let Some(wqlist) = wqlist.as_ref() {
+            self.scopes.push(Scope::default());
+            self.define_ctes(wqlist);
+        }
+
+        let arms = child(select, syntax_kind::r#selectnowith)
+            .map(|body| {
+                flatten_list(&body).iter()
+                    .filter_map(|item| child(item, syntax_kind::r#oneselect))
+                    .map(|oneselect| self.visit_oneselect(&oneselect))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+        ;
+
+        if wqlist.is_some() {
+            self.scopes.pop();
+        }
+
+        // Names come from the first arm of the compound select, nullability from all arms
+        let mut columns = arms.first().cloned().flatten();
+        if let Some(columns) = columns.as_mut() {
+            for (i, column) in columns.iter_mut().enumerate() {
+                column.column_type.nullable |= arms.iter().skip(1).any(|arm| arm.as_ref().and_then(|arm| arm.get(i)).is_none_or(|x| x.column_type.nullable));
+            }
+            self.resolution.result_sets.push(ResultSet { range: select.token_range(), columns: columns.clone() });
+        }
+
+        columns
+    }
+
+    fn visit_oneselect(&mut self, oneselect: &SyntaxNode) -> Option<Vec<ResultColumn>> {
+        // VALUES (...), (...)
+        if let Some(values) = child(oneselect, syntax_kind::r#values).or_else(|| child(oneselect, syntax_kind::r#mvalues)) {
+            self.visit_children(&values);
+
+            let count = values.descendants()
+                .find(|node| node.kind() == syntax_kind::r#nexprlist)
+                .map(|nexprlist| flatten_list(&nexprlist).len())
+                .unwrap_or_default()
+            ;
+            return Some((1..=count).map(|i| ResultColumn { name: format!("column{i}"), column_type: ColumnType::unknown(), definition: None }).collect());
+        }
+
+        let mut sources = vec![];
+        let mut conditions = vec![];
+
+        if let Some(seltablist) = child(oneselect, syntax_kind::r#from).and_then(|from| child(&from, syntax_kind::r#seltablist)) {
+            self.collect_sources(&seltablist, &mut sources, &mut conditions);
+        }
+
+        self.scopes.push(Scope { sources, ..Default::default() });
+
+        for condition in conditions {
+            self.visit(&condition);
+        }
+
+        let grouped = child(oneselect, syntax_kind::r#groupby_opt).is_some();
+        let columns = child(oneselect, syntax_kind::r#selcollist).and_then(|node| self.visit_selcollist(&node, grouped));
+
+        for kind in [syntax_kind::r#where_opt, syntax_kind::r#groupby_opt, syntax_kind::r#having_opt, syntax_kind::r#window_clause] {
+            if let Some(child) = child(oneselect, kind) {
+                self.visit(&child);
+            }
+        }
+
+        // ORDER BY prefers result column aliases
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.result_columns = columns.clone().unwrap_or_default();
+        }
+        for kind in [syntax_kind::r#orderby_opt, syntax_kind::r#limit_opt] {
+            if let Some(child) = child(oneselect, kind) {
+                self.visit(&child);
+            }
+        }
+
+        self.scopes.pop();
+        columns
+    }
+
+    fn visit_selcollist(&mut self, selcollist: &SyntaxNode, grouped: bool) -> Option<Vec<ResultColumn>> {
+        let mut columns = Some(vec![]);
+
+        for item in selcollist_items(selcollist) {
+            if let Some(asterisk) = child(&item, syntax_kind::r#ASTERISK) {
+                let qualifier = child(&item, syntax_kind::r#nm).and_then(|nm| nm.identifier().ok());
+                let expanded = self.expand_star(qualifier.as_deref());
+
+                if let Some(targets) = expanded.as_ref() {
+                    self.resolution.stars.push(StarExpansion { range: asterisk.token_range(), columns: targets.clone() });
+                }
+                columns = columns.zip(expanded).map(|(mut columns, targets)| {
+                    columns.extend(targets.into_iter().map(|target| ResultColumn { name: target.column, column_type: target.column_type, definition: target.definition }));
+                    columns
+                });
+                continue;
+            }
+
+            let Some(expr) = child(&item, syntax_kind::r#expr) else {
+                continue;
+            };
+            self.visit(&expr);
+
+            let column_type = expr_type(&expr, &self.resolution, grouped);
+            let column = match child(&item, syntax_kind::r#as).and_then(|node| alias_of(&node)) {
+                Some((name, range)) => ResultColumn { name, column_type, definition: Some(range) },
+                None => ResultColumn { name: column_name_of(&expr), column_type, definition: Some(expr.token_range()) },
+            };
+            if let Some(columns) = columns.as_mut() {
+                columns.push(column);
+            }
+        }
+
+        columns
+    }
+
+    fn expand_star(&self, qualifier: Option<&str>) -> Option<Vec<ColumnTarget>> {
+        let scope = self.scopes.last()?;
+        let mut targets = vec![];
+
+        for source in &scope.sources {
+            if qualifier.is_some_and(|qualifier| !source.name.eq_ignore_ascii_case(qualifier)) {
+                continue;
+            }
+            for column in source.columns.as_ref()? {
+                // USING columns are merged into the left source
+                if qualifier.is_none() && source.using.iter().any(|name| name.eq_ignore_ascii_case(&column.name)) {
+                    continue;
+                }
+                targets.push(source.target(column));
+            }
+        }
+
+        Some(targets)
+    }
+
+    fn visit_expr(&mut self, expr: &SyntaxNode) {
+        let parts = expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+
+        match parts.as_slice() {
+            [id] if is_column_token(id.kind()) => {
+                if let Ok(name) = id.identifier() {
+                    self.resolve_column(expr, None, name);
+                }
+            }
+            // nm DOT nm / nm DOT nm DOT nm
+            [.., table, dot, column] if (table.kind() == syntax_kind::r#nm) && (dot.kind() == syntax_kind::r#DOT) && (column.kind() == syntax_kind::r#nm) => {
+                if let (Ok(table), Ok(column)) = (table.identifier(), column.identifier()) {
+                    self.resolve_column(expr, Some(table), column);
+                }
+            }
+            _ => self.visit_children(expr),
+        }
+    }
+
+    fn define_ctes(&mut self, wqlist: &SyntaxNode) {
+        let items = flatten_list(wqlist).iter().filter_map(|item| child(item, syntax_kind::r#wqitem)).collect::<Vec<_>>();
+
+        for wqitem in items {
+            let Some(name) = child(&wqitem, syntax_kind::r#withnm).and_then(|node| child(&node, syntax_kind::r#nm)).and_then(|nm| nm.identifier().ok()) else {
+                continue;
+            };
+            let declared = child(&wqitem, syntax_kind::r#eidlist_opt)
+                .and_then(|node| child(&node, syntax_kind::r#eidlist))
+                .map(|eidlist| {
+                    flatten_list(&eidlist).iter()
+                        .filter_map(|item| child(item, syntax_kind::r#nm))
+                        .filter_map(|nm| nm.identifier().ok().map(|name| ResultColumn { name, column_type: ColumnType::unknown(), definition: Some(nm.token_range()) }))
+                        .collect::<Vec<_>>()
+                })
+            ;
+
+            // Define before visiting the body for recursive CTE
+            let cte = Source { name: name.clone(), table: Some(name.clone()), kind: SourceKind::Cte, columns: declared.clone(), using: vec![], rowid: false, nullable: false };
+            if let Some(scope) = self.scopes.last_mut() {
+                scope.ctes.push(cte);
+            }
+
+            let columns = child(&wqitem, syntax_kind::r#select).and_then(|select| self.visit_select(&select));
+
+            // Declared column names take types from the body by position
+            let columns = match (declared, columns) {
+                (Some(declared), Some(columns)) => Some(declared.into_iter().enumerate().map(|(i, column)| ResultColumn {
+                    column_type: columns.get(i).map(|x| x.column_type.clone()).unwrap_or_else(ColumnType::unknown),
+                    ..column
+                }).collect()),
+                (declared, columns) => declared.or(columns),
+            };
+            if let Some(cte) = self.scopes.last_mut().and_then(|scope| scope.ctes.iter_mut().rev().find(|cte| cte.name == name)) {
+                cte.columns = columns;
+            }
+        }
+    }
+
+    fn collect_sources(&mut self, seltablist: &SyntaxNode, sources: &mut Vec<Source>, conditions: &mut Vec<SyntaxNode>) {
+        let prefix = child(seltablist, syntax_kind::r#stl_prefix);
+        let join_start = sources.len();
+
+        if let Some(inner) = prefix.as_ref().and_then(|prefix| child(prefix, syntax_kind::r#seltablist)) {
+            self.collect_sources(&inner, sources, conditions);
+        }
+
+        let join_type = prefix.as_ref()
+            .and_then(|prefix| child(prefix, syntax_kind::r#joinop))
+            .map(|joinop| joinop.token_text().to_ascii_uppercase())
+            .unwrap_or_default()
+        ;
+        // RIGHT/FULL JOIN makes the preceding sources nullable
+        if join_type.contains("RIGHT") || join_type.contains("FULL") {
+            for source in sources.iter_mut().skip(join_start) {
+                source.nullable = true;
+            }
+        }
+
+        let alias_def = child(seltablist, syntax_kind::r#as).and_then(|node| alias_of(&node));
+        let alias = alias_def.as_ref().map(|(name, _)| name.clone());
+
+        let source = if let Some(select) = child(seltablist, syntax_kind::r#select) {
+            // LP select RP as
+            let columns = self.visit_select(&select);
+            self.resolution.tables.push(TableReference {
+                name: alias.clone().unwrap_or_default(),
+                alias: alias.clone(),
+                alias_range: alias_def.as_ref().map(|(_, range)| *range),
+                kind: SourceKind::Subquery,
+                range: select.token_range(),
+                definition: Some(select.token_range()),
+            });
+            Some(Source { name: alias.unwrap_or_default(), table: None, kind: SourceKind::Subquery, columns, using: vec![], rowid: false, nullable: false })
+        }
+        else if let Some(inner) = child(seltablist, syntax_kind::r#seltablist) {
+            // LP seltablist RP
+            self.collect_sources(&inner, sources, conditions);
+            None
+        }
+        else if let Some((_, name)) = qualified_name(seltablist) {
+            let range = TextRange::new(
+                child(seltablist, syntax_kind::r#nm).map(|nm| nm.token_range().start()).unwrap_or_default(),
+                child(seltablist, syntax_kind::r#dbnm).or_else(|| child(seltablist, syntax_kind::r#nm)).map(|nm| nm.token_range().end()).unwrap_or_default(),
+            );
+
+            match child(seltablist, syntax_kind::r#exprlist) {
+                // Table-valued function
+                Some(args) => {
+                    self.visit(&args);
+                    self.resolution.tables.push(TableReference { name: name.clone(), alias: alias.clone(), alias_range: alias_def.as_ref().map(|(_, range)| *range), kind: SourceKind::TableFunction, range, definition: None });
+                    Some(Source { name: alias.unwrap_or_else(|| name.clone()), table: Some(name), kind: SourceKind::TableFunction, columns: None, using: vec![], rowid: false, nullable: false })
+                }
+                None => Some(self.table_source(&name, alias_def, range)),
+            }
+        }
+        else {
+            None
+        };
+
+        let Some(mut source) = source else {
+            return;
+        };
+        source.nullable = join_type.contains("LEFT") || join_type.contains("FULL");
+
+        if let Some(on_using) = child(seltablist, syntax_kind::r#on_using) {
+            if let Some(expr) = child(&on_using, syntax_kind::r#expr) {
+                conditions.push(expr);
+            }
+            if let Some(idlist) = child(&on_using, syntax_kind::r#idlist) {
+                source.using = idlist_items(&idlist).iter().filter_map(|nm| nm.identifier().ok()).collect();
+            }
+        }
+
+        sources.push(source);
+    }
+
+    // xfullname ::= nm [DOT nm] [AS nm]
+    fn target_source(&mut self, node: &SyntaxNode) -> Option<Source> {
+        let names = children(node, syntax_kind::r#nm).collect::<Vec<_>>();
+        let (names, alias) = match child(node, syntax_kind::r#AS) {
+            Some(_) => (&names[..names.len().saturating_sub(1)], names.last().and_then(|nm| Some((nm.identifier().ok()?, nm.token_range())))),
+            None => (&names[..], None),
+        };
+        let nm = names.last()?;
+        let name = nm.identifier().ok()?;
+
+        Some(self.table_source(&name, alias, nm.token_range()))
+    }
+
+    fn table_source(&mut self, name: &str, alias: Option<(String, TextRange)>, range: TextRange) -> Source {
+        let (alias, alias_range) = alias.unzip();
+        let visible_name = alias.clone().unwrap_or_else(|| name.to_string());
+
+        let cte = self.scopes.iter().rev()
+            .flat_map(|scope| scope.ctes.iter().rev())
+            .find(|cte| cte.name.eq_ignore_ascii_case(name))
+            .cloned()
+        ;
+
+        let (kind, columns, rowid, definition) = if let Some(cte) = cte {
+            (SourceKind::Cte, cte.columns, false, None)
+        }
+        else if let Some(table) = self.catalog.table(name) {
+            let columns = table.columns.iter()
+                .map(|column| ResultColumn { name: column.name.clone(), column_type: ColumnType::from_column(column, table), definition: Some(column.range) })
+                .collect()
+            ;
+            (SourceKind::Table, Some(columns), !table.without_rowid, Some(table.range))
+        }
+        else if let Some(view) = self.catalog.view(name) {
+            (SourceKind::View, self.view_columns(view), false, Some(view.range))
+        }
+        else {
+            self.resolution.diagnostics.push(Diagnostic::error("resolve/unresolved-table", format!("no such table: {name}"), range));
+            (SourceKind::Table, None, false, None)
+        };
+
+        self.resolution.tables.push(TableReference { name: name.to_string(), alias, alias_range, kind, range, definition });
+
+        Source { name: visible_name, table: Some(name.to_string()), kind, columns, using: vec![], rowid, nullable: false }
+    }
+
+    fn view_columns(&self, view: &crate::schema::View) -> Option<Vec<ResultColumn>> {
+        if !view.columns.is_empty() {
+            return Some(view.columns.iter().map(|name| ResultColumn { name: name.clone(), column_type: ColumnType::unknown(), definition: None }).collect());
+        }
+        // Prevent infinite recursion by self referencing views
+        if self.view_depth > 8 {
+            return None;
+        }
+
+        let tree = Parser::new().parse(format!("{};", view.select.as_ref()?)).ok()?;
+        let select = tree.root().descendants().find(|node| node.kind() == syntax_kind::r#select)?;
+
+        // Ranges in the view text are meaningless for the current tree
+        let columns = Resolver::new(self.catalog, self.view_depth + 1).visit_select(&select)?;
+        Some(columns.into_iter().map(|column| ResultColumn { definition: None, ..column }).collect())
+    }
+
+    fn resolve_in_source(&mut self, source: &Source, nm: &SyntaxNode) {
+        let Ok(name) = nm.identifier() else {
+            return;
+        };
+        let range = nm.token_range();
+
+        let target = match source.lookup(&name) {
+            Lookup::Found(target) => Some(target),
+            Lookup::Unknown => None,
+            Lookup::Missing => {
+                self.resolution.diagnostics.push(Diagnostic::error("resolve/unresolved-column", format!("table {} has no column named {name}", source.name), range));
+                None
+            }
+        };
+
+        self.resolution.columns.push(ColumnReference { qualifier: None, name, range, target });
+    }
+
+    fn resolve_column(&mut self, node: &SyntaxNode, qualifier: Option<String>, name: String) {
+        let range = node.token_range();
+        let result = match qualifier.as_deref() {
+            Some(qualifier) => self.lookup_qualified(qualifier, &name),
+            None => self.lookup_unqualified(&name),
+        };
+
+        let target = match result {
+            Ok(target) => target,
+            // Unresolved TRUE/FALSE are boolean literals
+            Err(None) if qualifier.is_none() && (name.eq_ignore_ascii_case("TRUE") || name.eq_ignore_ascii_case("FALSE")) => {
+                return;
+            }
+            Err(None) => {
+                let display_name = match qualifier.as_ref() {
+                    Some(qualifier) => format!("{qualifier}.{name}"),
+                    None => name.clone(),
+                };
+                self.resolution.diagnostics.push(Diagnostic::error("resolve/unresolved-column", format!("no such column: {display_name}"), range));
+                None
+            }
+            Err(Some(sources)) => {
+                self.resolution.diagnostics.push(Diagnostic::error("resolve/ambiguous-column", format!("ambiguous column name: {name} (candidates: {})", sources.join(", ")), range));
+                None
+            }
+        };
+
+        self.resolution.columns.push(ColumnReference { qualifier, name, range, target });
+    }
+
+    // Err(None): unresolved, Err(Some(..)): ambiguous
+    fn lookup_qualified(&self, qualifier: &str, name: &str) -> Result<Option<ColumnTarget>, Option<Vec<String>>> {
+        for scope in self.scopes.iter().rev() {
+            let Some(source) = scope.sources.iter().find(|source| source.name.eq_ignore_ascii_case(qualifier)) else {
+                continue;
+            };
+
+            return match source.lookup(name) {
+                Lookup::Found(target) => Ok(Some(target)),
+                Lookup::Unknown => Ok(None),
+                Lookup::Missing => Err(None),
+            };
+        }
+
+        Err(None)
+    }
+
+    fn lookup_unqualified(&self, name: &str) -> Result<Option<ColumnTarget>, Option<Vec<String>>> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(column) = scope.result_columns.iter().find(|column| column.name.eq_ignore_ascii_case(name)) {
+                return Ok(Some(ColumnTarget {
+                    source: String::new(),
+                    kind: SourceKind::ResultColumn,
+                    table: None,
+                    column: column.name.clone(),
+                    column_type: column.column_type.clone(),
+                    definition: column.definition,
+                }));
+            }
+
+            let mut found = vec![];
+            let mut unknown = false;
+
+            for source in &scope.sources {
+                match source.lookup(name) {
+                    Lookup::Found(target) => found.push((source, target)),
+                    Lookup::Unknown => unknown = true,
+                    Lookup::Missing => {}
+                }
+            }
+
+            // USING columns are merged into the left source
+            if found.len() > 1 {
+                found.retain(|(source, _)| !source.using.iter().any(|column| column.eq_ignore_ascii_case(name)));
+            }
+
+            match found.len() {
+                0 if unknown => return Ok(None),
+                0 => continue,
+                1 => return Ok(found.pop().map(|(_, target)| target)),
+                _ => return Err(Some(found.iter().map(|(source, _)| source.name.clone()).collect())),
+            }
+        }
+
+        Err(None)
+    }
+}
+
+// selcollist ::= sclp scanpt expr scanpt as
+// sclp ::= selcollist COMMA
+fn selcollist_items(selcollist: &SyntaxNode) -> Vec<SyntaxNode> {
+    let mut items = match child(selcollist, syntax_kind::r#sclp).and_then(|sclp| child(&sclp, syntax_kind::r#selcollist)) {
+        Some(inner) => selcollist_items(&inner),
+        None => vec![],
+    };
+    items.push(selcollist.clone());
+    items
+}
+
+fn idlist_items(idlist: &SyntaxNode) -> Vec<SyntaxNode> {
+    flatten_list(idlist).iter().filter_map(|item| child(item, syntax_kind::r#nm)).collect()
+}
+
+// as ::= AS nm | ids
+// (`ids` is reduced from a single token, so the token may be a direct child of `as`)
+fn alias_of(node: &SyntaxNode) -> Option<(String, TextRange)> {
+    let name = child(node, syntax_kind::r#nm).or_else(|| child(node, syntax_kind::r#ids)).unwrap_or_else(|| node.clone());
+    Some((name.identifier().ok()?, name.token_range()))
+}
+
+fn column_name_of(expr: &SyntaxNode) -> String {
+    let parts = expr.children().filter(|child| !child.is_error()).collect::<Vec<_>>();
+
+    match parts.last() {
+        Some(last) if (parts.len() == 1 && is_column_token(last.kind())) || (parts.len() > 1 && last.kind() == syntax_kind::r#nm) => {
+            last.identifier().unwrap_or_else(|_| expr.token_text())
+        }
+        _ => expr.token_text(),
+    }
+}
+
+pub(crate) fn is_column_token(kind: SyntaxKind) -> bool {
+    (kind == syntax_kind::r#ID) || (kind == syntax_kind::r#INDEXED) || (kind == syntax_kind::r#JOIN_KW) || (kind == syntax_kind::r#id)
+}
@@ -0,0 +1,434 @@
+use cstree::text::TextRange;
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::{SyntaxNode, SyntaxTree};
+
+mod migration;
+pub use migration::{replay, MigrationStep};
+
+#[derive(Default, Clone, Debug)]
+pub struct Catalog {
+    pub tables: Vec<Table>,
+    pub indexes: Vec<Index>,
+    pub views: Vec<View>,
+    pub triggers: Vec<Trigger>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Table {
+    pub schema: Option<String>,
+    pub name: String,
+    pub temporary: bool,
+    pub columns: Vec<Column>,
+    pub primary_key: Vec<String>,
+    pub unique_keys: Vec<Vec<String>>,
+    pub checks: Vec<String>,
+    pub foreign_keys: Vec<ForeignKey>,
+    pub without_rowid: bool,
+    pub strict: bool,
+    pub range: TextRange,
+}
+
+#[derive(Clone, Debug)]
+pub struct Column {
+    pub name: String,
+    pub declared_type: Option<String>,
+    pub affinity: Affinity,
+    pub primary_key: bool,
+    pub autoincrement: bool,
+    pub not_null: bool,
+    pub default: Option<String>,
+    pub unique: bool,
+    pub checks: Vec<String>,
+    pub collation: Option<String>,
+    pub generated: Option<String>,
+    pub range: TextRange,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Affinity {
+    Integer,
+    Text,
+    Blob,
+    Real,
+    Numeric,
+}
+
+#[derive(Clone, Debug)]
+pub struct ForeignKey {
+    pub columns: Vec<String>,
+    pub foreign_table: String,
+    pub foreign_columns: Vec<String>,
+    pub range: TextRange,
+}
+
+#[derive(Clone, Debug)]
+pub struct Index {
+    pub schema: Option<String>,
+    pub name: String,
+    pub table: String,
+    pub unique: bool,
+    pub columns: Vec<String>,
+    pub where_clause: Option<String>,
+    pub range: TextRange,
+}
+
+#[derive(Clone, Debug)]
+pub struct View {
+    pub schema: Option<String>,
+    pub name: String,
+    pub temporary: bool,
+    pub columns: Vec<String>,
+    pub select: Option<String>,
+    pub range: TextRange,
+}
+
+#[derive(Clone, Debug)]
+pub struct Trigger {
+    pub schema: Option<String>,
+    pub name: String,
+    pub table: String,
+    pub time: Option<String>,
+    pub event: String,
+    pub range: TextRange,
+}
+
+impl Catalog {
+    pub fn from_tree(tree: &SyntaxTree) -> Self {
+        let mut catalog = Self::default();
+        catalog.collect(&tree.root());
+        catalog
+    }
+
+    // Collect definitions from statements under the node.
+    // Partially broken statements are accepted as long as their names are resolved.
+    pub fn collect(&mut self, node: &SyntaxNode) {
+        for statement in node.descendants().filter(|node| node.as_inner_node().is_some()) {
+            if let Some(table) = table_from(&statement) {
+                self.tables.push(table);
+            }
+            else if let Some(index) = index_from(&statement) {
+                self.indexes.push(index);
+            }
+            else if let Some(view) = view_from(&statement) {
+                self.views.push(view);
+            }
+            else if let Some(trigger) = trigger_from(&statement) {
+                self.triggers.push(trigger);
+            }
+        }
+    }
+
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.tables.iter().find(|table| table.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn index(&self, name: &str) -> Option<&Index> {
+        self.indexes.iter().find(|index| index.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|view| view.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn trigger(&self, name: &str) -> Option<&Trigger> {
+        self.triggers.iter().find(|trigger| trigger.name.eq_ignore_ascii_case(name))
+    }
+}
+
+impl Table {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|column| column.name.eq_ignore_ascii_case(name))
+    }
+}
+
+impl Affinity {
+    // See: https://www.sqlite.org/datatype3.html#determination_of_column_affinity
+    pub fn from_declared_type(declared_type: Option<&str>) -> Self {
+        let Some(declared_type) = declared_type.map(|ty| ty.to_ascii_uppercase()) else {
+            return Affinity::Blob;
+        };
+
+        match declared_type {
+            ty if ty.contains("INT") => Affinity::Integer,
+            ty if ty.contains("CHAR") || ty.contains("CLOB") || ty.contains("TEXT") => Affinity::Text,
+            ty if ty.contains("BLOB") => Affinity::Blob,
+            ty if ty.contains("REAL") || ty.contains("FLOA") || ty.contains("DOUB") => Affinity::Real,
+            _ => Affinity::Numeric,
+        }
+    }
+}
+
+fn table_from(statement: &SyntaxNode) -> Option<Table> {
+    let create_table = child(statement, syntax_kind::r#create_table)?;
+    let (schema, name) = qualified_name(&create_table)?;
+
+    let mut table = Table {
+        schema,
+        name,
+        temporary: child(&create_table, syntax_kind::r#temp).is_some(),
+        columns: vec![],
+        primary_key: vec![],
+        unique_keys: vec![],
+        checks: vec![],
+        foreign_keys: vec![],
+        without_rowid: false,
+        strict: false,
+        range: statement.token_range(),
+    };
+
+    let Some(args) = child(statement, syntax_kind::r#create_table_args) else {
+        return Some(table);
+    };
+
+    if let Some(columnlist) = child(&args, syntax_kind::r#columnlist) {
+        for item in flatten_list(&columnlist) {
+            if let Some(column) = column_from(&item, &mut table) {
+                table.columns.push(column);
+            }
+        }
+    }
+
+    if let Some(conslist) = child(&args, syntax_kind::r#conslist_opt).and_then(|node| child(&node, syntax_kind::r#conslist)) {
+        for tcons in flatten_list(&conslist).iter().filter_map(|item| child(item, syntax_kind::r#tcons)) {
+            apply_table_constraint(&tcons, &mut table);
+        }
+    }
+
+    if let Some(options) = child(&args, syntax_kind::r#table_option_set) {
+        for option in flatten_list(&options).iter().filter_map(|item| child(item, syntax_kind::r#table_option)) {
+            let without = child(&option, syntax_kind::r#WITHOUT).is_some();
+            let Some(name) = child(&option, syntax_kind::r#nm).and_then(|nm| nm.identifier().ok()) else {
+                continue;
+            };
+
+            match name.to_ascii_uppercase().as_str() {
+                "ROWID" if without => table.without_rowid = true,
+                "STRICT" if !without => table.strict = true,
+                _ => {}
+            }
+        }
+    }
+
+    for key in table.primary_key.clone() {
+        if let Some(column) = table.columns.iter_mut().find(|column| column.name.eq_ignore_ascii_case(&key)) {
+            column.primary_key = true;
+        }
+    }
+
+    Some(table)
+}
+
+fn column_from(item: &SyntaxNode, table: &mut Table) -> Option<Column> {
+    let columnname = child(item, syntax_kind::r#columnname)?;
+    let name = child(&columnname, syntax_kind::r#nm)?.identifier().ok()?;
+    let declared_type = child(&columnname, syntax_kind::r#typetoken).map(|node| node.token_text());
+    let carglist = child(item, syntax_kind::r#carglist);
+    let range = TextRange::new(
+        columnname.token_range().start(),
+        carglist.as_ref().unwrap_or(&columnname).token_range().end()
+    );
+
+    let mut column = Column {
+        name,
+        affinity: Affinity::from_declared_type(declared_type.as_deref()),
+        declared_type,
+        primary_key: false,
+        autoincrement: false,
+        not_null: false,
+        default: None,
+        unique: false,
+        checks: vec![],
+        collation: None,
+        generated: None,
+        range,
+    };
+
+    let Some(carglist) = carglist else {
+        return Some(column);
+    };
+
+    for ccons in flatten_list(&carglist).iter().filter_map(|item| child(item, syntax_kind::r#ccons)) {
+        let children = ccons.children().filter(|node| !node.is_error()).collect::<Vec<_>>();
+        let Some(head) = children.first() else {
+            continue;
+        };
+
+        match head.kind() {
+            k if k == syntax_kind::r#DEFAULT => {
+                column.default = text_after(&ccons, head);
+            }
+            k if k == syntax_kind::r#NOT => {
+                column.not_null = true;
+            }
+            k if k == syntax_kind::r#PRIMARY => {
+                column.primary_key = true;
+                column.autoincrement = child(&ccons, syntax_kind::r#autoinc).is_some();
+                table.primary_key = vec![column.name.clone()];
+            }
+            k if k == syntax_kind::r#UNIQUE => {
+                column.unique = true;
+            }
+            k if k == syntax_kind::r#CHECK => {
+                column.checks.extend(child(&ccons, syntax_kind::r#expr).map(|expr| expr.token_text()));
+            }
+            k if k == syntax_kind::r#REFERENCES => {
+                if let Some(foreign_key) = foreign_key_from(&ccons, vec![column.name.clone()]) {
+                    table.foreign_keys.push(foreign_key);
+                }
+            }
+            k if k == syntax_kind::r#COLLATE => {
+                column.collation = text_after(&ccons, head);
+            }
+            k if (k == syntax_kind::r#GENERATED) || (k == syntax_kind::r#AS) => {
+                column.generated = child(&ccons, syntax_kind::r#generated).map(|node| node.token_text());
+            }
+            _ => {}
+        }
+    }
+
+    Some(column)
+}
+
+fn apply_table_constraint(tcons: &SyntaxNode, table: &mut Table) {
+    let Some(head) = tcons.children().find(|node| !node.is_error()) else {
+        return;
+    };
+
+    match head.kind() {
+        k if k == syntax_kind::r#PRIMARY => {
+            table.primary_key = child(tcons, syntax_kind::r#sortlist).map(|node| sortlist_names(&node)).unwrap_or_default();
+        }
+        k if k == syntax_kind::r#UNIQUE => {
+            table.unique_keys.extend(child(tcons, syntax_kind::r#sortlist).map(|node| sortlist_names(&node)));
+        }
+        k if k == syntax_kind::r#CHECK => {
+            table.checks.extend(child(tcons, syntax_kind::r#expr).map(|expr| expr.token_text()));
+        }
+        k if k == syntax_kind::r#FOREIGN => {
+            let columns = child(tcons, syntax_kind::r#eidlist).map(|node| eidlist_names(&node)).unwrap_or_default();
+            table.foreign_keys.extend(foreign_key_from(tcons, columns));
+        }
+        _ => {}
+    }
+}
+
+fn foreign_key_from(node: &SyntaxNode, columns: Vec<String>) -> Option<ForeignKey> {
+    let foreign_table = child(node, syntax_kind::r#nm)?.identifier().ok()?;
+    let foreign_columns = child(node, syntax_kind::r#eidlist_opt)
+        .and_then(|node| child(&node, syntax_kind::r#eidlist))
+        .map(|node| eidlist_names(&node))
+        .unwrap_or_default()
+    ;
+
+    Some(ForeignKey { columns, foreign_table, foreign_columns, range: node.token_range() })
+}
+
+fn index_from(statement: &SyntaxNode) -> Option<Index> {
+    child(statement, syntax_kind::r#createkw)?;
+    child(statement, syntax_kind::r#INDEX)?;
+
+    let (schema, name) = qualified_name(statement)?;
+    let table = children(statement, syntax_kind::r#nm).nth(1)?.identifier().ok()?;
+
+    Some(Index {
+        schema,
+        name,
+        table,
+        unique: child(statement, syntax_kind::r#uniqueflag).is_some(),
+        columns: child(statement, syntax_kind::r#sortlist).map(|node| sortlist_names(&node)).unwrap_or_default(),
+        where_clause: child(statement, syntax_kind::r#where_opt).and_then(|node| child(&node, syntax_kind::r#expr)).map(|expr| expr.token_text()),
+        range: statement.token_range(),
+    })
+}
+
+fn view_from(statement: &SyntaxNode) -> Option<View> {
+    child(statement, syntax_kind::r#createkw)?;
+    child(statement, syntax_kind::r#VIEW)?;
+
+    let (schema, name) = qualified_name(statement)?;
+
+    Some(View {
+        schema,
+        name,
+        temporary: child(statement, syntax_kind::r#temp).is_some(),
+        columns: child(statement, syntax_kind::r#eidlist_opt)
+            .and_then(|node| child(&node, syntax_kind::r#eidlist))
+            .map(|node| eidlist_names(&node))
+            .unwrap_or_default(),
+        select: child(statement, syntax_kind::r#select).map(|node| node.token_text()),
+        range: statement.token_range(),
+    })
+}
+
+fn trigger_from(statement: &SyntaxNode) -> Option<Trigger> {
+    let decl = child(statement, syntax_kind::r#trigger_decl)?;
+    let (schema, name) = qualified_name(&decl)?;
+    let table = child(&decl, syntax_kind::r#fullname).and_then(|node| qualified_name(&node)).map(|(_, name)| name)?;
+
+    Some(Trigger {
+        schema,
+        name,
+        table,
+        time: child(&decl, syntax_kind::r#trigger_time).map(|node| node.token_text()),
+        event: child(&decl, syntax_kind::r#trigger_event).map(|node| node.token_text()).unwrap_or_default(),
+        range: statement.token_range(),
+    })
+}
+
+// `nm dbnm` or `nm DOT nm`
+pub(crate) fn qualified_name(node: &SyntaxNode) -> Option<(Option<String>, String)> {
+    let first = child(node, syntax_kind::r#nm)?.identifier().ok()?;
+    let second = match child(node, syntax_kind::r#dbnm) {
+        Some(dbnm) => child(&dbnm, syntax_kind::r#nm),
+        None if child(node, syntax_kind::r#DOT).is_some() => children(node, syntax_kind::r#nm).nth(1),
+        None => None,
+    };
+
+    match second {
+        Some(nm) => Some((Some(first), nm.identifier().ok()?)),
+        None => Some((None, first)),
+    }
+}
+
+fn sortlist_names(sortlist: &SyntaxNode) -> Vec<String> {
+    flatten_list(sortlist).iter()
+        .filter_map(|item| child(item, syntax_kind::r#expr))
+        .map(|expr| expr.identifier().unwrap_or_else(|_| expr.token_text()))
+        .collect()
+}
+
+fn eidlist_names(eidlist: &SyntaxNode) -> Vec<String> {
+    flatten_list(eidlist).iter()
+        .filter_map(|item| child(item, syntax_kind::r#nm))
+        .filter_map(|nm| nm.identifier().ok())
+        .collect()
+}
+
+// Left recursive list (e.g. `columnlist ::= columnlist COMMA columnname carglist`) in source order
+pub(crate) fn flatten_list(node: &SyntaxNode) -> Vec<SyntaxNode> {
+    let mut items = match child(node, node.kind()) {
+        Some(inner) => flatten_list(&inner),
+        None => vec![],
+    };
+    items.push(node.clone());
+    items
+}
+
+// First child of the kind (error nodes are skipped)
+pub fn child(node: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxNode> {
+    children(node, kind).next()
+}
+
+pub(crate) fn children(node: &SyntaxNode, kind: SyntaxKind) -> impl Iterator<Item = SyntaxNode> {
+    node.children().filter(move |child| (child.kind() == kind) && !child.is_error())
+}
+
+// Source text following `head` in `node`
+fn text_after(node: &SyntaxNode, head: &SyntaxNode) -> Option<String> {
+    let range = TextRange::new(head.token_range().end(), node.token_range().end()) - node.token_range().start();
+    let text = node.token_text();
+    let text = text.get(std::ops::Range::<usize>::from(range))?.trim();
+
+    (!text.is_empty()).then(|| text.to_string())
+}
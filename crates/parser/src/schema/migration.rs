@@ -0,0 +1,234 @@
+use sqlite_parser_proto::engine::kinds as syntax_kind;
+
+use crate::{diagnostic::Diagnostic, SyntaxNode, SyntaxTree};
+use super::{child, children, column_from, index_from, qualified_name, table_from, trigger_from, view_from, Catalog};
+
+#[derive(Clone, Debug)]
+pub struct MigrationStep {
+    pub name: String,
+    // Effective schema after the migration
+    pub catalog: Catalog,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+// Replay migrations in order.
+pub fn replay<'a>(migrations: impl IntoIterator<Item = (&'a str, &'a SyntaxTree)>) -> Vec<MigrationStep> {
+    let mut catalog = Catalog::default();
+
+    migrations.into_iter()
+        .map(|(name, tree)| {
+            let diagnostics = catalog.apply(tree);
+            MigrationStep { name: name.to_string(), catalog: catalog.clone(), diagnostics }
+        })
+        .collect()
+}
+
+impl Catalog {
+    // Apply CREATE/ALTER/DROP statements in order
+    pub fn apply(&mut self, tree: &SyntaxTree) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+
+        for statement in tree.root().descendants().filter(|node| node.as_inner_node().is_some()) {
+            if child(&statement, syntax_kind::r#ALTER).is_some() {
+                self.apply_alter(&statement, &mut diagnostics);
+            }
+            else if child(&statement, syntax_kind::r#DROP).is_some() {
+                self.apply_drop(&statement, &mut diagnostics);
+            }
+            else {
+                self.apply_create(&statement, &mut diagnostics);
+            }
+        }
+
+        diagnostics
+    }
+
+    fn apply_create(&mut self, statement: &SyntaxNode, diagnostics: &mut Vec<Diagnostic>) {
+        if let Some(table) = table_from(statement) {
+            let if_not_exists = child(statement, syntax_kind::r#create_table).and_then(|node| child(&node, syntax_kind::r#ifnotexists)).is_some();
+
+            match self.has_relation(&table.name) {
+                true if if_not_exists => {}
+                true => diagnostics.push(Diagnostic::error("schema/duplicate-table", format!("table {} already exists", table.name), table.range)),
+                false => self.tables.push(table),
+            }
+        }
+        else if let Some(index) = index_from(statement) {
+            match (self.index(&index.name).is_some(), self.table(&index.table)) {
+                (true, _) if child(statement, syntax_kind::r#ifnotexists).is_some() => {}
+                (true, _) => diagnostics.push(Diagnostic::error("schema/duplicate-index", format!("index {} already exists", index.name), index.range)),
+                (false, None) => diagnostics.push(Diagnostic::error("schema/no-such-table", format!("no such table: {}", index.table), index.range)),
+                (false, Some(table)) => {
+                    for name in index.columns.iter().filter(|name| table.column(name).is_none()) {
+                        diagnostics.push(Diagnostic::warning("schema/no-such-column", format!("no such column: {}.{name}", table.name), index.range));
+                    }
+                    self.indexes.push(index);
+                }
+            }
+        }
+        else if let Some(view) = view_from(statement) {
+            match self.has_relation(&view.name) {
+                true if child(statement, syntax_kind::r#ifnotexists).is_some() => {}
+                true => diagnostics.push(Diagnostic::error("schema/duplicate-view", format!("view {} already exists", view.name), view.range)),
+                false => self.views.push(view),
+            }
+        }
+        else if let Some(trigger) = trigger_from(statement) {
+            let if_not_exists = child(statement, syntax_kind::r#trigger_decl).and_then(|node| child(&node, syntax_kind::r#ifnotexists)).is_some();
+
+            match (self.trigger(&trigger.name).is_some(), self.has_relation(&trigger.table)) {
+                (true, _) if if_not_exists => {}
+                (true, _) => diagnostics.push(Diagnostic::error("schema/duplicate-trigger", format!("trigger {} already exists", trigger.name), trigger.range)),
+                (false, false) => diagnostics.push(Diagnostic::error("schema/no-such-table", format!("no such table: {}", trigger.table), trigger.range)),
+                (false, true) => self.triggers.push(trigger),
+            }
+        }
+    }
+
+    fn apply_alter(&mut self, statement: &SyntaxNode, diagnostics: &mut Vec<Diagnostic>) {
+        let range = statement.token_range();
+        let fullname = child(statement, syntax_kind::r#add_column_fullname)
+            .and_then(|node| child(&node, syntax_kind::r#fullname))
+            .or_else(|| child(statement, syntax_kind::r#fullname))
+        ;
+        let Some((_, table_name)) = fullname.and_then(|node| qualified_name(&node)) else {
+            return;
+        };
+        let Some(table_index) = self.tables.iter().position(|table| table.name.eq_ignore_ascii_case(&table_name)) else {
+            diagnostics.push(Diagnostic::error("schema/no-such-table", format!("no such table: {table_name}"), range));
+            return;
+        };
+        let names = children(statement, syntax_kind::r#nm).filter_map(|nm| nm.identifier().ok()).collect::<Vec<_>>();
+
+        // ALTER TABLE fullname ADD kwcolumn_opt columnname carglist
+        if child(statement, syntax_kind::r#ADD).is_some() {
+            let mut table = self.tables[table_index].clone();
+            let Some(column) = column_from(statement, &mut table) else {
+                return;
+            };
+
+            if table.column(&column.name).is_some() {
+                diagnostics.push(Diagnostic::error("schema/duplicate-column", format!("duplicate column name: {}", column.name), range));
+                return;
+            }
+            if column.primary_key || column.unique {
+                diagnostics.push(Diagnostic::error("schema/add-key-column", format!("cannot add a PRIMARY KEY or UNIQUE column: {}", column.name), range));
+                return;
+            }
+            let has_default = column.default.as_deref().is_some_and(|x| !x.eq_ignore_ascii_case("NULL"));
+            if column.not_null && !has_default {
+                diagnostics.push(Diagnostic::error("schema/not-null-without-default", format!("cannot add a NOT NULL column with default value NULL: {}", column.name), range));
+                return;
+            }
+
+            table.columns.push(column);
+            self.tables[table_index] = table;
+        }
+        // ALTER TABLE fullname DROP kwcolumn_opt nm
+        else if child(statement, syntax_kind::r#DROP).is_some() {
+            let Some(column_name) = names.first() else {
+                return;
+            };
+            let table = &self.tables[table_index];
+            let Some(column) = table.column(column_name) else {
+                diagnostics.push(Diagnostic::error("schema/no-such-column", format!("no such column: {column_name}"), range));
+                return;
+            };
+
+            if column.primary_key || column.unique || table.unique_keys.iter().flatten().any(|key| key.eq_ignore_ascii_case(column_name)) {
+                diagnostics.push(Diagnostic::error("schema/drop-key-column", format!("cannot drop PRIMARY KEY or UNIQUE column: {column_name}"), range));
+                return;
+            }
+            if let Some(index) = self.indexes.iter().find(|index| index.table.eq_ignore_ascii_case(&table_name) && index.columns.iter().any(|c| c.eq_ignore_ascii_case(column_name))) {
+                diagnostics.push(Diagnostic::error("schema/drop-indexed-column", format!("cannot drop column {column_name}: indexed by {}", index.name), range));
+                return;
+            }
+
+            self.tables[table_index].columns.retain(|column| !column.name.eq_ignore_ascii_case(column_name));
+        }
+        // ALTER TABLE fullname RENAME kwcolumn_opt nm TO nm
+        else if let [old_name, new_name] = names.as_slice() {
+            let table = &mut self.tables[table_index];
+
+            if table.column(new_name).is_some() {
+                diagnostics.push(Diagnostic::error("schema/duplicate-column", format!("duplicate column name: {new_name}"), range));
+                return;
+            }
+            let Some(column) = table.columns.iter_mut().find(|column| column.name.eq_ignore_ascii_case(old_name)) else {
+                diagnostics.push(Diagnostic::error("schema/no-such-column", format!("no such column: {old_name}"), range));
+                return;
+            };
+            column.name = new_name.clone();
+
+            let keys = table.primary_key.iter_mut().chain(table.unique_keys.iter_mut().flatten());
+            let index_columns = self.indexes.iter_mut()
+                .filter(|index| index.table.eq_ignore_ascii_case(&table_name))
+                .flat_map(|index| index.columns.iter_mut())
+            ;
+            for name in keys.chain(index_columns).filter(|name| name.eq_ignore_ascii_case(old_name)) {
+                *name = new_name.clone();
+            }
+        }
+        // ALTER TABLE fullname RENAME TO nm
+        else if let [new_name] = names.as_slice() {
+            if self.has_relation(new_name) {
+                diagnostics.push(Diagnostic::error("schema/duplicate-table", format!("there is already another table or index with this name: {new_name}"), range));
+                return;
+            }
+
+            self.tables[table_index].name = new_name.clone();
+
+            let indexes = self.indexes.iter_mut().map(|index| &mut index.table);
+            let triggers = self.triggers.iter_mut().map(|trigger| &mut trigger.table);
+            let foreign_keys = self.tables.iter_mut().flat_map(|table| table.foreign_keys.iter_mut()).map(|key| &mut key.foreign_table);
+
+            for name in indexes.chain(triggers).chain(foreign_keys).filter(|name| name.eq_ignore_ascii_case(&table_name)) {
+                *name = new_name.clone();
+            }
+        }
+    }
+
+    // DROP TABLE/INDEX/VIEW/TRIGGER ifexists fullname
+    fn apply_drop(&mut self, statement: &SyntaxNode, diagnostics: &mut Vec<Diagnostic>) {
+        let Some((_, name)) = child(statement, syntax_kind::r#fullname).and_then(|node| qualified_name(&node)) else {
+            return;
+        };
+        let if_exists = child(statement, syntax_kind::r#ifexists).is_some();
+        let range = statement.token_range();
+
+        let (found, label) = if child(statement, syntax_kind::r#TABLE).is_some() {
+            let found = self.tables.iter().any(|table| table.name.eq_ignore_ascii_case(&name));
+            self.tables.retain(|table| !table.name.eq_ignore_ascii_case(&name));
+            self.indexes.retain(|index| !index.table.eq_ignore_ascii_case(&name));
+            self.triggers.retain(|trigger| !trigger.table.eq_ignore_ascii_case(&name));
+            (found, "table")
+        }
+        else if child(statement, syntax_kind::r#VIEW).is_some() {
+            let found = self.view(&name).is_some();
+            self.views.retain(|view| !view.name.eq_ignore_ascii_case(&name));
+            self.triggers.retain(|trigger| !trigger.table.eq_ignore_ascii_case(&name));
+            (found, "view")
+        }
+        else if child(statement, syntax_kind::r#INDEX).is_some() {
+            let found = self.index(&name).is_some();
+            self.indexes.retain(|index| !index.name.eq_ignore_ascii_case(&name));
+            (found, "index")
+        }
+        else if child(statement, syntax_kind::r#TRIGGER).is_some() {
+            let found = self.trigger(&name).is_some();
+            self.triggers.retain(|trigger| !trigger.name.eq_ignore_ascii_case(&name));
+            (found, "trigger")
+        }
+        else {
+            return;
+        };
+
+        if !found && !if_exists {
+            diagnostics.push(Diagnostic::error(&format!("schema/no-such-{label}"), format!("no such {label}: {name}"), range));
+        }
+    }
+
+    fn has_relation(&self, name: &str) -> bool {
+        self.table(name).is_some() || self.view(name).is_some()
+    }
+}
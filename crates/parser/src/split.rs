@@ -0,0 +1,99 @@
+use cstree::text::{TextRange, TextSize};
+use scanner::{Scanner, TokenItem};
+use sqlite_parser_proto::{engine::kinds as syntax_kind, SyntaxKind};
+
+use crate::Parser;
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct StatementSpan {
+    // From the first main token to the terminating `;` (leading/trailing trivia are excluded)
+    pub range: TextRange,
+    pub terminated: bool,
+}
+
+#[derive(Default)]
+struct SplitState {
+    start: Option<usize>,
+    end: usize,
+    token_count: usize,
+    maybe_trigger: bool,
+    in_trigger: bool,
+    in_trigger_body: bool,
+    case_depth: usize,
+}
+
+impl SplitState {
+    fn accept(&mut self, item: &TokenItem) {
+        match self.token_count {
+            0 => {
+                self.start = Some(item.offset);
+                self.maybe_trigger = is_kind_of(item, syntax_kind::r#CREATE);
+            }
+            // CREATE [TEMP] TRIGGER
+            1 | 2 if self.maybe_trigger && is_kind_of(item, syntax_kind::r#TRIGGER) => {
+                self.in_trigger = true;
+            }
+            _ if self.in_trigger && !self.in_trigger_body && is_kind_of(item, syntax_kind::r#BEGIN) => {
+                self.in_trigger_body = true;
+            }
+            _ if is_kind_of(item, syntax_kind::r#CASE) => {
+                self.case_depth += 1;
+            }
+            _ if is_kind_of(item, syntax_kind::r#END) => {
+                match self.case_depth {
+                    0 => self.in_trigger_body = false,
+                    _ => self.case_depth -= 1,
+                }
+            }
+            _ => {}
+        }
+
+        self.token_count += 1;
+        self.end = item.offset + item.len;
+    }
+
+    fn take_span(&mut self, terminated: bool) -> Option<StatementSpan> {
+        let start = self.start?;
+        let range = TextRange::new(TextSize::from(start as u32), TextSize::from(self.end as u32));
+        *self = SplitState::default();
+
+        Some(StatementSpan { range, terminated })
+    }
+}
+
+impl Parser {
+    // Split source into statements by scanning tokens only.
+    // Semicolons in strings, comments and trigger bodies do not split statements.
+    pub fn split_statements(source: &str) -> Vec<StatementSpan> {
+        let mut scanner = Scanner::create(source.to_string(), 0);
+        let mut state = SplitState::default();
+        let mut spans = vec![];
+
+        while let Some(token) = scanner.shift() {
+            let item = &token.main;
+
+            if item.tag == syntax_kind::r#EOF {
+                break;
+            }
+            if (item.tag == syntax_kind::r#SEMI) && !state.in_trigger_body {
+                state.end = item.offset + item.len;
+                spans.extend(state.take_span(true));
+                continue;
+            }
+
+            state.accept(item);
+        }
+
+        spans.extend(state.take_span(false));
+        spans
+    }
+}
+
+// Lowercase keywords are scanned as ID
+fn is_kind_of(item: &TokenItem, kind: SyntaxKind) -> bool {
+    match item.value.as_ref() {
+        _ if item.tag == kind => true,
+        Some(value) if item.tag == syntax_kind::r#ID => value.eq_ignore_ascii_case(kind.text),
+        _ => false,
+    }
+}
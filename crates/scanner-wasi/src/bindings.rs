@@ -1,6 +1,10 @@
 pub mod scanner_world;
+#[cfg(feature = "compat")]
+pub mod compat_world;
 mod resources;
 
 use resources::ScannerComponent;
 
-scanner_world::export!(ScannerComponent);
\ No newline at end of file
+scanner_world::export!(ScannerComponent);
+#[cfg(feature = "compat")]
+compat_world::export!(ScannerComponent);
\ No newline at end of file
@@ -62,6 +62,7 @@ impl From<&types::TokenItem> for ::scanner::TokenItem {
             offset: value.offset as usize, 
             len: value.len as usize, 
             value: value.value.clone(),
+            error: value.error.as_ref().map(Into::into),
         }
     }
 }
@@ -72,6 +73,24 @@ impl From<&::scanner::TokenItem> for types::TokenItem {
             offset: value.offset as u32, 
             len: value.len as u32, 
             value: value.value.clone(),
+            error: value.error.as_ref().map(Into::into),
+        }
+    }
+}
+
+impl From<&types::TokenError> for ::scanner::TokenError {
+    fn from(value: &types::TokenError) -> Self {
+        match value {
+            types::TokenError::IllegalCharacter => ::scanner::TokenError::IllegalCharacter,
+            types::TokenError::Unterminated(kind) => ::scanner::TokenError::Unterminated(From::from(kind)),
+        }
+    }
+}
+impl From<&::scanner::TokenError> for types::TokenError {
+    fn from(value: &::scanner::TokenError) -> Self {
+        match value {
+            ::scanner::TokenError::IllegalCharacter => types::TokenError::IllegalCharacter,
+            ::scanner::TokenError::Unterminated(kind) => types::TokenError::Unterminated(From::from(kind)),
         }
     }
 }
@@ -105,7 +124,124 @@ impl From<types::ScannerScope> for ::scanner::ScannerScope {
     }
 }
 
+impl From<types::EditScope> for ::scanner::EditScope {
+    fn from(value: types::EditScope) -> Self {
+        Self { offset: value.offset, from_len: value.from_len, to_len: value.to_len }
+    }
+}
+
 pub struct ScannerComponent;
 impl<'a> scanners::Guest for ScannerComponent {
     type Scanner = ScannerImpl;
+
+    fn tokenize(source: String,) -> Vec::<scanners::Token> {
+        ::scanner::tokenize(&source).into_iter().map(From::from).collect()
+    }
+
+    fn relex(source: String,tokens: Vec::<scanners::Token>,edit: scanners::EditScope,) -> Vec::<scanners::Token> {
+        let tokens = tokens.into_iter().map(From::from).collect::<Vec<::scanner::Token>>();
+        ::scanner::relex(&source, &tokens, &edit.into()).into_iter().map(From::from).collect()
+    }
+}
+
+// Exports of `ritalin:scanner@0.0.1` sharing the resources above (token items have no `error`)
+#[cfg(feature = "compat")]
+mod compat {
+    use super::{ScannerComponent, ScannerImpl};
+    use super::super::compat_world::exports::ritalin::scanner::{scanners, types};
+
+    impl scanners::GuestScanner for ScannerImpl {
+        fn create(source: String,index_from: u32,) -> scanners::Scanner {
+            scanners::Scanner::new(Self::new(source, index_from))
+        }
+
+        fn lookahead(&self,) -> Option<scanners::Token> {
+            self.inner.borrow().lookahead().map(|x| From::from(x.clone()))
+        }
+
+        fn shift(&self,) -> Option<scanners::Token> {
+            self.inner.borrow_mut().shift().map(|x| From::from(x.clone()))
+        }
+
+        fn scope(&self,) -> scanners::ScannerScope {
+            self.inner.borrow().scope().into()
+        }
+
+        fn revert(&self,scope: scanners::ScannerScope,) -> () {
+            self.inner.borrow_mut().revert(scope.into());
+        }
+    }
+
+    impl From<::scanner::Token> for types::Token {
+        fn from(value: ::scanner::Token) -> Self {
+            Self { 
+                leading: value.leading.map(|trivia| trivia.iter().map(Into::into).collect()), 
+                main: From::from(&value.main), 
+                trailing: value.trailing.map(|trivia| trivia.iter().map(Into::into).collect())
+            }
+        }
+    }
+    impl From<types::Token> for ::scanner::Token {
+        fn from(value: types::Token) -> Self {
+            Self { 
+                leading: value.leading.map(|trivia| trivia.iter().map(Into::into).collect()), 
+                main: From::from(&value.main), 
+                trailing: value.trailing.map(|trivia| trivia.iter().map(Into::into).collect())
+            }
+        }
+    }
+
+    impl From<&types::TokenItem> for ::scanner::TokenItem {
+        fn from(value: &types::TokenItem) -> Self {
+            Self { 
+                tag: From::from(&value.kind), 
+                offset: value.offset as usize, 
+                len: value.len as usize, 
+                value: value.value.clone(),
+                error: None,
+            }
+        }
+    }
+    impl From<&::scanner::TokenItem> for types::TokenItem {
+        fn from(value: &::scanner::TokenItem) -> Self {
+            Self { 
+                kind: From::from(&value.tag), 
+                offset: value.offset as u32, 
+                len: value.len as u32, 
+                value: value.value.clone(),
+            }
+        }
+    }
+
+    impl From<&sqlite_parser_proto::SyntaxKind> for types::SyntaxKind {
+        fn from(value: &sqlite_parser_proto::SyntaxKind) -> Self {
+            Self {
+                id: value.id,
+                text: value.text.into(),
+                is_keyword: value.is_keyword,
+                is_terminal: value.is_terminal,
+            }
+        }
+    }
+    impl From<&types::SyntaxKind> for sqlite_parser_proto::SyntaxKind {
+        fn from(value: &types::SyntaxKind) -> Self {
+            use cstree::{RawSyntaxKind, Syntax};
+            Self::from_raw(RawSyntaxKind(value.id))
+        }
+    }
+
+    impl From<::scanner::ScannerScope> for types::ScannerScope {
+        fn from(value: ::scanner::ScannerScope) -> Self {
+            Self { next_index: value.next_index as u32, lookahead: value.lookahead.map(Into::into) }
+        }
+    }
+    impl From<types::ScannerScope> for ::scanner::ScannerScope {
+        fn from(value: types::ScannerScope) -> Self {
+            Self { next_index: value.next_index as usize, lookahead: value.lookahead.map(Into::into) }    
+        }
+    }
+
+    impl scanners::Guest for ScannerComponent {
+        type Scanner = ScannerImpl;
+    }
 }
@@ -27,15 +27,35 @@ pub mod exports {
           }
         }
         #[derive(Clone)]
+        pub enum TokenError {
+          IllegalCharacter,
+          /// String, quoted identifier or block comment reaching the end of input (kind is the intended one)
+          Unterminated(SyntaxKind),
+        }
+        impl ::core::fmt::Debug for TokenError {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+              TokenError::IllegalCharacter => {
+                f.debug_tuple("TokenError::IllegalCharacter").finish()
+              }
+              TokenError::Unterminated(e) => {
+                f.debug_tuple("TokenError::Unterminated").field(e).finish()
+              }
+            }
+          }
+        }
+        #[derive(Clone)]
         pub struct TokenItem {
           pub kind: SyntaxKind,
           pub offset: u32,
           pub len: u32,
           pub value: Option<_rt::String>,
+          /// Set on `ILLEGAL` items
+          pub error: Option<TokenError>,
         }
         impl ::core::fmt::Debug for TokenItem {
           fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_struct("TokenItem").field("kind", &self.kind).field("offset", &self.offset).field("len", &self.len).field("value", &self.value).finish()
+            f.debug_struct("TokenItem").field("kind", &self.kind).field("offset", &self.offset).field("len", &self.len).field("value", &self.value).field("error", &self.error).finish()
           }
         }
         #[derive(Clone)]
@@ -59,15 +79,27 @@ pub mod exports {
             f.debug_struct("ScannerScope").field("next-index", &self.next_index).field("lookahead", &self.lookahead).finish()
           }
         }
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        pub struct EditScope {
+          pub offset: u32,
+          pub from_len: u32,
+          pub to_len: u32,
+        }
+        impl ::core::fmt::Debug for EditScope {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_struct("EditScope").field("offset", &self.offset).field("from-len", &self.from_len).field("to-len", &self.to_len).finish()
+          }
+        }
         #[doc(hidden)]
 
-        macro_rules! __export_ritalin_scanner_types_0_0_1_cabi{
+        macro_rules! __export_ritalin_scanner_types_0_1_0_cabi{
           ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
           };);
         }
         #[doc(hidden)]
-        pub(crate) use __export_ritalin_scanner_types_0_0_1_cabi;
+        pub(crate) use __export_ritalin_scanner_types_0_1_0_cabi;
 
       }
 
@@ -82,6 +114,7 @@ pub mod exports {
         use super::super::super::super::_rt;
         pub type Token = super::super::super::super::exports::ritalin::scanner::types::Token;
         pub type ScannerScope = super::super::super::super::exports::ritalin::scanner::types::ScannerScope;
+        pub type EditScope = super::super::super::super::exports::ritalin::scanner::types::EditScope;
 
         #[derive(Debug)]
         #[repr(transparent)]
@@ -213,7 +246,7 @@ pub mod exports {
 
             #[cfg(target_arch = "wasm32")]
             {
-              #[link(wasm_import_module = "[export]ritalin:scanner/scanners@0.0.1")]
+              #[link(wasm_import_module = "[export]ritalin:scanner/scanners@0.1.0")]
               unsafe extern "C" {
                 #[link_name = "[resource-drop]scanner"]
                 fn drop(_: u32);
@@ -226,664 +259,1345 @@ pub mod exports {
         
         #[doc(hidden)]
         #[allow(non_snake_case)]
-        pub unsafe fn _export_static_scanner_create_cabi<T: GuestScanner>(arg0: *mut u8,arg1: usize,arg2: i32,) -> i32 {#[cfg(target_arch="wasm32")]
+        pub unsafe fn _export_tokenize_cabi<T: Guest>(arg0: *mut u8,arg1: usize,) -> *mut u8 {#[cfg(target_arch="wasm32")]
         _rt::run_ctors_once();let len0 = arg1;
         let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-        let result1 = T::create(_rt::string_lift(bytes0), arg2 as u32);
-        (result1).take_handle() as i32
-      }
-      #[doc(hidden)]
-      #[allow(non_snake_case)]
-      pub unsafe fn _export_method_scanner_lookahead_cabi<T: GuestScanner>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
-      _rt::run_ctors_once();let result0 = T::lookahead(unsafe { ScannerBorrow::lift(arg0 as u32 as usize) }.get());
-      let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-      match result0 {
-        Some(e) => {
-          *ptr1.add(0).cast::<u8>() = (1i32) as u8;
-          let super::super::super::super::exports::ritalin::scanner::types::Token{ leading:leading2, main:main2, trailing:trailing2, } = e;
-          match leading2 {
-            Some(e) => {
-              *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-              let vec7 = e;
-              let len7 = vec7.len();
-              let layout7 = _rt::alloc::Layout::from_size_align_unchecked(vec7.len() * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-              let result7 = if layout7.size() != 0 {
-                let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
-                if ptr.is_null()
-                {
-                  _rt::alloc::handle_alloc_error(layout7);
-                }
-                ptr
-              }else {
-                ::core::ptr::null_mut()
-              };
-              for (i, e) in vec7.into_iter().enumerate() {
-                let base = result7.add(i * (8+7*::core::mem::size_of::<*const u8>()));
-                {
-                  let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind3, offset:offset3, len:len3, value:value3, } = e;
-                  let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id4, text:text4, is_keyword:is_keyword4, is_terminal:is_terminal4, } = kind3;
-                  *base.add(0).cast::<i32>() = _rt::as_i32(id4);
-                  let vec5 = (text4.into_bytes()).into_boxed_slice();
-                  let ptr5 = vec5.as_ptr().cast::<u8>();
-                  let len5 = vec5.len();
-                  ::core::mem::forget(vec5);
-                  *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
-                  *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
-                  *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword4 { true => 1, false => 0 }) as u8;
-                  *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal4 { true => 1, false => 0 }) as u8;
-                  *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset3);
-                  *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len3);
-                  match value3 {
-                    Some(e) => {
-                      *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                      let vec6 = (e.into_bytes()).into_boxed_slice();
-                      let ptr6 = vec6.as_ptr().cast::<u8>();
-                      let len6 = vec6.len();
-                      ::core::mem::forget(vec6);
-                      *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
-                      *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
-                    },
-                    None => {
-                      {
-                        *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
-                      }
-                    },
-                  };}
-                }
-                *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
-                *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result7;
-              },
-              None => {
-                {
-                  *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
-                }
-              },
-            };let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind8, offset:offset8, len:len8, value:value8, } = main2;
-            let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id9, text:text9, is_keyword:is_keyword9, is_terminal:is_terminal9, } = kind8;
-            *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id9);
-            let vec10 = (text9.into_bytes()).into_boxed_slice();
-            let ptr10 = vec10.as_ptr().cast::<u8>();
-            let len10 = vec10.len();
-            ::core::mem::forget(vec10);
-            *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len10;
-            *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr10.cast_mut();
-            *ptr1.add(7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword9 { true => 1, false => 0 }) as u8;
-            *ptr1.add(1+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal9 { true => 1, false => 0 }) as u8;
-            *ptr1.add(8*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset8);
-            *ptr1.add(4+8*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len8);
-            match value8 {
+        let result1 = T::tokenize(_rt::string_lift(bytes0));
+        let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+        let vec27 = result1;
+        let len27 = vec27.len();
+        let layout27 = _rt::alloc::Layout::from_size_align_unchecked(vec27.len() * (8+19*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+        let result27 = if layout27.size() != 0 {
+          let ptr = _rt::alloc::alloc(layout27).cast::<u8>();
+          if ptr.is_null()
+          {
+            _rt::alloc::handle_alloc_error(layout27);
+          }
+          ptr
+        }else {
+          ::core::ptr::null_mut()
+        };
+        for (i, e) in vec27.into_iter().enumerate() {
+          let base = result27.add(i * (8+19*::core::mem::size_of::<*const u8>()));
+          {
+            let super::super::super::super::exports::ritalin::scanner::types::Token{ leading:leading3, main:main3, trailing:trailing3, } = e;
+            match leading3 {
               Some(e) => {
-                *ptr1.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                let vec11 = (e.into_bytes()).into_boxed_slice();
-                let ptr11 = vec11.as_ptr().cast::<u8>();
+                *base.add(0).cast::<u8>() = (1i32) as u8;
+                let vec11 = e;
                 let len11 = vec11.len();
-                ::core::mem::forget(vec11);
-                *ptr1.add(8+10*::core::mem::size_of::<*const u8>()).cast::<usize>() = len11;
-                *ptr1.add(8+9*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr11.cast_mut();
-              },
-              None => {
-                {
-                  *ptr1.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
-                }
-              },
-            };match trailing2 {
-              Some(e) => {
-                *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                let vec16 = e;
-                let len16 = vec16.len();
-                let layout16 = _rt::alloc::Layout::from_size_align_unchecked(vec16.len() * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-                let result16 = if layout16.size() != 0 {
-                  let ptr = _rt::alloc::alloc(layout16).cast::<u8>();
+                let layout11 = _rt::alloc::Layout::from_size_align_unchecked(vec11.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                let result11 = if layout11.size() != 0 {
+                  let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
                   if ptr.is_null()
                   {
-                    _rt::alloc::handle_alloc_error(layout16);
+                    _rt::alloc::handle_alloc_error(layout11);
                   }
                   ptr
                 }else {
                   ::core::ptr::null_mut()
                 };
-                for (i, e) in vec16.into_iter().enumerate() {
-                  let base = result16.add(i * (8+7*::core::mem::size_of::<*const u8>()));
+                for (i, e) in vec11.into_iter().enumerate() {
+                  let base = result11.add(i * (8+13*::core::mem::size_of::<*const u8>()));
                   {
-                    let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind12, offset:offset12, len:len12, value:value12, } = e;
-                    let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id13, text:text13, is_keyword:is_keyword13, is_terminal:is_terminal13, } = kind12;
-                    *base.add(0).cast::<i32>() = _rt::as_i32(id13);
-                    let vec14 = (text13.into_bytes()).into_boxed_slice();
-                    let ptr14 = vec14.as_ptr().cast::<u8>();
-                    let len14 = vec14.len();
-                    ::core::mem::forget(vec14);
-                    *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len14;
-                    *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr14.cast_mut();
-                    *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword13 { true => 1, false => 0 }) as u8;
-                    *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal13 { true => 1, false => 0 }) as u8;
-                    *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset12);
-                    *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len12);
-                    match value12 {
+                    let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind4, offset:offset4, len:len4, value:value4, error:error4, } = e;
+                    let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id5, text:text5, is_keyword:is_keyword5, is_terminal:is_terminal5, } = kind4;
+                    *base.add(0).cast::<i32>() = _rt::as_i32(id5);
+                    let vec6 = (text5.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
+                    *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword5 { true => 1, false => 0 }) as u8;
+                    *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal5 { true => 1, false => 0 }) as u8;
+                    *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset4);
+                    *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len4);
+                    match value4 {
                       Some(e) => {
                         *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                        let vec15 = (e.into_bytes()).into_boxed_slice();
-                        let ptr15 = vec15.as_ptr().cast::<u8>();
-                        let len15 = vec15.len();
-                        ::core::mem::forget(vec15);
-                        *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len15;
-                        *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr15.cast_mut();
+                        let vec7 = (e.into_bytes()).into_boxed_slice();
+                        let ptr7 = vec7.as_ptr().cast::<u8>();
+                        let len7 = vec7.len();
+                        ::core::mem::forget(vec7);
+                        *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                        *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7.cast_mut();
                       },
                       None => {
                         {
                           *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                         }
                       },
+                    };match error4 {
+                      Some(e) => {
+                        *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                        use super::super::super::super::exports::ritalin::scanner::types::TokenError as V10;
+                        match e {
+                          V10::IllegalCharacter=> {
+                            {
+                              *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                            }
+                          }
+                          V10::Unterminated(e) => {
+                            *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id8, text:text8, is_keyword:is_keyword8, is_terminal:is_terminal8, } = e;
+                            *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id8);
+                            let vec9 = (text8.into_bytes()).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+                            *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr9.cast_mut();
+                            *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword8 { true => 1, false => 0 }) as u8;
+                            *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal8 { true => 1, false => 0 }) as u8;
+                          },
+                        }
+                      },
+                      None => {
+                        {
+                          *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                        }
+                      },
                     };}
                   }
-                  *ptr1.add(8+13*::core::mem::size_of::<*const u8>()).cast::<usize>() = len16;
-                  *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result16;
+                  *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len11;
+                  *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result11;
                 },
                 None => {
                   {
-                    *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                    *base.add(0).cast::<u8>() = (0i32) as u8;
                   }
                 },
-              };},
-              None => {
-                {
-                  *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                }
-              },
-            };ptr1
-          }
-          #[doc(hidden)]
-          #[allow(non_snake_case)]
-          pub unsafe fn __post_return_method_scanner_lookahead<T: GuestScanner>(arg0: *mut u8,) {
-            let l0 = i32::from(*arg0.add(0).cast::<u8>());
-            match l0 {
-              0 => (),
-              _ => {
-                let l1 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
-                match l1 {
-                  0 => (),
-                  _ => {
-                    let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                    let l3 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                    let base9 = l2;
-                    let len9 = l3;
-                    for i in 0..len9 {
-                      let base = base9.add(i * (8+7*::core::mem::size_of::<*const u8>()));
-                      {
-                        let l4 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                        let l5 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                        _rt::cabi_dealloc(l4, l5, 1);
-                        let l6 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                        match l6 {
-                          0 => (),
-                          _ => {
-                            let l7 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                            let l8 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                            _rt::cabi_dealloc(l7, l8, 1);
-                          },
-                        }
-                      }
-                    }
-                    _rt::cabi_dealloc(base9, len9 * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-                  },
-                }
-                let l10 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                let l11 = *arg0.add(6*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                _rt::cabi_dealloc(l10, l11, 1);
-                let l12 = i32::from(*arg0.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                match l12 {
-                  0 => (),
-                  _ => {
-                    let l13 = *arg0.add(8+9*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                    let l14 = *arg0.add(8+10*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                    _rt::cabi_dealloc(l13, l14, 1);
-                  },
-                }
-                let l15 = i32::from(*arg0.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                match l15 {
-                  0 => (),
-                  _ => {
-                    let l16 = *arg0.add(8+12*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                    let l17 = *arg0.add(8+13*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                    let base23 = l16;
-                    let len23 = l17;
-                    for i in 0..len23 {
-                      let base = base23.add(i * (8+7*::core::mem::size_of::<*const u8>()));
+              };let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind12, offset:offset12, len:len12, value:value12, error:error12, } = main3;
+              let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id13, text:text13, is_keyword:is_keyword13, is_terminal:is_terminal13, } = kind12;
+              *base.add(3*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id13);
+              let vec14 = (text13.into_bytes()).into_boxed_slice();
+              let ptr14 = vec14.as_ptr().cast::<u8>();
+              let len14 = vec14.len();
+              ::core::mem::forget(vec14);
+              *base.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len14;
+              *base.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr14.cast_mut();
+              *base.add(6*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword13 { true => 1, false => 0 }) as u8;
+              *base.add(1+6*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal13 { true => 1, false => 0 }) as u8;
+              *base.add(7*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset12);
+              *base.add(4+7*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len12);
+              match value12 {
+                Some(e) => {
+                  *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                  let vec15 = (e.into_bytes()).into_boxed_slice();
+                  let ptr15 = vec15.as_ptr().cast::<u8>();
+                  let len15 = vec15.len();
+                  ::core::mem::forget(vec15);
+                  *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<usize>() = len15;
+                  *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr15.cast_mut();
+                },
+                None => {
+                  {
+                    *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                  }
+                },
+              };match error12 {
+                Some(e) => {
+                  *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                  use super::super::super::super::exports::ritalin::scanner::types::TokenError as V18;
+                  match e {
+                    V18::IllegalCharacter=> {
                       {
-                        let l18 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                        let l19 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                        _rt::cabi_dealloc(l18, l19, 1);
-                        let l20 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                        match l20 {
-                          0 => (),
-                          _ => {
-                            let l21 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                            let l22 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                            _rt::cabi_dealloc(l21, l22, 1);
-                          },
-                        }
+                        *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                       }
                     }
-                    _rt::cabi_dealloc(base23, len23 * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-                  },
-                }
-              },
-            }
-          }
-          #[doc(hidden)]
-          #[allow(non_snake_case)]
-          pub unsafe fn _export_method_scanner_shift_cabi<T: GuestScanner>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
-          _rt::run_ctors_once();let result0 = T::shift(unsafe { ScannerBorrow::lift(arg0 as u32 as usize) }.get());
-          let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-          match result0 {
-            Some(e) => {
-              *ptr1.add(0).cast::<u8>() = (1i32) as u8;
-              let super::super::super::super::exports::ritalin::scanner::types::Token{ leading:leading2, main:main2, trailing:trailing2, } = e;
-              match leading2 {
+                    V18::Unterminated(e) => {
+                      *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                      let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id16, text:text16, is_keyword:is_keyword16, is_terminal:is_terminal16, } = e;
+                      *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id16);
+                      let vec17 = (text16.into_bytes()).into_boxed_slice();
+                      let ptr17 = vec17.as_ptr().cast::<u8>();
+                      let len17 = vec17.len();
+                      ::core::mem::forget(vec17);
+                      *base.add(8+14*::core::mem::size_of::<*const u8>()).cast::<usize>() = len17;
+                      *base.add(8+13*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr17.cast_mut();
+                      *base.add(8+15*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword16 { true => 1, false => 0 }) as u8;
+                      *base.add(9+15*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal16 { true => 1, false => 0 }) as u8;
+                    },
+                  }
+                },
+                None => {
+                  {
+                    *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                  }
+                },
+              };match trailing3 {
                 Some(e) => {
-                  *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                  let vec7 = e;
-                  let len7 = vec7.len();
-                  let layout7 = _rt::alloc::Layout::from_size_align_unchecked(vec7.len() * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-                  let result7 = if layout7.size() != 0 {
-                    let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                  *base.add(8+16*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                  let vec26 = e;
+                  let len26 = vec26.len();
+                  let layout26 = _rt::alloc::Layout::from_size_align_unchecked(vec26.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                  let result26 = if layout26.size() != 0 {
+                    let ptr = _rt::alloc::alloc(layout26).cast::<u8>();
                     if ptr.is_null()
                     {
-                      _rt::alloc::handle_alloc_error(layout7);
+                      _rt::alloc::handle_alloc_error(layout26);
                     }
                     ptr
                   }else {
                     ::core::ptr::null_mut()
                   };
-                  for (i, e) in vec7.into_iter().enumerate() {
-                    let base = result7.add(i * (8+7*::core::mem::size_of::<*const u8>()));
+                  for (i, e) in vec26.into_iter().enumerate() {
+                    let base = result26.add(i * (8+13*::core::mem::size_of::<*const u8>()));
                     {
-                      let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind3, offset:offset3, len:len3, value:value3, } = e;
-                      let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id4, text:text4, is_keyword:is_keyword4, is_terminal:is_terminal4, } = kind3;
-                      *base.add(0).cast::<i32>() = _rt::as_i32(id4);
-                      let vec5 = (text4.into_bytes()).into_boxed_slice();
-                      let ptr5 = vec5.as_ptr().cast::<u8>();
-                      let len5 = vec5.len();
-                      ::core::mem::forget(vec5);
-                      *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
-                      *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
-                      *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword4 { true => 1, false => 0 }) as u8;
-                      *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal4 { true => 1, false => 0 }) as u8;
-                      *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset3);
-                      *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len3);
-                      match value3 {
+                      let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind19, offset:offset19, len:len19, value:value19, error:error19, } = e;
+                      let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id20, text:text20, is_keyword:is_keyword20, is_terminal:is_terminal20, } = kind19;
+                      *base.add(0).cast::<i32>() = _rt::as_i32(id20);
+                      let vec21 = (text20.into_bytes()).into_boxed_slice();
+                      let ptr21 = vec21.as_ptr().cast::<u8>();
+                      let len21 = vec21.len();
+                      ::core::mem::forget(vec21);
+                      *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len21;
+                      *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr21.cast_mut();
+                      *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword20 { true => 1, false => 0 }) as u8;
+                      *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal20 { true => 1, false => 0 }) as u8;
+                      *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset19);
+                      *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len19);
+                      match value19 {
                         Some(e) => {
                           *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                          let vec6 = (e.into_bytes()).into_boxed_slice();
-                          let ptr6 = vec6.as_ptr().cast::<u8>();
-                          let len6 = vec6.len();
-                          ::core::mem::forget(vec6);
-                          *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
-                          *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
+                          let vec22 = (e.into_bytes()).into_boxed_slice();
+                          let ptr22 = vec22.as_ptr().cast::<u8>();
+                          let len22 = vec22.len();
+                          ::core::mem::forget(vec22);
+                          *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len22;
+                          *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr22.cast_mut();
                         },
                         None => {
                           {
                             *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                           }
                         },
+                      };match error19 {
+                        Some(e) => {
+                          *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                          use super::super::super::super::exports::ritalin::scanner::types::TokenError as V25;
+                          match e {
+                            V25::IllegalCharacter=> {
+                              {
+                                *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                              }
+                            }
+                            V25::Unterminated(e) => {
+                              *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                              let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id23, text:text23, is_keyword:is_keyword23, is_terminal:is_terminal23, } = e;
+                              *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id23);
+                              let vec24 = (text23.into_bytes()).into_boxed_slice();
+                              let ptr24 = vec24.as_ptr().cast::<u8>();
+                              let len24 = vec24.len();
+                              ::core::mem::forget(vec24);
+                              *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len24;
+                              *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr24.cast_mut();
+                              *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword23 { true => 1, false => 0 }) as u8;
+                              *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal23 { true => 1, false => 0 }) as u8;
+                            },
+                          }
+                        },
+                        None => {
+                          {
+                            *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                          }
+                        },
                       };}
                     }
-                    *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
-                    *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result7;
+                    *base.add(8+18*::core::mem::size_of::<*const u8>()).cast::<usize>() = len26;
+                    *base.add(8+17*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result26;
                   },
                   None => {
                     {
-                      *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                      *base.add(8+16*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                     }
                   },
-                };let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind8, offset:offset8, len:len8, value:value8, } = main2;
-                let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id9, text:text9, is_keyword:is_keyword9, is_terminal:is_terminal9, } = kind8;
-                *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id9);
-                let vec10 = (text9.into_bytes()).into_boxed_slice();
-                let ptr10 = vec10.as_ptr().cast::<u8>();
-                let len10 = vec10.len();
-                ::core::mem::forget(vec10);
-                *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len10;
-                *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr10.cast_mut();
-                *ptr1.add(7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword9 { true => 1, false => 0 }) as u8;
-                *ptr1.add(1+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal9 { true => 1, false => 0 }) as u8;
-                *ptr1.add(8*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset8);
-                *ptr1.add(4+8*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len8);
-                match value8 {
-                  Some(e) => {
-                    *ptr1.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                    let vec11 = (e.into_bytes()).into_boxed_slice();
-                    let ptr11 = vec11.as_ptr().cast::<u8>();
-                    let len11 = vec11.len();
-                    ::core::mem::forget(vec11);
-                    *ptr1.add(8+10*::core::mem::size_of::<*const u8>()).cast::<usize>() = len11;
-                    *ptr1.add(8+9*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr11.cast_mut();
+                };}
+              }
+              *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len27;
+              *ptr2.add(0).cast::<*mut u8>() = result27;
+              ptr2
+            }
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            pub unsafe fn __post_return_tokenize<T: Guest>(arg0: *mut u8,) {
+              let l0 = *arg0.add(0).cast::<*mut u8>();
+              let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+              let base37 = l0;
+              let len37 = l1;
+              for i in 0..len37 {
+                let base = base37.add(i * (8+19*::core::mem::size_of::<*const u8>()));
+                {
+                  let l2 = i32::from(*base.add(0).cast::<u8>());
+                  match l2 {
+                    0 => (),
+                    _ => {
+                      let l3 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                      let l4 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                      let base14 = l3;
+                      let len14 = l4;
+                      for i in 0..len14 {
+                        let base = base14.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                        {
+                          let l5 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                          let l6 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                          _rt::cabi_dealloc(l5, l6, 1);
+                          let l7 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                          match l7 {
+                            0 => (),
+                            _ => {
+                              let l8 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                              let l9 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                              _rt::cabi_dealloc(l8, l9, 1);
+                            },
+                          }
+                          let l10 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                          match l10 {
+                            0 => (),
+                            _ => {
+                              let l11 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                              match l11 {
+                                0 => (),
+                                _ => {
+                                  let l12 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                  let l13 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                  _rt::cabi_dealloc(l12, l13, 1);
+                                },
+                              }
+                            },
+                          }
+                        }
+                      }
+                      _rt::cabi_dealloc(base14, len14 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                    },
+                  }
+                  let l15 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                  let l16 = *base.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                  _rt::cabi_dealloc(l15, l16, 1);
+                  let l17 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                  match l17 {
+                    0 => (),
+                    _ => {
+                      let l18 = *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                      let l19 = *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                      _rt::cabi_dealloc(l18, l19, 1);
+                    },
+                  }
+                  let l20 = i32::from(*base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                  match l20 {
+                    0 => (),
+                    _ => {
+                      let l21 = i32::from(*base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                      match l21 {
+                        0 => (),
+                        _ => {
+                          let l22 = *base.add(8+13*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                          let l23 = *base.add(8+14*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                          _rt::cabi_dealloc(l22, l23, 1);
+                        },
+                      }
+                    },
+                  }
+                  let l24 = i32::from(*base.add(8+16*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                  match l24 {
+                    0 => (),
+                    _ => {
+                      let l25 = *base.add(8+17*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                      let l26 = *base.add(8+18*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                      let base36 = l25;
+                      let len36 = l26;
+                      for i in 0..len36 {
+                        let base = base36.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                        {
+                          let l27 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                          let l28 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                          _rt::cabi_dealloc(l27, l28, 1);
+                          let l29 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                          match l29 {
+                            0 => (),
+                            _ => {
+                              let l30 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                              let l31 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                              _rt::cabi_dealloc(l30, l31, 1);
+                            },
+                          }
+                          let l32 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                          match l32 {
+                            0 => (),
+                            _ => {
+                              let l33 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                              match l33 {
+                                0 => (),
+                                _ => {
+                                  let l34 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                  let l35 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                  _rt::cabi_dealloc(l34, l35, 1);
+                                },
+                              }
+                            },
+                          }
+                        }
+                      }
+                      _rt::cabi_dealloc(base36, len36 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                    },
+                  }
+                }
+              }
+              _rt::cabi_dealloc(base37, len37 * (8+19*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+            }
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            pub unsafe fn _export_relex_cabi<T: Guest>(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: i32,arg5: i32,arg6: i32,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+            _rt::run_ctors_once();let len0 = arg1;
+            let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+            let base72 = arg2;
+            let len72 = arg3;
+            let mut result72 = _rt::Vec::with_capacity(len72);
+            for i in 0..len72 {
+              let base = base72.add(i * (8+19*::core::mem::size_of::<*const u8>()));
+              let e72 = {
+                let l1 = i32::from(*base.add(0).cast::<u8>());
+                let l26 = *base.add(3*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                let l27 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                let l28 = *base.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                let len29 = l28;
+                let bytes29 = _rt::Vec::from_raw_parts(l27.cast(), len29, len29);
+                let l30 = i32::from(*base.add(6*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                let l31 = i32::from(*base.add(1+6*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                let l32 = *base.add(7*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                let l33 = *base.add(4+7*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                let l34 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                let l38 = i32::from(*base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                let l47 = i32::from(*base.add(8+16*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                super::super::super::super::exports::ritalin::scanner::types::Token{
+                  leading: match l1 {
+                    0 => None,
+                    1 => {
+                      let e = {
+                        let l2 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l3 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let base25 = l2;
+                        let len25 = l3;
+                        let mut result25 = _rt::Vec::with_capacity(len25);
+                        for i in 0..len25 {
+                          let base = base25.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                          let e25 = {
+                            let l4 = *base.add(0).cast::<i32>();
+                            let l5 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                            let l6 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                            let len7 = l6;
+                            let bytes7 = _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+                            let l8 = i32::from(*base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            let l9 = i32::from(*base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            let l10 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                            let l11 = *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                            let l12 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            let l16 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                            super::super::super::super::exports::ritalin::scanner::types::TokenItem{
+                              kind: super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                id: l4 as u32,
+                                text: _rt::string_lift(bytes7),
+                                is_keyword: _rt::bool_lift(l8 as u8),
+                                is_terminal: _rt::bool_lift(l9 as u8),
+                              },
+                              offset: l10 as u32,
+                              len: l11 as u32,
+                              value: match l12 {
+                                0 => None,
+                                1 => {
+                                  let e = {
+                                    let l13 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                    let l14 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                    let len15 = l14;
+                                    let bytes15 = _rt::Vec::from_raw_parts(l13.cast(), len15, len15);
+
+                                    _rt::string_lift(bytes15)
+                                  };
+                                  Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                              },
+                              error: match l16 {
+                                0 => None,
+                                1 => {
+                                  let e = {
+                                    let l17 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                    use super::super::super::super::exports::ritalin::scanner::types::TokenError as V24;
+                                    let v24 = match l17 {
+                                      0 => {
+                                        V24::IllegalCharacter
+                                      }
+                                      n => {
+                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                        let e24 = {
+                                          let l18 = *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                          let l19 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                          let l20 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                          let len21 = l20;
+                                          let bytes21 = _rt::Vec::from_raw_parts(l19.cast(), len21, len21);
+                                          let l22 = i32::from(*base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                          let l23 = i32::from(*base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                                          super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                            id: l18 as u32,
+                                            text: _rt::string_lift(bytes21),
+                                            is_keyword: _rt::bool_lift(l22 as u8),
+                                            is_terminal: _rt::bool_lift(l23 as u8),
+                                          }
+                                        };
+                                        V24::Unterminated(e24)
+                                      }
+                                    };
+
+                                    v24
+                                  };
+                                  Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                              },
+                            }
+                          };
+                          result25.push(e25);
+                        }
+                        _rt::cabi_dealloc(base25, len25 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+
+                        result25
+                      };
+                      Some(e)
+                    }
+                    _ => _rt::invalid_enum_discriminant(),
                   },
-                  None => {
-                    {
-                      *ptr1.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                  main: super::super::super::super::exports::ritalin::scanner::types::TokenItem{
+                    kind: super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                      id: l26 as u32,
+                      text: _rt::string_lift(bytes29),
+                      is_keyword: _rt::bool_lift(l30 as u8),
+                      is_terminal: _rt::bool_lift(l31 as u8),
+                    },
+                    offset: l32 as u32,
+                    len: l33 as u32,
+                    value: match l34 {
+                      0 => None,
+                      1 => {
+                        let e = {
+                          let l35 = *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                          let l36 = *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                          let len37 = l36;
+                          let bytes37 = _rt::Vec::from_raw_parts(l35.cast(), len37, len37);
+
+                          _rt::string_lift(bytes37)
+                        };
+                        Some(e)
+                      }
+                      _ => _rt::invalid_enum_discriminant(),
+                    },
+                    error: match l38 {
+                      0 => None,
+                      1 => {
+                        let e = {
+                          let l39 = i32::from(*base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                          use super::super::super::super::exports::ritalin::scanner::types::TokenError as V46;
+                          let v46 = match l39 {
+                            0 => {
+                              V46::IllegalCharacter
+                            }
+                            n => {
+                              debug_assert_eq!(n, 1, "invalid enum discriminant");
+                              let e46 = {
+                                let l40 = *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                let l41 = *base.add(8+13*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                let l42 = *base.add(8+14*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                let len43 = l42;
+                                let bytes43 = _rt::Vec::from_raw_parts(l41.cast(), len43, len43);
+                                let l44 = i32::from(*base.add(8+15*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                let l45 = i32::from(*base.add(9+15*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                                super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                  id: l40 as u32,
+                                  text: _rt::string_lift(bytes43),
+                                  is_keyword: _rt::bool_lift(l44 as u8),
+                                  is_terminal: _rt::bool_lift(l45 as u8),
+                                }
+                              };
+                              V46::Unterminated(e46)
+                            }
+                          };
+
+                          v46
+                        };
+                        Some(e)
+                      }
+                      _ => _rt::invalid_enum_discriminant(),
+                    },
+                  },
+                  trailing: match l47 {
+                    0 => None,
+                    1 => {
+                      let e = {
+                        let l48 = *base.add(8+17*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l49 = *base.add(8+18*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        let base71 = l48;
+                        let len71 = l49;
+                        let mut result71 = _rt::Vec::with_capacity(len71);
+                        for i in 0..len71 {
+                          let base = base71.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                          let e71 = {
+                            let l50 = *base.add(0).cast::<i32>();
+                            let l51 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                            let l52 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                            let len53 = l52;
+                            let bytes53 = _rt::Vec::from_raw_parts(l51.cast(), len53, len53);
+                            let l54 = i32::from(*base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            let l55 = i32::from(*base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            let l56 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                            let l57 = *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                            let l58 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            let l62 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                            super::super::super::super::exports::ritalin::scanner::types::TokenItem{
+                              kind: super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                id: l50 as u32,
+                                text: _rt::string_lift(bytes53),
+                                is_keyword: _rt::bool_lift(l54 as u8),
+                                is_terminal: _rt::bool_lift(l55 as u8),
+                              },
+                              offset: l56 as u32,
+                              len: l57 as u32,
+                              value: match l58 {
+                                0 => None,
+                                1 => {
+                                  let e = {
+                                    let l59 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                    let l60 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                    let len61 = l60;
+                                    let bytes61 = _rt::Vec::from_raw_parts(l59.cast(), len61, len61);
+
+                                    _rt::string_lift(bytes61)
+                                  };
+                                  Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                              },
+                              error: match l62 {
+                                0 => None,
+                                1 => {
+                                  let e = {
+                                    let l63 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                    use super::super::super::super::exports::ritalin::scanner::types::TokenError as V70;
+                                    let v70 = match l63 {
+                                      0 => {
+                                        V70::IllegalCharacter
+                                      }
+                                      n => {
+                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                        let e70 = {
+                                          let l64 = *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                          let l65 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                          let l66 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                          let len67 = l66;
+                                          let bytes67 = _rt::Vec::from_raw_parts(l65.cast(), len67, len67);
+                                          let l68 = i32::from(*base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                          let l69 = i32::from(*base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                                          super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                            id: l64 as u32,
+                                            text: _rt::string_lift(bytes67),
+                                            is_keyword: _rt::bool_lift(l68 as u8),
+                                            is_terminal: _rt::bool_lift(l69 as u8),
+                                          }
+                                        };
+                                        V70::Unterminated(e70)
+                                      }
+                                    };
+
+                                    v70
+                                  };
+                                  Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                              },
+                            }
+                          };
+                          result71.push(e71);
+                        }
+                        _rt::cabi_dealloc(base71, len71 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+
+                        result71
+                      };
+                      Some(e)
                     }
+                    _ => _rt::invalid_enum_discriminant(),
                   },
-                };match trailing2 {
+                }
+              };
+              result72.push(e72);
+            }
+            _rt::cabi_dealloc(base72, len72 * (8+19*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+            let result73 = T::relex(_rt::string_lift(bytes0), result72, super::super::super::super::exports::ritalin::scanner::types::EditScope{
+              offset: arg4 as u32,
+              from_len: arg5 as u32,
+              to_len: arg6 as u32,
+            });
+            let ptr74 = (&raw mut _RET_AREA.0).cast::<u8>();
+            let vec99 = result73;
+            let len99 = vec99.len();
+            let layout99 = _rt::alloc::Layout::from_size_align_unchecked(vec99.len() * (8+19*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+            let result99 = if layout99.size() != 0 {
+              let ptr = _rt::alloc::alloc(layout99).cast::<u8>();
+              if ptr.is_null()
+              {
+                _rt::alloc::handle_alloc_error(layout99);
+              }
+              ptr
+            }else {
+              ::core::ptr::null_mut()
+            };
+            for (i, e) in vec99.into_iter().enumerate() {
+              let base = result99.add(i * (8+19*::core::mem::size_of::<*const u8>()));
+              {
+                let super::super::super::super::exports::ritalin::scanner::types::Token{ leading:leading75, main:main75, trailing:trailing75, } = e;
+                match leading75 {
                   Some(e) => {
-                    *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                    let vec16 = e;
-                    let len16 = vec16.len();
-                    let layout16 = _rt::alloc::Layout::from_size_align_unchecked(vec16.len() * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-                    let result16 = if layout16.size() != 0 {
-                      let ptr = _rt::alloc::alloc(layout16).cast::<u8>();
+                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                    let vec83 = e;
+                    let len83 = vec83.len();
+                    let layout83 = _rt::alloc::Layout::from_size_align_unchecked(vec83.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                    let result83 = if layout83.size() != 0 {
+                      let ptr = _rt::alloc::alloc(layout83).cast::<u8>();
                       if ptr.is_null()
                       {
-                        _rt::alloc::handle_alloc_error(layout16);
+                        _rt::alloc::handle_alloc_error(layout83);
                       }
                       ptr
                     }else {
                       ::core::ptr::null_mut()
                     };
-                    for (i, e) in vec16.into_iter().enumerate() {
-                      let base = result16.add(i * (8+7*::core::mem::size_of::<*const u8>()));
+                    for (i, e) in vec83.into_iter().enumerate() {
+                      let base = result83.add(i * (8+13*::core::mem::size_of::<*const u8>()));
                       {
-                        let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind12, offset:offset12, len:len12, value:value12, } = e;
-                        let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id13, text:text13, is_keyword:is_keyword13, is_terminal:is_terminal13, } = kind12;
-                        *base.add(0).cast::<i32>() = _rt::as_i32(id13);
-                        let vec14 = (text13.into_bytes()).into_boxed_slice();
-                        let ptr14 = vec14.as_ptr().cast::<u8>();
-                        let len14 = vec14.len();
-                        ::core::mem::forget(vec14);
-                        *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len14;
-                        *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr14.cast_mut();
-                        *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword13 { true => 1, false => 0 }) as u8;
-                        *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal13 { true => 1, false => 0 }) as u8;
-                        *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset12);
-                        *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len12);
-                        match value12 {
+                        let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind76, offset:offset76, len:len76, value:value76, error:error76, } = e;
+                        let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id77, text:text77, is_keyword:is_keyword77, is_terminal:is_terminal77, } = kind76;
+                        *base.add(0).cast::<i32>() = _rt::as_i32(id77);
+                        let vec78 = (text77.into_bytes()).into_boxed_slice();
+                        let ptr78 = vec78.as_ptr().cast::<u8>();
+                        let len78 = vec78.len();
+                        ::core::mem::forget(vec78);
+                        *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len78;
+                        *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr78.cast_mut();
+                        *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword77 { true => 1, false => 0 }) as u8;
+                        *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal77 { true => 1, false => 0 }) as u8;
+                        *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset76);
+                        *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len76);
+                        match value76 {
                           Some(e) => {
                             *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                            let vec15 = (e.into_bytes()).into_boxed_slice();
-                            let ptr15 = vec15.as_ptr().cast::<u8>();
-                            let len15 = vec15.len();
-                            ::core::mem::forget(vec15);
-                            *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len15;
-                            *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr15.cast_mut();
+                            let vec79 = (e.into_bytes()).into_boxed_slice();
+                            let ptr79 = vec79.as_ptr().cast::<u8>();
+                            let len79 = vec79.len();
+                            ::core::mem::forget(vec79);
+                            *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len79;
+                            *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr79.cast_mut();
                           },
                           None => {
                             {
                               *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                             }
                           },
+                        };match error76 {
+                          Some(e) => {
+                            *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::ritalin::scanner::types::TokenError as V82;
+                            match e {
+                              V82::IllegalCharacter=> {
+                                {
+                                  *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                }
+                              }
+                              V82::Unterminated(e) => {
+                                *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id80, text:text80, is_keyword:is_keyword80, is_terminal:is_terminal80, } = e;
+                                *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id80);
+                                let vec81 = (text80.into_bytes()).into_boxed_slice();
+                                let ptr81 = vec81.as_ptr().cast::<u8>();
+                                let len81 = vec81.len();
+                                ::core::mem::forget(vec81);
+                                *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len81;
+                                *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr81.cast_mut();
+                                *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword80 { true => 1, false => 0 }) as u8;
+                                *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal80 { true => 1, false => 0 }) as u8;
+                              },
+                            }
+                          },
+                          None => {
+                            {
+                              *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                            }
+                          },
                         };}
                       }
-                      *ptr1.add(8+13*::core::mem::size_of::<*const u8>()).cast::<usize>() = len16;
-                      *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result16;
+                      *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len83;
+                      *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result83;
                     },
                     None => {
                       {
-                        *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                        *base.add(0).cast::<u8>() = (0i32) as u8;
                       }
                     },
-                  };},
-                  None => {
-                    {
-                      *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                    }
-                  },
-                };ptr1
-              }
-              #[doc(hidden)]
-              #[allow(non_snake_case)]
-              pub unsafe fn __post_return_method_scanner_shift<T: GuestScanner>(arg0: *mut u8,) {
-                let l0 = i32::from(*arg0.add(0).cast::<u8>());
-                match l0 {
-                  0 => (),
-                  _ => {
-                    let l1 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
-                    match l1 {
-                      0 => (),
-                      _ => {
-                        let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                        let l3 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                        let base9 = l2;
-                        let len9 = l3;
-                        for i in 0..len9 {
-                          let base = base9.add(i * (8+7*::core::mem::size_of::<*const u8>()));
+                  };let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind84, offset:offset84, len:len84, value:value84, error:error84, } = main75;
+                  let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id85, text:text85, is_keyword:is_keyword85, is_terminal:is_terminal85, } = kind84;
+                  *base.add(3*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id85);
+                  let vec86 = (text85.into_bytes()).into_boxed_slice();
+                  let ptr86 = vec86.as_ptr().cast::<u8>();
+                  let len86 = vec86.len();
+                  ::core::mem::forget(vec86);
+                  *base.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>() = len86;
+                  *base.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr86.cast_mut();
+                  *base.add(6*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword85 { true => 1, false => 0 }) as u8;
+                  *base.add(1+6*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal85 { true => 1, false => 0 }) as u8;
+                  *base.add(7*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset84);
+                  *base.add(4+7*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len84);
+                  match value84 {
+                    Some(e) => {
+                      *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                      let vec87 = (e.into_bytes()).into_boxed_slice();
+                      let ptr87 = vec87.as_ptr().cast::<u8>();
+                      let len87 = vec87.len();
+                      ::core::mem::forget(vec87);
+                      *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<usize>() = len87;
+                      *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr87.cast_mut();
+                    },
+                    None => {
+                      {
+                        *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                      }
+                    },
+                  };match error84 {
+                    Some(e) => {
+                      *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                      use super::super::super::super::exports::ritalin::scanner::types::TokenError as V90;
+                      match e {
+                        V90::IllegalCharacter=> {
                           {
-                            let l4 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                            let l5 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                            _rt::cabi_dealloc(l4, l5, 1);
-                            let l6 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                            match l6 {
-                              0 => (),
-                              _ => {
-                                let l7 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                let l8 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                _rt::cabi_dealloc(l7, l8, 1);
-                              },
-                            }
+                            *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                           }
                         }
-                        _rt::cabi_dealloc(base9, len9 * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-                      },
-                    }
-                    let l10 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                    let l11 = *arg0.add(6*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                    _rt::cabi_dealloc(l10, l11, 1);
-                    let l12 = i32::from(*arg0.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                    match l12 {
-                      0 => (),
-                      _ => {
-                        let l13 = *arg0.add(8+9*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                        let l14 = *arg0.add(8+10*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                        _rt::cabi_dealloc(l13, l14, 1);
+                        V90::Unterminated(e) => {
+                          *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                          let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id88, text:text88, is_keyword:is_keyword88, is_terminal:is_terminal88, } = e;
+                          *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id88);
+                          let vec89 = (text88.into_bytes()).into_boxed_slice();
+                          let ptr89 = vec89.as_ptr().cast::<u8>();
+                          let len89 = vec89.len();
+                          ::core::mem::forget(vec89);
+                          *base.add(8+14*::core::mem::size_of::<*const u8>()).cast::<usize>() = len89;
+                          *base.add(8+13*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr89.cast_mut();
+                          *base.add(8+15*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword88 { true => 1, false => 0 }) as u8;
+                          *base.add(9+15*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal88 { true => 1, false => 0 }) as u8;
+                        },
+                      }
+                    },
+                    None => {
+                      {
+                        *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                      }
+                    },
+                  };match trailing75 {
+                    Some(e) => {
+                      *base.add(8+16*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                      let vec98 = e;
+                      let len98 = vec98.len();
+                      let layout98 = _rt::alloc::Layout::from_size_align_unchecked(vec98.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                      let result98 = if layout98.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout98).cast::<u8>();
+                        if ptr.is_null()
+                        {
+                          _rt::alloc::handle_alloc_error(layout98);
+                        }
+                        ptr
+                      }else {
+                        ::core::ptr::null_mut()
+                      };
+                      for (i, e) in vec98.into_iter().enumerate() {
+                        let base = result98.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                        {
+                          let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind91, offset:offset91, len:len91, value:value91, error:error91, } = e;
+                          let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id92, text:text92, is_keyword:is_keyword92, is_terminal:is_terminal92, } = kind91;
+                          *base.add(0).cast::<i32>() = _rt::as_i32(id92);
+                          let vec93 = (text92.into_bytes()).into_boxed_slice();
+                          let ptr93 = vec93.as_ptr().cast::<u8>();
+                          let len93 = vec93.len();
+                          ::core::mem::forget(vec93);
+                          *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len93;
+                          *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr93.cast_mut();
+                          *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword92 { true => 1, false => 0 }) as u8;
+                          *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal92 { true => 1, false => 0 }) as u8;
+                          *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset91);
+                          *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len91);
+                          match value91 {
+                            Some(e) => {
+                              *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                              let vec94 = (e.into_bytes()).into_boxed_slice();
+                              let ptr94 = vec94.as_ptr().cast::<u8>();
+                              let len94 = vec94.len();
+                              ::core::mem::forget(vec94);
+                              *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len94;
+                              *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr94.cast_mut();
+                            },
+                            None => {
+                              {
+                                *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                              }
+                            },
+                          };match error91 {
+                            Some(e) => {
+                              *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                              use super::super::super::super::exports::ritalin::scanner::types::TokenError as V97;
+                              match e {
+                                V97::IllegalCharacter=> {
+                                  {
+                                    *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                  }
+                                }
+                                V97::Unterminated(e) => {
+                                  *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                  let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id95, text:text95, is_keyword:is_keyword95, is_terminal:is_terminal95, } = e;
+                                  *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id95);
+                                  let vec96 = (text95.into_bytes()).into_boxed_slice();
+                                  let ptr96 = vec96.as_ptr().cast::<u8>();
+                                  let len96 = vec96.len();
+                                  ::core::mem::forget(vec96);
+                                  *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len96;
+                                  *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr96.cast_mut();
+                                  *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword95 { true => 1, false => 0 }) as u8;
+                                  *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal95 { true => 1, false => 0 }) as u8;
+                                },
+                              }
+                            },
+                            None => {
+                              {
+                                *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                              }
+                            },
+                          };}
+                        }
+                        *base.add(8+18*::core::mem::size_of::<*const u8>()).cast::<usize>() = len98;
+                        *base.add(8+17*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result98;
                       },
-                    }
-                    let l15 = i32::from(*arg0.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                    match l15 {
-                      0 => (),
-                      _ => {
-                        let l16 = *arg0.add(8+12*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                        let l17 = *arg0.add(8+13*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                        let base23 = l16;
-                        let len23 = l17;
-                        for i in 0..len23 {
-                          let base = base23.add(i * (8+7*::core::mem::size_of::<*const u8>()));
-                          {
-                            let l18 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                            let l19 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                            _rt::cabi_dealloc(l18, l19, 1);
-                            let l20 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                            match l20 {
-                              0 => (),
-                              _ => {
-                                let l21 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                let l22 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                _rt::cabi_dealloc(l21, l22, 1);
-                              },
-                            }
-                          }
+                      None => {
+                        {
+                          *base.add(8+16*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                         }
-                        _rt::cabi_dealloc(base23, len23 * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
                       },
+                    };}
+                  }
+                  *ptr74.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len99;
+                  *ptr74.add(0).cast::<*mut u8>() = result99;
+                  ptr74
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_relex<T: Guest>(arg0: *mut u8,) {
+                  let l0 = *arg0.add(0).cast::<*mut u8>();
+                  let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+                  let base37 = l0;
+                  let len37 = l1;
+                  for i in 0..len37 {
+                    let base = base37.add(i * (8+19*::core::mem::size_of::<*const u8>()));
+                    {
+                      let l2 = i32::from(*base.add(0).cast::<u8>());
+                      match l2 {
+                        0 => (),
+                        _ => {
+                          let l3 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                          let l4 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                          let base14 = l3;
+                          let len14 = l4;
+                          for i in 0..len14 {
+                            let base = base14.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                            {
+                              let l5 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                              let l6 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                              _rt::cabi_dealloc(l5, l6, 1);
+                              let l7 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                              match l7 {
+                                0 => (),
+                                _ => {
+                                  let l8 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                  let l9 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                  _rt::cabi_dealloc(l8, l9, 1);
+                                },
+                              }
+                              let l10 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                              match l10 {
+                                0 => (),
+                                _ => {
+                                  let l11 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                  match l11 {
+                                    0 => (),
+                                    _ => {
+                                      let l12 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                      let l13 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                      _rt::cabi_dealloc(l12, l13, 1);
+                                    },
+                                  }
+                                },
+                              }
+                            }
+                          }
+                          _rt::cabi_dealloc(base14, len14 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                        },
+                      }
+                      let l15 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                      let l16 = *base.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                      _rt::cabi_dealloc(l15, l16, 1);
+                      let l17 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                      match l17 {
+                        0 => (),
+                        _ => {
+                          let l18 = *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                          let l19 = *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                          _rt::cabi_dealloc(l18, l19, 1);
+                        },
+                      }
+                      let l20 = i32::from(*base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                      match l20 {
+                        0 => (),
+                        _ => {
+                          let l21 = i32::from(*base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                          match l21 {
+                            0 => (),
+                            _ => {
+                              let l22 = *base.add(8+13*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                              let l23 = *base.add(8+14*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                              _rt::cabi_dealloc(l22, l23, 1);
+                            },
+                          }
+                        },
+                      }
+                      let l24 = i32::from(*base.add(8+16*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                      match l24 {
+                        0 => (),
+                        _ => {
+                          let l25 = *base.add(8+17*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                          let l26 = *base.add(8+18*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                          let base36 = l25;
+                          let len36 = l26;
+                          for i in 0..len36 {
+                            let base = base36.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                            {
+                              let l27 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                              let l28 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                              _rt::cabi_dealloc(l27, l28, 1);
+                              let l29 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                              match l29 {
+                                0 => (),
+                                _ => {
+                                  let l30 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                  let l31 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                  _rt::cabi_dealloc(l30, l31, 1);
+                                },
+                              }
+                              let l32 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                              match l32 {
+                                0 => (),
+                                _ => {
+                                  let l33 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                  match l33 {
+                                    0 => (),
+                                    _ => {
+                                      let l34 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                      let l35 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                      _rt::cabi_dealloc(l34, l35, 1);
+                                    },
+                                  }
+                                },
+                              }
+                            }
+                          }
+                          _rt::cabi_dealloc(base36, len36 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                        },
+                      }
                     }
-                  },
+                  }
+                  _rt::cabi_dealloc(base37, len37 * (8+19*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
                 }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_static_scanner_create_cabi<T: GuestScanner>(arg0: *mut u8,arg1: usize,arg2: i32,) -> i32 {#[cfg(target_arch="wasm32")]
+                _rt::run_ctors_once();let len0 = arg1;
+                let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                let result1 = T::create(_rt::string_lift(bytes0), arg2 as u32);
+                (result1).take_handle() as i32
               }
               #[doc(hidden)]
               #[allow(non_snake_case)]
-              pub unsafe fn _export_method_scanner_scope_cabi<T: GuestScanner>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
-              _rt::run_ctors_once();let result0 = T::scope(unsafe { ScannerBorrow::lift(arg0 as u32 as usize) }.get());
+              pub unsafe fn _export_method_scanner_lookahead_cabi<T: GuestScanner>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+              _rt::run_ctors_once();let result0 = T::lookahead(unsafe { ScannerBorrow::lift(arg0 as u32 as usize) }.get());
               let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-              let super::super::super::super::exports::ritalin::scanner::types::ScannerScope{ next_index:next_index2, lookahead:lookahead2, } = result0;
-              *ptr1.add(0).cast::<i32>() = _rt::as_i32(next_index2);
-              match lookahead2 {
+              match result0 {
                 Some(e) => {
-                  *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                  let super::super::super::super::exports::ritalin::scanner::types::Token{ leading:leading3, main:main3, trailing:trailing3, } = e;
-                  match leading3 {
+                  *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                  let super::super::super::super::exports::ritalin::scanner::types::Token{ leading:leading2, main:main2, trailing:trailing2, } = e;
+                  match leading2 {
                     Some(e) => {
-                      *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                      let vec8 = e;
-                      let len8 = vec8.len();
-                      let layout8 = _rt::alloc::Layout::from_size_align_unchecked(vec8.len() * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-                      let result8 = if layout8.size() != 0 {
-                        let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                      *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                      let vec10 = e;
+                      let len10 = vec10.len();
+                      let layout10 = _rt::alloc::Layout::from_size_align_unchecked(vec10.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                      let result10 = if layout10.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
                         if ptr.is_null()
                         {
-                          _rt::alloc::handle_alloc_error(layout8);
+                          _rt::alloc::handle_alloc_error(layout10);
                         }
                         ptr
                       }else {
                         ::core::ptr::null_mut()
                       };
-                      for (i, e) in vec8.into_iter().enumerate() {
-                        let base = result8.add(i * (8+7*::core::mem::size_of::<*const u8>()));
+                      for (i, e) in vec10.into_iter().enumerate() {
+                        let base = result10.add(i * (8+13*::core::mem::size_of::<*const u8>()));
                         {
-                          let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind4, offset:offset4, len:len4, value:value4, } = e;
-                          let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id5, text:text5, is_keyword:is_keyword5, is_terminal:is_terminal5, } = kind4;
-                          *base.add(0).cast::<i32>() = _rt::as_i32(id5);
-                          let vec6 = (text5.into_bytes()).into_boxed_slice();
-                          let ptr6 = vec6.as_ptr().cast::<u8>();
-                          let len6 = vec6.len();
-                          ::core::mem::forget(vec6);
-                          *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
-                          *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
-                          *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword5 { true => 1, false => 0 }) as u8;
-                          *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal5 { true => 1, false => 0 }) as u8;
-                          *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset4);
-                          *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len4);
-                          match value4 {
+                          let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind3, offset:offset3, len:len3, value:value3, error:error3, } = e;
+                          let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id4, text:text4, is_keyword:is_keyword4, is_terminal:is_terminal4, } = kind3;
+                          *base.add(0).cast::<i32>() = _rt::as_i32(id4);
+                          let vec5 = (text4.into_bytes()).into_boxed_slice();
+                          let ptr5 = vec5.as_ptr().cast::<u8>();
+                          let len5 = vec5.len();
+                          ::core::mem::forget(vec5);
+                          *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                          *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
+                          *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword4 { true => 1, false => 0 }) as u8;
+                          *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal4 { true => 1, false => 0 }) as u8;
+                          *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset3);
+                          *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len3);
+                          match value3 {
                             Some(e) => {
                               *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                              let vec7 = (e.into_bytes()).into_boxed_slice();
-                              let ptr7 = vec7.as_ptr().cast::<u8>();
-                              let len7 = vec7.len();
-                              ::core::mem::forget(vec7);
-                              *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
-                              *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7.cast_mut();
+                              let vec6 = (e.into_bytes()).into_boxed_slice();
+                              let ptr6 = vec6.as_ptr().cast::<u8>();
+                              let len6 = vec6.len();
+                              ::core::mem::forget(vec6);
+                              *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                              *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
                             },
                             None => {
                               {
                                 *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                               }
                             },
+                          };match error3 {
+                            Some(e) => {
+                              *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                              use super::super::super::super::exports::ritalin::scanner::types::TokenError as V9;
+                              match e {
+                                V9::IllegalCharacter=> {
+                                  {
+                                    *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                  }
+                                }
+                                V9::Unterminated(e) => {
+                                  *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                  let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id7, text:text7, is_keyword:is_keyword7, is_terminal:is_terminal7, } = e;
+                                  *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id7);
+                                  let vec8 = (text7.into_bytes()).into_boxed_slice();
+                                  let ptr8 = vec8.as_ptr().cast::<u8>();
+                                  let len8 = vec8.len();
+                                  ::core::mem::forget(vec8);
+                                  *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                                  *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr8.cast_mut();
+                                  *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword7 { true => 1, false => 0 }) as u8;
+                                  *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal7 { true => 1, false => 0 }) as u8;
+                                },
+                              }
+                            },
+                            None => {
+                              {
+                                *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                              }
+                            },
                           };}
                         }
-                        *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
-                        *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result8;
+                        *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len10;
+                        *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result10;
+                      },
+                      None => {
+                        {
+                          *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                        }
+                      },
+                    };let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind11, offset:offset11, len:len11, value:value11, error:error11, } = main2;
+                    let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id12, text:text12, is_keyword:is_keyword12, is_terminal:is_terminal12, } = kind11;
+                    *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id12);
+                    let vec13 = (text12.into_bytes()).into_boxed_slice();
+                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                    let len13 = vec13.len();
+                    ::core::mem::forget(vec13);
+                    *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len13;
+                    *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr13.cast_mut();
+                    *ptr1.add(7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword12 { true => 1, false => 0 }) as u8;
+                    *ptr1.add(1+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal12 { true => 1, false => 0 }) as u8;
+                    *ptr1.add(8*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset11);
+                    *ptr1.add(4+8*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len11);
+                    match value11 {
+                      Some(e) => {
+                        *ptr1.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                        let vec14 = (e.into_bytes()).into_boxed_slice();
+                        let ptr14 = vec14.as_ptr().cast::<u8>();
+                        let len14 = vec14.len();
+                        ::core::mem::forget(vec14);
+                        *ptr1.add(8+10*::core::mem::size_of::<*const u8>()).cast::<usize>() = len14;
+                        *ptr1.add(8+9*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr14.cast_mut();
                       },
                       None => {
                         {
-                          *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                          *ptr1.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                         }
                       },
-                    };let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind9, offset:offset9, len:len9, value:value9, } = main3;
-                    let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id10, text:text10, is_keyword:is_keyword10, is_terminal:is_terminal10, } = kind9;
-                    *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id10);
-                    let vec11 = (text10.into_bytes()).into_boxed_slice();
-                    let ptr11 = vec11.as_ptr().cast::<u8>();
-                    let len11 = vec11.len();
-                    ::core::mem::forget(vec11);
-                    *ptr1.add(7*::core::mem::size_of::<*const u8>()).cast::<usize>() = len11;
-                    *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr11.cast_mut();
-                    *ptr1.add(8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword10 { true => 1, false => 0 }) as u8;
-                    *ptr1.add(1+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal10 { true => 1, false => 0 }) as u8;
-                    *ptr1.add(9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset9);
-                    *ptr1.add(4+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len9);
-                    match value9 {
+                    };match error11 {
                       Some(e) => {
-                        *ptr1.add(8+9*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                        let vec12 = (e.into_bytes()).into_boxed_slice();
-                        let ptr12 = vec12.as_ptr().cast::<u8>();
-                        let len12 = vec12.len();
-                        ::core::mem::forget(vec12);
-                        *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len12;
-                        *ptr1.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr12.cast_mut();
+                        *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                        use super::super::super::super::exports::ritalin::scanner::types::TokenError as V17;
+                        match e {
+                          V17::IllegalCharacter=> {
+                            {
+                              *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                            }
+                          }
+                          V17::Unterminated(e) => {
+                            *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id15, text:text15, is_keyword:is_keyword15, is_terminal:is_terminal15, } = e;
+                            *ptr1.add(8+13*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id15);
+                            let vec16 = (text15.into_bytes()).into_boxed_slice();
+                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                            let len16 = vec16.len();
+                            ::core::mem::forget(vec16);
+                            *ptr1.add(8+15*::core::mem::size_of::<*const u8>()).cast::<usize>() = len16;
+                            *ptr1.add(8+14*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr16.cast_mut();
+                            *ptr1.add(8+16*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword15 { true => 1, false => 0 }) as u8;
+                            *ptr1.add(9+16*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal15 { true => 1, false => 0 }) as u8;
+                          },
+                        }
                       },
                       None => {
                         {
-                          *ptr1.add(8+9*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                          *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                         }
                       },
-                    };match trailing3 {
+                    };match trailing2 {
                       Some(e) => {
-                        *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                        let vec17 = e;
-                        let len17 = vec17.len();
-                        let layout17 = _rt::alloc::Layout::from_size_align_unchecked(vec17.len() * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-                        let result17 = if layout17.size() != 0 {
-                          let ptr = _rt::alloc::alloc(layout17).cast::<u8>();
+                        *ptr1.add(8+17*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                        let vec25 = e;
+                        let len25 = vec25.len();
+                        let layout25 = _rt::alloc::Layout::from_size_align_unchecked(vec25.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                        let result25 = if layout25.size() != 0 {
+                          let ptr = _rt::alloc::alloc(layout25).cast::<u8>();
                           if ptr.is_null()
                           {
-                            _rt::alloc::handle_alloc_error(layout17);
+                            _rt::alloc::handle_alloc_error(layout25);
                           }
                           ptr
                         }else {
                           ::core::ptr::null_mut()
                         };
-                        for (i, e) in vec17.into_iter().enumerate() {
-                          let base = result17.add(i * (8+7*::core::mem::size_of::<*const u8>()));
+                        for (i, e) in vec25.into_iter().enumerate() {
+                          let base = result25.add(i * (8+13*::core::mem::size_of::<*const u8>()));
                           {
-                            let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind13, offset:offset13, len:len13, value:value13, } = e;
-                            let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id14, text:text14, is_keyword:is_keyword14, is_terminal:is_terminal14, } = kind13;
-                            *base.add(0).cast::<i32>() = _rt::as_i32(id14);
-                            let vec15 = (text14.into_bytes()).into_boxed_slice();
-                            let ptr15 = vec15.as_ptr().cast::<u8>();
-                            let len15 = vec15.len();
-                            ::core::mem::forget(vec15);
-                            *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len15;
-                            *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr15.cast_mut();
-                            *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword14 { true => 1, false => 0 }) as u8;
-                            *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal14 { true => 1, false => 0 }) as u8;
-                            *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset13);
-                            *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len13);
-                            match value13 {
+                            let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind18, offset:offset18, len:len18, value:value18, error:error18, } = e;
+                            let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id19, text:text19, is_keyword:is_keyword19, is_terminal:is_terminal19, } = kind18;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(id19);
+                            let vec20 = (text19.into_bytes()).into_boxed_slice();
+                            let ptr20 = vec20.as_ptr().cast::<u8>();
+                            let len20 = vec20.len();
+                            ::core::mem::forget(vec20);
+                            *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len20;
+                            *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr20.cast_mut();
+                            *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword19 { true => 1, false => 0 }) as u8;
+                            *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal19 { true => 1, false => 0 }) as u8;
+                            *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset18);
+                            *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len18);
+                            match value18 {
                               Some(e) => {
                                 *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
-                                let vec16 = (e.into_bytes()).into_boxed_slice();
-                                let ptr16 = vec16.as_ptr().cast::<u8>();
-                                let len16 = vec16.len();
-                                ::core::mem::forget(vec16);
-                                *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len16;
-                                *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr16.cast_mut();
+                                let vec21 = (e.into_bytes()).into_boxed_slice();
+                                let ptr21 = vec21.as_ptr().cast::<u8>();
+                                let len21 = vec21.len();
+                                ::core::mem::forget(vec21);
+                                *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len21;
+                                *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr21.cast_mut();
                               },
                               None => {
                                 {
                                   *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                                 }
                               },
+                            };match error18 {
+                              Some(e) => {
+                                *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                use super::super::super::super::exports::ritalin::scanner::types::TokenError as V24;
+                                match e {
+                                  V24::IllegalCharacter=> {
+                                    {
+                                      *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                    }
+                                  }
+                                  V24::Unterminated(e) => {
+                                    *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                    let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id22, text:text22, is_keyword:is_keyword22, is_terminal:is_terminal22, } = e;
+                                    *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id22);
+                                    let vec23 = (text22.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len23;
+                                    *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr23.cast_mut();
+                                    *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword22 { true => 1, false => 0 }) as u8;
+                                    *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal22 { true => 1, false => 0 }) as u8;
+                                  },
+                                }
+                              },
+                              None => {
+                                {
+                                  *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                }
+                              },
                             };}
                           }
-                          *ptr1.add(8+14*::core::mem::size_of::<*const u8>()).cast::<usize>() = len17;
-                          *ptr1.add(8+13*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result17;
+                          *ptr1.add(8+19*::core::mem::size_of::<*const u8>()).cast::<usize>() = len25;
+                          *ptr1.add(8+18*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result25;
                         },
                         None => {
                           {
-                            *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                            *ptr1.add(8+17*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                           }
                         },
                       };},
                       None => {
                         {
-                          *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                          *ptr1.add(0).cast::<u8>() = (0i32) as u8;
                         }
                       },
                     };ptr1
                   }
                   #[doc(hidden)]
                   #[allow(non_snake_case)]
-                  pub unsafe fn __post_return_method_scanner_scope<T: GuestScanner>(arg0: *mut u8,) {
-                    let l0 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+                  pub unsafe fn __post_return_method_scanner_lookahead<T: GuestScanner>(arg0: *mut u8,) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
                       0 => (),
                       _ => {
-                        let l1 = i32::from(*arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                        let l1 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
                         match l1 {
                           0 => (),
                           _ => {
-                            let l2 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                            let l3 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                            let base9 = l2;
-                            let len9 = l3;
-                            for i in 0..len9 {
-                              let base = base9.add(i * (8+7*::core::mem::size_of::<*const u8>()));
+                            let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                            let l3 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                            let base13 = l2;
+                            let len13 = l3;
+                            for i in 0..len13 {
+                              let base = base13.add(i * (8+13*::core::mem::size_of::<*const u8>()));
                               {
                                 let l4 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
                                 let l5 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
@@ -897,49 +1611,94 @@ pub mod exports {
                                     _rt::cabi_dealloc(l7, l8, 1);
                                   },
                                 }
+                                let l9 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                match l9 {
+                                  0 => (),
+                                  _ => {
+                                    let l10 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                    match l10 {
+                                      0 => (),
+                                      _ => {
+                                        let l11 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                        let l12 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                        _rt::cabi_dealloc(l11, l12, 1);
+                                      },
+                                    }
+                                  },
+                                }
                               }
                             }
-                            _rt::cabi_dealloc(base9, len9 * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                            _rt::cabi_dealloc(base13, len13 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                          },
+                        }
+                        let l14 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                        let l15 = *arg0.add(6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                        _rt::cabi_dealloc(l14, l15, 1);
+                        let l16 = i32::from(*arg0.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                        match l16 {
+                          0 => (),
+                          _ => {
+                            let l17 = *arg0.add(8+9*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                            let l18 = *arg0.add(8+10*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                            _rt::cabi_dealloc(l17, l18, 1);
                           },
                         }
-                        let l10 = *arg0.add(6*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                        let l11 = *arg0.add(7*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                        _rt::cabi_dealloc(l10, l11, 1);
-                        let l12 = i32::from(*arg0.add(8+9*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                        match l12 {
+                        let l19 = i32::from(*arg0.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                        match l19 {
                           0 => (),
                           _ => {
-                            let l13 = *arg0.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                            let l14 = *arg0.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                            _rt::cabi_dealloc(l13, l14, 1);
+                            let l20 = i32::from(*arg0.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            match l20 {
+                              0 => (),
+                              _ => {
+                                let l21 = *arg0.add(8+14*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                let l22 = *arg0.add(8+15*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                _rt::cabi_dealloc(l21, l22, 1);
+                              },
+                            }
                           },
                         }
-                        let l15 = i32::from(*arg0.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                        match l15 {
+                        let l23 = i32::from(*arg0.add(8+17*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                        match l23 {
                           0 => (),
                           _ => {
-                            let l16 = *arg0.add(8+13*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                            let l17 = *arg0.add(8+14*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                            let base23 = l16;
-                            let len23 = l17;
-                            for i in 0..len23 {
-                              let base = base23.add(i * (8+7*::core::mem::size_of::<*const u8>()));
+                            let l24 = *arg0.add(8+18*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                            let l25 = *arg0.add(8+19*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                            let base35 = l24;
+                            let len35 = l25;
+                            for i in 0..len35 {
+                              let base = base35.add(i * (8+13*::core::mem::size_of::<*const u8>()));
                               {
-                                let l18 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                let l19 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                _rt::cabi_dealloc(l18, l19, 1);
-                                let l20 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                                match l20 {
+                                let l26 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                let l27 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                _rt::cabi_dealloc(l26, l27, 1);
+                                let l28 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                match l28 {
                                   0 => (),
                                   _ => {
-                                    let l21 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                    let l22 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                    _rt::cabi_dealloc(l21, l22, 1);
+                                    let l29 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                    let l30 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                    _rt::cabi_dealloc(l29, l30, 1);
+                                  },
+                                }
+                                let l31 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                match l31 {
+                                  0 => (),
+                                  _ => {
+                                    let l32 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                    match l32 {
+                                      0 => (),
+                                      _ => {
+                                        let l33 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                        let l34 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                        _rt::cabi_dealloc(l33, l34, 1);
+                                      },
+                                    }
                                   },
                                 }
                               }
                             }
-                            _rt::cabi_dealloc(base23, len23 * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                            _rt::cabi_dealloc(base35, len35 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
                           },
                         }
                       },
@@ -947,572 +1706,1460 @@ pub mod exports {
                   }
                   #[doc(hidden)]
                   #[allow(non_snake_case)]
-                  pub unsafe fn _export_method_scanner_revert_cabi<T: GuestScanner>(arg0: *mut u8,) {#[cfg(target_arch="wasm32")]
-                  _rt::run_ctors_once();let l0 = *arg0.add(0).cast::<i32>();
-                  let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<i32>();
-                  let l2 = i32::from(*arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                  T::revert(unsafe { ScannerBorrow::lift(l0 as u32 as usize) }.get(), super::super::super::super::exports::ritalin::scanner::types::ScannerScope{
-                    next_index: l1 as u32,
-                    lookahead: match l2 {
-                      0 => None,
-                      1 => {
-                        let e = {
-                          let l3 = i32::from(*arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                          let l19 = *arg0.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
-                          let l20 = *arg0.add(7*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                          let l21 = *arg0.add(8*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                          let len22 = l21;
-                          let bytes22 = _rt::Vec::from_raw_parts(l20.cast(), len22, len22);
-                          let l23 = i32::from(*arg0.add(9*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                          let l24 = i32::from(*arg0.add(1+9*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                          let l25 = *arg0.add(10*::core::mem::size_of::<*const u8>()).cast::<i32>();
-                          let l26 = *arg0.add(4+10*::core::mem::size_of::<*const u8>()).cast::<i32>();
-                          let l27 = i32::from(*arg0.add(8+10*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                          let l31 = i32::from(*arg0.add(8+13*::core::mem::size_of::<*const u8>()).cast::<u8>());
-
-                          super::super::super::super::exports::ritalin::scanner::types::Token{
-                            leading: match l3 {
-                              0 => None,
-                              1 => {
-                                let e = {
-                                  let l4 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                  let l5 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                  let base18 = l4;
-                                  let len18 = l5;
-                                  let mut result18 = _rt::Vec::with_capacity(len18);
-                                  for i in 0..len18 {
-                                    let base = base18.add(i * (8+7*::core::mem::size_of::<*const u8>()));
-                                    let e18 = {
-                                      let l6 = *base.add(0).cast::<i32>();
-                                      let l7 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                      let l8 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                      let len9 = l8;
-                                      let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
-                                      let l10 = i32::from(*base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                                      let l11 = i32::from(*base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                                      let l12 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>();
-                                      let l13 = *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
-                                      let l14 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-
-                                      super::super::super::super::exports::ritalin::scanner::types::TokenItem{
-                                        kind: super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
-                                          id: l6 as u32,
-                                          text: _rt::string_lift(bytes9),
-                                          is_keyword: _rt::bool_lift(l10 as u8),
-                                          is_terminal: _rt::bool_lift(l11 as u8),
-                                        },
-                                        offset: l12 as u32,
-                                        len: l13 as u32,
-                                        value: match l14 {
-                                          0 => None,
-                                          1 => {
-                                            let e = {
-                                              let l15 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                              let l16 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                              let len17 = l16;
-                                              let bytes17 = _rt::Vec::from_raw_parts(l15.cast(), len17, len17);
-
-                                              _rt::string_lift(bytes17)
-                                            };
-                                            Some(e)
-                                          }
-                                          _ => _rt::invalid_enum_discriminant(),
-                                        },
+                  pub unsafe fn _export_method_scanner_shift_cabi<T: GuestScanner>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+                  _rt::run_ctors_once();let result0 = T::shift(unsafe { ScannerBorrow::lift(arg0 as u32 as usize) }.get());
+                  let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                  match result0 {
+                    Some(e) => {
+                      *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                      let super::super::super::super::exports::ritalin::scanner::types::Token{ leading:leading2, main:main2, trailing:trailing2, } = e;
+                      match leading2 {
+                        Some(e) => {
+                          *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                          let vec10 = e;
+                          let len10 = vec10.len();
+                          let layout10 = _rt::alloc::Layout::from_size_align_unchecked(vec10.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                          let result10 = if layout10.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
+                            if ptr.is_null()
+                            {
+                              _rt::alloc::handle_alloc_error(layout10);
+                            }
+                            ptr
+                          }else {
+                            ::core::ptr::null_mut()
+                          };
+                          for (i, e) in vec10.into_iter().enumerate() {
+                            let base = result10.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                            {
+                              let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind3, offset:offset3, len:len3, value:value3, error:error3, } = e;
+                              let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id4, text:text4, is_keyword:is_keyword4, is_terminal:is_terminal4, } = kind3;
+                              *base.add(0).cast::<i32>() = _rt::as_i32(id4);
+                              let vec5 = (text4.into_bytes()).into_boxed_slice();
+                              let ptr5 = vec5.as_ptr().cast::<u8>();
+                              let len5 = vec5.len();
+                              ::core::mem::forget(vec5);
+                              *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                              *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5.cast_mut();
+                              *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword4 { true => 1, false => 0 }) as u8;
+                              *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal4 { true => 1, false => 0 }) as u8;
+                              *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset3);
+                              *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len3);
+                              match value3 {
+                                Some(e) => {
+                                  *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                  let vec6 = (e.into_bytes()).into_boxed_slice();
+                                  let ptr6 = vec6.as_ptr().cast::<u8>();
+                                  let len6 = vec6.len();
+                                  ::core::mem::forget(vec6);
+                                  *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                                  *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
+                                },
+                                None => {
+                                  {
+                                    *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                  }
+                                },
+                              };match error3 {
+                                Some(e) => {
+                                  *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                  use super::super::super::super::exports::ritalin::scanner::types::TokenError as V9;
+                                  match e {
+                                    V9::IllegalCharacter=> {
+                                      {
+                                        *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                                       }
-                                    };
-                                    result18.push(e18);
+                                    }
+                                    V9::Unterminated(e) => {
+                                      *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                      let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id7, text:text7, is_keyword:is_keyword7, is_terminal:is_terminal7, } = e;
+                                      *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id7);
+                                      let vec8 = (text7.into_bytes()).into_boxed_slice();
+                                      let ptr8 = vec8.as_ptr().cast::<u8>();
+                                      let len8 = vec8.len();
+                                      ::core::mem::forget(vec8);
+                                      *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                                      *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr8.cast_mut();
+                                      *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword7 { true => 1, false => 0 }) as u8;
+                                      *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal7 { true => 1, false => 0 }) as u8;
+                                    },
                                   }
-                                  _rt::cabi_dealloc(base18, len18 * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-
-                                  result18
-                                };
-                                Some(e)
+                                },
+                                None => {
+                                  {
+                                    *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                  }
+                                },
+                              };}
+                            }
+                            *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>() = len10;
+                            *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result10;
+                          },
+                          None => {
+                            {
+                              *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                            }
+                          },
+                        };let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind11, offset:offset11, len:len11, value:value11, error:error11, } = main2;
+                        let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id12, text:text12, is_keyword:is_keyword12, is_terminal:is_terminal12, } = kind11;
+                        *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id12);
+                        let vec13 = (text12.into_bytes()).into_boxed_slice();
+                        let ptr13 = vec13.as_ptr().cast::<u8>();
+                        let len13 = vec13.len();
+                        ::core::mem::forget(vec13);
+                        *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len13;
+                        *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr13.cast_mut();
+                        *ptr1.add(7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword12 { true => 1, false => 0 }) as u8;
+                        *ptr1.add(1+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal12 { true => 1, false => 0 }) as u8;
+                        *ptr1.add(8*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset11);
+                        *ptr1.add(4+8*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len11);
+                        match value11 {
+                          Some(e) => {
+                            *ptr1.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                            let len14 = vec14.len();
+                            ::core::mem::forget(vec14);
+                            *ptr1.add(8+10*::core::mem::size_of::<*const u8>()).cast::<usize>() = len14;
+                            *ptr1.add(8+9*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr14.cast_mut();
+                          },
+                          None => {
+                            {
+                              *ptr1.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                            }
+                          },
+                        };match error11 {
+                          Some(e) => {
+                            *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::ritalin::scanner::types::TokenError as V17;
+                            match e {
+                              V17::IllegalCharacter=> {
+                                {
+                                  *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                }
                               }
-                              _ => _rt::invalid_enum_discriminant(),
+                              V17::Unterminated(e) => {
+                                *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id15, text:text15, is_keyword:is_keyword15, is_terminal:is_terminal15, } = e;
+                                *ptr1.add(8+13*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id15);
+                                let vec16 = (text15.into_bytes()).into_boxed_slice();
+                                let ptr16 = vec16.as_ptr().cast::<u8>();
+                                let len16 = vec16.len();
+                                ::core::mem::forget(vec16);
+                                *ptr1.add(8+15*::core::mem::size_of::<*const u8>()).cast::<usize>() = len16;
+                                *ptr1.add(8+14*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr16.cast_mut();
+                                *ptr1.add(8+16*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword15 { true => 1, false => 0 }) as u8;
+                                *ptr1.add(9+16*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal15 { true => 1, false => 0 }) as u8;
+                              },
+                            }
+                          },
+                          None => {
+                            {
+                              *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                            }
+                          },
+                        };match trailing2 {
+                          Some(e) => {
+                            *ptr1.add(8+17*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                            let vec25 = e;
+                            let len25 = vec25.len();
+                            let layout25 = _rt::alloc::Layout::from_size_align_unchecked(vec25.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                            let result25 = if layout25.size() != 0 {
+                              let ptr = _rt::alloc::alloc(layout25).cast::<u8>();
+                              if ptr.is_null()
+                              {
+                                _rt::alloc::handle_alloc_error(layout25);
+                              }
+                              ptr
+                            }else {
+                              ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec25.into_iter().enumerate() {
+                              let base = result25.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                              {
+                                let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind18, offset:offset18, len:len18, value:value18, error:error18, } = e;
+                                let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id19, text:text19, is_keyword:is_keyword19, is_terminal:is_terminal19, } = kind18;
+                                *base.add(0).cast::<i32>() = _rt::as_i32(id19);
+                                let vec20 = (text19.into_bytes()).into_boxed_slice();
+                                let ptr20 = vec20.as_ptr().cast::<u8>();
+                                let len20 = vec20.len();
+                                ::core::mem::forget(vec20);
+                                *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len20;
+                                *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr20.cast_mut();
+                                *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword19 { true => 1, false => 0 }) as u8;
+                                *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal19 { true => 1, false => 0 }) as u8;
+                                *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset18);
+                                *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len18);
+                                match value18 {
+                                  Some(e) => {
+                                    *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len21;
+                                    *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr21.cast_mut();
+                                  },
+                                  None => {
+                                    {
+                                      *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                    }
+                                  },
+                                };match error18 {
+                                  Some(e) => {
+                                    *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                    use super::super::super::super::exports::ritalin::scanner::types::TokenError as V24;
+                                    match e {
+                                      V24::IllegalCharacter=> {
+                                        {
+                                          *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                        }
+                                      }
+                                      V24::Unterminated(e) => {
+                                        *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                        let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id22, text:text22, is_keyword:is_keyword22, is_terminal:is_terminal22, } = e;
+                                        *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id22);
+                                        let vec23 = (text22.into_bytes()).into_boxed_slice();
+                                        let ptr23 = vec23.as_ptr().cast::<u8>();
+                                        let len23 = vec23.len();
+                                        ::core::mem::forget(vec23);
+                                        *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len23;
+                                        *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr23.cast_mut();
+                                        *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword22 { true => 1, false => 0 }) as u8;
+                                        *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal22 { true => 1, false => 0 }) as u8;
+                                      },
+                                    }
+                                  },
+                                  None => {
+                                    {
+                                      *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                    }
+                                  },
+                                };}
+                              }
+                              *ptr1.add(8+19*::core::mem::size_of::<*const u8>()).cast::<usize>() = len25;
+                              *ptr1.add(8+18*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result25;
                             },
-                            main: super::super::super::super::exports::ritalin::scanner::types::TokenItem{
-                              kind: super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
-                                id: l19 as u32,
-                                text: _rt::string_lift(bytes22),
-                                is_keyword: _rt::bool_lift(l23 as u8),
-                                is_terminal: _rt::bool_lift(l24 as u8),
+                            None => {
+                              {
+                                *ptr1.add(8+17*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                              }
+                            },
+                          };},
+                          None => {
+                            {
+                              *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            }
+                          },
+                        };ptr1
+                      }
+                      #[doc(hidden)]
+                      #[allow(non_snake_case)]
+                      pub unsafe fn __post_return_method_scanner_shift<T: GuestScanner>(arg0: *mut u8,) {
+                        let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                        match l0 {
+                          0 => (),
+                          _ => {
+                            let l1 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            match l1 {
+                              0 => (),
+                              _ => {
+                                let l2 = *arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                let l3 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                let base13 = l2;
+                                let len13 = l3;
+                                for i in 0..len13 {
+                                  let base = base13.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                                  {
+                                    let l4 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                    let l5 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                    let l6 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                    match l6 {
+                                      0 => (),
+                                      _ => {
+                                        let l7 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                        let l8 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                        _rt::cabi_dealloc(l7, l8, 1);
+                                      },
+                                    }
+                                    let l9 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                    match l9 {
+                                      0 => (),
+                                      _ => {
+                                        let l10 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                        match l10 {
+                                          0 => (),
+                                          _ => {
+                                            let l11 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                            let l12 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                            _rt::cabi_dealloc(l11, l12, 1);
+                                          },
+                                        }
+                                      },
+                                    }
+                                  }
+                                }
+                                _rt::cabi_dealloc(base13, len13 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
                               },
-                              offset: l25 as u32,
-                              len: l26 as u32,
-                              value: match l27 {
-                                0 => None,
-                                1 => {
-                                  let e = {
-                                    let l28 = *arg0.add(8+11*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                    let l29 = *arg0.add(8+12*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                    let len30 = l29;
-                                    let bytes30 = _rt::Vec::from_raw_parts(l28.cast(), len30, len30);
-
-                                    _rt::string_lift(bytes30)
-                                  };
-                                  Some(e)
+                            }
+                            let l14 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                            let l15 = *arg0.add(6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                            _rt::cabi_dealloc(l14, l15, 1);
+                            let l16 = i32::from(*arg0.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            match l16 {
+                              0 => (),
+                              _ => {
+                                let l17 = *arg0.add(8+9*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                let l18 = *arg0.add(8+10*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                _rt::cabi_dealloc(l17, l18, 1);
+                              },
+                            }
+                            let l19 = i32::from(*arg0.add(8+11*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            match l19 {
+                              0 => (),
+                              _ => {
+                                let l20 = i32::from(*arg0.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                match l20 {
+                                  0 => (),
+                                  _ => {
+                                    let l21 = *arg0.add(8+14*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                    let l22 = *arg0.add(8+15*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                  },
                                 }
-                                _ => _rt::invalid_enum_discriminant(),
                               },
-                            },
-                            trailing: match l31 {
-                              0 => None,
-                              1 => {
-                                let e = {
-                                  let l32 = *arg0.add(8+14*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                  let l33 = *arg0.add(8+15*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                  let base46 = l32;
-                                  let len46 = l33;
-                                  let mut result46 = _rt::Vec::with_capacity(len46);
-                                  for i in 0..len46 {
-                                    let base = base46.add(i * (8+7*::core::mem::size_of::<*const u8>()));
-                                    let e46 = {
-                                      let l34 = *base.add(0).cast::<i32>();
-                                      let l35 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                      let l36 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                      let len37 = l36;
-                                      let bytes37 = _rt::Vec::from_raw_parts(l35.cast(), len37, len37);
-                                      let l38 = i32::from(*base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                                      let l39 = i32::from(*base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
-                                      let l40 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>();
-                                      let l41 = *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
-                                      let l42 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
-
-                                      super::super::super::super::exports::ritalin::scanner::types::TokenItem{
-                                        kind: super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
-                                          id: l34 as u32,
-                                          text: _rt::string_lift(bytes37),
-                                          is_keyword: _rt::bool_lift(l38 as u8),
-                                          is_terminal: _rt::bool_lift(l39 as u8),
-                                        },
-                                        offset: l40 as u32,
-                                        len: l41 as u32,
-                                        value: match l42 {
-                                          0 => None,
-                                          1 => {
-                                            let e = {
-                                              let l43 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-                                              let l44 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
-                                              let len45 = l44;
-                                              let bytes45 = _rt::Vec::from_raw_parts(l43.cast(), len45, len45);
-
-                                              _rt::string_lift(bytes45)
-                                            };
-                                            Some(e)
+                            }
+                            let l23 = i32::from(*arg0.add(8+17*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            match l23 {
+                              0 => (),
+                              _ => {
+                                let l24 = *arg0.add(8+18*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                let l25 = *arg0.add(8+19*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                let base35 = l24;
+                                let len35 = l25;
+                                for i in 0..len35 {
+                                  let base = base35.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                                  {
+                                    let l26 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                    let l27 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                    let l28 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                    match l28 {
+                                      0 => (),
+                                      _ => {
+                                        let l29 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                        let l30 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                        _rt::cabi_dealloc(l29, l30, 1);
+                                      },
+                                    }
+                                    let l31 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                    match l31 {
+                                      0 => (),
+                                      _ => {
+                                        let l32 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                        match l32 {
+                                          0 => (),
+                                          _ => {
+                                            let l33 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                            let l34 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                            _rt::cabi_dealloc(l33, l34, 1);
+                                          },
+                                        }
+                                      },
+                                    }
+                                  }
+                                }
+                                _rt::cabi_dealloc(base35, len35 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                              },
+                            }
+                          },
+                        }
+                      }
+                      #[doc(hidden)]
+                      #[allow(non_snake_case)]
+                      pub unsafe fn _export_method_scanner_scope_cabi<T: GuestScanner>(arg0: *mut u8,) -> *mut u8 {#[cfg(target_arch="wasm32")]
+                      _rt::run_ctors_once();let result0 = T::scope(unsafe { ScannerBorrow::lift(arg0 as u32 as usize) }.get());
+                      let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                      let super::super::super::super::exports::ritalin::scanner::types::ScannerScope{ next_index:next_index2, lookahead:lookahead2, } = result0;
+                      *ptr1.add(0).cast::<i32>() = _rt::as_i32(next_index2);
+                      match lookahead2 {
+                        Some(e) => {
+                          *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                          let super::super::super::super::exports::ritalin::scanner::types::Token{ leading:leading3, main:main3, trailing:trailing3, } = e;
+                          match leading3 {
+                            Some(e) => {
+                              *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                              let vec11 = e;
+                              let len11 = vec11.len();
+                              let layout11 = _rt::alloc::Layout::from_size_align_unchecked(vec11.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                              let result11 = if layout11.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                                if ptr.is_null()
+                                {
+                                  _rt::alloc::handle_alloc_error(layout11);
+                                }
+                                ptr
+                              }else {
+                                ::core::ptr::null_mut()
+                              };
+                              for (i, e) in vec11.into_iter().enumerate() {
+                                let base = result11.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                                {
+                                  let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind4, offset:offset4, len:len4, value:value4, error:error4, } = e;
+                                  let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id5, text:text5, is_keyword:is_keyword5, is_terminal:is_terminal5, } = kind4;
+                                  *base.add(0).cast::<i32>() = _rt::as_i32(id5);
+                                  let vec6 = (text5.into_bytes()).into_boxed_slice();
+                                  let ptr6 = vec6.as_ptr().cast::<u8>();
+                                  let len6 = vec6.len();
+                                  ::core::mem::forget(vec6);
+                                  *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                                  *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6.cast_mut();
+                                  *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword5 { true => 1, false => 0 }) as u8;
+                                  *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal5 { true => 1, false => 0 }) as u8;
+                                  *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset4);
+                                  *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len4);
+                                  match value4 {
+                                    Some(e) => {
+                                      *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                      let vec7 = (e.into_bytes()).into_boxed_slice();
+                                      let ptr7 = vec7.as_ptr().cast::<u8>();
+                                      let len7 = vec7.len();
+                                      ::core::mem::forget(vec7);
+                                      *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                                      *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7.cast_mut();
+                                    },
+                                    None => {
+                                      {
+                                        *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                      }
+                                    },
+                                  };match error4 {
+                                    Some(e) => {
+                                      *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                      use super::super::super::super::exports::ritalin::scanner::types::TokenError as V10;
+                                      match e {
+                                        V10::IllegalCharacter=> {
+                                          {
+                                            *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
                                           }
-                                          _ => _rt::invalid_enum_discriminant(),
+                                        }
+                                        V10::Unterminated(e) => {
+                                          *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                          let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id8, text:text8, is_keyword:is_keyword8, is_terminal:is_terminal8, } = e;
+                                          *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id8);
+                                          let vec9 = (text8.into_bytes()).into_boxed_slice();
+                                          let ptr9 = vec9.as_ptr().cast::<u8>();
+                                          let len9 = vec9.len();
+                                          ::core::mem::forget(vec9);
+                                          *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+                                          *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr9.cast_mut();
+                                          *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword8 { true => 1, false => 0 }) as u8;
+                                          *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal8 { true => 1, false => 0 }) as u8;
                                         },
                                       }
-                                    };
-                                    result46.push(e46);
-                                  }
-                                  _rt::cabi_dealloc(base46, len46 * (8+7*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
-
-                                  result46
+                                    },
+                                    None => {
+                                      {
+                                        *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                      }
+                                    },
+                                  };}
+                                }
+                                *ptr1.add(4*::core::mem::size_of::<*const u8>()).cast::<usize>() = len11;
+                                *ptr1.add(3*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result11;
+                              },
+                              None => {
+                                {
+                                  *ptr1.add(2*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                }
+                              },
+                            };let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind12, offset:offset12, len:len12, value:value12, error:error12, } = main3;
+                            let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id13, text:text13, is_keyword:is_keyword13, is_terminal:is_terminal13, } = kind12;
+                            *ptr1.add(5*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id13);
+                            let vec14 = (text13.into_bytes()).into_boxed_slice();
+                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                            let len14 = vec14.len();
+                            ::core::mem::forget(vec14);
+                            *ptr1.add(7*::core::mem::size_of::<*const u8>()).cast::<usize>() = len14;
+                            *ptr1.add(6*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr14.cast_mut();
+                            *ptr1.add(8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword13 { true => 1, false => 0 }) as u8;
+                            *ptr1.add(1+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal13 { true => 1, false => 0 }) as u8;
+                            *ptr1.add(9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset12);
+                            *ptr1.add(4+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len12);
+                            match value12 {
+                              Some(e) => {
+                                *ptr1.add(8+9*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                let vec15 = (e.into_bytes()).into_boxed_slice();
+                                let ptr15 = vec15.as_ptr().cast::<u8>();
+                                let len15 = vec15.len();
+                                ::core::mem::forget(vec15);
+                                *ptr1.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len15;
+                                *ptr1.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr15.cast_mut();
+                              },
+                              None => {
+                                {
+                                  *ptr1.add(8+9*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                }
+                              },
+                            };match error12 {
+                              Some(e) => {
+                                *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                use super::super::super::super::exports::ritalin::scanner::types::TokenError as V18;
+                                match e {
+                                  V18::IllegalCharacter=> {
+                                    {
+                                      *ptr1.add(8+13*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                    }
+                                  }
+                                  V18::Unterminated(e) => {
+                                    *ptr1.add(8+13*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                    let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id16, text:text16, is_keyword:is_keyword16, is_terminal:is_terminal16, } = e;
+                                    *ptr1.add(8+14*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id16);
+                                    let vec17 = (text16.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr1.add(8+16*::core::mem::size_of::<*const u8>()).cast::<usize>() = len17;
+                                    *ptr1.add(8+15*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr1.add(8+17*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword16 { true => 1, false => 0 }) as u8;
+                                    *ptr1.add(9+17*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal16 { true => 1, false => 0 }) as u8;
+                                  },
+                                }
+                              },
+                              None => {
+                                {
+                                  *ptr1.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                }
+                              },
+                            };match trailing3 {
+                              Some(e) => {
+                                *ptr1.add(8+18*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                let vec26 = e;
+                                let len26 = vec26.len();
+                                let layout26 = _rt::alloc::Layout::from_size_align_unchecked(vec26.len() * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                                let result26 = if layout26.size() != 0 {
+                                  let ptr = _rt::alloc::alloc(layout26).cast::<u8>();
+                                  if ptr.is_null()
+                                  {
+                                    _rt::alloc::handle_alloc_error(layout26);
+                                  }
+                                  ptr
+                                }else {
+                                  ::core::ptr::null_mut()
+                                };
+                                for (i, e) in vec26.into_iter().enumerate() {
+                                  let base = result26.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                                  {
+                                    let super::super::super::super::exports::ritalin::scanner::types::TokenItem{ kind:kind19, offset:offset19, len:len19, value:value19, error:error19, } = e;
+                                    let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id20, text:text20, is_keyword:is_keyword20, is_terminal:is_terminal20, } = kind19;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(id20);
+                                    let vec21 = (text20.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>() = len21;
+                                    *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr21.cast_mut();
+                                    *base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword20 { true => 1, false => 0 }) as u8;
+                                    *base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal20 { true => 1, false => 0 }) as u8;
+                                    *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(offset19);
+                                    *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(len19);
+                                    match value19 {
+                                      Some(e) => {
+                                        *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                        let vec22 = (e.into_bytes()).into_boxed_slice();
+                                        let ptr22 = vec22.as_ptr().cast::<u8>();
+                                        let len22 = vec22.len();
+                                        ::core::mem::forget(vec22);
+                                        *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>() = len22;
+                                        *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr22.cast_mut();
+                                      },
+                                      None => {
+                                        {
+                                          *base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                        }
+                                      },
+                                    };match error19 {
+                                      Some(e) => {
+                                        *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                        use super::super::super::super::exports::ritalin::scanner::types::TokenError as V25;
+                                        match e {
+                                          V25::IllegalCharacter=> {
+                                            {
+                                              *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                            }
+                                          }
+                                          V25::Unterminated(e) => {
+                                            *base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                                            let super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{ id:id23, text:text23, is_keyword:is_keyword23, is_terminal:is_terminal23, } = e;
+                                            *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(id23);
+                                            let vec24 = (text23.into_bytes()).into_boxed_slice();
+                                            let ptr24 = vec24.as_ptr().cast::<u8>();
+                                            let len24 = vec24.len();
+                                            ::core::mem::forget(vec24);
+                                            *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>() = len24;
+                                            *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr24.cast_mut();
+                                            *base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_keyword23 { true => 1, false => 0 }) as u8;
+                                            *base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>() = (match is_terminal23 { true => 1, false => 0 }) as u8;
+                                          },
+                                        }
+                                      },
+                                      None => {
+                                        {
+                                          *base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                        }
+                                      },
+                                    };}
+                                  }
+                                  *ptr1.add(8+20*::core::mem::size_of::<*const u8>()).cast::<usize>() = len26;
+                                  *ptr1.add(8+19*::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result26;
+                                },
+                                None => {
+                                  {
+                                    *ptr1.add(8+18*::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                  }
+                                },
+                              };},
+                              None => {
+                                {
+                                  *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                                }
+                              },
+                            };ptr1
+                          }
+                          #[doc(hidden)]
+                          #[allow(non_snake_case)]
+                          pub unsafe fn __post_return_method_scanner_scope<T: GuestScanner>(arg0: *mut u8,) {
+                            let l0 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+                            match l0 {
+                              0 => (),
+                              _ => {
+                                let l1 = i32::from(*arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                match l1 {
+                                  0 => (),
+                                  _ => {
+                                    let l2 = *arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                    let l3 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                    let base13 = l2;
+                                    let len13 = l3;
+                                    for i in 0..len13 {
+                                      let base = base13.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                                      {
+                                        let l4 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                        let l5 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                        _rt::cabi_dealloc(l4, l5, 1);
+                                        let l6 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                        match l6 {
+                                          0 => (),
+                                          _ => {
+                                            let l7 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                            let l8 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                            _rt::cabi_dealloc(l7, l8, 1);
+                                          },
+                                        }
+                                        let l9 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                        match l9 {
+                                          0 => (),
+                                          _ => {
+                                            let l10 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                            match l10 {
+                                              0 => (),
+                                              _ => {
+                                                let l11 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                                let l12 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                                _rt::cabi_dealloc(l11, l12, 1);
+                                              },
+                                            }
+                                          },
+                                        }
+                                      }
+                                    }
+                                    _rt::cabi_dealloc(base13, len13 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                                  },
+                                }
+                                let l14 = *arg0.add(6*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                let l15 = *arg0.add(7*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                _rt::cabi_dealloc(l14, l15, 1);
+                                let l16 = i32::from(*arg0.add(8+9*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                match l16 {
+                                  0 => (),
+                                  _ => {
+                                    let l17 = *arg0.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                    let l18 = *arg0.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                    _rt::cabi_dealloc(l17, l18, 1);
+                                  },
+                                }
+                                let l19 = i32::from(*arg0.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                match l19 {
+                                  0 => (),
+                                  _ => {
+                                    let l20 = i32::from(*arg0.add(8+13*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                    match l20 {
+                                      0 => (),
+                                      _ => {
+                                        let l21 = *arg0.add(8+15*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                        let l22 = *arg0.add(8+16*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                        _rt::cabi_dealloc(l21, l22, 1);
+                                      },
+                                    }
+                                  },
+                                }
+                                let l23 = i32::from(*arg0.add(8+18*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                match l23 {
+                                  0 => (),
+                                  _ => {
+                                    let l24 = *arg0.add(8+19*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                    let l25 = *arg0.add(8+20*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                    let base35 = l24;
+                                    let len35 = l25;
+                                    for i in 0..len35 {
+                                      let base = base35.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                                      {
+                                        let l26 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                        let l27 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                        _rt::cabi_dealloc(l26, l27, 1);
+                                        let l28 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                        match l28 {
+                                          0 => (),
+                                          _ => {
+                                            let l29 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                            let l30 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                            _rt::cabi_dealloc(l29, l30, 1);
+                                          },
+                                        }
+                                        let l31 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                        match l31 {
+                                          0 => (),
+                                          _ => {
+                                            let l32 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                            match l32 {
+                                              0 => (),
+                                              _ => {
+                                                let l33 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                                let l34 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                                _rt::cabi_dealloc(l33, l34, 1);
+                                              },
+                                            }
+                                          },
+                                        }
+                                      }
+                                    }
+                                    _rt::cabi_dealloc(base35, len35 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+                                  },
+                                }
+                              },
+                            }
+                          }
+                          #[doc(hidden)]
+                          #[allow(non_snake_case)]
+                          pub unsafe fn _export_method_scanner_revert_cabi<T: GuestScanner>(arg0: *mut u8,) {#[cfg(target_arch="wasm32")]
+                          _rt::run_ctors_once();let l0 = *arg0.add(0).cast::<i32>();
+                          let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<i32>();
+                          let l2 = i32::from(*arg0.add(2*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                          T::revert(unsafe { ScannerBorrow::lift(l0 as u32 as usize) }.get(), super::super::super::super::exports::ritalin::scanner::types::ScannerScope{
+                            next_index: l1 as u32,
+                            lookahead: match l2 {
+                              0 => None,
+                              1 => {
+                                let e = {
+                                  let l3 = i32::from(*arg0.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                  let l28 = *arg0.add(6*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                  let l29 = *arg0.add(7*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                  let l30 = *arg0.add(8*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                  let len31 = l30;
+                                  let bytes31 = _rt::Vec::from_raw_parts(l29.cast(), len31, len31);
+                                  let l32 = i32::from(*arg0.add(9*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                  let l33 = i32::from(*arg0.add(1+9*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                  let l34 = *arg0.add(10*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                  let l35 = *arg0.add(4+10*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                  let l36 = i32::from(*arg0.add(8+10*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                  let l40 = i32::from(*arg0.add(8+13*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                  let l49 = i32::from(*arg0.add(8+19*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                                  super::super::super::super::exports::ritalin::scanner::types::Token{
+                                    leading: match l3 {
+                                      0 => None,
+                                      1 => {
+                                        let e = {
+                                          let l4 = *arg0.add(4*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                          let l5 = *arg0.add(5*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                          let base27 = l4;
+                                          let len27 = l5;
+                                          let mut result27 = _rt::Vec::with_capacity(len27);
+                                          for i in 0..len27 {
+                                            let base = base27.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                                            let e27 = {
+                                              let l6 = *base.add(0).cast::<i32>();
+                                              let l7 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                              let l8 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                              let len9 = l8;
+                                              let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+                                              let l10 = i32::from(*base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                              let l11 = i32::from(*base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                              let l12 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                              let l13 = *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                              let l14 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                              let l18 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                                              super::super::super::super::exports::ritalin::scanner::types::TokenItem{
+                                                kind: super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                                  id: l6 as u32,
+                                                  text: _rt::string_lift(bytes9),
+                                                  is_keyword: _rt::bool_lift(l10 as u8),
+                                                  is_terminal: _rt::bool_lift(l11 as u8),
+                                                },
+                                                offset: l12 as u32,
+                                                len: l13 as u32,
+                                                value: match l14 {
+                                                  0 => None,
+                                                  1 => {
+                                                    let e = {
+                                                      let l15 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                                      let l16 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                                      let len17 = l16;
+                                                      let bytes17 = _rt::Vec::from_raw_parts(l15.cast(), len17, len17);
+
+                                                      _rt::string_lift(bytes17)
+                                                    };
+                                                    Some(e)
+                                                  }
+                                                  _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                error: match l18 {
+                                                  0 => None,
+                                                  1 => {
+                                                    let e = {
+                                                      let l19 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                                      use super::super::super::super::exports::ritalin::scanner::types::TokenError as V26;
+                                                      let v26 = match l19 {
+                                                        0 => {
+                                                          V26::IllegalCharacter
+                                                        }
+                                                        n => {
+                                                          debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                          let e26 = {
+                                                            let l20 = *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                                            let l21 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                                            let l22 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                                            let len23 = l22;
+                                                            let bytes23 = _rt::Vec::from_raw_parts(l21.cast(), len23, len23);
+                                                            let l24 = i32::from(*base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                                            let l25 = i32::from(*base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                                                            super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                                              id: l20 as u32,
+                                                              text: _rt::string_lift(bytes23),
+                                                              is_keyword: _rt::bool_lift(l24 as u8),
+                                                              is_terminal: _rt::bool_lift(l25 as u8),
+                                                            }
+                                                          };
+                                                          V26::Unterminated(e26)
+                                                        }
+                                                      };
+
+                                                      v26
+                                                    };
+                                                    Some(e)
+                                                  }
+                                                  _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                              }
+                                            };
+                                            result27.push(e27);
+                                          }
+                                          _rt::cabi_dealloc(base27, len27 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+
+                                          result27
+                                        };
+                                        Some(e)
+                                      }
+                                      _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    main: super::super::super::super::exports::ritalin::scanner::types::TokenItem{
+                                      kind: super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                        id: l28 as u32,
+                                        text: _rt::string_lift(bytes31),
+                                        is_keyword: _rt::bool_lift(l32 as u8),
+                                        is_terminal: _rt::bool_lift(l33 as u8),
+                                      },
+                                      offset: l34 as u32,
+                                      len: l35 as u32,
+                                      value: match l36 {
+                                        0 => None,
+                                        1 => {
+                                          let e = {
+                                            let l37 = *arg0.add(8+11*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                            let l38 = *arg0.add(8+12*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                            let len39 = l38;
+                                            let bytes39 = _rt::Vec::from_raw_parts(l37.cast(), len39, len39);
+
+                                            _rt::string_lift(bytes39)
+                                          };
+                                          Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                      },
+                                      error: match l40 {
+                                        0 => None,
+                                        1 => {
+                                          let e = {
+                                            let l41 = i32::from(*arg0.add(8+14*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                            use super::super::super::super::exports::ritalin::scanner::types::TokenError as V48;
+                                            let v48 = match l41 {
+                                              0 => {
+                                                V48::IllegalCharacter
+                                              }
+                                              n => {
+                                                debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                let e48 = {
+                                                  let l42 = *arg0.add(8+15*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                                  let l43 = *arg0.add(8+16*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                                  let l44 = *arg0.add(8+17*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                                  let len45 = l44;
+                                                  let bytes45 = _rt::Vec::from_raw_parts(l43.cast(), len45, len45);
+                                                  let l46 = i32::from(*arg0.add(8+18*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                                  let l47 = i32::from(*arg0.add(9+18*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                                                  super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                                    id: l42 as u32,
+                                                    text: _rt::string_lift(bytes45),
+                                                    is_keyword: _rt::bool_lift(l46 as u8),
+                                                    is_terminal: _rt::bool_lift(l47 as u8),
+                                                  }
+                                                };
+                                                V48::Unterminated(e48)
+                                              }
+                                            };
+
+                                            v48
+                                          };
+                                          Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                      },
+                                    },
+                                    trailing: match l49 {
+                                      0 => None,
+                                      1 => {
+                                        let e = {
+                                          let l50 = *arg0.add(8+20*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                          let l51 = *arg0.add(8+21*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                          let base73 = l50;
+                                          let len73 = l51;
+                                          let mut result73 = _rt::Vec::with_capacity(len73);
+                                          for i in 0..len73 {
+                                            let base = base73.add(i * (8+13*::core::mem::size_of::<*const u8>()));
+                                            let e73 = {
+                                              let l52 = *base.add(0).cast::<i32>();
+                                              let l53 = *base.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                              let l54 = *base.add(2*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                              let len55 = l54;
+                                              let bytes55 = _rt::Vec::from_raw_parts(l53.cast(), len55, len55);
+                                              let l56 = i32::from(*base.add(3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                              let l57 = i32::from(*base.add(1+3*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                              let l58 = *base.add(4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                              let l59 = *base.add(4+4*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                              let l60 = i32::from(*base.add(8+4*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                              let l64 = i32::from(*base.add(8+7*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                                              super::super::super::super::exports::ritalin::scanner::types::TokenItem{
+                                                kind: super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                                  id: l52 as u32,
+                                                  text: _rt::string_lift(bytes55),
+                                                  is_keyword: _rt::bool_lift(l56 as u8),
+                                                  is_terminal: _rt::bool_lift(l57 as u8),
+                                                },
+                                                offset: l58 as u32,
+                                                len: l59 as u32,
+                                                value: match l60 {
+                                                  0 => None,
+                                                  1 => {
+                                                    let e = {
+                                                      let l61 = *base.add(8+5*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                                      let l62 = *base.add(8+6*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                                      let len63 = l62;
+                                                      let bytes63 = _rt::Vec::from_raw_parts(l61.cast(), len63, len63);
+
+                                                      _rt::string_lift(bytes63)
+                                                    };
+                                                    Some(e)
+                                                  }
+                                                  _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                error: match l64 {
+                                                  0 => None,
+                                                  1 => {
+                                                    let e = {
+                                                      let l65 = i32::from(*base.add(8+8*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                                      use super::super::super::super::exports::ritalin::scanner::types::TokenError as V72;
+                                                      let v72 = match l65 {
+                                                        0 => {
+                                                          V72::IllegalCharacter
+                                                        }
+                                                        n => {
+                                                          debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                          let e72 = {
+                                                            let l66 = *base.add(8+9*::core::mem::size_of::<*const u8>()).cast::<i32>();
+                                                            let l67 = *base.add(8+10*::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+                                                            let l68 = *base.add(8+11*::core::mem::size_of::<*const u8>()).cast::<usize>();
+                                                            let len69 = l68;
+                                                            let bytes69 = _rt::Vec::from_raw_parts(l67.cast(), len69, len69);
+                                                            let l70 = i32::from(*base.add(8+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+                                                            let l71 = i32::from(*base.add(9+12*::core::mem::size_of::<*const u8>()).cast::<u8>());
+
+                                                            super::super::super::super::exports::ritalin::scanner::types::SyntaxKind{
+                                                              id: l66 as u32,
+                                                              text: _rt::string_lift(bytes69),
+                                                              is_keyword: _rt::bool_lift(l70 as u8),
+                                                              is_terminal: _rt::bool_lift(l71 as u8),
+                                                            }
+                                                          };
+                                                          V72::Unterminated(e72)
+                                                        }
+                                                      };
+
+                                                      v72
+                                                    };
+                                                    Some(e)
+                                                  }
+                                                  _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                              }
+                                            };
+                                            result73.push(e73);
+                                          }
+                                          _rt::cabi_dealloc(base73, len73 * (8+13*::core::mem::size_of::<*const u8>()), ::core::mem::size_of::<*const u8>());
+
+                                          result73
+                                        };
+                                        Some(e)
+                                      }
+                                      _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                  }
                                 };
                                 Some(e)
                               }
                               _ => _rt::invalid_enum_discriminant(),
                             },
+                          });
+                          _rt::cabi_dealloc(arg0, 8+22*::core::mem::size_of::<*const u8>(), ::core::mem::size_of::<*const u8>());
+                        }
+                        pub trait Guest {
+                          type Scanner: GuestScanner;
+                          /// All tokens of the source (the last one is `EOF`)
+                          fn tokenize(source: _rt::String,) -> _rt::Vec::<Token>;
+                          /// Tokens of `source` (the text after the edit) reusing `tokens` of the text before the edit
+                          fn relex(source: _rt::String,tokens: _rt::Vec::<Token>,edit: EditScope,) -> _rt::Vec::<Token>;
+                        }
+                        pub trait GuestScanner: 'static {
+
+                          #[doc(hidden)]
+                          unsafe fn _resource_new(val: *mut u8) -> u32
+                          where Self: Sized
+                          {
+                            #[cfg(not(target_arch = "wasm32"))]
+                            {
+                              let _ = val;
+                              unreachable!();
+                            }
+
+                            #[cfg(target_arch = "wasm32")]
+                            {
+                              #[link(wasm_import_module = "[export]ritalin:scanner/scanners@0.1.0")]
+                              unsafe extern "C" {
+                                #[link_name = "[resource-new]scanner"]
+                                fn new(_: *mut u8) -> u32;
+                              }
+                              unsafe { new(val) }
+                            }
                           }
-                        };
-                        Some(e)
+
+                          #[doc(hidden)]
+                          fn _resource_rep(handle: u32) -> *mut u8
+                          where Self: Sized
+                          {
+                            #[cfg(not(target_arch = "wasm32"))]
+                            {
+                              let _ = handle;
+                              unreachable!();
+                            }
+
+                            #[cfg(target_arch = "wasm32")]
+                            {
+                              #[link(wasm_import_module = "[export]ritalin:scanner/scanners@0.1.0")]
+                              unsafe extern "C" {
+                                #[link_name = "[resource-rep]scanner"]
+                                fn rep(_: u32) -> *mut u8;
+                              }
+                              unsafe {
+                                rep(handle)
+                              }
+                            }
+                          }
+
+                          
+                          /// Create new scanner instance.
+                          /// If you want to skip index for scouece string, pass `index-from` to more `0`.
+                          fn create(source: _rt::String,index_from: u32,) -> Scanner;
+                          fn lookahead(&self,) -> Option<Token>;
+                          fn shift(&self,) -> Option<Token>;
+                          fn scope(&self,) -> ScannerScope;
+                          fn revert(&self,scope: ScannerScope,) -> ();
+                        }
+                        #[doc(hidden)]
+
+                        macro_rules! __export_ritalin_scanner_scanners_0_1_0_cabi{
+                          ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+                            #[unsafe(export_name = "ritalin:scanner/scanners@0.1.0#tokenize")]
+                            unsafe extern "C" fn export_tokenize(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+                              unsafe { $($path_to_types)*::_export_tokenize_cabi::<$ty>(arg0, arg1) }
+                            }
+                            #[unsafe(export_name = "cabi_post_ritalin:scanner/scanners@0.1.0#tokenize")]
+                            unsafe extern "C" fn _post_return_tokenize(arg0: *mut u8,) {
+                              unsafe { $($path_to_types)*::__post_return_tokenize::<$ty>(arg0) }
+                            }
+                            #[unsafe(export_name = "ritalin:scanner/scanners@0.1.0#relex")]
+                            unsafe extern "C" fn export_relex(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: i32,arg5: i32,arg6: i32,) -> *mut u8 {
+                              unsafe { $($path_to_types)*::_export_relex_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6) }
+                            }
+                            #[unsafe(export_name = "cabi_post_ritalin:scanner/scanners@0.1.0#relex")]
+                            unsafe extern "C" fn _post_return_relex(arg0: *mut u8,) {
+                              unsafe { $($path_to_types)*::__post_return_relex::<$ty>(arg0) }
+                            }
+                            #[unsafe(export_name = "ritalin:scanner/scanners@0.1.0#[static]scanner.create")]
+                            unsafe extern "C" fn export_static_scanner_create(arg0: *mut u8,arg1: usize,arg2: i32,) -> i32 {
+                              unsafe { $($path_to_types)*::_export_static_scanner_create_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0, arg1, arg2) }
+                            }
+                            #[unsafe(export_name = "ritalin:scanner/scanners@0.1.0#[method]scanner.lookahead")]
+                            unsafe extern "C" fn export_method_scanner_lookahead(arg0: *mut u8,) -> *mut u8 {
+                              unsafe { $($path_to_types)*::_export_method_scanner_lookahead_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                            }
+                            #[unsafe(export_name = "cabi_post_ritalin:scanner/scanners@0.1.0#[method]scanner.lookahead")]
+                            unsafe extern "C" fn _post_return_method_scanner_lookahead(arg0: *mut u8,) {
+                              unsafe { $($path_to_types)*::__post_return_method_scanner_lookahead::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                            }
+                            #[unsafe(export_name = "ritalin:scanner/scanners@0.1.0#[method]scanner.shift")]
+                            unsafe extern "C" fn export_method_scanner_shift(arg0: *mut u8,) -> *mut u8 {
+                              unsafe { $($path_to_types)*::_export_method_scanner_shift_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                            }
+                            #[unsafe(export_name = "cabi_post_ritalin:scanner/scanners@0.1.0#[method]scanner.shift")]
+                            unsafe extern "C" fn _post_return_method_scanner_shift(arg0: *mut u8,) {
+                              unsafe { $($path_to_types)*::__post_return_method_scanner_shift::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                            }
+                            #[unsafe(export_name = "ritalin:scanner/scanners@0.1.0#[method]scanner.scope")]
+                            unsafe extern "C" fn export_method_scanner_scope(arg0: *mut u8,) -> *mut u8 {
+                              unsafe { $($path_to_types)*::_export_method_scanner_scope_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                            }
+                            #[unsafe(export_name = "cabi_post_ritalin:scanner/scanners@0.1.0#[method]scanner.scope")]
+                            unsafe extern "C" fn _post_return_method_scanner_scope(arg0: *mut u8,) {
+                              unsafe { $($path_to_types)*::__post_return_method_scanner_scope::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                            }
+                            #[unsafe(export_name = "ritalin:scanner/scanners@0.1.0#[method]scanner.revert")]
+                            unsafe extern "C" fn export_method_scanner_revert(arg0: *mut u8,) {
+                              unsafe { $($path_to_types)*::_export_method_scanner_revert_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                            }
+
+                            const _: () = {
+                              #[doc(hidden)]
+                              #[unsafe(export_name = "ritalin:scanner/scanners@0.1.0#[dtor]scanner")]
+                              #[allow(non_snake_case)]
+                              unsafe extern "C" fn dtor(rep: *mut u8) {
+                                unsafe {
+                                  $($path_to_types)*::Scanner::dtor::<
+                                  <$ty as $($path_to_types)*::Guest>::Scanner
+                                  >(rep)
+                                }
+                              }
+                            };
+                            
+                          };);
+                        }
+                        #[doc(hidden)]
+                        pub(crate) use __export_ritalin_scanner_scanners_0_1_0_cabi;
+
+                        #[cfg_attr(target_pointer_width="64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width="32", repr(align(4)))]
+                        struct _RetArea([::core::mem::MaybeUninit::<u8>; 8+21*::core::mem::size_of::<*const u8>()]);
+                        static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8+21*::core::mem::size_of::<*const u8>()]);
+
                       }
-                      _ => _rt::invalid_enum_discriminant(),
-                    },
-                  });
-                  _rt::cabi_dealloc(arg0, 8+16*::core::mem::size_of::<*const u8>(), ::core::mem::size_of::<*const u8>());
-                }
-                pub trait Guest {
-                  type Scanner: GuestScanner;
-                }
-                pub trait GuestScanner: 'static {
 
-                  #[doc(hidden)]
-                  unsafe fn _resource_new(val: *mut u8) -> u32
-                  where Self: Sized
-                  {
-                    #[cfg(not(target_arch = "wasm32"))]
-                    {
-                      let _ = val;
-                      unreachable!();
                     }
+                  }
+                }
+                mod _rt {
+                  #![allow(dead_code, clippy::all)]
+                  pub use alloc_crate::string::String;
+                  pub use alloc_crate::vec::Vec;
 
-                    #[cfg(target_arch = "wasm32")]
-                    {
-                      #[link(wasm_import_module = "[export]ritalin:scanner/scanners@0.0.1")]
-                      unsafe extern "C" {
-                        #[link_name = "[resource-new]scanner"]
-                        fn new(_: *mut u8) -> u32;
+
+                  use core::fmt;
+                  use core::marker;
+                  use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+
+                  /// A type which represents a component model resource, either imported or
+                  /// exported into this component.
+                  ///
+                  /// This is a low-level wrapper which handles the lifetime of the resource
+                  /// (namely this has a destructor). The `T` provided defines the component model
+                  /// intrinsics that this wrapper uses.
+                  ///
+                  /// One of the chief purposes of this type is to provide `Deref` implementations
+                  /// to access the underlying data when it is owned.
+                  ///
+                  /// This type is primarily used in generated code for exported and imported
+                  /// resources.
+                  #[repr(transparent)]
+                  pub struct Resource<T: WasmResource> {
+                    // NB: This would ideally be `u32` but it is not. The fact that this has
+                    // interior mutability is not exposed in the API of this type except for the
+                    // `take_handle` method which is supposed to in theory be private.
+                    //
+                    // This represents, almost all the time, a valid handle value. When it's
+                    // invalid it's stored as `u32::MAX`.
+                    handle: AtomicU32,
+                    _marker: marker::PhantomData<T>,
+                  }
+
+                  /// A trait which all wasm resources implement, namely providing the ability to
+                  /// drop a resource.
+                  ///
+                  /// This generally is implemented by generated code, not user-facing code.
+                  #[allow(clippy::missing_safety_doc)]
+                  pub unsafe trait WasmResource {
+                    /// Invokes the `[resource-drop]...` intrinsic.
+                    unsafe fn drop(handle: u32);
+                  }
+
+                  impl<T: WasmResource> Resource<T> {
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                      debug_assert!(handle != u32::MAX);
+                      Self {
+                        handle: AtomicU32::new(handle),
+                        _marker: marker::PhantomData,
                       }
-                      unsafe { new(val) }
+                    }
+
+                    /// Takes ownership of the handle owned by `resource`.
+                    ///
+                    /// Note that this ideally would be `into_handle` taking `Resource<T>` by
+                    /// ownership. The code generator does not enable that in all situations,
+                    /// unfortunately, so this is provided instead.
+                    ///
+                    /// Also note that `take_handle` is in theory only ever called on values
+                    /// owned by a generated function. For example a generated function might
+                    /// take `Resource<T>` as an argument but then call `take_handle` on a
+                    /// reference to that argument. In that sense the dynamic nature of
+                    /// `take_handle` should only be exposed internally to generated code, not
+                    /// to user code.
+                    #[doc(hidden)]
+                    pub fn take_handle(resource: &Resource<T>) -> u32 {
+                      resource.handle.swap(u32::MAX, Relaxed)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn handle(resource: &Resource<T>) -> u32 {
+                      resource.handle.load(Relaxed)
                     }
                   }
 
-                  #[doc(hidden)]
-                  fn _resource_rep(handle: u32) -> *mut u8
-                  where Self: Sized
-                  {
-                    #[cfg(not(target_arch = "wasm32"))]
-                    {
-                      let _ = handle;
-                      unreachable!();
+                  impl<T: WasmResource> fmt::Debug for Resource<T> {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                      f.debug_struct("Resource")
+                      .field("handle", &self.handle)
+                      .finish()
                     }
+                  }
 
-                    #[cfg(target_arch = "wasm32")]
-                    {
-                      #[link(wasm_import_module = "[export]ritalin:scanner/scanners@0.0.1")]
-                      unsafe extern "C" {
-                        #[link_name = "[resource-rep]scanner"]
-                        fn rep(_: u32) -> *mut u8;
-                      }
+                  impl<T: WasmResource> Drop for Resource<T> {
+                    fn drop(&mut self) {
                       unsafe {
-                        rep(handle)
+                        match self.handle.load(Relaxed) {
+                          // If this handle was "taken" then don't do anything in the
+                          // destructor.
+                          u32::MAX => {}
+
+                          // ... but otherwise do actually destroy it with the imported
+                          // component model intrinsic as defined through `T`.
+                          other => T::drop(other),
+                        }
                       }
                     }
                   }
+                  pub use alloc_crate::boxed::Box;
 
+                  #[cfg(target_arch = "wasm32")]
+                  pub fn run_ctors_once() {
+                    wit_bindgen::rt::run_ctors_once();
+                  }
+                  pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+                    if cfg!(debug_assertions) {
+                      String::from_utf8(bytes).unwrap()
+                    } else {
+                      String::from_utf8_unchecked(bytes)
+                    }
+                  }
                   
-                  /// Create new scanner instance.
-                  /// If you want to skip index for scouece string, pass `index-from` to more `0`.
-                  fn create(source: _rt::String,index_from: u32,) -> Scanner;
-                  fn lookahead(&self,) -> Option<Token>;
-                  fn shift(&self,) -> Option<Token>;
-                  fn scope(&self,) -> ScannerScope;
-                  fn revert(&self,scope: ScannerScope,) -> ();
-                }
-                #[doc(hidden)]
+                  pub fn as_i32<T: AsI32>(t: T) -> i32 {
+                    t.as_i32()
+                  }
 
-                macro_rules! __export_ritalin_scanner_scanners_0_0_1_cabi{
-                  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+                  pub trait AsI32 {
+                    fn as_i32(self) -> i32;
+                  }
 
-                    #[unsafe(export_name = "ritalin:scanner/scanners@0.0.1#[static]scanner.create")]
-                    unsafe extern "C" fn export_static_scanner_create(arg0: *mut u8,arg1: usize,arg2: i32,) -> i32 {
-                      unsafe { $($path_to_types)*::_export_static_scanner_create_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0, arg1, arg2) }
+                  impl<'a, T: Copy + AsI32> AsI32 for &'a T {
+                    fn as_i32(self) -> i32 {
+                      (*self).as_i32()
                     }
-                    #[unsafe(export_name = "ritalin:scanner/scanners@0.0.1#[method]scanner.lookahead")]
-                    unsafe extern "C" fn export_method_scanner_lookahead(arg0: *mut u8,) -> *mut u8 {
-                      unsafe { $($path_to_types)*::_export_method_scanner_lookahead_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                  }
+                  
+                  impl AsI32 for i32 {
+                    #[inline]
+                    fn as_i32(self) -> i32 {
+                      self as i32
                     }
-                    #[unsafe(export_name = "cabi_post_ritalin:scanner/scanners@0.0.1#[method]scanner.lookahead")]
-                    unsafe extern "C" fn _post_return_method_scanner_lookahead(arg0: *mut u8,) {
-                      unsafe { $($path_to_types)*::__post_return_method_scanner_lookahead::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                  }
+                  
+                  impl AsI32 for u32 {
+                    #[inline]
+                    fn as_i32(self) -> i32 {
+                      self as i32
                     }
-                    #[unsafe(export_name = "ritalin:scanner/scanners@0.0.1#[method]scanner.shift")]
-                    unsafe extern "C" fn export_method_scanner_shift(arg0: *mut u8,) -> *mut u8 {
-                      unsafe { $($path_to_types)*::_export_method_scanner_shift_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                  }
+                  
+                  impl AsI32 for i16 {
+                    #[inline]
+                    fn as_i32(self) -> i32 {
+                      self as i32
                     }
-                    #[unsafe(export_name = "cabi_post_ritalin:scanner/scanners@0.0.1#[method]scanner.shift")]
-                    unsafe extern "C" fn _post_return_method_scanner_shift(arg0: *mut u8,) {
-                      unsafe { $($path_to_types)*::__post_return_method_scanner_shift::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                  }
+                  
+                  impl AsI32 for u16 {
+                    #[inline]
+                    fn as_i32(self) -> i32 {
+                      self as i32
                     }
-                    #[unsafe(export_name = "ritalin:scanner/scanners@0.0.1#[method]scanner.scope")]
-                    unsafe extern "C" fn export_method_scanner_scope(arg0: *mut u8,) -> *mut u8 {
-                      unsafe { $($path_to_types)*::_export_method_scanner_scope_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                  }
+                  
+                  impl AsI32 for i8 {
+                    #[inline]
+                    fn as_i32(self) -> i32 {
+                      self as i32
                     }
-                    #[unsafe(export_name = "cabi_post_ritalin:scanner/scanners@0.0.1#[method]scanner.scope")]
-                    unsafe extern "C" fn _post_return_method_scanner_scope(arg0: *mut u8,) {
-                      unsafe { $($path_to_types)*::__post_return_method_scanner_scope::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                  }
+                  
+                  impl AsI32 for u8 {
+                    #[inline]
+                    fn as_i32(self) -> i32 {
+                      self as i32
                     }
-                    #[unsafe(export_name = "ritalin:scanner/scanners@0.0.1#[method]scanner.revert")]
-                    unsafe extern "C" fn export_method_scanner_revert(arg0: *mut u8,) {
-                      unsafe { $($path_to_types)*::_export_method_scanner_revert_cabi::<<$ty as $($path_to_types)*::Guest>::Scanner>(arg0) }
+                  }
+                  
+                  impl AsI32 for char {
+                    #[inline]
+                    fn as_i32(self) -> i32 {
+                      self as i32
                     }
-
-                    const _: () = {
-                      #[doc(hidden)]
-                      #[unsafe(export_name = "ritalin:scanner/scanners@0.0.1#[dtor]scanner")]
-                      #[allow(non_snake_case)]
-                      unsafe extern "C" fn dtor(rep: *mut u8) {
-                        unsafe {
-                          $($path_to_types)*::Scanner::dtor::<
-                          <$ty as $($path_to_types)*::Guest>::Scanner
-                          >(rep)
-                        }
+                  }
+                  
+                  impl AsI32 for usize {
+                    #[inline]
+                    fn as_i32(self) -> i32 {
+                      self as i32
+                    }
+                  }
+                  pub use alloc_crate::alloc;
+                  pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+                    if size == 0 {
+                      return;
+                    }
+                    let layout = alloc::Layout::from_size_align_unchecked(size, align);
+                    alloc::dealloc(ptr, layout);
+                  }
+                  pub unsafe fn bool_lift(val: u8) -> bool {
+                    if cfg!(debug_assertions) {
+                      match val {
+                        0 => false,
+                        1 => true,
+                        _ => panic!("invalid bool discriminant"),
                       }
-                    };
-                    
-                  };);
+                    } else {
+                      val != 0
+                    }
+                  }
+                  pub unsafe fn invalid_enum_discriminant<T>() -> T {
+                    if cfg!(debug_assertions) {
+                      panic!("invalid enum discriminant")
+                    } else {
+                      unsafe { core::hint::unreachable_unchecked() }
+                    }
+                  }
+                  extern crate alloc as alloc_crate;
                 }
-                #[doc(hidden)]
-                pub(crate) use __export_ritalin_scanner_scanners_0_0_1_cabi;
-
-                #[cfg_attr(target_pointer_width="64", repr(align(8)))]
-                #[cfg_attr(target_pointer_width="32", repr(align(4)))]
-                struct _RetArea([::core::mem::MaybeUninit::<u8>; 8+15*::core::mem::size_of::<*const u8>()]);
-                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8+15*::core::mem::size_of::<*const u8>()]);
-
-              }
-
-            }
-          }
-        }
-        mod _rt {
-          #![allow(dead_code, clippy::all)]
-          pub use alloc_crate::string::String;
-          pub use alloc_crate::vec::Vec;
-
-
-          use core::fmt;
-          use core::marker;
-          use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
-
-          /// A type which represents a component model resource, either imported or
-          /// exported into this component.
-          ///
-          /// This is a low-level wrapper which handles the lifetime of the resource
-          /// (namely this has a destructor). The `T` provided defines the component model
-          /// intrinsics that this wrapper uses.
-          ///
-          /// One of the chief purposes of this type is to provide `Deref` implementations
-          /// to access the underlying data when it is owned.
-          ///
-          /// This type is primarily used in generated code for exported and imported
-          /// resources.
-          #[repr(transparent)]
-          pub struct Resource<T: WasmResource> {
-            // NB: This would ideally be `u32` but it is not. The fact that this has
-            // interior mutability is not exposed in the API of this type except for the
-            // `take_handle` method which is supposed to in theory be private.
-            //
-            // This represents, almost all the time, a valid handle value. When it's
-            // invalid it's stored as `u32::MAX`.
-            handle: AtomicU32,
-            _marker: marker::PhantomData<T>,
-          }
-
-          /// A trait which all wasm resources implement, namely providing the ability to
-          /// drop a resource.
-          ///
-          /// This generally is implemented by generated code, not user-facing code.
-          #[allow(clippy::missing_safety_doc)]
-          pub unsafe trait WasmResource {
-            /// Invokes the `[resource-drop]...` intrinsic.
-            unsafe fn drop(handle: u32);
-          }
-
-          impl<T: WasmResource> Resource<T> {
-            #[doc(hidden)]
-            pub unsafe fn from_handle(handle: u32) -> Self {
-              debug_assert!(handle != u32::MAX);
-              Self {
-                handle: AtomicU32::new(handle),
-                _marker: marker::PhantomData,
-              }
-            }
-
-            /// Takes ownership of the handle owned by `resource`.
-            ///
-            /// Note that this ideally would be `into_handle` taking `Resource<T>` by
-            /// ownership. The code generator does not enable that in all situations,
-            /// unfortunately, so this is provided instead.
-            ///
-            /// Also note that `take_handle` is in theory only ever called on values
-            /// owned by a generated function. For example a generated function might
-            /// take `Resource<T>` as an argument but then call `take_handle` on a
-            /// reference to that argument. In that sense the dynamic nature of
-            /// `take_handle` should only be exposed internally to generated code, not
-            /// to user code.
-            #[doc(hidden)]
-            pub fn take_handle(resource: &Resource<T>) -> u32 {
-              resource.handle.swap(u32::MAX, Relaxed)
-            }
-
-            #[doc(hidden)]
-            pub fn handle(resource: &Resource<T>) -> u32 {
-              resource.handle.load(Relaxed)
-            }
-          }
 
-          impl<T: WasmResource> fmt::Debug for Resource<T> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-              f.debug_struct("Resource")
-              .field("handle", &self.handle)
-              .finish()
-            }
-          }
+                /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
+                /// the root implementation of all generated traits.
+                ///
+                /// For more information see the documentation of `wit_bindgen::generate!`.
+                ///
+                /// ```rust
+                /// # macro_rules! export{ ($($t:tt)*) => (); }
+                /// # trait Guest {}
+                /// struct MyType;
+                ///
+                /// impl Guest for MyType {
+                ///     // ...
+                /// }
+                ///
+                /// export!(MyType);
+                /// ```
+                #[allow(unused_macros)]
+                #[doc(hidden)]
 
-          impl<T: WasmResource> Drop for Resource<T> {
-            fn drop(&mut self) {
-              unsafe {
-                match self.handle.load(Relaxed) {
-                  // If this handle was "taken" then don't do anything in the
-                  // destructor.
-                  u32::MAX => {}
-
-                  // ... but otherwise do actually destroy it with the imported
-                  // component model intrinsic as defined through `T`.
-                  other => T::drop(other),
+                macro_rules! __export_scanner_world_impl {
+                  ($ty:ident) => (crate::bindings::scanner_world::export!($ty with_types_in crate::bindings::scanner_world););
+                  ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
+                  $($path_to_types_root)*::exports::ritalin::scanner::types::__export_ritalin_scanner_types_0_1_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::ritalin::scanner::types);
+                  $($path_to_types_root)*::exports::ritalin::scanner::scanners::__export_ritalin_scanner_scanners_0_1_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::ritalin::scanner::scanners);
+                  )
                 }
-              }
-            }
-          }
-          pub use alloc_crate::boxed::Box;
+                #[doc(inline)]
+                pub(crate) use __export_scanner_world_impl as export;
 
-          #[cfg(target_arch = "wasm32")]
-          pub fn run_ctors_once() {
-            wit_bindgen::rt::run_ctors_once();
-          }
-          pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
-            if cfg!(debug_assertions) {
-              String::from_utf8(bytes).unwrap()
-            } else {
-              String::from_utf8_unchecked(bytes)
-            }
-          }
-          
-          pub fn as_i32<T: AsI32>(t: T) -> i32 {
-            t.as_i32()
-          }
-
-          pub trait AsI32 {
-            fn as_i32(self) -> i32;
-          }
-
-          impl<'a, T: Copy + AsI32> AsI32 for &'a T {
-            fn as_i32(self) -> i32 {
-              (*self).as_i32()
-            }
-          }
-          
-          impl AsI32 for i32 {
-            #[inline]
-            fn as_i32(self) -> i32 {
-              self as i32
-            }
-          }
-          
-          impl AsI32 for u32 {
-            #[inline]
-            fn as_i32(self) -> i32 {
-              self as i32
-            }
-          }
-          
-          impl AsI32 for i16 {
-            #[inline]
-            fn as_i32(self) -> i32 {
-              self as i32
-            }
-          }
-          
-          impl AsI32 for u16 {
-            #[inline]
-            fn as_i32(self) -> i32 {
-              self as i32
-            }
-          }
-          
-          impl AsI32 for i8 {
-            #[inline]
-            fn as_i32(self) -> i32 {
-              self as i32
-            }
-          }
-          
-          impl AsI32 for u8 {
-            #[inline]
-            fn as_i32(self) -> i32 {
-              self as i32
-            }
-          }
-          
-          impl AsI32 for char {
-            #[inline]
-            fn as_i32(self) -> i32 {
-              self as i32
-            }
-          }
-          
-          impl AsI32 for usize {
-            #[inline]
-            fn as_i32(self) -> i32 {
-              self as i32
-            }
-          }
-          pub use alloc_crate::alloc;
-          pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
-            if size == 0 {
-              return;
-            }
-            let layout = alloc::Layout::from_size_align_unchecked(size, align);
-            alloc::dealloc(ptr, layout);
-          }
-          pub unsafe fn bool_lift(val: u8) -> bool {
-            if cfg!(debug_assertions) {
-              match val {
-                0 => false,
-                1 => true,
-                _ => panic!("invalid bool discriminant"),
-              }
-            } else {
-              val != 0
-            }
-          }
-          pub unsafe fn invalid_enum_discriminant<T>() -> T {
-            if cfg!(debug_assertions) {
-              panic!("invalid enum discriminant")
-            } else {
-              unsafe { core::hint::unreachable_unchecked() }
-            }
-          }
-          extern crate alloc as alloc_crate;
-        }
-
-        /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
-        /// the root implementation of all generated traits.
-        ///
-        /// For more information see the documentation of `wit_bindgen::generate!`.
-        ///
-        /// ```rust
-        /// # macro_rules! export{ ($($t:tt)*) => (); }
-        /// # trait Guest {}
-        /// struct MyType;
-        ///
-        /// impl Guest for MyType {
-        ///     // ...
-        /// }
-        ///
-        /// export!(MyType);
-        /// ```
-        #[allow(unused_macros)]
-        #[doc(hidden)]
-
-        macro_rules! __export_scanner_world_impl {
-          ($ty:ident) => (crate::bindings::scanner_world::export!($ty with_types_in crate::bindings::scanner_world););
-          ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
-          $($path_to_types_root)*::exports::ritalin::scanner::types::__export_ritalin_scanner_types_0_0_1_cabi!($ty with_types_in $($path_to_types_root)*::exports::ritalin::scanner::types);
-          $($path_to_types_root)*::exports::ritalin::scanner::scanners::__export_ritalin_scanner_scanners_0_0_1_cabi!($ty with_types_in $($path_to_types_root)*::exports::ritalin::scanner::scanners);
-          )
-        }
-        #[doc(inline)]
-        pub(crate) use __export_scanner_world_impl as export;
+                #[cfg(target_arch = "wasm32")]
+                #[unsafe(link_section = "component-type:wit-bindgen:0.41.0:ritalin:scanner@0.1.0:scanner-world:encoded world")]
+                #[doc(hidden)]
+                #[allow(clippy::octal_escapes)]
+                pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 950] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xb2\x06\x01A\x02\x01\
+A\x07\x01B\x11\x01r\x04\x02idy\x04texts\x0ais-keyword\x7f\x0bis-terminal\x7f\x04\
+\0\x0bsyntax-kind\x03\0\0\x01q\x02\x11illegal-character\0\0\x0cunterminated\x01\x01\
+\0\x04\0\x0btoken-error\x03\0\x02\x01ks\x01k\x03\x01r\x05\x04kind\x01\x06offsety\
+\x03leny\x05value\x04\x05error\x05\x04\0\x0atoken-item\x03\0\x06\x01p\x07\x01k\x08\
+\x01r\x03\x07leading\x09\x04main\x07\x08trailing\x09\x04\0\x05token\x03\0\x0a\x01\
+k\x0b\x01r\x02\x0anext-indexy\x09lookahead\x0c\x04\0\x0dscanner-scope\x03\0\x0d\x01\
+r\x03\x06offsety\x08from-leny\x06to-leny\x04\0\x0aedit-scope\x03\0\x0f\x04\0\x1b\
+ritalin:scanner/types@0.1.0\x05\0\x02\x03\0\0\x05token\x02\x03\0\0\x0dscanner-sc\
+ope\x02\x03\0\0\x0aedit-scope\x01B\x18\x02\x03\x02\x01\x01\x04\0\x05token\x03\0\0\
+\x02\x03\x02\x01\x02\x04\0\x0dscanner-scope\x03\0\x02\x02\x03\x02\x01\x03\x04\0\x0a\
+edit-scope\x03\0\x04\x04\0\x07scanner\x03\x01\x01i\x06\x01@\x02\x06sources\x0ain\
+dex-fromy\0\x07\x04\0\x16[static]scanner.create\x01\x08\x01h\x06\x01k\x01\x01@\x01\
+\x04self\x09\0\x0a\x04\0\x19[method]scanner.lookahead\x01\x0b\x04\0\x15[method]s\
+canner.shift\x01\x0b\x01@\x01\x04self\x09\0\x03\x04\0\x15[method]scanner.scope\x01\
+\x0c\x01@\x02\x04self\x09\x05scope\x03\x01\0\x04\0\x16[method]scanner.revert\x01\
+\x0d\x01p\x01\x01@\x01\x06sources\0\x0e\x04\0\x08tokenize\x01\x0f\x01@\x03\x06so\
+urces\x06tokens\x0e\x04edit\x05\0\x0e\x04\0\x05relex\x01\x10\x04\0\x1eritalin:sc\
+anner/scanners@0.1.0\x05\x04\x04\0#ritalin:scanner/scanner-world@0.1.0\x04\0\x0b\
+\x13\x01\0\x0dscanner-world\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dw\
+it-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 
-        #[cfg(target_arch = "wasm32")]
-        #[unsafe(link_section = "component-type:wit-bindgen:0.41.0:ritalin:scanner@0.0.1:scanner-world:encoded world")]
-        #[doc(hidden)]
-        #[allow(clippy::octal_escapes)]
-        pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 737] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xdd\x04\x01A\x02\x01\
-A\x06\x01B\x0c\x01r\x04\x02idy\x04texts\x0ais-keyword\x7f\x0bis-terminal\x7f\x04\
-\0\x0bsyntax-kind\x03\0\0\x01ks\x01r\x04\x04kind\x01\x06offsety\x03leny\x05value\
-\x02\x04\0\x0atoken-item\x03\0\x03\x01p\x04\x01k\x05\x01r\x03\x07leading\x06\x04\
-main\x04\x08trailing\x06\x04\0\x05token\x03\0\x07\x01k\x08\x01r\x02\x0anext-inde\
-xy\x09lookahead\x09\x04\0\x0dscanner-scope\x03\0\x0a\x04\0\x1britalin:scanner/ty\
-pes@0.0.1\x05\0\x02\x03\0\0\x05token\x02\x03\0\0\x0dscanner-scope\x01B\x11\x02\x03\
-\x02\x01\x01\x04\0\x05token\x03\0\0\x02\x03\x02\x01\x02\x04\0\x0dscanner-scope\x03\
-\0\x02\x04\0\x07scanner\x03\x01\x01i\x04\x01@\x02\x06sources\x0aindex-fromy\0\x05\
-\x04\0\x16[static]scanner.create\x01\x06\x01h\x04\x01k\x01\x01@\x01\x04self\x07\0\
-\x08\x04\0\x19[method]scanner.lookahead\x01\x09\x04\0\x15[method]scanner.shift\x01\
-\x09\x01@\x01\x04self\x07\0\x03\x04\0\x15[method]scanner.scope\x01\x0a\x01@\x02\x04\
-self\x07\x05scope\x03\x01\0\x04\0\x16[method]scanner.revert\x01\x0b\x04\0\x1erit\
-alin:scanner/scanners@0.0.1\x05\x03\x04\0#ritalin:scanner/scanner-world@0.0.1\x04\
-\0\x0b\x13\x01\0\x0dscanner-world\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\
-\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
-
-        #[inline(never)]
-        #[doc(hidden)]
-        pub fn __link_custom_section_describing_imports() {
-          wit_bindgen::rt::maybe_link_cabi_realloc();
-        }
-        
+                #[inline(never)]
+                #[doc(hidden)]
+                pub fn __link_custom_section_describing_imports() {
+                  wit_bindgen::rt::maybe_link_cabi_realloc();
+                }
+                
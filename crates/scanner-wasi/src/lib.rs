@@ -7,3 +7,7 @@ pub mod scanners {
 pub mod scanner_types {
     pub use super::bindings::scanner_world::exports::ritalin::scanner::types::*;
 }
+#[cfg(feature = "compat")]
+pub mod compat_scanner_types {
+    pub use super::bindings::compat_world::exports::ritalin::scanner::types::*;
+}
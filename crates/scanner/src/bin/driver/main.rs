@@ -22,4 +22,51 @@ pub fn main() -> Result<(), anyhow::Error> {
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod scanner_tests {
+    use scanner::{relex, tokenize, EditScope, Token, TokenError};
+    use sqlite_parser_proto::engine::kinds as syntax_kind;
+
+    #[test]
+    fn test_tokenize_and_relex() -> Result<(), anyhow::Error> {
+        let items = |tokens: &[Token]| tokens.iter()
+            .flat_map(|token| token.leading.iter().flatten().chain(std::iter::once(&token.main)).chain(token.trailing.iter().flatten()))
+            .map(|item| (item.tag, item.offset, item.len, item.error))
+            .collect::<Vec<_>>()
+        ;
+
+        let tokens = tokenize("SELECT 'abc");
+        assert_eq!(vec![syntax_kind::r#SELECT, syntax_kind::r#ILLEGAL, syntax_kind::r#EOF], tokens.iter().map(|token| token.main.tag).collect::<Vec<_>>());
+        assert_eq!((7, 4, Some(TokenError::Unterminated(syntax_kind::r#STRING))), (tokens[1].main.offset, tokens[1].main.len, tokens[1].main.error));
+
+        // `''` is an escaped quote, not the closing one
+        let tokens = tokenize("SELECT 'a\nit''s");
+        assert_eq!((syntax_kind::r#ILLEGAL, 8, Some(TokenError::Unterminated(syntax_kind::r#STRING))), (tokens[1].main.tag, tokens[1].main.len, tokens[1].main.error));
+        let tokens = tokenize("SELECT \"a\nb\"\"c");
+        assert_eq!(Some(TokenError::Unterminated(syntax_kind::r#ID)), tokens[1].main.error);
+        // Closed on a later line (not matched by the pattern, but not unterminated either)
+        let tokens = tokenize("SELECT 'a\nb'';'");
+        assert_eq!(Some(TokenError::IllegalCharacter), tokens[1].main.error);
+
+        let tokens = tokenize("SELECT ¥ 1");
+        assert_eq!((syntax_kind::r#ILLEGAL, 2, Some(TokenError::IllegalCharacter)), (tokens[1].main.tag, tokens[1].main.len, tokens[1].main.error));
+        assert_eq!(syntax_kind::r#INTEGER, tokens[2].main.tag);
+
+        let source = "SELECT a FROM foo WHERE b = 1;";
+        let tokens = tokenize(source);
+        let cases = [
+            // Replace `foo` with `foobar`
+            (source.replace("foo", "foobar"), EditScope { offset: 14, from_len: 3, to_len: 6 }),
+            // Extend `a` to `ac`
+            (source.replace("a ", "ac "), EditScope { offset: 8, from_len: 0, to_len: 1 }),
+            // Open a string literal
+            (source.replace("1;", "'1;"), EditScope { offset: 28, from_len: 0, to_len: 1 }),
+        ];
+        for (new_source, edit) in cases {
+            assert_eq!(items(&tokenize(&new_source)), items(&relex(&new_source, &tokens, &edit)));
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 mod scanner;
+mod tokenize;
 
 pub use scanner::{Scanner, ScannerScope};
+pub use tokenize::{tokenize, relex, EditScope};
 
 use sqlite_parser_proto::SyntaxKind;
 
@@ -36,4 +38,13 @@ pub struct TokenItem {
     pub offset: usize,
     pub len: usize,
     pub value: Option<String>,
+    // Set on `ILLEGAL` items
+    pub error: Option<TokenError>,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TokenError {
+    IllegalCharacter,
+    // String, quoted identifier or block comment reaching the end of input (kind is the intended one)
+    Unterminated(SyntaxKind),
 }
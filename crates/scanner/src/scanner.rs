@@ -2,7 +2,7 @@ use sqlite_parser_proto::engine;
 use sqlite_parser_proto::engine::RegexScanPattern;
 use sqlite_parser_proto::SyntaxKind;
 
-use crate::{Token, TokenItem};
+use crate::{Token, TokenError, TokenItem};
 
 pub struct Scanner {
     source: String,
@@ -92,15 +92,14 @@ fn scan_extra_internal(source: &str, index: usize, scanners: &[&RegexScanPattern
         return None;
     };
 
-    let mut offset = 0;
-
-    for scanner in scanners {
-        if let Some(item) = scan_regex(scanner, source, index + offset) {
-            offset = item.len;
-            items.push(item);
-            break
-        }
-    }
+    // Longest match first (e.g. `0x1F` rather than `0`), the earlier pattern on a tie
+    let item = scanners.iter()
+        .filter_map(|scanner| scan_regex(scanner, source, index))
+        .rev()
+        .max_by_key(|item| item.len)?
+    ;
+    let offset = item.len;
+    items.push(item);
 
     (offset > 0).then(|| index + offset)
 }
@@ -112,7 +111,8 @@ fn scan_regex(scanner: &RegexScanPattern, source: &str, index: usize) -> Option<
                 tag: scanner.kind.clone(), 
                 offset: index,
                 len: m.len(), 
-                value: Some(m.as_str().to_string())
+                value: Some(m.as_str().to_string()),
+                error: None,
             })
         }
         _ => None
@@ -125,18 +125,24 @@ fn scan_main(source: &str, index: usize, extra_scanners: &[usize]) -> Option<(us
         return None;
     }
     if source.len() == index {
-        let item = TokenItem { tag: engine::kinds::r#EOF, offset: index, len: 0, value: None };
+        let item = TokenItem { tag: engine::kinds::r#EOF, offset: index, len: 0, value: None, error: None };
         return Some((index + 1, item));
     }
 
     if let Some(sub_source) = source.get(index..) {
+        // Block comments are consumed as trivia if terminated
+        if sub_source.starts_with("/*") {
+            return Some(unterminated_item(sub_source, index, engine::kinds::r#COMMENT));
+        }
+
         let item1 = engine::scan_by_lexme_rule(sub_source).and_then(|item| {
             let tag = SyntaxKind::from_raw(cstree::RawSyntaxKind(item.id));
             let item = TokenItem { 
                 tag, 
                 offset: index,
                 len: item.len, 
-                value: Some(item.pattern.to_string())
+                value: Some(item.pattern.to_string()),
+                error: None,
             };
             Some((item.offset + item.len, item))
         });
@@ -163,20 +169,55 @@ fn scan_main(source: &str, index: usize, extra_scanners: &[usize]) -> Option<(us
         }
     }
 
-    let (len, illegal_char) = source.get(index..)
-        .and_then(|s| s.char_indices().next())
-        .unwrap_or((1, '\0'))
-    ;
+    let sub_source = source.get(index..).unwrap_or_default();
+    // Quotes closed on a later line are not matched by the patterns, but are not unterminated
+    let unterminated = match sub_source.chars().next() {
+        Some('\'') if !is_quote_closed(sub_source, '\'') => Some(engine::kinds::r#STRING),
+        Some('"') if !is_quote_closed(sub_source, '"') => Some(engine::kinds::r#ID),
+        _ => None,
+    };
+    if let Some(kind) = unterminated {
+        return Some(unterminated_item(sub_source, index, kind));
+    }
 
+    let illegal_char = sub_source.chars().next().unwrap_or('\0');
     let item = TokenItem { 
         tag: engine::kinds::r#ILLEGAL,
         offset: index,
-        len,
+        len: illegal_char.len_utf8(),
         value: Some(illegal_char.to_string()),
+        error: Some(TokenError::IllegalCharacter),
     };
     Some((item.offset + item.len, item))
 }
 
+// True if the quote at the start has a closing quote (a doubled quote is an escape, e.g. `'it''s'`)
+fn is_quote_closed(sub_source: &str, quote: char) -> bool {
+    let mut chars = sub_source.chars().skip(1).peekable();
+
+    while let Some(c) = chars.next() {
+        if c != quote {
+            continue;
+        }
+        if chars.next_if_eq(&quote).is_none() {
+            return true;
+        }
+    }
+    false
+}
+
+// The rest of the input is one `ILLEGAL` item
+fn unterminated_item(sub_source: &str, index: usize, kind: SyntaxKind) -> (usize, TokenItem) {
+    let item = TokenItem {
+        tag: engine::kinds::r#ILLEGAL,
+        offset: index,
+        len: sub_source.len(),
+        value: Some(sub_source.to_string()),
+        error: Some(TokenError::Unterminated(kind)),
+    };
+    (item.offset + item.len, item)
+}
+
 #[derive(Clone)]
 pub struct ScannerScope {
     pub next_index: usize,
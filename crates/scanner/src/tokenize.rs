@@ -0,0 +1,64 @@
+use crate::{Scanner, Token, TokenItem};
+
+#[derive(Clone, Copy, Debug)]
+pub struct EditScope {
+    pub offset: u32,
+    pub from_len: u32,
+    pub to_len: u32,
+}
+
+// All tokens of the source (the last one is `EOF`)
+pub fn tokenize(source: &str) -> Vec<Token> {
+    let mut scanner = Scanner::create(source.to_string(), 0);
+    std::iter::from_fn(|| scanner.shift()).collect()
+}
+
+// Tokens of `source` (the text after the edit) reusing `tokens` of the text before the edit.
+// Scanning restarts one token before the edit and stops when it reaches the start of an old token following the edit.
+pub fn relex(source: &str, tokens: &[Token], edit: &EditScope) -> Vec<Token> {
+    let offset = edit.offset as usize;
+    let old_end = offset + edit.from_len as usize;
+    let new_end = offset + edit.to_len as usize;
+    let delta = new_end as isize - old_end as isize;
+
+    // The last token before the edit may be extended by it (e.g. `ab` + `c`)
+    let prefix = tokens.iter()
+        .take_while(|token| token.offset_start() + token.token_len() < offset)
+        .count()
+        .saturating_sub(1)
+    ;
+    let start = tokens.get(prefix).map_or(0, |token| token.offset_start());
+
+    let mut old = tokens[prefix..].iter()
+        .filter(|token| token.offset_start() >= old_end)
+        .peekable()
+    ;
+    let shifted_start = |token: &Token| token.offset_start().saturating_add_signed(delta);
+
+    let mut result = tokens[..prefix].to_vec();
+    let mut scanner = Scanner::create(source.to_string(), start);
+
+    while let Some(token) = scanner.shift() {
+        let token_start = token.offset_start();
+        while old.next_if(|next| shifted_start(next) < token_start).is_some() {}
+
+        // Scanning from the same text gives the same tokens
+        if (token_start >= new_end) && old.peek().is_some_and(|next| shifted_start(next) == token_start) {
+            result.extend(old.map(|token| shift_token(token, delta)));
+            return result;
+        }
+        result.push(token);
+    }
+
+    result
+}
+
+fn shift_token(token: &Token, delta: isize) -> Token {
+    let shift = |item: &TokenItem| TokenItem { offset: item.offset.saturating_add_signed(delta), ..item.clone() };
+
+    Token {
+        leading: token.leading.as_ref().map(|items| items.iter().map(shift).collect()),
+        main: shift(&token.main),
+        trailing: token.trailing.as_ref().map(|items| items.iter().map(shift).collect()),
+    }
+}
@@ -12,21 +12,20 @@ export function setupCounter(_element: HTMLButtonElement) {
   dump_tree(tree)
 }
 
+// Fetch the whole tree in one call instead of a round-trip per node
 function dump_tree(tree: syntax.Tree) {
-  dump_tree_internal(tree.root(), 0);
-}
+  const nodes = tree.flatten();
+  const depths: number[] = [];
 
-function dump_tree_internal(node: syntax.Node, indent: number) {
-  let metadata = node.metadata();
-  let rangeStr = `${node.offsetStart()} - ${node.offsetEnd()}`;
-  let indentStr = padLeft(' ', indent * 2);
-  let value = node.value() ? `"${node.value()?.replace(/\n/g, "\\n")}"` : "";
-  let nodeType = node.metadata().nodeType;
+  for (const node of nodes) {
+    const indent = node.parent === undefined ? 0 : depths[node.parent] + 1;
+    depths.push(indent);
 
-  console.log(`${padLeft(rangeStr, 16)}${padLeft(nodeType, 24)}${indentStr}${metadata.kind.text} (${metadata.kind.id}) ${value}`);
+    let rangeStr = `${node.offsetStart} - ${node.offsetEnd}`;
+    let indentStr = padLeft(' ', indent * 2);
+    let value = node.value ? `"${node.value.replace(/\n/g, "\\n")}"` : "";
 
-  for (const child of node.children()) {
-      dump_tree_internal(child, indent + 1);
+    console.log(`${padLeft(rangeStr, 16)}${padLeft(node.nodeType, 24)}${indentStr}${node.kind.text} (${node.kind.id}) ${value}`);
   }
 }
 
@@ -0,0 +1,37 @@
+import {parsers, syntax} from 'pkg/parser/parser'
+
+export function setupHighlight(input: HTMLTextAreaElement, output: HTMLPreElement) {
+  const parser = new parsers.Parser()
+
+  const render = () => {
+    const tree = parser.parse(input.value)
+    output.replaceChildren(...render_highlights(input.value, tree.highlight()))
+  }
+
+  input.addEventListener('input', render)
+  render()
+}
+
+// Offsets of highlights are UTF-8 based
+function render_highlights(source: string, highlights: syntax.Highlight[]): Node[] {
+  const bytes = new TextEncoder().encode(source)
+  const decoder = new TextDecoder()
+  const nodes: Node[] = []
+  let offset = 0
+
+  for (const highlight of highlights) {
+    if (highlight.offsetStart < offset) continue
+
+    nodes.push(document.createTextNode(decoder.decode(bytes.slice(offset, highlight.offsetStart))))
+
+    const span = document.createElement('span')
+    span.className = `hl-${highlight.tag}`
+    span.textContent = decoder.decode(bytes.slice(highlight.offsetStart, highlight.offsetEnd))
+    nodes.push(span)
+
+    offset = highlight.offsetEnd
+  }
+  nodes.push(document.createTextNode(decoder.decode(bytes.slice(offset))))
+
+  return nodes
+}
@@ -2,6 +2,7 @@ import './style.css'
 import typescriptLogo from './typescript.svg'
 import viteLogo from '/vite.svg'
 import { setupCounter } from './counter.ts'
+import { setupHighlight } from './highlight.ts'
 
 document.querySelector<HTMLDivElement>('#app')!.innerHTML = `
   <div>
@@ -15,6 +16,13 @@ document.querySelector<HTMLDivElement>('#app')!.innerHTML = `
     <div class="card">
       <button id="counter" type="button"></button>
     </div>
+    <div class="card editor">
+      <textarea id="source" spellcheck="false">SELECT f.id, count(*) AS n -- per user
+FROM foo AS f
+WHERE f.name LIKE 'a%' AND f.flags = x'01' AND f.id > ?1
+GROUP BY f.id;</textarea>
+      <pre id="highlighted"></pre>
+    </div>
     <p class="read-the-docs">
       Click on the Vite and TypeScript logos to learn more
     </p>
@@ -22,3 +30,4 @@ document.querySelector<HTMLDivElement>('#app')!.innerHTML = `
 `
 
 setupCounter(document.querySelector<HTMLButtonElement>('#counter')!)
+setupHighlight(document.querySelector<HTMLTextAreaElement>('#source')!, document.querySelector<HTMLPreElement>('#highlighted')!)
@@ -67,6 +67,7 @@ pub static LEXME_SCAN_RULE: phf::Map<char, &'static [ScanPattern]> = phf_map!{
     ScanPattern { id : 198u32 , pattern : "ALL" , len : 3usize } ,
     ScanPattern { id : 280u32 , pattern : "ALTER" , len : 5usize } ,
     ScanPattern { id : 282u32 , pattern : "ADD" , len : 3usize } ,
+    ScanPattern { id : 171u32 , pattern : "AUTOINCREMENT" , len : 13usize } ,
   ],
   'b' => &[
     ScanPattern { id : 12u32 , pattern : "BEGIN" , len : 5usize } ,
@@ -270,9 +271,10 @@ pub static REGEX_SCAN_RULE: &[ScanPattern] = &[
   ScanPattern { id : 143u32 , pattern : "\".*?\"" , len : 5usize } ,
   ScanPattern { id : 143u32 , pattern : "[a-zA-Z_][0-9a-zA-Z_]*" , len : 22usize } ,
   ScanPattern { id : 249u32 , pattern : "(\\d+(_\\d+)*)" , len : 12usize } ,
-  ScanPattern { id : 324u32 , pattern : "(x|X)(\\d+(_\\d+)*)" , len : 17usize } ,
+  ScanPattern { id : 324u32 , pattern : "0(x|X)[0-9a-fA-F]+(_[0-9a-fA-F]+)*" , len : 34usize } ,
   ScanPattern { id : 325u32 , pattern : "\\s+" , len : 3usize } ,
   ScanPattern { id : 145u32 , pattern : "'.*?'" , len : 5usize } ,
+  ScanPattern { id : 250u32 , pattern : "\\?\\d+|[:@$][0-9a-zA-Z_]+" , len : 24usize } ,
 ];
 pub static SUPPORT_LEADING: &[usize] = &[
   1, // (?s)/\*.*?\*/
@@ -290,8 +292,9 @@ pub static SUPPORT_MAIN: &[usize] = &[
   6, // ".*?"
   7, // [a-zA-Z_][0-9a-zA-Z_]*
   8, // (\d+(_\d+)*)
-  9, // (x|X)(\d+(_\d+)*)
+  9, // 0(x|X)[0-9a-fA-F]+(_[0-9a-fA-F]+)*
   11, // '.*?'
+  12, // \?\d+|[:@$][0-9a-zA-Z_]+
 ];
 pub static ALTERNATIVE_SYMBOL_TABLE: phf::Map<u32, &[u32]> = phf_map!{
   134u32 => &[
@@ -67,7 +67,7 @@ impl<'a> Scanner<'a> {
                         tag: TokenTag::Identifier,
                     },
                     ExtraScanner { 
-                        re: Regex::new(r"(x|X)(\d+(_\d+)*)")?, 
+                        re: Regex::new(r"0(x|X)[0-9a-fA-F]+(_[0-9a-fA-F]+)*")?, 
                         tag: TokenTag::HexLiteral,
                     },
                     ExtraScanner { 
@@ -60,7 +60,8 @@ impl cstree::Syntax for SyntaxKind {
     }
 
     fn static_text(self) -> Option<&'static str> {
-        if self.is_keyword {
+        // Keywords also scanned by regex (e.g. FLOAT) keep the source text
+        if self.is_keyword && ! scan_rules::REGEX_SCAN_RULE.iter().any(|rule| rule.id == self.id) {
             return Some(self.text);
         }
         None
@@ -172,10 +173,43 @@ pub fn scan_by_lexme_rule(source: &str) -> Option<crate::ScanPattern> {
         return None;
     };
 
-    match patterns.iter().filter(|p| source.starts_with(p.pattern)).next() {
-        Some(pattern) => Some(pattern.clone()),
-        None => None,
-    }
+    // Longest match first (e.g. `ORDER` rather than `OR`)
+    patterns.iter()
+    .filter(|p| source.starts_with(p.pattern))
+    .max_by_key(|p| p.len)
+    .cloned()
+}
+
+// Token kind spelled exactly as `text` (e.g. SELECT for `SELECT`, but nothing for `SELECTED`)
+pub fn lexme_kind(text: &str) -> Option<SyntaxKind> {
+    let prefix = text.chars().next()?;
+
+    scan_rules::LEXME_SCAN_RULE.get(&prefix.to_ascii_lowercase())?
+    .iter()
+    .find(|pattern| pattern.pattern == text)
+    .map(|pattern| SyntaxKind::from_raw(cstree::RawSyntaxKind(pattern.id)))
+}
+
+// Fixed spelling of the token kind (e.g. `)` for RP)
+pub fn lexme_of(kind: SyntaxKind) -> Option<&'static str> {
+    scan_rules::LEXME_SCAN_RULE.values()
+    .flat_map(|patterns| patterns.iter())
+    .filter(|pattern| pattern.id == kind.id)
+    .map(|pattern| pattern.pattern)
+    .min_by_key(|pattern| (pattern.len(), *pattern))
+}
+
+// All spellings of the token kind (e.g. `LEFT`, `RIGHT`, ... for JOIN_KW)
+pub fn lexmes_of(kind: SyntaxKind) -> Vec<&'static str> {
+    let mut lexmes = scan_rules::LEXME_SCAN_RULE.values()
+    .flat_map(|patterns| patterns.iter())
+    .filter(|pattern| pattern.id == kind.id)
+    .map(|pattern| pattern.pattern)
+    .collect::<Vec<_>>();
+
+    lexmes.sort();
+    lexmes.dedup();
+    lexmes
 }
 
 pub fn alternative_symbols(id: u32) -> Option<Vec<u32>> {